@@ -4,23 +4,17 @@ use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use tokio_tui::{
     CommandSet, CommandSetBuilder, ConsoleCommand, ConsoleWidget, Tui, TuiApp, TuiWidget,
 };
-use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
 
 // Main application struct
 struct ConsoleDemo {
     console_widget: ConsoleWidget,
-    run_token: CancellationToken,
     append_during_render: bool,
     entry_counter: usize,
 }
 
 impl ConsoleDemo {
-    fn new(
-        run_token: CancellationToken,
-        tracer: tokio_tracer::Tracer,
-        append_during_render: bool,
-    ) -> Result<Self> {
+    fn new(tracer: tokio_tracer::Tracer, append_during_render: bool) -> Result<Self> {
         // Create command set
         let command_set = Self::create_command_set();
 
@@ -30,7 +24,6 @@ impl ConsoleDemo {
         // Create app
         let mut app = Self {
             console_widget,
-            run_token,
             append_during_render,
             entry_counter: 0,
         };
@@ -152,8 +145,9 @@ impl TuiApp for ConsoleDemo {
         self.console_widget.draw(frame.area(), frame.buffer_mut());
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
+    // Show the real terminal cursor in the input line, for IMEs
+    fn cursor_position(&self) -> Option<tokio_tui::Position> {
+        self.console_widget.cursor_position()
     }
 
     fn handle_mouse_events(&mut self, mouse_events: Vec<crossterm::event::MouseEvent>) {
@@ -162,6 +156,10 @@ impl TuiApp for ConsoleDemo {
         }
     }
 
+    fn handle_paste(&mut self, text: String) {
+        self.console_widget.paste_event(&text);
+    }
+
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
             // Skip key release events
@@ -195,11 +193,6 @@ impl TuiApp for ConsoleDemo {
                     self.add_styled_entry();
                 }
 
-                // Quit application on Ctrl+Q
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
-                }
-
                 // Handle key in console widget
                 _ => {
                     self.console_widget.key_event(key);
@@ -219,9 +212,6 @@ async fn main() -> Result<()> {
         .iter()
         .any(|arg| arg == "--append-render" || arg == "-ar");
 
-    // Set up cancellation token for graceful shutdown
-    let run_token = CancellationToken::new();
-
     // Initialize tracer
     let tracer = tokio_tracer::Tracer::init_default()?;
 
@@ -238,10 +228,12 @@ async fn main() -> Result<()> {
         }
     }
     // Create and run the application
-    let app = ConsoleDemo::new(run_token.clone(), tracer, append_during_render)?;
+    let app = ConsoleDemo::new(tracer, append_during_render)?;
 
-    // Run the application
-    Tui::new()?.run(app)?;
+    // Run the application, quitting on Ctrl+Q
+    Tui::new()?
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
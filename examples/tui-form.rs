@@ -1,16 +1,17 @@
 // tokio-tui/examples/tui-form.rs
 use anyhow::Result;
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent},
     layout::Rect,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
 use tokio_tui::{
-    FormData, FormWidget, TracerWidget, Tui, TuiApp, TuiEdit, TuiForm, TuiList, TuiWidget, layout,
-    vertical,
+    FocusManager, FormData, FormWidget, QuitConcerns, QuitGuard, TracerWidget, Tui, TuiApp,
+    TuiEdit, TuiForm, TuiList, TuiWidget, layout, vertical,
 };
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, TuiEdit)]
@@ -33,7 +34,17 @@ pub struct AddressForm {
 #[derive(Debug, Clone, Default, Serialize, TuiEdit)]
 pub struct UserProfileForm {
     pub name: String,
+    #[field(
+        help = "The name you sign in with. Letters, digits and underscores only; it can't be changed once the account is created."
+    )]
     pub username: String,
+    #[field(section = "Preferences")]
+    pub newsletter: bool,
+    #[field(slider(min = 0, max = 100, step = 5))]
+    pub volume: u8,
+    #[field(required = false)]
+    pub shortcuts: BTreeMap<String, String>,
+    #[field(section = "Contact")]
     pub emails: Vec<String>,
     pub address: TuiForm<AddressForm>,
     pub other_addresses: TuiList<AddressForm>,
@@ -57,13 +68,16 @@ pub struct ContactMetadata {
 struct NestedFormDemoApp {
     form_widget: FormWidget,
     tracer_widget: TracerWidget,
-    active_widget: ActiveWidget,
-    run_token: CancellationToken,
+    focus: FocusManager<ActiveWidget>,
+    quit_guard: QuitGuard,
 }
 
+#[derive(Debug, Clone, PartialEq)]
 enum ActiveWidget {
     Form,
     Tracer,
+    // The quit confirmation, while it's open
+    QuitPrompt,
 }
 
 impl NestedFormDemoApp {
@@ -72,6 +86,12 @@ impl NestedFormDemoApp {
         let user_profile = UserProfileForm {
             name: "John Doe".to_string(),
             username: "johndoe".to_string(),
+            newsletter: true,
+            volume: 80,
+            shortcuts: BTreeMap::from([
+                ("Ctrl+S".to_string(), "Save".to_string()),
+                ("Ctrl+O".to_string(), "Open".to_string()),
+            ]),
             emails: vec![
                 "johndoe@example.com".to_string(),
                 "jdoe@threeletteragency.gov".to_string(),
@@ -102,6 +122,12 @@ impl NestedFormDemoApp {
         let run_token2 = run_token.clone();
         let mut form_widget = FormWidget::new("User Profile Form")
             .with_data(&user_profile)
+            .with_discard_confirmation()
+            .on_change(|key, field| info!("{key} is now {}", field.get_value_as_string()))
+            .with_action("Check", |form| match form.validate() {
+                Ok(()) => info!("Form checks out"),
+                Err(errors) => info!("Form has {} invalid fields", errors.len()),
+            })
             .with_submit(move |_| {
                 info!("Form submit");
                 run_token2.cancel();
@@ -116,23 +142,33 @@ impl NestedFormDemoApp {
         Ok(NestedFormDemoApp {
             form_widget,
             tracer_widget,
-            active_widget: ActiveWidget::Form,
-            run_token,
+            focus: FocusManager::new().with_targets([ActiveWidget::Form, ActiveWidget::Tracer]),
+            quit_guard: QuitGuard::new(run_token),
         })
     }
 
     fn focus_widget(&mut self, widget: ActiveWidget) {
-        // Unfocus all widgets
-        self.form_widget.unfocus();
-        self.tracer_widget.unfocus();
-
-        // Focus the selected widget
-        match widget {
-            ActiveWidget::Form => self.form_widget.focus(),
-            ActiveWidget::Tracer => self.tracer_widget.focus(),
+        self.focus.focus(&widget);
+        self.apply_focus();
+    }
+
+    // Focus goes back to where it was once the quit prompt closes
+    fn release_quit_prompt(&mut self) {
+        if self.focus.is_trapped() && !self.quit_guard.is_open() {
+            self.focus.pop_scope();
+            self.apply_focus();
         }
+    }
 
-        self.active_widget = widget;
+    // Focus the widget the focus manager has focused, and only that one
+    fn apply_focus(&mut self) {
+        self.focus.apply([
+            (
+                ActiveWidget::Form,
+                &mut self.form_widget as &mut dyn TuiWidget,
+            ),
+            (ActiveWidget::Tracer, &mut self.tracer_widget),
+        ]);
     }
 
     fn widget_refs(&mut self, area: Option<Rect>) -> [(&mut dyn TuiWidget, Rect); 2] {
@@ -168,6 +204,9 @@ impl NestedFormDemoApp {
 
 impl TuiApp for NestedFormDemoApp {
     fn should_draw(&mut self) -> bool {
+        if self.quit_guard.need_draw() {
+            return true;
+        }
         // Check if any widget needs drawing
         for (widget, _) in self.widget_refs(None) {
             if widget.need_draw() {
@@ -178,6 +217,7 @@ impl TuiApp for NestedFormDemoApp {
     }
 
     fn before_frame(&mut self, _terminal: &tokio_tui::TerminalBackend) {
+        self.release_quit_prompt();
         // Preprocess all widgets
         for (widget, _) in self.widget_refs(None) {
             widget.preprocess();
@@ -194,55 +234,89 @@ impl TuiApp for NestedFormDemoApp {
                 widget.draw(widget_area, buf);
             }
         }
+
+        // Drawn last, over everything else
+        self.quit_guard.draw(area, buf);
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
+    // Ctrl+Q, SIGINT and SIGTERM all end up here
+    fn confirm_quit(&mut self) -> bool {
+        let concerns =
+            QuitConcerns::new().collect([&self.form_widget as &dyn TuiWidget, &self.tracer_widget]);
+        // Asking to quit again while asked means it
+        if self.quit_guard.is_open() && !concerns.is_vetoed() {
+            return true;
+        }
+        let quit = self.quit_guard.request(concerns);
+        // Keys stay with the prompt until it closes
+        if self.quit_guard.is_open() && !self.focus.is_trapped() {
+            self.focus.push_scope([ActiveWidget::QuitPrompt]);
+            self.apply_focus();
+        }
+        quit
     }
 
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
+            if self.focus.is_focused(&ActiveWidget::QuitPrompt) {
+                self.quit_guard.key_event(key);
+                self.release_quit_prompt();
+                continue;
+            }
             match key.code {
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
-                }
                 KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.focus_widget(ActiveWidget::Form);
                 }
                 KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.focus_widget(ActiveWidget::Tracer);
                 }
-                KeyCode::Tab => {
+                KeyCode::Tab if self.focus.handle_key(&key) => {
                     // Toggle focus between widgets
-                    match self.active_widget {
-                        ActiveWidget::Form => self.focus_widget(ActiveWidget::Tracer),
-                        ActiveWidget::Tracer => self.focus_widget(ActiveWidget::Form),
-                    }
+                    self.apply_focus();
                 }
                 _ => {
                     // Pass key to the focused widget
-                    match self.active_widget {
-                        ActiveWidget::Form => self.form_widget.key_event(key),
-                        ActiveWidget::Tracer => self.tracer_widget.key_event(key),
+                    match self.focus.focused() {
+                        Some(ActiveWidget::Form) => self.form_widget.key_event(key),
+                        Some(ActiveWidget::Tracer) => self.tracer_widget.key_event(key),
+                        _ => false,
                     };
                 }
             }
         }
     }
+
+    fn handle_mouse_events(&mut self, mouse_events: Vec<MouseEvent>) {
+        if self.focus.is_focused(&ActiveWidget::Form) {
+            for event in mouse_events {
+                self.form_widget.mouse_event(event);
+            }
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) {
+        match self.focus.focused() {
+            Some(ActiveWidget::Form) => self.form_widget.paste_event(&text),
+            Some(ActiveWidget::Tracer) => self.tracer_widget.paste_event(&text),
+            _ => false,
+        };
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create cancellation token for graceful shutdown
     let run_token = CancellationToken::new();
-    let run_token_clone = run_token.clone();
 
     let tracer = tokio_tracer::Tracer::init_default()?;
     // Create app instance
-    let app = NestedFormDemoApp::new(run_token_clone, tracer)?;
+    let app = NestedFormDemoApp::new(run_token.clone(), tracer)?;
 
     // Run the TUI application
-    let app = Tui::new()?.run(app)?;
+    let app = Tui::new()?
+        .with_run_token(run_token)
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     // Get form data after submission
     let form_data = app.get_form_data();
@@ -15,7 +15,6 @@ const TITLE: &str = "Scrollbox Demo";
 struct ScrollingDemoApp {
     scrolling: ScrollbackWidget,
     refresh: bool,
-    quit: bool,
     last_styled_update: Instant,
     init_line_count: usize,
     append_during_render: bool, // Flag to control appending during render
@@ -27,7 +26,6 @@ impl ScrollingDemoApp {
         Self {
             scrolling: ScrollbackWidget::new(TITLE, 99999).wrap_indent(27),
             refresh: false,
-            quit: false,
             last_styled_update: Instant::now(),
             init_line_count: 10,
             append_during_render: false,
@@ -179,18 +177,11 @@ impl TuiApp for ScrollingDemoApp {
                     KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                         self.toggle_append_mode(!self.append_during_render);
                     }
-                    KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        self.quit = true;
-                    }
                     _ => {}
                 }
             }
         }
     }
-
-    fn should_quit(&self) -> bool {
-        self.quit
-    }
 }
 
 #[tokio::main]
@@ -218,7 +209,9 @@ async fn main() -> Result<()> {
     }
     app.initialize_styled_demo();
 
-    Tui::new()?.run(app)?;
+    Tui::new()?
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
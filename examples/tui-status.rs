@@ -9,10 +9,10 @@ use ratatui::{
 };
 use std::time::{Duration, Instant};
 use tokio_tui::{
-    ETAStatus, FileSizeStatus, IconMode, IconStatus, ProgressStatus, StatusLine, StatusWidget,
-    TextAlignment, TextStatus, TimerStatus, Tui, TuiApp, TuiWidget, status_line,
+    ETAStatus, FileSizeStatus, GlyphSet, IconMode, IconStatus, Metric, MetricsPanel,
+    ProgressStatus, StatusLayout, StatusLine, StatusWidget, TextAlignment, TextStatus, Threshold,
+    TimerStatus, Tui, TuiApp, TuiWidget, status_line,
 };
-use tokio_util::sync::CancellationToken;
 
 // Define status lines using the macro
 status_line! {
@@ -58,7 +58,7 @@ status_line! {
 
 struct StatusDemoApp {
     status_widget: StatusWidget,
-    run_token: CancellationToken,
+    metrics_panel: MetricsPanel,
 
     // Status line references
     download_line: DownloadLine,
@@ -78,7 +78,7 @@ struct StatusDemoApp {
 }
 
 impl StatusDemoApp {
-    fn new(run_token: CancellationToken) -> Result<Self> {
+    fn new() -> Result<Self> {
         let mut status_widget = StatusWidget::new();
 
         // Create status lines using the macro-generated structs
@@ -127,9 +127,23 @@ impl StatusDemoApp {
             upload_line.show(),
         ]);
 
+        let metrics_panel = MetricsPanel::new("Metrics")
+            .with_metric(
+                Metric::new("cpu")
+                    .with_label("CPU")
+                    .with_unit("%")
+                    .with_threshold(Threshold::above(70.0, 90.0)),
+            )
+            .with_metric(
+                Metric::new("download")
+                    .with_label("Download")
+                    .with_unit("MB/s")
+                    .with_threshold(Threshold::below(0.5, 0.1)),
+            );
+
         Ok(Self {
             status_widget,
-            run_token,
+            metrics_panel,
             download_line,
             timer_line,
             system_line,
@@ -217,6 +231,18 @@ impl StatusDemoApp {
             Style::default().fg(Color::Green),
         ));
 
+        // Feed the metrics panel; unknown names like "queue" get a row on first use
+        let wave = (self.system_counter as f64 / 3.0).sin();
+        self.metrics_panel.record("cpu", 60.0 + 35.0 * wave);
+        let download_speed = if self.download_current < self.download_total {
+            self.download_speed as f64 / (1024.0 * 1024.0) * 2.0
+        } else {
+            0.0
+        };
+        self.metrics_panel.record("download", download_speed);
+        self.metrics_panel
+            .record("queue", (self.system_counter % 17) as f64);
+
         // Process all updates
         self.status_widget.process_updates(updates);
         self.last_update = Instant::now();
@@ -272,15 +298,14 @@ impl StatusDemoApp {
         self.status_widget.process_cell_update(update);
     }
 
-    fn widget_refs(&mut self, area: Option<Rect>) -> [(&mut dyn TuiWidget, Rect); 1] {
+    fn widget_refs(&mut self, areas: Option<[Rect; 2]>) -> [(&mut dyn TuiWidget, Rect); 2] {
         let zero_rect = Rect::new(0, 0, 0, 0);
-        let mut status_area = zero_rect;
+        let [status_area, metrics_area] = areas.unwrap_or([zero_rect; 2]);
 
-        if let Some(area) = area {
-            status_area = area;
-        }
-
-        [(&mut self.status_widget, status_area)]
+        [
+            (&mut self.status_widget, status_area),
+            (&mut self.metrics_panel, metrics_area),
+        ]
     }
 }
 
@@ -306,21 +331,34 @@ impl TuiApp for StatusDemoApp {
 
     fn render(&mut self, frame: &mut tokio_tui::TerminalFrame) {
         let area = frame.area();
+        let status_height = self.status_widget.desired_height(area.width) + 2;
 
-        // Render status widget at the bottom of the screen
+        // Render status widget at the top of the screen
         let status_area = Rect {
             x: area.x,
-            y: area.y, // Reserve 7 lines for status
+            y: area.y,
+            width: area.width,
+            height: status_height,
+        };
+
+        // Metrics panel right below it
+        let metrics_height = self
+            .metrics_panel
+            .desired_height()
+            .min(area.height.saturating_sub(status_height));
+        let metrics_area = Rect {
+            x: area.x,
+            y: area.y + status_height,
             width: area.width,
-            height: 7,
+            height: metrics_height,
         };
 
         // Render help text in the remaining area
         let help_area = Rect {
             x: area.x,
-            y: area.y + 7,
+            y: area.y + status_height + metrics_height,
             width: area.width,
-            height: area.height.saturating_sub(7),
+            height: area.height.saturating_sub(status_height + metrics_height),
         };
 
         // Render help text
@@ -344,10 +382,13 @@ impl TuiApp for StatusDemoApp {
             Line::from("• NetworkLine { network_icon, network_status, bandwidth }"),
             Line::from("• UploadLine { upload_icon, upload_label, upload_progress }"),
             Line::from(""),
+            Line::from("MetricsPanel: record(metric, value) adds a sparkline row per metric"),
+            Line::from(""),
             Line::from("Controls:"),
             Line::from("• Ctrl+R - Reset download simulation"),
             Line::from("• Ctrl+I - Cycle through different icon modes"),
             Line::from("• Ctrl+T - Reset timer"),
+            Line::from("• Ctrl+L - Toggle row / tile layout"),
             Line::from("• Ctrl+Q - Quit"),
             Line::from(""),
             Line::from("Watch the status bars below update in real-time!"),
@@ -366,25 +407,18 @@ impl TuiApp for StatusDemoApp {
             help_area,
         );
 
-        // Render status widget
+        // Render status widget and metrics panel
         let buf = frame.buffer_mut();
-        for (widget, widget_area) in self.widget_refs(Some(status_area)) {
+        for (widget, widget_area) in self.widget_refs(Some([status_area, metrics_area])) {
             if widget_area.width > 0 && widget_area.height > 0 {
                 widget.draw(widget_area, buf);
             }
         }
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
-    }
-
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
             match key.code {
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
-                }
                 KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.reset_download();
                 }
@@ -394,6 +428,13 @@ impl TuiApp for StatusDemoApp {
                 KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.reset_timer();
                 }
+                KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    let layout = match self.status_widget.layout() {
+                        StatusLayout::Rows => StatusLayout::columns(3, 40),
+                        StatusLayout::Columns { .. } => StatusLayout::Rows,
+                    };
+                    self.status_widget.set_layout(layout);
+                }
                 _ => {
                     // Pass other keys to status widget if it needs them
                     for (widget, _) in self.widget_refs(None) {
@@ -407,12 +448,13 @@ impl TuiApp for StatusDemoApp {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Create cancellation token for graceful shutdown
-    let run_token = CancellationToken::new();
-
     // Create and run the application
-    let app = StatusDemoApp::new(run_token)?;
-    Tui::new()?.run(app)?;
+    let app = StatusDemoApp::new()?;
+    // TOKIO_TUI_GLYPHS=ascii shows the plain fallbacks
+    Tui::new()?
+        .with_glyphs(GlyphSet::from_env())
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
@@ -6,7 +6,6 @@ use ratatui::{
     style::{Color, Style},
 };
 use std::time::{Duration, Instant};
-use tokio_util::sync::CancellationToken;
 
 use tokio_tui::{
     InputWidget, TabbedScrollbox, Tui, TuiApp, TuiWidget, horizontal, layout, vertical,
@@ -41,7 +40,6 @@ struct TabbedDemo {
     last_update: Instant,
     counter: u32,
     dynamic_tab_counter: u32,
-    run_token: CancellationToken,
     active_widget: ActiveWidget,
 }
 
@@ -52,7 +50,7 @@ enum ActiveWidget {
 }
 
 impl TabbedDemo {
-    fn new(run_token: CancellationToken) -> Result<Self> {
+    fn new() -> Result<Self> {
         // Create a tabbed scrollbox with enum-based tabs
         let mut enum_tabs =
             TabbedScrollbox::<LogTab>::new("Enum Tabs").style(Style::default().fg(Color::Green));
@@ -102,7 +100,6 @@ impl TabbedDemo {
             last_update: Instant::now(),
             counter: 0,
             dynamic_tab_counter: 0,
-            run_token,
             active_widget: ActiveWidget::EnumTabs,
         })
     }
@@ -220,16 +217,9 @@ impl TuiApp for TabbedDemo {
         }
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
-    }
-
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
             match key.code {
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
-                }
                 KeyCode::Char('1') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     // Select first tab in both widgets
                     self.enum_tabs.select_tab(&LogTab::System);
@@ -309,13 +299,11 @@ impl TuiApp for TabbedDemo {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Create cancellation token for graceful shutdown
-    let run_token = CancellationToken::new();
-    let run_token_clone = run_token.clone();
-
-    let app = TabbedDemo::new(run_token_clone.clone())?;
+    let app = TabbedDemo::new()?;
 
-    Tui::new()?.run(app)?;
+    Tui::new()?
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
@@ -0,0 +1,82 @@
+// tokio-tui/examples/tui-tags.rs
+use anyhow::Result;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent},
+    layout::{Constraint, Layout, Position},
+};
+use tokio_tui::{ScrollbackWidget, TagsInputWidget, Tui, TuiApp, TuiWidget};
+
+// Tags typed at the top, with each change logged below
+struct TagsDemoApp {
+    tags: TagsInputWidget,
+    log: ScrollbackWidget,
+}
+
+impl TagsDemoApp {
+    fn new() -> Self {
+        let mut tags = TagsInputWidget::new()
+            .with_title("Tags (Enter, comma or space adds; Backspace, Delete remove)")
+            .with_hint("type a tag")
+            .with_separators([',', ' '])
+            .with_max_tags(8)
+            .with_tags(["rust", "tokio"]);
+        tags.focus();
+        Self {
+            tags,
+            log: ScrollbackWidget::new("Changes", 1000),
+        }
+    }
+
+    fn log_changes(&mut self) {
+        if self.tags.take_changed() {
+            let line = format!("[{}]", self.tags.tags().join(", "));
+            self.log.add_styled_line(line.into());
+        }
+    }
+}
+
+impl TuiApp for TagsDemoApp {
+    fn should_draw(&mut self) -> bool {
+        self.tags.need_draw() || self.log.need_draw()
+    }
+
+    fn render(&mut self, frame: &mut tokio_tui::TerminalFrame) {
+        let buf = frame.buffer_mut();
+        let [tags_area, log_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(buf.area);
+        self.tags.draw(tags_area, buf);
+        self.log.draw(log_area, buf);
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        self.tags.cursor_position()
+    }
+
+    fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
+        for key in keys {
+            self.tags.key_event(key);
+        }
+        self.log_changes();
+    }
+
+    fn handle_mouse_events(&mut self, mouse_events: Vec<MouseEvent>) {
+        for event in mouse_events {
+            self.log.mouse_event(event);
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) {
+        self.tags.paste_event(&text);
+        self.log_changes();
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let app = Tui::new()?
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(TagsDemoApp::new(), std::future::pending())?;
+
+    println!("\nTags: {}", app.tags.tags().join(", "));
+    Ok(())
+}
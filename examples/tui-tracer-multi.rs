@@ -8,17 +8,17 @@ use ratatui::{
 };
 use std::time::Duration;
 use tokio_tracer::{TraceData, TraceLevel, Tracer};
-use tokio_tui::{TraceEventSender, TracerWidget, Tui, TuiApp, TuiWidget};
+use tokio_tui::{TraceSource, TracerWidget, Tui, TuiApp, TuiWidget};
 use tokio_util::sync::CancellationToken;
 use tracing::{Level, debug, error, info, trace, warn};
 
 struct MultiSourceTracerDemo {
     tracer_widget: TracerWidget,
-    run_token: CancellationToken,
+    sources: Vec<TraceSource>,
 }
 
 impl MultiSourceTracerDemo {
-    fn new(run_token: CancellationToken, tracer: Tracer) -> Result<Self> {
+    fn new(run_token: &CancellationToken, tracer: Tracer) -> Result<Self> {
         // Create the tracer widget
         let mut tracer_widget = TracerWidget::new(tracer)?;
 
@@ -40,6 +40,14 @@ impl MultiSourceTracerDemo {
 
         let file_sender = tracer_widget.register_source("filesystem", "F");
 
+        // Keep handles so the sources can be muted and filtered at runtime
+        let sources = vec![
+            database_sender.clone(),
+            network_sender.clone(),
+            auth_sender.clone(),
+            file_sender.clone(),
+        ];
+
         // Spawn tasks to generate logs from each source
         spawn_database_logs(database_sender, run_token.clone());
         spawn_network_logs(network_sender, run_token.clone());
@@ -51,7 +59,7 @@ impl MultiSourceTracerDemo {
 
         Ok(Self {
             tracer_widget,
-            run_token,
+            sources,
         })
     }
 
@@ -99,16 +107,24 @@ impl TuiApp for MultiSourceTracerDemo {
         }
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
-    }
-
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
             match key.code {
-                // Quit application on Ctrl+Q
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
+                // F1-F4 mute/unmute one source
+                KeyCode::F(n @ 1..=4) => {
+                    let source = &self.sources[n as usize - 1];
+                    source.set_muted(!source.is_muted());
+                }
+                // F5 raises the minimum level of every source, then wraps
+                KeyCode::F(5) => {
+                    for source in &self.sources {
+                        source.set_min_level(match source.min_level() {
+                            None => Some(Level::INFO),
+                            Some(Level::INFO) => Some(Level::WARN),
+                            Some(Level::WARN) => Some(Level::ERROR),
+                            _ => None,
+                        });
+                    }
                 }
                 // Pass other key events to the tracer widget
                 _ => {
@@ -140,7 +156,7 @@ fn create_trace_event(id: u64, level: Level, message: String) -> tokio_tracer::T
 }
 
 // Spawn tasks for each type of log source
-fn spawn_database_logs(sender: TraceEventSender, token: CancellationToken) {
+fn spawn_database_logs(sender: TraceSource, token: CancellationToken) {
     tokio::spawn(async move {
         let mut counter = 0;
         let operations = ["SELECT", "INSERT", "UPDATE", "DELETE", "JOIN", "INDEX"];
@@ -166,7 +182,7 @@ fn spawn_database_logs(sender: TraceEventSender, token: CancellationToken) {
             };
 
             let event = create_trace_event(counter as u64, level, message);
-            sender(event, vec!["Main".to_string()]);
+            sender.send(event, vec!["Main".to_string()]);
 
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(800)) => {}
@@ -178,7 +194,7 @@ fn spawn_database_logs(sender: TraceEventSender, token: CancellationToken) {
     });
 }
 
-fn spawn_network_logs(sender: TraceEventSender, token: CancellationToken) {
+fn spawn_network_logs(sender: TraceSource, token: CancellationToken) {
     tokio::spawn(async move {
         let mut counter = 0;
         let endpoints = [
@@ -219,7 +235,7 @@ fn spawn_network_logs(sender: TraceEventSender, token: CancellationToken) {
             }
 
             let event = create_trace_event(counter as u64 + 1000, level, message);
-            sender(event, vec!["Main".to_string()]);
+            sender.send(event, vec!["Main".to_string()]);
 
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(650)) => {}
@@ -231,7 +247,7 @@ fn spawn_network_logs(sender: TraceEventSender, token: CancellationToken) {
     });
 }
 
-fn spawn_auth_logs(sender: TraceEventSender, token: CancellationToken) {
+fn spawn_auth_logs(sender: TraceSource, token: CancellationToken) {
     tokio::spawn(async move {
         let mut counter = 0;
         let actions = [
@@ -263,7 +279,7 @@ fn spawn_auth_logs(sender: TraceEventSender, token: CancellationToken) {
             };
 
             let event = create_trace_event(counter as u64 + 2000, level, message);
-            sender(event, vec!["Main".to_string()]);
+            sender.send(event, vec!["Main".to_string()]);
 
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(1200)) => {}
@@ -275,7 +291,7 @@ fn spawn_auth_logs(sender: TraceEventSender, token: CancellationToken) {
     });
 }
 
-fn spawn_filesystem_logs(sender: TraceEventSender, token: CancellationToken) {
+fn spawn_filesystem_logs(sender: TraceSource, token: CancellationToken) {
     tokio::spawn(async move {
         let mut counter = 0;
         let operations = ["read", "write", "delete", "create", "move", "copy"];
@@ -304,7 +320,7 @@ fn spawn_filesystem_logs(sender: TraceEventSender, token: CancellationToken) {
             };
 
             let event = create_trace_event(counter as u64 + 3000, level, message);
-            sender(event, vec!["Main".to_string()]);
+            sender.send(event, vec!["Main".to_string()]);
 
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(950)) => {}
@@ -356,8 +372,12 @@ async fn main() -> Result<()> {
     let tracer = Tracer::init_default()?;
 
     // Create and run the application
-    let app = MultiSourceTracerDemo::new(run_token, tracer)?;
-    Tui::new()?.run(app)?;
+    let app = MultiSourceTracerDemo::new(&run_token, tracer)?;
+    // Quit on Ctrl+Q; the run token also stops the log tasks
+    Tui::new()?
+        .with_run_token(run_token)
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
@@ -9,19 +9,15 @@ use tokio_util::sync::CancellationToken;
 
 struct TracerTuiDemo {
     tracer_widget: TracerWidget,
-    run_token: CancellationToken,
 }
 
 impl TracerTuiDemo {
-    fn new(run_token: CancellationToken, tracer: tokio_tracer::Tracer) -> Result<Self> {
+    fn new(tracer: tokio_tracer::Tracer) -> Result<Self> {
         // Create the tracer widget
         let mut tracer_widget = TracerWidget::new(tracer)?;
         tracer_widget.focus();
 
-        Ok(Self {
-            tracer_widget,
-            run_token,
-        })
+        Ok(Self { tracer_widget })
     }
 
     fn widget_refs(&mut self, area: Option<Rect>) -> [(&mut dyn TuiWidget, Rect); 1] {
@@ -68,22 +64,10 @@ impl TuiApp for TracerTuiDemo {
         }
     }
 
-    fn should_quit(&self) -> bool {
-        self.run_token.is_cancelled()
-    }
-
     fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
         for key in keys {
-            match key.code {
-                // Quit application on Ctrl+Q
-                KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.run_token.cancel();
-                }
-                // Pass other key events to the tracer widget
-                _ => {
-                    self.tracer_widget.key_event(key);
-                }
-            }
+            // Pass key events to the tracer widget
+            self.tracer_widget.key_event(key);
         }
     }
 }
@@ -129,8 +113,12 @@ async fn main() -> Result<()> {
     let tracer = tokio_tracer::Tracer::init_default()?;
 
     // Create and run the application
-    let app = TracerTuiDemo::new(run_token, tracer)?;
-    Tui::new()?.run(app)?;
+    let app = TracerTuiDemo::new(tracer)?;
+    // Quit on Ctrl+Q; the run token also stops the background task
+    Tui::new()?
+        .with_run_token(run_token)
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(app, std::future::pending())?;
 
     Ok(())
 }
@@ -0,0 +1,103 @@
+// tokio-tui/examples/tui-wizard.rs
+use std::time::Duration;
+
+use anyhow::Result;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use serde::Serialize;
+
+use tokio_tui::{Tui, TuiApp, TuiEdit, TuiWidget, WizardWidget};
+
+#[derive(Debug, Clone, Default, Serialize, TuiEdit)]
+pub struct AccountSetup {
+    #[field(help = "Letters, digits and underscores.")]
+    pub username: String,
+    pub email: String,
+    #[field(section = "Server")]
+    pub host: String,
+    pub port: u16,
+    #[field(help = "How long to wait for the server, e.g. 30s or 1m30s.")]
+    pub timeout: Duration,
+    #[field(required = false)]
+    pub use_tls: bool,
+    #[field(section = "Profile", required = false)]
+    pub display_name: Option<String>,
+    #[field(required = false)]
+    pub newsletter: bool,
+}
+
+struct WizardDemoApp {
+    wizard: WizardWidget,
+    done: Option<bool>,
+}
+
+impl TuiApp for WizardDemoApp {
+    fn should_quit(&self) -> bool {
+        self.done.is_some()
+    }
+
+    fn before_frame(&mut self, _terminal: &tokio_tui::TerminalBackend) {
+        self.wizard.preprocess();
+    }
+
+    fn render(&mut self, frame: &mut tokio_tui::TerminalFrame) {
+        let area = frame.area();
+        self.wizard.draw(area, frame.buffer_mut());
+        // Finishing shows on the draw after an async check passes
+        self.check_done();
+    }
+
+    fn handle_key_events(&mut self, keys: Vec<KeyEvent>) {
+        for key in keys {
+            self.wizard.key_event(key);
+        }
+        self.check_done();
+    }
+
+    fn handle_mouse_events(&mut self, mouse_events: Vec<MouseEvent>) {
+        for event in mouse_events {
+            self.wizard.mouse_event(event);
+        }
+    }
+
+    fn handle_paste(&mut self, text: String) {
+        self.wizard.paste_event(&text);
+    }
+}
+
+impl WizardDemoApp {
+    fn check_done(&mut self) {
+        if self.wizard.reset_submit() {
+            self.done = Some(true);
+        } else if self.wizard.reset_closed() {
+            self.done = Some(false);
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let setup = AccountSetup {
+        port: 443,
+        timeout: Duration::from_secs(30),
+        use_tls: true,
+        ..Default::default()
+    };
+    let mut wizard = WizardWidget::new("Account Setup").with_data(&setup);
+    wizard.focus();
+
+    let mut app = Tui::new()?
+        .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+        .run_until(WizardDemoApp { wizard, done: None }, std::future::pending())?;
+
+    if app.done == Some(true) {
+        let setup: AccountSetup = app.wizard.get_data();
+        println!(
+            "\nSetup finished:\n{}",
+            serde_json::to_string_pretty(&setup)?
+        );
+    } else {
+        println!("\nSetup cancelled");
+    }
+
+    Ok(())
+}
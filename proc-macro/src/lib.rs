@@ -1,8 +1,9 @@
 // tokio-tui/proc-macro/src/lib.rs
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
-    Data, DeriveInput, Field, Fields, FieldsNamed, Ident, LitBool, LitStr, parse_macro_input,
+    Data, DataEnum, DeriveInput, Field, Fields, FieldsNamed, GenericArgument, Ident, Lit, LitBool,
+    LitInt, LitStr, PathArguments, Token, meta::ParseNestedMeta, parse_macro_input,
 };
 
 // Helper function to convert snake_case to Title Case
@@ -28,19 +29,20 @@ fn snake_to_title_case(input: &str) -> String {
         .join(" ")
 }
 
-#[proc_macro_derive(TuiEdit, attributes(field))]
+#[proc_macro_derive(TuiEdit, attributes(field, tui_edit))]
 pub fn derive_tui_value(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    match input.data {
+    match &input.data {
         Data::Struct(data_struct) => {
             // Handle structs - implement FormData and SubFormData
-            let fields = match data_struct.fields {
+            let fields = match &data_struct.fields {
                 Fields::Named(fields) => fields,
                 _ => {
                     return syn::Error::new_spanned(
-                        input.ident,
+                        name,
                         "TuiEdit can only be derived for structs with named fields",
                     )
                     .to_compile_error()
@@ -48,13 +50,22 @@ pub fn derive_tui_value(input: TokenStream) -> TokenStream {
                 }
             };
 
+            let fields = match parse_named_fields(fields) {
+                Ok(fields) => fields,
+                Err(error) => return error.to_compile_error().into(),
+            };
             let field_definitions = generate_field_definitions(&fields);
             let to_fields_impl = generate_to_fields_impl(&fields);
             let from_fields_impl = generate_from_fields_impl(&fields);
+            let form_default_impl = generate_form_default_impl(&fields);
+            let form_meta_impl = match generate_form_meta_impl(&input) {
+                Ok(form_meta_impl) => form_meta_impl,
+                Err(error) => return error.to_compile_error().into(),
+            };
 
             // Generate FormData and SubFormData implementations
             let expanded = quote! {
-                impl ::tokio_tui::FormData for #name {
+                impl #impl_generics ::tokio_tui::FormData for #name #ty_generics #where_clause {
                     fn field_definitions() -> Vec<::tokio_tui::FieldMeta> {
                         vec![
                             #(#field_definitions),*
@@ -72,10 +83,14 @@ pub fn derive_tui_value(input: TokenStream) -> TokenStream {
                             #(#from_fields_impl),*
                         }
                     }
+
+                    #form_default_impl
+
+                    #form_meta_impl
                 }
 
                 // Automatically implement SubFormData for structs
-                impl ::tokio_tui::SubFormData for #name {
+                impl #impl_generics ::tokio_tui::SubFormData for #name #ty_generics #where_clause {
                     fn to_form_widget(&self) -> ::tokio_tui::FormWidget {
                         ::tokio_tui::FormWidget::new_nested().with_data(self)
                     }
@@ -88,60 +103,246 @@ pub fn derive_tui_value(input: TokenStream) -> TokenStream {
 
             TokenStream::from(expanded)
         }
-        Data::Enum(data_enum) => {
-            // Handle enums - implement EnumFormValue
-            let variants: Vec<&Ident> = data_enum
-                .variants
+        Data::Enum(data_enum) => TokenStream::from(generate_enum_impl(&input, data_enum)),
+        _ => syn::Error::new_spanned(name, "TuiEdit can only be derived for structs or enums")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+// EnumFormValue for an enum: a select of its variants, and for variants
+// holding data, a form of the data for the one chosen.
+fn generate_enum_impl(input: &DeriveInput, data_enum: &DataEnum) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut patterns = Vec::new();
+    let mut defaults = Vec::new();
+    let mut variant_strings = Vec::new();
+    let mut payload_arms = Vec::new();
+    let mut with_payload_arms = Vec::new();
+
+    for variant in &data_enum.variants {
+        let ident = &variant.ident;
+        variant_strings.push(ident.to_string());
+        // Named fields by name, and tuple fields as `value` (or `value_1`,
+        // `value_2`, ...) bound to `__0`, `__1`, ...
+        let fields: Vec<(Ident, String, &Field)> = match &variant.fields {
+            Fields::Named(fields) => fields
+                .named
                 .iter()
-                .map(|variant| &variant.ident)
-                .collect();
+                .filter_map(|field| {
+                    let ident = field.ident.clone()?;
+                    Some((ident.clone(), ident.to_string(), field))
+                })
+                .collect(),
+            Fields::Unnamed(fields) => {
+                let single = fields.unnamed.len() == 1;
+                fields
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, field)| {
+                        let name = if single {
+                            "value".to_string()
+                        } else {
+                            format!("value_{}", idx + 1)
+                        };
+                        (format_ident!("__{idx}"), name, field)
+                    })
+                    .collect()
+            }
+            Fields::Unit => Vec::new(),
+        };
+        let bindings: Vec<&Ident> = fields.iter().map(|(binding, ..)| binding).collect();
+        // Binds the fields when matched, and builds the variant from them
+        let build = match &variant.fields {
+            Fields::Named(_) => quote! { Self::#ident { #(#bindings),* } },
+            Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings),*) },
+            Fields::Unit => quote! { Self::#ident },
+        };
+        patterns.push(match &variant.fields {
+            Fields::Named(_) => quote! { Self::#ident { .. } },
+            Fields::Unnamed(_) => quote! { Self::#ident(..) },
+            Fields::Unit => quote! { Self::#ident },
+        });
+        if fields.is_empty() {
+            defaults.push(build);
+            continue;
+        }
+        let mut initial = Vec::new();
+        let mut payload_fields = Vec::new();
+        let mut read_back = Vec::new();
+        let mut unused = Vec::new();
+        let mut sections = Vec::new();
+        for (binding, field_name, field) in &fields {
+            let attrs = match parse_field_attr(field, field_name) {
+                Ok(attrs) => attrs,
+                Err(error) => return error.to_compile_error(),
+            };
+            let default = default_value(attrs.default.as_ref());
+            initial.push(quote! { let #binding = #default; });
+            if attrs.skip {
+                read_back.push(quote! { let #binding = #default; });
+                unused.push(binding);
+                continue;
+            }
+            // The field as the struct path builds it from its `FieldMeta`
+            let (widget, validators) = match widget_exprs(field, &attrs)
+                .and_then(|widget| validator_stmts(&attrs).map(|validators| (widget, validators)))
+            {
+                Ok(built) => built,
+                Err(error) => return error.to_compile_error(),
+            };
+            let FieldAttrs {
+                label,
+                required,
+                help,
+                placeholder,
+                readonly,
+                order,
+                ..
+            } = attrs;
+            let help = help.map(|help| quote! { field = field.with_help_text(#help); });
+            let placeholder =
+                placeholder.map(|hint| quote! { field = field.with_placeholder(#hint); });
+            let readonly = readonly.then(|| quote! { field = field.with_readonly(); });
+            let WidgetExprs {
+                unit,
+                slider,
+                editor,
+            } = widget;
+            let editor = editor.map(|editor| quote! { field = field.with_editor(#editor); });
+            let unit = unit.map(|unit| quote! { field = field.with_unit(#unit); });
+            let slider = slider.map(|range| quote! { field = field.with_slider(#range); });
+            if let Some(title) = attrs.section {
+                sections.push(quote! { .with_section(#title, #field_name) });
+            }
+            payload_fields.push((
+                order,
+                quote! {
+                    {
+                        let mut field = <_ as ::tokio_tui::FormValue>::to_field_widget(
+                            #binding,
+                            #label,
+                            #required
+                        );
+                        #editor
+                        #help
+                        #placeholder
+                        #readonly
+                        #unit
+                        #slider
+                        #(#validators)*
+                        (#field_name.to_string(), field)
+                    }
+                },
+            ));
+            read_back.push(quote! {
+                let #binding = if let Some(field) = form.get_fields().get(#field_name) {
+                    <_ as ::tokio_tui::FormValue>::from_field_widget(field)
+                } else {
+                    #default
+                };
+            });
+        }
+        defaults.push(quote! {
+            {
+                #(#initial)*
+                #build
+            }
+        });
 
-            let variant_strings: Vec<String> =
-                variants.iter().map(|ident| ident.to_string()).collect();
+        // A variant whose fields are all skipped shows no form
+        if payload_fields.is_empty() {
+            continue;
+        }
+        // By `order`, then as declared
+        payload_fields.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let payload_fields = payload_fields.into_iter().map(|(_, field)| field);
+        payload_arms.push(quote! {
+            #build => {
+                #(let _ = #unused;)*
+                Some(
+                    ::tokio_tui::FormWidget::new_nested()
+                        .with_fields(vec![#(#payload_fields),*])
+                        #(#sections)*
+                )
+            }
+        });
+        with_payload_arms.push(quote! {
+            Self::#ident { .. } => {
+                #(#read_back)*
+                #build
+            }
+        });
+    }
 
-            let expanded = quote! {
-                impl ::tokio_tui::EnumFormValue for #name {
-                    fn all_options() -> Vec<Self> {
-                        vec![
-                            #(Self::#variants),*
-                        ]
-                    }
+    // Enums of unit variants keep the plain select
+    let payload_impl = (!payload_arms.is_empty()).then(|| {
+        quote! {
+            fn payload_form(&self) -> Option<::tokio_tui::FormWidget> {
+                match self {
+                    #(#payload_arms,)*
+                    _ => None,
+                }
+            }
 
-                    fn to_string(&self) -> String {
-                        match self {
-                            #(Self::#variants => #variant_strings.to_string()),*
-                        }
-                    }
+            fn with_payload(self, form: &::tokio_tui::FormWidget) -> Self {
+                match self {
+                    #(#with_payload_arms)*
+                    other => other,
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::tokio_tui::EnumFormValue for #name #ty_generics #where_clause {
+            fn all_options() -> Vec<Self> {
+                vec![
+                    #(#defaults),*
+                ]
+            }
 
-                    fn from_string(s: &str) -> Option<Self> {
-                        match s {
-                            #(#variant_strings => Some(Self::#variants)),*,
-                            _ => None,
-                        }
-                    }
+            fn to_string(&self) -> String {
+                match self {
+                    #(#patterns => #variant_strings.to_string()),*
                 }
-            };
+            }
 
-            TokenStream::from(expanded)
+            fn from_string(s: &str) -> Option<Self> {
+                match s {
+                    #(#variant_strings => Some(#defaults)),*,
+                    _ => None,
+                }
+            }
+
+            #payload_impl
         }
-        _ => syn::Error::new_spanned(
-            input.ident,
-            "TuiEdit can only be derived for structs or enums",
-        )
-        .to_compile_error()
-        .into(),
     }
 }
 
-fn generate_field_definitions(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStream> {
-    fields
-        .named
+fn generate_field_definitions(fields: &[(&Field, FieldAttrs)]) -> Vec<proc_macro2::TokenStream> {
+    let mut definitions: Vec<(f64, proc_macro2::TokenStream)> = fields
         .iter()
-        .filter_map(|field| {
+        .filter_map(|(field, attrs)| {
             let field_name = field.ident.as_ref()?;
             let field_name_str = field_name.to_string();
 
-            let (label, required, help) = parse_field_attr(field, &field_name_str);
+            let FieldAttrs {
+                label,
+                required,
+                help,
+                section,
+                skip,
+                placeholder,
+                order,
+                readonly,
+                ..
+            } = attrs.clone();
+            if skip {
+                return None;
+            }
 
             let help_expr = if let Some(help_text) = help {
                 quote! { Some(#help_text) }
@@ -149,25 +350,79 @@ fn generate_field_definitions(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStr
                 quote! { None }
             };
 
-            Some(quote! {
-                ::tokio_tui::FieldMeta {
-                    id: #field_name_str,
-                    label: #label,
-                    required: #required,
-                    help_text: #help_expr
-                }
-            })
+            let WidgetExprs {
+                unit,
+                slider,
+                editor,
+            } = match widget_exprs(field, attrs) {
+                Ok(widget) => widget,
+                Err(error) => return Some((order, error.to_compile_error())),
+            };
+            let unit_expr = match unit {
+                Some(unit) => quote! { Some(#unit) },
+                None => quote! { None },
+            };
+            let slider_expr = match slider {
+                Some(range) => quote! { Some(#range) },
+                None => quote! { None },
+            };
+            let editor_expr = match editor {
+                Some(editor) => quote! { Some(#editor) },
+                None => quote! { None },
+            };
+
+            let section_expr = match section {
+                Some(title) => quote! { Some(#title) },
+                None => quote! { None },
+            };
+
+            let placeholder_expr = match placeholder {
+                Some(hint) => quote! { Some(#hint) },
+                None => quote! { None },
+            };
+
+            Some((
+                order,
+                quote! {
+                    ::tokio_tui::FieldMeta {
+                        id: #field_name_str,
+                        label: #label,
+                        required: #required,
+                        help_text: #help_expr,
+                        unit: #unit_expr,
+                        slider: #slider_expr,
+                        section: #section_expr,
+                        editor: #editor_expr,
+                        placeholder: #placeholder_expr,
+                        readonly: #readonly
+                    }
+                },
+            ))
         })
+        .collect();
+
+    // By `order`, then as declared
+    definitions.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    definitions
+        .into_iter()
+        .map(|(_, definition)| definition)
         .collect()
 }
 
-fn generate_to_fields_impl(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStream> {
+fn generate_to_fields_impl(fields: &[(&Field, FieldAttrs)]) -> Vec<proc_macro2::TokenStream> {
     fields
-        .named
         .iter()
-        .filter_map(|field| {
+        .filter_map(|(field, attrs)| {
             let field_name = field.ident.as_ref()?;
             let field_name_str = field_name.to_string();
+            if attrs.skip {
+                return None;
+            }
+
+            let validators = match validator_stmts(attrs) {
+                Ok(validators) => validators,
+                Err(error) => return Some(error.to_compile_error()),
+            };
 
             Some(quote! {
                 {
@@ -182,10 +437,32 @@ fn generate_to_fields_impl(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStream
                         meta.required
                     );
 
+                    if let Some(editor) = meta.editor {
+                        field = field.with_editor(editor);
+                    }
+
                     if let Some(help) = meta.help_text {
                         field = field.with_help_text(help);
                     }
 
+                    if let Some(hint) = meta.placeholder {
+                        field = field.with_placeholder(hint);
+                    }
+
+                    if meta.readonly {
+                        field = field.with_readonly();
+                    }
+
+                    if let Some(unit) = meta.unit {
+                        field = field.with_unit(unit);
+                    }
+
+                    if let Some(range) = meta.slider {
+                        field = field.with_slider(range);
+                    }
+
+                    #(#validators)*
+
                     fields.insert(#field_name_str.to_string(), field);
                 }
             })
@@ -193,38 +470,358 @@ fn generate_to_fields_impl(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStream
         .collect()
 }
 
-fn generate_from_fields_impl(fields: &FieldsNamed) -> Vec<proc_macro2::TokenStream> {
+fn generate_from_fields_impl(fields: &[(&Field, FieldAttrs)]) -> Vec<proc_macro2::TokenStream> {
     fields
-        .named
         .iter()
-        .filter_map(|field| {
+        .filter_map(|(field, attrs)| {
             let field_name = field.ident.as_ref()?;
             let field_name_str = field_name.to_string();
+            let default = default_value(attrs.default.as_ref());
+            // Skipped fields aren't in the form
+            if attrs.skip {
+                return Some(quote! { #field_name: #default });
+            }
 
             Some(quote! {
                 #field_name: if let Some(field) = fields.get(#field_name_str) {
                     <_ as ::tokio_tui::FormValue>::from_field_widget(field)
                 } else {
                     // Default value if field is missing
-                    Default::default()
+                    #default
                 }
             })
         })
         .collect()
 }
 
-fn parse_field_attr(field: &Field, field_name: &str) -> (String, bool, Option<String>) {
+// `FormData::form_default`, for structs whose fields give their own
+// defaults.
+fn generate_form_default_impl(fields: &[(&Field, FieldAttrs)]) -> Option<proc_macro2::TokenStream> {
+    let defaults: Vec<_> = fields
+        .iter()
+        .filter_map(|(field, attrs)| {
+            let field_name = field.ident.as_ref()?;
+            let default = attrs.default.as_ref()?;
+            Some(quote! { #field_name: #default })
+        })
+        .collect();
+    if defaults.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn form_default() -> Self {
+            Self {
+                #(#defaults,)*
+                ..Default::default()
+            }
+        }
+    })
+}
+
+// `FormData::form_meta`, for structs with `#[tui_edit(...)]` settings for
+// their form: `title`, `columns` and `submit_label`.
+fn generate_form_meta_impl(input: &DeriveInput) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    let mut title = None;
+    let mut columns = None;
+    let mut submit_label = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("tui_edit"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("title") {
+                title = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("columns") {
+                let value: LitInt = meta.value()?.parse()?;
+                if value.base10_parse::<u16>()? == 0 {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "a form needs at least 1 column",
+                    ));
+                }
+                columns = Some(value);
+            } else if meta.path.is_ident("submit_label") {
+                submit_label = Some(meta.value()?.parse::<LitStr>()?);
+            } else {
+                return Err(meta.error("expected `title`, `columns` or `submit_label`"));
+            }
+            Ok(())
+        })?;
+    }
+    if title.is_none() && columns.is_none() && submit_label.is_none() {
+        return Ok(None);
+    }
+
+    let title = match title {
+        Some(title) => quote! { Some(#title) },
+        None => quote! { None },
+    };
+    let columns = match columns {
+        Some(columns) => quote! { Some(#columns) },
+        None => quote! { None },
+    };
+    let submit_label = match submit_label {
+        Some(label) => quote! { Some(#label) },
+        None => quote! { None },
+    };
+    Ok(Some(quote! {
+        fn form_meta() -> ::tokio_tui::FormMeta {
+            ::tokio_tui::FormMeta {
+                title: #title,
+                columns: #columns,
+                submit_label: #submit_label,
+            }
+        }
+    }))
+}
+
+// The widget a field's attributes ask for, as expressions of the types
+// `FormFieldWidget::with_unit`, `with_slider` and `with_editor` take.
+struct WidgetExprs {
+    unit: Option<proc_macro2::TokenStream>,
+    slider: Option<proc_macro2::TokenStream>,
+    editor: Option<proc_macro2::TokenStream>,
+}
+
+fn widget_exprs(field: &Field, attrs: &FieldAttrs) -> syn::Result<WidgetExprs> {
+    // `FieldUnit::named` runs at compile time, failing the build on a name
+    // it doesn't know
+    let unit = attrs.unit.as_ref().map(|name| {
+        quote_spanned! {name.span()=> {
+            const UNIT: ::tokio_tui::FieldUnit = ::tokio_tui::FieldUnit::named(#name);
+            UNIT
+        }}
+    });
+
+    // Only a text field turns into a password field; anything else would
+    // show the secret as it is
+    if attrs.secret && !is_text_type(&field.ty) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "a secret field must be a `String` or `Option<String>` to be masked",
+        ));
+    }
+
+    // `widget = "slider"` is a bare `slider` unless its range is given,
+    // and a secret is a password
+    let widget = &attrs.widget;
+    let mut editor = None;
+    let mut slider = attrs.slider;
+    match widget.as_ref().map(LitStr::value).as_deref() {
+        None if attrs.secret => editor = Some(quote! { ::tokio_tui::FieldEditor::Password }),
+        None => {}
+        Some(other) if attrs.secret && other != "password" => {
+            return Err(syn::Error::new_spanned(
+                widget,
+                format!("a secret field is a password field, not `{other}`"),
+            ));
+        }
+        Some("slider") => slider = slider.or(Some((0.0, 100.0, 1.0))),
+        Some("textarea") => editor = Some(quote! { ::tokio_tui::FieldEditor::TextArea }),
+        Some("password") => editor = Some(quote! { ::tokio_tui::FieldEditor::Password }),
+        Some("path") => editor = Some(quote! { ::tokio_tui::FieldEditor::Path }),
+        Some(other) => {
+            return Err(syn::Error::new_spanned(
+                widget,
+                format!(
+                    "unknown widget `{other}`, expected \"textarea\", \"slider\", \"password\" or \"path\""
+                ),
+            ));
+        }
+    }
+
+    // Editors only replace a text field, and a slider a number field, so
+    // on any other type they would be silently ignored
+    let name = widget.as_ref().map(LitStr::value);
+    let edits_text = is_text_type(&field.ty)
+        || (name.as_deref() == Some("path") && is_type_of(&field.ty, &["PathBuf"]));
+    if editor.is_some() && !edits_text {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "`widget = \"{}\"` needs a `String` or `Option<String>` field",
+                name.unwrap_or_default()
+            ),
+        ));
+    }
+    if slider.is_some() && !is_type_of(&field.ty, NUMBER_TYPES) {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "a slider needs a number field, such as `u8`, `i32` or `f64`",
+        ));
+    }
+
+    let slider = slider.map(|(min, max, step)| {
+        quote! { ::tokio_tui::SliderRange::new(#min, #max).with_step(#step) }
+    });
+    Ok(WidgetExprs {
+        unit,
+        slider,
+        editor,
+    })
+}
+
+// Types edited in a number field.
+const NUMBER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize", "f32", "f64",
+];
+
+// Whether a field of type `ty` is edited in a text field: a `String`, or
+// an `Option` of one.
+fn is_text_type(ty: &syn::Type) -> bool {
+    is_type_of(ty, &["String"])
+}
+
+// Whether `ty` is one of the types `names`, or an `Option` of one.
+fn is_type_of(ty: &syn::Type, names: &[&str]) -> bool {
+    let syn::Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(last) = path.path.segments.last() else {
+        return false;
+    };
+    match (last.ident.to_string().as_str(), &last.arguments) {
+        ("Option", PathArguments::AngleBracketed(args)) => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => args.args.len() == 1 && is_type_of(inner, names),
+            _ => false,
+        },
+        (name, PathArguments::None) => names.contains(&name),
+        _ => false,
+    }
+}
+
+// Statements adding the validators from a field's attributes to `field`,
+// checked in the order given.
+fn validator_stmts(attrs: &FieldAttrs) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let mut validators = Vec::new();
+    if attrs.min.is_some() || attrs.max.is_some() {
+        let min = option_number(attrs.min);
+        let max = option_number(attrs.max);
+        validators.push(quote! { field = field.with_bounds(#min, #max); });
+    }
+    if let Some(pattern) = &attrs.regex {
+        if let Err(error) = regex::Regex::new(&pattern.value()) {
+            return Err(syn::Error::new_spanned(
+                pattern,
+                format!("invalid regex: {error}"),
+            ));
+        }
+        validators.push(quote! { field = field.with_pattern(#pattern); });
+    }
+    if let Some(check) = &attrs.validate {
+        validators.push(quote! { field = field.with_validator(#check); });
+    }
+    Ok(validators)
+}
+
+// A field's `#[field(default = ...)]` value, or its type's `Default`.
+fn default_value(default: Option<&syn::Expr>) -> proc_macro2::TokenStream {
+    match default {
+        Some(value) => quote! { #value },
+        None => quote! { Default::default() },
+    }
+}
+
+// What a field's `#[field(...)]` attributes say about it.
+#[derive(Clone)]
+struct FieldAttrs {
+    label: String,
+    required: bool,
+    help: Option<String>,
+    unit: Option<LitStr>,
+    // min, max and step
+    slider: Option<(f64, f64, f64)>,
+    // Title of a section starting at the field
+    section: Option<String>,
+    // Left out of the form, and `Default` when read back from it
+    skip: bool,
+    // Bounds of a number, or of a text's length
+    min: Option<f64>,
+    max: Option<f64>,
+    regex: Option<LitStr>,
+    // Function checking the value, as `FormFieldWidget::with_validator` takes
+    validate: Option<syn::Path>,
+    // Name of the editor to use instead of the type's own
+    widget: Option<LitStr>,
+    // Hint shown in the empty field
+    placeholder: Option<String>,
+    // Value of a new form's field, instead of the type's `Default`
+    default: Option<syn::Expr>,
+    // Place among the form's fields, before those of higher order; 0 when
+    // not given
+    order: f64,
+    // Shown but not editable
+    readonly: bool,
+    // Edited as a password, its value masked wherever it's shown
+    secret: bool,
+}
+
+// Each named field with its attributes, or every error in them.
+fn parse_named_fields(fields: &FieldsNamed) -> syn::Result<Vec<(&Field, FieldAttrs)>> {
+    let mut parsed = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    for field in &fields.named {
+        let field_name = field
+            .ident
+            .as_ref()
+            .map(Ident::to_string)
+            .unwrap_or_default();
+        match parse_field_attr(field, &field_name) {
+            Ok(attrs) => parsed.push((field, attrs)),
+            Err(error) => match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            },
+        }
+    }
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(parsed),
+    }
+}
+
+fn parse_field_attr(field: &Field, field_name: &str) -> syn::Result<FieldAttrs> {
     let mut label = None;
     let mut required = None;
     let mut help = None;
+    let mut unit = None;
+    let mut slider = None;
+    let mut section = None;
+    let mut skip = false;
+    let mut min = None;
+    let mut max = None;
+    let mut regex = None;
+    let mut validate = None;
+    let mut widget = None;
+    let mut placeholder = None;
+    let mut default = None;
+    let mut order = 0.0;
+    let mut readonly = false;
+    let mut secret = false;
+    let mut doc = Vec::new();
 
     for attr in &field.attrs {
+        if attr.path().is_ident("doc") {
+            if let syn::Meta::NameValue(meta) = &attr.meta
+                && let syn::Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(line),
+                    ..
+                }) = &meta.value
+            {
+                doc.push(line.value());
+            }
+            continue;
+        }
         if !attr.path().is_ident("field") {
             continue;
         }
 
-        let _ = attr.parse_nested_meta(|meta| {
-            let path = meta.path.get_ident().unwrap().to_string();
+        attr.parse_nested_meta(|meta| {
+            let Some(path) = meta.path.get_ident().map(Ident::to_string) else {
+                return Err(meta.error("expected a field attribute name"));
+            };
 
             if path == "label" {
                 let value: LitStr = meta.value()?.parse()?;
@@ -235,17 +832,121 @@ fn parse_field_attr(field: &Field, field_name: &str) -> (String, bool, Option<St
             } else if path == "help" {
                 let value: LitStr = meta.value()?.parse()?;
                 help = Some(value.value());
+            } else if path == "unit" {
+                unit = Some(meta.value()?.parse::<LitStr>()?);
+            } else if path == "slider" {
+                // `slider` alone is a percentage: 0 to 100 in steps of 1
+                let (mut min, mut max, mut step) = (0.0, 100.0, 1.0);
+                if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|bound| {
+                        if bound.path.is_ident("min") {
+                            min = parse_number(&bound)?;
+                        } else if bound.path.is_ident("max") {
+                            max = parse_number(&bound)?;
+                        } else if bound.path.is_ident("step") {
+                            step = parse_number(&bound)?;
+                        } else {
+                            return Err(bound.error("expected `min`, `max` or `step`"));
+                        }
+                        Ok(())
+                    })?;
+                }
+                slider = Some((min, max, step));
+            } else if path == "section" {
+                let value: LitStr = meta.value()?.parse()?;
+                section = Some(value.value());
+            } else if path == "skip" {
+                skip = true;
+            } else if path == "min" {
+                min = Some(parse_number(&meta)?);
+            } else if path == "max" {
+                max = Some(parse_number(&meta)?);
+            } else if path == "regex" {
+                regex = Some(meta.value()?.parse()?);
+            } else if path == "validate" {
+                validate = Some(meta.value()?.parse()?);
+            } else if path == "widget" {
+                widget = Some(meta.value()?.parse()?);
+            } else if path == "placeholder" {
+                let value: LitStr = meta.value()?.parse()?;
+                placeholder = Some(value.value());
+            } else if path == "default" {
+                default = Some(meta.value()?.parse()?);
+            } else if path == "order" {
+                order = parse_number(&meta)?;
+            } else if path == "readonly" {
+                readonly = true;
+            } else if path == "secret" {
+                secret = true;
+            } else {
+                return Err(meta.error(format!("unknown field attribute `{path}`")));
             }
 
             Ok(())
-        });
+        })?;
     }
 
-    // Default label: convert field_name from snake_case to Title Case
-    let final_label = label.unwrap_or_else(|| snake_to_title_case(field_name));
+    Ok(FieldAttrs {
+        // Default label: convert field_name from snake_case to Title Case
+        label: label.unwrap_or_else(|| snake_to_title_case(field_name)),
+        // Default required: true
+        required: required.unwrap_or(true),
+        // Default help: the field's doc comment
+        help: help.or_else(|| doc_text(&doc)),
+        unit,
+        slider,
+        section,
+        skip,
+        min,
+        max,
+        regex,
+        validate,
+        widget,
+        placeholder,
+        default,
+        order,
+        readonly,
+        secret,
+    })
+}
 
-    // Default required: true
-    let final_required = required.unwrap_or(true);
+// A doc comment's lines as one text: lines of a paragraph joined with
+// spaces, paragraphs with a newline. None for no comment.
+fn doc_text(lines: &[String]) -> Option<String> {
+    let mut text = String::new();
+    let mut new_paragraph = false;
+    for line in lines.iter().flat_map(|line| line.split('\n')) {
+        let line = line.trim();
+        if line.is_empty() {
+            new_paragraph = !text.is_empty();
+            continue;
+        }
+        if new_paragraph {
+            text.push('\n');
+            new_paragraph = false;
+        } else if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(line);
+    }
+    (!text.is_empty()).then_some(text)
+}
+
+fn option_number(value: Option<f64>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
 
-    (final_label, final_required, help)
+// An integer or float literal, optionally negative: `= 0`, `= -1.5`.
+fn parse_number(meta: &ParseNestedMeta) -> syn::Result<f64> {
+    let input = meta.value()?;
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let value = match input.parse::<Lit>()? {
+        Lit::Int(lit) => lit.base10_parse::<f64>()?,
+        Lit::Float(lit) => lit.base10_parse::<f64>()?,
+        lit => return Err(syn::Error::new_spanned(lit, "expected a number")),
+    };
+    Ok(if negative { -value } else { value })
 }
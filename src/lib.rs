@@ -11,5 +11,7 @@ pub use tui::*;
 
 pub mod tui_theme;
 
+pub mod testing;
+
 pub use ratatui;
 pub use tokio_tui_macro::TuiEdit;
@@ -0,0 +1,20 @@
+// tokio-tui/src/testing/mod.rs
+//! Helpers for testing [`TuiWidget`](crate::TuiWidget)s with scripted
+//! interactions, e.g.
+//!
+//! ```
+//! use tokio_tui::{InputWidget, testing::*};
+//!
+//! let mut harness = WidgetHarness::new(InputWidget::new(), 20, 3);
+//! harness.assert([
+//!     focus(),
+//!     type_text("hello"),
+//!     press("Backspace"),
+//!     expect_cell(1, 1, "h"),
+//!     expect_contains("hell "),
+//! ]);
+//! ```
+mod step;
+pub use step::*;
+mod widget_harness;
+pub use widget_harness::*;
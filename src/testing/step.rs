@@ -0,0 +1,247 @@
+// tokio-tui/src/testing/step.rs
+use std::fmt;
+
+use anyhow::{Result, anyhow, bail};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent, MouseEventKind,
+};
+
+/// One scripted action or expectation run by a
+/// [`WidgetHarness`](super::WidgetHarness).
+#[derive(Debug, Clone)]
+pub enum Step {
+    /// Send a key event.
+    Key(KeyEvent),
+    /// Send one key event per character.
+    Type(String),
+    /// Send a mouse event.
+    Mouse(MouseEvent),
+    /// Press and release the left button at (x, y).
+    Click(u16, u16),
+    /// Scroll the wheel at (x, y); positive amounts scroll down.
+    Scroll(u16, u16, i32),
+    Focus,
+    Unfocus,
+    /// Resize the render area.
+    Resize(u16, u16),
+    /// Force a draw, even if the widget doesn't report `need_draw`.
+    Draw,
+    /// The cell at (x, y) holds this symbol.
+    ExpectCell(u16, u16, String),
+    /// Row `y`, with trailing spaces trimmed, equals this text.
+    ExpectLine(u16, String),
+    /// Some row contains this text.
+    ExpectContains(String),
+    /// The widget reported the last input event as handled (or not).
+    ExpectHandled(bool),
+}
+
+/// Press a key described like `"Tab"`, `"Enter"`, `"a"`, `"F5"` or
+/// `"Ctrl+Shift+Left"`.
+///
+/// Panics if the description can't be parsed; see [`parse_key`] for a
+/// fallible version.
+pub fn press(key: &str) -> Step {
+    match parse_key(key) {
+        Ok(event) => Step::Key(event),
+        Err(err) => panic!("{err}"),
+    }
+}
+
+/// Type `text` one character at a time.
+pub fn type_text(text: impl Into<String>) -> Step {
+    Step::Type(text.into())
+}
+
+/// Left-click (press and release) at (x, y).
+pub fn click(x: u16, y: u16) -> Step {
+    Step::Click(x, y)
+}
+
+/// Scroll the mouse wheel at (x, y); positive `lines` scroll down.
+pub fn scroll(x: u16, y: u16, lines: i32) -> Step {
+    Step::Scroll(x, y, lines)
+}
+
+pub fn focus() -> Step {
+    Step::Focus
+}
+
+pub fn unfocus() -> Step {
+    Step::Unfocus
+}
+
+pub fn resize(width: u16, height: u16) -> Step {
+    Step::Resize(width, height)
+}
+
+pub fn draw() -> Step {
+    Step::Draw
+}
+
+pub fn expect_cell(x: u16, y: u16, symbol: impl Into<String>) -> Step {
+    Step::ExpectCell(x, y, symbol.into())
+}
+
+pub fn expect_line(y: u16, text: impl Into<String>) -> Step {
+    Step::ExpectLine(y, text.into())
+}
+
+pub fn expect_contains(text: impl Into<String>) -> Step {
+    Step::ExpectContains(text.into())
+}
+
+pub fn expect_handled(handled: bool) -> Step {
+    Step::ExpectHandled(handled)
+}
+
+pub(crate) fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+/// Parse a key description such as `"Ctrl+C"` or `"Shift+Tab"`.
+pub fn parse_key(desc: &str) -> Result<KeyEvent> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut parts: Vec<&str> = desc.split('+').collect();
+    // "Ctrl++" means Ctrl and the plus key
+    if desc == "+" || desc.ends_with("++") {
+        parts.truncate(parts.len() - 2);
+        parts.push("+");
+    }
+    let Some((name, mods)) = parts.split_last() else {
+        bail!("empty key description");
+    };
+
+    for m in mods {
+        modifiers |= match m.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "shift" => KeyModifiers::SHIFT,
+            "alt" | "meta" => KeyModifiers::ALT,
+            "super" | "cmd" => KeyModifiers::SUPER,
+            other => bail!("unknown modifier `{other}` in key `{desc}`"),
+        };
+    }
+
+    let mut chars = name.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => {
+            if c.is_uppercase() {
+                modifiers |= KeyModifiers::SHIFT;
+            }
+            KeyCode::Char(c)
+        }
+        _ => match name.to_ascii_lowercase().as_str() {
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => {
+                modifiers |= KeyModifiers::SHIFT;
+                KeyCode::BackTab
+            }
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            f if f.starts_with('f') => f[1..]
+                .parse::<u8>()
+                .map(KeyCode::F)
+                .map_err(|_| anyhow!("unknown key `{name}`"))?,
+            _ => bail!("unknown key `{name}`"),
+        },
+    };
+
+    Ok(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn describe_key(key: &KeyEvent) -> String {
+    let mut out = String::new();
+    for (flag, name) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SUPER, "Super+"),
+    ] {
+        if key.modifiers.contains(flag) {
+            out.push_str(name);
+        }
+    }
+    match key.code {
+        KeyCode::Char(c) => out.push(c),
+        KeyCode::F(n) => out.push_str(&format!("F{n}")),
+        code => out.push_str(&format!("{code:?}")),
+    }
+    out
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Key(key) => write!(f, "press({:?})", describe_key(key)),
+            Step::Type(text) => write!(f, "type_text({text:?})"),
+            Step::Mouse(m) => write!(f, "mouse({:?} at {},{})", m.kind, m.column, m.row),
+            Step::Click(x, y) => write!(f, "click({x}, {y})"),
+            Step::Scroll(x, y, n) => write!(f, "scroll({x}, {y}, {n})"),
+            Step::Focus => write!(f, "focus()"),
+            Step::Unfocus => write!(f, "unfocus()"),
+            Step::Resize(w, h) => write!(f, "resize({w}, {h})"),
+            Step::Draw => write!(f, "draw()"),
+            Step::ExpectCell(x, y, s) => write!(f, "expect_cell({x}, {y}, {s:?})"),
+            Step::ExpectLine(y, s) => write!(f, "expect_line({y}, {s:?})"),
+            Step::ExpectContains(s) => write!(f, "expect_contains({s:?})"),
+            Step::ExpectHandled(h) => write!(f, "expect_handled({h})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modifiers_and_named_keys() {
+        let key = parse_key("Ctrl+Alt+Delete").unwrap();
+        assert_eq!(key.code, KeyCode::Delete);
+        assert_eq!(key.modifiers, KeyModifiers::CONTROL | KeyModifiers::ALT);
+        assert_eq!(parse_key("Shift+Tab").unwrap().code, KeyCode::BackTab);
+        assert_eq!(parse_key("F12").unwrap().code, KeyCode::F(12));
+    }
+
+    #[test]
+    fn uppercase_letters_and_plus_keys() {
+        let key = parse_key("A").unwrap();
+        assert_eq!(
+            (key.code, key.modifiers),
+            (KeyCode::Char('A'), KeyModifiers::SHIFT)
+        );
+        let key = parse_key("Ctrl++").unwrap();
+        assert_eq!(
+            (key.code, key.modifiers),
+            (KeyCode::Char('+'), KeyModifiers::CONTROL)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_modifiers() {
+        assert!(parse_key("Hyper+a").is_err());
+        assert!(parse_key("Nope").is_err());
+        assert!(parse_key("").is_err());
+    }
+}
@@ -0,0 +1,301 @@
+// tokio-tui/src/testing/widget_harness.rs
+use std::fmt::Write as _;
+
+use anyhow::{Result, bail};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+};
+
+use crate::TuiWidget;
+
+use super::{Step, mouse, parse_key};
+
+/// Drives a [`TuiWidget`] through a script of [`Step`]s against an
+/// off-screen buffer.
+///
+/// The widget is drawn lazily before each expectation, the same way the app
+/// loop would, so expectations always see the result of the preceding
+/// input. A failing expectation reports the step, what was expected and a
+/// dump of the rendered buffer.
+pub struct WidgetHarness<W: TuiWidget> {
+    widget: W,
+    area: Rect,
+    buffer: Buffer,
+    drawn: bool,
+    last_handled: Option<bool>,
+}
+
+impl<W: TuiWidget> WidgetHarness<W> {
+    pub fn new(widget: W, width: u16, height: u16) -> Self {
+        let area = Rect::new(0, 0, width, height);
+        Self {
+            widget,
+            area,
+            buffer: Buffer::empty(area),
+            drawn: false,
+            last_handled: None,
+        }
+    }
+
+    pub fn widget(&self) -> &W {
+        &self.widget
+    }
+
+    pub fn widget_mut(&mut self) -> &mut W {
+        self.drawn = false;
+        &mut self.widget
+    }
+
+    pub fn into_widget(self) -> W {
+        self.widget
+    }
+
+    /// The buffer as of the last draw.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Send a key described like [`press`](super::press) does.
+    pub fn press(&mut self, key: &str) -> Result<bool> {
+        let event = parse_key(key)?;
+        Ok(self.key(event))
+    }
+
+    pub fn key(&mut self, event: KeyEvent) -> bool {
+        let handled = self.widget.key_event(event);
+        self.after_input(handled)
+    }
+
+    /// Draw into a freshly reset buffer, as ratatui does every frame.
+    pub fn draw(&mut self) -> &Buffer {
+        self.buffer.reset();
+        self.widget.draw(self.area, &mut self.buffer);
+        self.drawn = true;
+        &self.buffer
+    }
+
+    /// Run `steps` in order, stopping at the first failed expectation.
+    pub fn run(&mut self, steps: impl IntoIterator<Item = Step>) -> Result<()> {
+        for (idx, step) in steps.into_iter().enumerate() {
+            if let Err(err) = self.step(&step) {
+                bail!("step {} `{step}` failed: {err}\n\n{}", idx + 1, self.dump());
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`run`](Self::run), but panics on failure. Meant for tests.
+    #[track_caller]
+    pub fn assert(&mut self, steps: impl IntoIterator<Item = Step>) {
+        if let Err(err) = self.run(steps) {
+            panic!("{err}");
+        }
+    }
+
+    /// The rendered buffer as text, with a column ruler and row numbers.
+    pub fn dump(&self) -> String {
+        let width = self.area.width as usize;
+        let mut out = String::new();
+
+        let tens: String = (0..width)
+            .map(|x| {
+                if x % 10 == 0 {
+                    char::from(b'0' + (x / 10 % 10) as u8)
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        let ones: String = (0..width)
+            .map(|x| char::from(b'0' + (x % 10) as u8))
+            .collect();
+        let _ = writeln!(out, "     {tens}");
+        let _ = writeln!(out, "     {ones}");
+        let _ = writeln!(out, "    ┌{}┐", "─".repeat(width));
+        for y in 0..self.area.height {
+            let _ = writeln!(out, "{y:>3} │{}│", self.row_text(y));
+        }
+        let _ = write!(out, "    └{}┘", "─".repeat(width));
+        out
+    }
+
+    fn after_input(&mut self, handled: bool) -> bool {
+        self.last_handled = Some(handled);
+        self.drawn = false;
+        handled
+    }
+
+    // Draw if input arrived since the last draw or the widget asks for it.
+    fn ensure_drawn(&mut self) {
+        if !self.drawn || self.widget.need_draw() {
+            self.draw();
+        }
+    }
+
+    fn row_text(&self, y: u16) -> String {
+        (0..self.area.width).map(|x| self.symbol_at(x, y)).collect()
+    }
+
+    fn symbol_at(&self, x: u16, y: u16) -> &str {
+        self.buffer
+            .cell(Position::new(x, y))
+            .map_or(" ", |cell| cell.symbol())
+    }
+
+    fn step(&mut self, step: &Step) -> Result<()> {
+        match step {
+            Step::Key(event) => {
+                self.key(*event);
+            }
+            Step::Type(text) => {
+                let mut handled = true;
+                for ch in text.chars() {
+                    let modifiers = if ch.is_uppercase() {
+                        KeyModifiers::SHIFT
+                    } else {
+                        KeyModifiers::NONE
+                    };
+                    handled &= self
+                        .widget
+                        .key_event(KeyEvent::new(KeyCode::Char(ch), modifiers));
+                }
+                self.after_input(handled);
+            }
+            Step::Mouse(event) => {
+                let handled = self.widget.mouse_event(*event);
+                self.after_input(handled);
+            }
+            Step::Click(x, y) => {
+                let down = mouse(MouseEventKind::Down(MouseButton::Left), *x, *y);
+                let up = mouse(MouseEventKind::Up(MouseButton::Left), *x, *y);
+                let handled = self.widget.mouse_event(down);
+                self.widget.mouse_event(up);
+                self.after_input(handled);
+            }
+            Step::Scroll(x, y, lines) => {
+                let kind = if *lines >= 0 {
+                    MouseEventKind::ScrollDown
+                } else {
+                    MouseEventKind::ScrollUp
+                };
+                let mut handled = true;
+                for _ in 0..lines.unsigned_abs() {
+                    handled &= self.widget.mouse_event(mouse(kind, *x, *y));
+                }
+                self.after_input(handled);
+            }
+            Step::Focus => {
+                self.widget.focus();
+                self.drawn = false;
+            }
+            Step::Unfocus => {
+                self.widget.unfocus();
+                self.drawn = false;
+            }
+            Step::Resize(width, height) => {
+                self.area = Rect::new(0, 0, *width, *height);
+                self.buffer.resize(self.area);
+                self.drawn = false;
+            }
+            Step::Draw => {
+                self.draw();
+            }
+            Step::ExpectCell(x, y, expected) => {
+                self.ensure_drawn();
+                if *x >= self.area.width || *y >= self.area.height {
+                    bail!("cell ({x}, {y}) is outside the {} area", self.area_size());
+                }
+                let actual = self.symbol_at(*x, *y);
+                if actual != expected {
+                    bail!("expected {expected:?} at ({x}, {y}), found {actual:?}");
+                }
+            }
+            Step::ExpectLine(y, expected) => {
+                self.ensure_drawn();
+                if *y >= self.area.height {
+                    bail!("row {y} is outside the {} area", self.area_size());
+                }
+                let row = self.row_text(*y);
+                let actual = row.trim_end();
+                if actual != expected.trim_end() {
+                    bail!("expected row {y} to be {expected:?}, found {actual:?}");
+                }
+            }
+            Step::ExpectContains(expected) => {
+                self.ensure_drawn();
+                if !(0..self.area.height).any(|y| self.row_text(y).contains(expected.as_str())) {
+                    bail!("no row contains {expected:?}");
+                }
+            }
+            Step::ExpectHandled(expected) => match self.last_handled {
+                Some(actual) if actual == *expected => {}
+                Some(actual) => bail!("expected handled = {expected}, widget returned {actual}"),
+                None => bail!("no input event has been sent yet"),
+            },
+        }
+        Ok(())
+    }
+
+    fn area_size(&self) -> String {
+        format!("{}x{}", self.area.width, self.area.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InputWidget, testing::*};
+
+    #[test]
+    fn drives_an_input_through_key_events() {
+        let mut harness = WidgetHarness::new(InputWidget::new(), 20, 3);
+        harness.assert([
+            focus(),
+            type_text("hello"),
+            press("Backspace"),
+            expect_contains("hell "),
+            press("Left"),
+            press("Left"),
+            type_text("X"),
+            expect_cell(3, 1, "X"),
+            expect_contains("heXll"),
+        ]);
+        assert_eq!(harness.widget().text(), "heXll");
+    }
+
+    #[test]
+    fn a_failed_expectation_is_an_error() {
+        let mut harness = WidgetHarness::new(InputWidget::new(), 20, 3);
+        assert!(
+            harness
+                .run([type_text("abc"), expect_contains("xyz")])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn types_over_a_selection_in_a_full_input() {
+        let input = InputWidget::new().with_max_len(5);
+        let mut harness = WidgetHarness::new(input, 20, 3);
+        harness.assert([
+            focus(),
+            type_text("hello"),
+            type_text("!"),
+            press("Shift+Left"),
+            press("Shift+Left"),
+            type_text("p"),
+        ]);
+        assert_eq!(harness.widget().text(), "help");
+    }
+
+    #[test]
+    fn vi_paste_goes_through_the_filter() {
+        let input = InputWidget::new().with_vi_mode(true);
+        let mut harness = WidgetHarness::new(input, 20, 3);
+        harness.assert([focus(), type_text("a1b2"), press("Esc"), type_text("y0")]);
+        harness.widget_mut().set_filter(|ch| ch.is_ascii_digit());
+        harness.assert([type_text("$p")]);
+        assert_eq!(harness.widget().text(), "a1b21");
+    }
+}
@@ -0,0 +1,236 @@
+// tokio-tui/src/tui/focus_manager.rs
+use std::fmt::Debug;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::TuiWidget;
+
+// One level of the focus stack: the targets Tab cycles through and the
+// index of the focused one.
+#[derive(Debug, Clone)]
+struct FocusScope<K> {
+    targets: Vec<K>,
+    current: usize,
+}
+
+impl<K: PartialEq> FocusScope<K> {
+    fn new(targets: Vec<K>) -> Self {
+        Self {
+            targets,
+            current: 0,
+        }
+    }
+
+    fn focused(&self) -> Option<&K> {
+        self.targets.get(self.current)
+    }
+
+    fn position(&self, target: &K) -> Option<usize> {
+        self.targets.iter().position(|t| t == target)
+    }
+}
+
+/// Tab-order focus tracking with a stack of focus scopes.
+///
+/// The base scope holds the app's top-level focus targets. Opening a modal or
+/// nested form pushes a scope with just its own targets (fields, buttons);
+/// while it is on top, Tab and Shift+Tab cycle only within it and focus can't
+/// be moved to a background target. Popping the scope restores the focus the
+/// previous scope had.
+#[derive(Debug, Clone)]
+pub struct FocusManager<K: Clone + PartialEq + Debug> {
+    scopes: Vec<FocusScope<K>>,
+}
+
+impl<K: Clone + PartialEq + Debug> Default for FocusManager<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + PartialEq + Debug> FocusManager<K> {
+    /// Create a focus manager with no targets.
+    pub fn new() -> Self {
+        Self {
+            scopes: vec![FocusScope::new(Vec::new())],
+        }
+    }
+
+    /// Set the targets of the base scope, in tab order.
+    pub fn with_targets(mut self, targets: impl IntoIterator<Item = K>) -> Self {
+        self.set_targets(targets);
+        self
+    }
+
+    /// Replace the targets of the base scope, keeping the focused one if it
+    /// is still present.
+    pub fn set_targets(&mut self, targets: impl IntoIterator<Item = K>) {
+        let base = &mut self.scopes[0];
+        let focused = base.focused().cloned();
+        base.targets = targets.into_iter().collect();
+        base.current = focused.and_then(|f| base.position(&f)).unwrap_or(0);
+    }
+
+    fn active(&self) -> &FocusScope<K> {
+        self.scopes.last().expect("base scope is never popped")
+    }
+
+    fn active_mut(&mut self) -> &mut FocusScope<K> {
+        self.scopes.last_mut().expect("base scope is never popped")
+    }
+
+    /// The focused target of the innermost scope.
+    pub fn focused(&self) -> Option<&K> {
+        self.active().focused()
+    }
+
+    pub fn is_focused(&self, target: &K) -> bool {
+        self.focused() == Some(target)
+    }
+
+    /// Whether `target` can currently receive focus.
+    pub fn in_scope(&self, target: &K) -> bool {
+        self.active().position(target).is_some()
+    }
+
+    /// Focus `target`. Targets outside the innermost scope are refused.
+    pub fn focus(&mut self, target: &K) -> bool {
+        let scope = self.active_mut();
+        match scope.position(target) {
+            Some(idx) => {
+                scope.current = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move to the next target of the innermost scope, wrapping around.
+    pub fn focus_next(&mut self) -> Option<&K> {
+        let scope = self.active_mut();
+        if !scope.targets.is_empty() {
+            scope.current = (scope.current + 1) % scope.targets.len();
+        }
+        self.focused()
+    }
+
+    /// Move to the previous target of the innermost scope, wrapping around.
+    pub fn focus_prev(&mut self) -> Option<&K> {
+        let scope = self.active_mut();
+        let len = scope.targets.len();
+        if len > 0 {
+            scope.current = (scope.current + len - 1) % len;
+        }
+        self.focused()
+    }
+
+    /// Trap focus inside `targets` (e.g. a modal's fields and buttons) until
+    /// the matching [`pop_scope`](Self::pop_scope). The first target gets
+    /// focus.
+    pub fn push_scope(&mut self, targets: impl IntoIterator<Item = K>) {
+        self.scopes
+            .push(FocusScope::new(targets.into_iter().collect()));
+    }
+
+    /// Leave the innermost scope and return the target that regains focus.
+    /// The base scope is never popped.
+    pub fn pop_scope(&mut self) -> Option<&K> {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+        self.focused()
+    }
+
+    /// Number of scopes pushed on top of the base scope.
+    pub fn depth(&self) -> usize {
+        self.scopes.len() - 1
+    }
+
+    /// Whether focus is currently trapped in a pushed scope.
+    pub fn is_trapped(&self) -> bool {
+        self.depth() > 0
+    }
+
+    /// Handle Tab / Shift+Tab. While a scope is pushed, these keys are always
+    /// consumed so they never reach background widgets.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab => self.focus_next().is_some() || self.is_trapped(),
+            KeyCode::BackTab => self.focus_prev().is_some() || self.is_trapped(),
+            _ => false,
+        }
+    }
+
+    /// Focus the widget matching the focused target and unfocus the rest.
+    pub fn apply<'a>(&self, widgets: impl IntoIterator<Item = (K, &'a mut dyn TuiWidget)>) {
+        for (target, widget) in widgets {
+            if self.is_focused(&target) {
+                if !widget.is_focused() {
+                    widget.focus();
+                }
+            } else if widget.is_focused() {
+                widget.unfocus();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+
+    use super::*;
+
+    fn tab() -> KeyEvent {
+        KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)
+    }
+
+    fn back_tab() -> KeyEvent {
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+    }
+
+    #[test]
+    fn tab_wraps_around_both_ways() {
+        let mut focus = FocusManager::new().with_targets(["a", "b", "c"]);
+        assert_eq!(focus.focused(), Some(&"a"));
+        assert!(focus.handle_key(&back_tab()));
+        assert_eq!(focus.focused(), Some(&"c"));
+        assert!(focus.handle_key(&tab()));
+        assert_eq!(focus.focused(), Some(&"a"));
+    }
+
+    #[test]
+    fn pushed_scope_traps_focus_until_popped() {
+        let mut focus = FocusManager::new().with_targets(["a", "b"]);
+        focus.focus(&"b");
+        focus.push_scope(["ok", "cancel"]);
+        assert!(focus.is_trapped());
+        assert_eq!(focus.focused(), Some(&"ok"));
+        // Background targets can't be focused, and Tab stays inside
+        assert!(!focus.focus(&"a"));
+        focus.handle_key(&tab());
+        focus.handle_key(&tab());
+        assert_eq!(focus.focused(), Some(&"ok"));
+        // Popping gives focus back to where it was
+        assert_eq!(focus.pop_scope(), Some(&"b"));
+        assert!(!focus.is_trapped());
+    }
+
+    #[test]
+    fn base_scope_is_never_popped() {
+        let mut focus = FocusManager::new().with_targets(["a"]);
+        assert_eq!(focus.pop_scope(), Some(&"a"));
+        assert_eq!(focus.depth(), 0);
+    }
+
+    #[test]
+    fn empty_trapped_scope_still_consumes_tab() {
+        let mut focus = FocusManager::new().with_targets(["a"]);
+        focus.push_scope(Vec::new());
+        assert!(focus.handle_key(&tab()));
+        assert_eq!(focus.focused(), None);
+    }
+}
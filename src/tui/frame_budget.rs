@@ -0,0 +1,163 @@
+// tokio-tui/src/tui/frame_budget.rs
+use std::{
+    sync::{
+        RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use super::GlyphSet;
+
+/// How long a frame may take before the app starts shedding work.
+///
+/// When [`overruns`](Self::overruns) frames in a row exceed the budget the
+/// app enters degraded mode: expensive features such as search highlighting
+/// over large scrollbacks and status animations switch off, and dev
+/// overlays show a warning. It leaves degraded mode again after
+/// [`recover_after`](Self::recover_after) frames in a row fit the budget.
+///
+/// Install one with [`Tui::with_frame_budget`](crate::Tui::with_frame_budget);
+/// without it the app never degrades.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameBudget {
+    /// Time allowed for event handling and rendering of a single frame.
+    pub budget: Duration,
+    /// Consecutive slow frames before degrading.
+    pub overruns: u32,
+    /// Consecutive fast frames before restoring full rendering.
+    pub recover_after: u32,
+    /// Scrollbacks with more lines than this count as large, and lose
+    /// per-character search highlighting while degraded.
+    pub large_buffer: usize,
+}
+
+impl Default for FrameBudget {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_millis(50),
+            overruns: 5,
+            recover_after: 50,
+            large_buffer: 10_000,
+        }
+    }
+}
+
+/// Timing of recent frames, as tracked against the installed budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Time taken by the last frame, excluding the frame-sync sleep.
+    pub last: Duration,
+    /// Slowest frame so far, reset each time the app enters degraded mode.
+    pub worst: Duration,
+    /// Consecutive frames over budget.
+    pub overruns: u32,
+    /// Consecutive frames within budget.
+    pub within: u32,
+    pub degraded: bool,
+}
+
+static CURRENT: RwLock<Option<FrameBudget>> = RwLock::new(None);
+static STATS: RwLock<FrameStats> = RwLock::new(FrameStats {
+    last: Duration::ZERO,
+    worst: Duration::ZERO,
+    overruns: 0,
+    within: 0,
+    degraded: false,
+});
+// Mirrors `STATS.degraded` so render paths can check it without locking.
+static DEGRADED: AtomicBool = AtomicBool::new(false);
+
+impl FrameBudget {
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
+
+    /// The installed budget, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.read().ok().and_then(|cfg| *cfg)
+    }
+
+    /// Make this budget the app-wide one and reset the frame stats.
+    pub fn install(self) {
+        if let Ok(mut cfg) = CURRENT.write() {
+            *cfg = Some(self);
+        }
+        if let Ok(mut stats) = STATS.write() {
+            *stats = FrameStats::default();
+        }
+        DEGRADED.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether the app is currently shedding expensive work.
+    pub fn is_degraded() -> bool {
+        DEGRADED.load(Ordering::Relaxed)
+    }
+
+    /// Whether a scrollback of `lines` lines should skip expensive
+    /// highlighting right now.
+    pub fn skip_heavy_highlighting(lines: usize) -> bool {
+        Self::is_degraded() && Self::current().is_some_and(|cfg| lines > cfg.large_buffer)
+    }
+
+    pub fn stats() -> FrameStats {
+        STATS.read().map(|stats| *stats).unwrap_or_default()
+    }
+
+    /// A short warning for dev overlays while degraded.
+    pub fn warning() -> Option<String> {
+        let stats = Self::stats();
+        if !stats.degraded {
+            return None;
+        }
+        let budget = Self::current()?.budget;
+        Some(format!(
+            "{} slow frames ({}ms > {}ms), reduced rendering",
+            GlyphSet::current().pick("⚠", "!"),
+            stats.worst.as_millis(),
+            budget.as_millis()
+        ))
+    }
+
+    pub fn with_overruns(mut self, overruns: u32) -> Self {
+        self.overruns = overruns.max(1);
+        self
+    }
+
+    pub fn with_recover_after(mut self, frames: u32) -> Self {
+        self.recover_after = frames.max(1);
+        self
+    }
+
+    pub fn with_large_buffer(mut self, lines: usize) -> Self {
+        self.large_buffer = lines;
+        self
+    }
+
+    // Account for one frame, switching degraded mode on or off as needed.
+    pub(crate) fn record(&self, elapsed: Duration) {
+        let Ok(mut stats) = STATS.write() else {
+            return;
+        };
+        stats.last = elapsed;
+        stats.worst = stats.worst.max(elapsed);
+        if elapsed > self.budget {
+            stats.overruns += 1;
+            stats.within = 0;
+            if !stats.degraded && stats.overruns >= self.overruns {
+                stats.degraded = true;
+                stats.worst = elapsed;
+            }
+        } else {
+            stats.within += 1;
+            stats.overruns = 0;
+            if stats.degraded && stats.within >= self.recover_after {
+                stats.degraded = false;
+            }
+        }
+        DEGRADED.store(stats.degraded, Ordering::Relaxed);
+    }
+}
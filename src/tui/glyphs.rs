@@ -0,0 +1,79 @@
+// tokio-tui/src/tui/glyphs.rs
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Which symbols widgets may draw, from plain ASCII up to patched-font
+/// icons.
+///
+/// Every widget that draws icons, spinners, arrows or scrollbar thumbs has
+/// an ASCII fallback for each of them, so the UI stays legible on terminals
+/// (or fonts) that can't show the fancier glyphs. Install one with
+/// [`Tui::with_glyphs`](crate::Tui::with_glyphs); without it widgets use
+/// [`Unicode`](Self::Unicode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum GlyphSet {
+    /// Printable ASCII only.
+    Ascii,
+    /// Box drawing, braille spinners, arrows and a few emoji, as found in
+    /// most modern terminal fonts.
+    #[default]
+    Unicode,
+    /// Like `Unicode`, with icons from a Nerd Font patched font.
+    NerdFont,
+}
+
+/// Environment variable [`GlyphSet::from_env`] reads: `ascii`, `unicode`
+/// or `nerd`.
+pub const GLYPHS_ENV: &str = "TOKIO_TUI_GLYPHS";
+
+static CURRENT: AtomicU8 = AtomicU8::new(GlyphSet::Unicode as u8);
+
+impl GlyphSet {
+    /// The app-wide glyph set.
+    pub fn current() -> Self {
+        match CURRENT.load(Ordering::Relaxed) {
+            0 => Self::Ascii,
+            2 => Self::NerdFont,
+            _ => Self::Unicode,
+        }
+    }
+
+    /// Make this the app-wide glyph set.
+    pub fn install(self) {
+        CURRENT.store(self as u8, Ordering::Relaxed);
+    }
+
+    /// Look a glyph set up by name, as used in [`GLYPHS_ENV`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "ascii" | "plain" => Some(Self::Ascii),
+            "unicode" => Some(Self::Unicode),
+            "nerd" | "nerdfont" | "nerd-font" => Some(Self::NerdFont),
+            _ => None,
+        }
+    }
+
+    /// The glyph set named by [`GLYPHS_ENV`], falling back to ASCII on the
+    /// Linux console (whose font lacks most symbols) and `Unicode`
+    /// elsewhere.
+    pub fn from_env() -> Self {
+        if let Some(set) = std::env::var(GLYPHS_ENV)
+            .ok()
+            .and_then(|name| Self::from_name(&name))
+        {
+            return set;
+        }
+        match std::env::var("TERM") {
+            Ok(term) if term == "linux" || term == "dumb" => Self::Ascii,
+            _ => Self::Unicode,
+        }
+    }
+
+    pub fn is_ascii(self) -> bool {
+        self == Self::Ascii
+    }
+
+    /// `fancy` unless restricted to ASCII, in which case `ascii`.
+    pub fn pick<T>(self, fancy: T, ascii: T) -> T {
+        if self.is_ascii() { ascii } else { fancy }
+    }
+}
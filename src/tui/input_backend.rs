@@ -1,5 +1,5 @@
 // tokio-tui/src/tui/input_backend.rs
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Result, anyhow};
 use crossterm::event::{
@@ -11,18 +11,61 @@ use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
-pub type InputEvents = (Option<Vec<KeyEvent>>, Option<Vec<MouseEvent>>);
+pub type InputEvents = (
+    Option<Vec<Coalesced<KeyEvent>>>,
+    Option<Vec<Coalesced<MouseEvent>>>,
+    Option<String>,
+);
 pub enum InputEvent {
     Mouse(MouseEvent),
     Key(KeyEvent),
 }
 
+/// An input event together with the number of identical copies that arrived
+/// back to back (wheel ticks, held-down keys).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Coalesced<E> {
+    pub event: E,
+    pub count: usize,
+}
+
+impl<E: Clone> Coalesced<E> {
+    pub fn new(event: E) -> Self {
+        Self { event, count: 1 }
+    }
+
+    /// Turn runs back into individual events.
+    pub fn expand(events: Vec<Coalesced<E>>) -> Vec<E> {
+        events
+            .into_iter()
+            .flat_map(|c| std::iter::repeat_n(c.event, c.count))
+            .collect()
+    }
+}
+
+/// What to do with newlines that arrive as part of a paste.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PasteNewlines {
+    /// Deliver them as Enter presses (may submit an input mid-paste), or
+    /// keep them in bracketed paste text
+    Enter,
+    /// Replace each newline with a space
+    #[default]
+    Space,
+    /// Drop newlines entirely
+    Strip,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct InputBackendOpts {
     key_buffer: usize,
     mouse_buffer: usize,
     tick_rate: Duration,
     flush_cap: usize,
+    coalesce: bool,
+    paste_newlines: PasteNewlines,
+    paste_threshold: Duration,
+    paste_events: bool,
 }
 impl Default for InputBackendOpts {
     fn default() -> Self {
@@ -31,10 +74,45 @@ impl Default for InputBackendOpts {
             mouse_buffer: 8,
             tick_rate: Duration::from_millis(75),
             flush_cap: 512,
+            coalesce: true,
+            paste_newlines: PasteNewlines::default(),
+            paste_threshold: Duration::ZERO,
+            paste_events: true,
         }
     }
 }
 
+impl InputBackendOpts {
+    /// Collapse runs of identical events into a single [`Coalesced`] entry.
+    pub fn with_coalescing(mut self, coalesce: bool) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// How newlines inside pasted text are delivered.
+    pub fn with_paste_newlines(mut self, mode: PasteNewlines) -> Self {
+        self.paste_newlines = mode;
+        self
+    }
+
+    /// Keys arriving faster than this after the previous key are treated as
+    /// pasted text, for terminals that don't report bracketed paste. Off
+    /// (zero) by default, since keys typed over a slow link can arrive just
+    /// as close together; a few milliseconds is enough when it's needed.
+    pub fn with_paste_threshold(mut self, threshold: Duration) -> Self {
+        self.paste_threshold = threshold;
+        self
+    }
+
+    /// Deliver bracketed pastes whole, through
+    /// [`TuiApp::handle_paste`](crate::TuiApp::handle_paste), instead of as
+    /// one key press per character. On by default.
+    pub fn with_paste_events(mut self, enabled: bool) -> Self {
+        self.paste_events = enabled;
+        self
+    }
+}
+
 // Threaded key handler (captures keys in a separate tokio thread)
 pub struct InputHandler {
     key_rx: UnboundedReceiver<InputEvents>,
@@ -91,28 +169,36 @@ impl InputHandler {
             return None;
         }
 
-        let mut key_events: Vec<KeyEvent> = Vec::new();
-        let mut mouse_events: Vec<MouseEvent> = Vec::new();
+        let mut key_events: Vec<Coalesced<KeyEvent>> = Vec::new();
+        let mut mouse_events: Vec<Coalesced<MouseEvent>> = Vec::new();
+        let mut paste = None;
 
         // pull **everything** that is ready right now
-        while let Ok((k, m)) = self.key_rx.try_recv() {
+        while let Ok((k, m, p)) = self.key_rx.try_recv() {
             if let Some(k) = k {
                 key_events.extend(k);
             }
             if let Some(m) = m {
                 mouse_events.extend(m);
             }
+            // a paste ends the batch, so keys typed after it stay after it
+            if p.is_some() {
+                paste = p;
+                break;
+            }
             // optional hard cap so we never stall a frame forever
             if key_events.len() + mouse_events.len() > self.opts.flush_cap {
                 break;
             }
         }
-        match (key_events.len(), mouse_events.len()) {
-            (0, 0) => None,
-            (_, 0) => Some((Some(key_events), None)),
-            (0, _) => Some((None, Some(mouse_events))),
-            (_, _) => Some((Some(key_events), Some(mouse_events))),
+        if key_events.is_empty() && mouse_events.is_empty() && paste.is_none() {
+            return None;
         }
+        Some((
+            (!key_events.is_empty()).then_some(key_events),
+            (!mouse_events.is_empty()).then_some(mouse_events),
+            paste,
+        ))
     }
 }
 
@@ -124,13 +210,14 @@ impl Default for InputHandler {
 
 struct InputBackend {
     tx: UnboundedSender<InputEvents>,
-    key_buffer: Vec<KeyEvent>,
-    mouse_buffer: Vec<MouseEvent>,
+    key_buffer: Vec<Coalesced<KeyEvent>>,
+    mouse_buffer: Vec<Coalesced<MouseEvent>>,
     cancel: CancellationToken,
     event_reader: EventStream,
     interval: tokio::time::Interval,
     scroll_delta: i32,
-    backspace_cnt: usize,
+    last_scroll: Option<MouseEvent>,
+    last_key_at: Option<Instant>,
     opts: InputBackendOpts,
 }
 
@@ -147,8 +234,9 @@ impl InputBackend {
             cancel,
             event_reader: EventStream::new(),
             interval: tokio::time::interval(opts.tick_rate),
-            scroll_delta: 0,  // +N down, -N up
-            backspace_cnt: 0, // pending back-spaces
+            scroll_delta: 0, // +N down, -N up
+            last_scroll: None,
+            last_key_at: None,
             opts,
         }
     }
@@ -170,7 +258,7 @@ impl InputBackend {
             Some(std::mem::take(&mut self.mouse_buffer))
         };
 
-        let _ = self.tx.send((keys, mouses));
+        let _ = self.tx.send((keys, mouses, None));
     }
 
     /// Main loop – runs in a spawned async task
@@ -187,42 +275,46 @@ impl InputBackend {
                             /* ---------- Mouse ---------- */
                             CrosstermEvent::Mouse(mev) => {
                                 match mev.kind {
-                                    MouseEventKind::ScrollUp   => self.scroll_delta -= 1,
-                                    MouseEventKind::ScrollDown => self.scroll_delta += 1,
-                                    MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
-                                        // horizontal wheel straight through
-                                        self.mouse_buffer.push(mev);
+                                    MouseEventKind::ScrollUp if self.opts.coalesce => {
+                                        self.scroll_delta -= 1;
+                                        self.last_scroll = Some(mev);
+                                    }
+                                    MouseEventKind::ScrollDown if self.opts.coalesce => {
+                                        self.scroll_delta += 1;
+                                        self.last_scroll = Some(mev);
                                     }
                                     _ => {
-                                        // clicks / moves
-                                        self.mouse_buffer.push(mev);
+                                        // clicks / moves / horizontal wheel
+                                        self.push_mouse(mev);
                                     }
                                 }
 
-                                if self.mouse_buffer.len() >= self.opts.mouse_buffer{
+                                if self.mouse_buffer.len() >= self.opts.mouse_buffer {
                                     self.flush();
                                 }
                             }
 
                             /* ---------- Keys ---------- */
                             CrosstermEvent::Key(kev) if kev.kind == KeyEventKind::Press => {
-                                match kev.code {
-                                    KeyCode::Backspace => {
-                                        self.backspace_cnt += 1;      // coalesce
-                                    }
-                                    _ => {
-                                        // materialise pending back-spaces first
-                                        if self.backspace_cnt > 0 {
-                                            self.push_backspaces();
-                                        }
-
-                                        self.key_buffer.push(kev);
-                                        if self.key_buffer.len() >= self.opts.key_buffer{
-                                            self.flush();
-                                        }
-                                    }
+                                let now = Instant::now();
+                                let pasted = self.last_key_at.is_some_and(|at| {
+                                    now.duration_since(at) < self.opts.paste_threshold
+                                });
+                                self.last_key_at = Some(now);
+
+                                if kev.code == KeyCode::Enter && pasted {
+                                    self.push_pasted_newline();
+                                } else {
+                                    self.push_key(kev);
                                 }
                             }
+
+                            /* ---------- Bracketed paste ---------- */
+                            CrosstermEvent::Paste(text) if self.opts.paste_events => {
+                                self.send_paste(&text);
+                            }
+                            CrosstermEvent::Paste(text) => self.push_paste(&text),
+
                             _ => {} // ignore key releases etc.
                         }
                     }
@@ -231,29 +323,7 @@ impl InputBackend {
                 /* ---------- Tick ---------- */
                 _ = self.interval.tick() => {
                     /*          materialise wheel delta          */
-                    if self.scroll_delta != 0 {
-                        use MouseEventKind::{ScrollDown, ScrollUp};
-                        let dir  = if self.scroll_delta > 0 { ScrollDown } else { ScrollUp };
-                        let reps = self.scroll_delta.unsigned_abs();
-
-                        for _ in 0..reps {
-                            self.mouse_buffer.push(MouseEvent {
-                                kind:       dir,
-                                column:     0,
-                                row:        0,
-                                modifiers:  KeyModifiers::NONE,
-                            });
-                            if self.mouse_buffer.len() >= self.opts.key_buffer {
-                                self.flush();
-                            }
-                        }
-                        self.scroll_delta = 0;
-                    }
-
-                    /*          materialise back-spaces          */
-                    if self.backspace_cnt > 0 {
-                        self.push_backspaces();
-                    }
+                    self.push_scroll_delta();
 
                     /*          ship it          */
                     self.flush();
@@ -262,16 +332,89 @@ impl InputBackend {
         }
     }
 
-    /// Turn the pending back-space count into individual events.
-    fn push_backspaces(&mut self) {
-        while self.backspace_cnt > 0 {
-            self.key_buffer
-                .push(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
-            self.backspace_cnt -= 1;
+    /// Add a key, merging it into the previous entry when identical.
+    fn push_key(&mut self, kev: KeyEvent) {
+        push_coalesced(&mut self.key_buffer, kev, self.opts.coalesce);
+        if self.key_buffer.len() >= self.opts.key_buffer {
+            self.flush();
+        }
+    }
+
+    /// Add a mouse event, merging it into the previous entry when identical.
+    fn push_mouse(&mut self, mev: MouseEvent) {
+        push_coalesced(&mut self.mouse_buffer, mev, self.opts.coalesce);
+    }
 
-            if self.key_buffer.len() >= self.opts.key_buffer {
-                break; // leave the rest for the next flush
+    /// Turn the net wheel delta into a single counted scroll event.
+    fn push_scroll_delta(&mut self) {
+        if self.scroll_delta == 0 {
+            return;
+        }
+        let kind = if self.scroll_delta > 0 {
+            MouseEventKind::ScrollDown
+        } else {
+            MouseEventKind::ScrollUp
+        };
+        let base = self.last_scroll.unwrap_or(MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        });
+        self.mouse_buffer.push(Coalesced {
+            event: MouseEvent { kind, ..base },
+            count: self.scroll_delta.unsigned_abs() as usize,
+        });
+        self.scroll_delta = 0;
+    }
+
+    /// Apply the paste newline policy to an Enter that arrived mid-paste.
+    fn push_pasted_newline(&mut self) {
+        match self.opts.paste_newlines {
+            PasteNewlines::Enter => {
+                self.push_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
             }
+            PasteNewlines::Space => {
+                self.push_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE))
+            }
+            PasteNewlines::Strip => {}
         }
     }
+
+    /// Send bracketed-paste text on its own, after everything before it,
+    /// with newlines normalized and the newline policy applied.
+    fn send_paste(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let text = match self.opts.paste_newlines {
+            PasteNewlines::Enter => text,
+            PasteNewlines::Space => text.replace('\n', " "),
+            PasteNewlines::Strip => text.replace('\n', ""),
+        };
+        self.push_scroll_delta();
+        self.flush();
+        let _ = self.tx.send((None, None, Some(text)));
+    }
+
+    /// Feed bracketed-paste text through as individual key presses.
+    fn push_paste(&mut self, text: &str) {
+        let mut chars = text.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\r' | '\n' => self.push_pasted_newline(),
+                _ => self.push_key(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)),
+            }
+        }
+    }
+}
+
+fn push_coalesced<E: PartialEq + Clone>(buffer: &mut Vec<Coalesced<E>>, event: E, coalesce: bool) {
+    if coalesce
+        && let Some(last) = buffer.last_mut()
+        && last.event == event
+    {
+        last.count += 1;
+        return;
+    }
+    buffer.push(Coalesced::new(event));
 }
@@ -0,0 +1,149 @@
+// tokio-tui/src/tui/interaction.rs
+use std::{cell::Cell, time::Duration};
+
+/// Timing and distance thresholds used by interactive widgets.
+///
+/// The defaults are tuned for a local terminal. Over slow links (e.g. SSH
+/// with high latency) mouse events arrive in bursts, so the drag-scroll
+/// intervals and key chord timeout may need to be widened.
+///
+/// A config given to [`Tui::with_interaction`](crate::Tui::with_interaction)
+/// applies to every widget that `Tui` runs; individual widgets may also carry
+/// their own override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteractionConfig {
+    /// Maximum delay between the keys of a chord such as `gg`.
+    pub key_chord_timeout: Duration,
+    /// Distance (in cells) from the content edge that starts drag-scrolling.
+    pub drag_edge_margin: usize,
+    /// Interval between drag-scroll steps before acceleration kicks in.
+    pub drag_speed_slow: Duration,
+    /// Interval between drag-scroll steps once accelerated.
+    pub drag_speed_fast: Duration,
+    /// How long a drag-scroll must be held before it accelerates.
+    pub drag_accelerate_after: Duration,
+    /// Lines scrolled per drag step, before and after acceleration.
+    pub drag_vertical_step: (usize, usize),
+    /// Columns scrolled per drag step, before and after acceleration.
+    pub drag_horizontal_step: (usize, usize),
+    /// Lines scrolled per mouse wheel notch.
+    pub wheel_vertical_step: usize,
+    /// Columns scrolled per horizontal wheel notch (or Shift+wheel). 1 by
+    /// default; a few columns at a time, e.g. 4, suits wide logs better.
+    pub wheel_horizontal_step: usize,
+}
+
+impl Default for InteractionConfig {
+    fn default() -> Self {
+        Self {
+            key_chord_timeout: Duration::from_secs(1),
+            drag_edge_margin: 4,
+            drag_speed_slow: Duration::from_millis(150),
+            drag_speed_fast: Duration::from_millis(50),
+            drag_accelerate_after: Duration::from_millis(500),
+            drag_vertical_step: (2, 3),
+            drag_horizontal_step: (8, 5),
+            wheel_vertical_step: 1,
+            wheel_horizontal_step: 1,
+        }
+    }
+}
+
+thread_local! {
+    // Config of the `Tui` running on this thread
+    static SCOPED: Cell<Option<InteractionConfig>> = const { Cell::new(None) };
+}
+
+/// Keeps a config current on this thread until dropped; see
+/// [`InteractionConfig::enter`].
+#[must_use = "the config is only current until the scope is dropped"]
+pub struct InteractionScope {
+    outer: Option<InteractionConfig>,
+}
+
+impl Drop for InteractionScope {
+    fn drop(&mut self) {
+        SCOPED.set(self.outer);
+    }
+}
+
+impl InteractionConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The config of the [`Tui`](crate::Tui) running on this thread, or the
+    /// defaults.
+    pub fn current() -> Self {
+        SCOPED.get().unwrap_or_default()
+    }
+
+    /// Make `config` the [`current`](Self::current) one on this thread until
+    /// the scope is dropped, as a [`Tui`](crate::Tui) does while it runs.
+    /// `None` leaves the current one as it is.
+    pub fn enter(config: Option<InteractionConfig>) -> InteractionScope {
+        let outer = SCOPED.get();
+        if config.is_some() {
+            SCOPED.set(config);
+        }
+        InteractionScope { outer }
+    }
+
+    pub fn with_key_chord_timeout(mut self, timeout: Duration) -> Self {
+        self.key_chord_timeout = timeout;
+        self
+    }
+
+    pub fn with_drag_edge_margin(mut self, margin: usize) -> Self {
+        self.drag_edge_margin = margin;
+        self
+    }
+
+    /// Set the slow and fast drag-scroll intervals.
+    pub fn with_drag_speeds(mut self, slow: Duration, fast: Duration) -> Self {
+        self.drag_speed_slow = slow;
+        self.drag_speed_fast = fast;
+        self
+    }
+
+    pub fn with_drag_accelerate_after(mut self, delay: Duration) -> Self {
+        self.drag_accelerate_after = delay;
+        self
+    }
+
+    /// Set the vertical step (lines) before and after acceleration.
+    pub fn with_drag_vertical_step(mut self, slow: usize, fast: usize) -> Self {
+        self.drag_vertical_step = (slow, fast);
+        self
+    }
+
+    /// Set the horizontal step (columns) before and after acceleration.
+    pub fn with_drag_horizontal_step(mut self, slow: usize, fast: usize) -> Self {
+        self.drag_horizontal_step = (slow, fast);
+        self
+    }
+
+    /// Set the lines and columns scrolled per mouse wheel notch.
+    pub fn with_wheel_steps(mut self, vertical: usize, horizontal: usize) -> Self {
+        self.wheel_vertical_step = vertical;
+        self.wheel_horizontal_step = horizontal;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_scope_ends_with_its_guard() {
+        let custom = InteractionConfig::new().with_wheel_steps(5, 7);
+        {
+            let _scope = InteractionConfig::enter(Some(custom));
+            assert_eq!(InteractionConfig::current(), custom);
+            let _inner = InteractionConfig::enter(None);
+            assert_eq!(InteractionConfig::current(), custom);
+        }
+        assert_eq!(InteractionConfig::current(), InteractionConfig::default());
+    }
+}
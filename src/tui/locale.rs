@@ -0,0 +1,336 @@
+// tokio-tui/src/tui/locale.rs
+use std::{
+    cell::RefCell,
+    fmt::{Display, Write},
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use chrono::{DateTime, TimeZone};
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+
+/// Number and date formats, and the text of labels widgets draw on their
+/// own, such as form buttons and the quit prompt.
+///
+/// Install one with [`Tui::with_locale`](crate::Tui::with_locale) (or
+/// [`install`](Self::install) before building the app); without it widgets
+/// use English labels, `.` for decimals and `,` between thousands. Widgets
+/// look labels up as they draw, so a locale installed after they were built
+/// still applies. Forms, wizards, inputs, scrollbacks and metrics panels
+/// can also be given one of their own, which the widgets inside them
+/// follow too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    pub decimal_separator: char,
+    pub thousands_separator: char,
+    /// `chrono` format for dates. An invalid one falls back to the default.
+    pub date_format: String,
+    /// `chrono` format for times of day. An invalid one falls back to the
+    /// default.
+    pub time_format: String,
+
+    pub submit: String,
+    pub cancel: String,
+    /// Button that puts a form back to the data it was loaded with.
+    pub reset: String,
+    pub ok: String,
+    /// Row that adds an item to a list field.
+    pub add: String,
+    pub edit: String,
+    pub delete: String,
+    /// Asked on a list item before it's deleted, when the list asks.
+    pub delete_confirm: String,
+    /// Shown in a form field left unset, as an `Option` field's `None`.
+    pub unset: String,
+    /// After the label of a form field that can't be edited.
+    pub readonly: String,
+    /// Select choosing the variant of an enum field whose variants hold
+    /// data.
+    pub variant: String,
+    /// Scrollback status while a search is open.
+    pub search: String,
+    /// Hint in an empty scrollback search box.
+    pub search_hint: String,
+    pub quit_title: String,
+    pub quit_prompt: String,
+    pub quit_anyway: String,
+    pub wait: String,
+    /// Shown while an input's async submit handler runs, and if it panics.
+    pub working: String,
+    pub submit_failed: String,
+    /// Error on a required form field left empty.
+    pub required: String,
+    /// Error on a form field whose value doesn't parse.
+    pub invalid: String,
+    /// Errors on a form field out of its bounds: "At least 1", "At most 3
+    /// characters".
+    pub at_least: String,
+    pub at_most: String,
+    pub characters: String,
+    /// Shown on a form field while an async validator checks it, and if
+    /// the check panics.
+    pub checking: String,
+    pub check_failed: String,
+    /// Hints on a password field's reveal toggle.
+    pub show: String,
+    pub hide: String,
+    /// Prompt before a form with changes is cancelled.
+    pub discard_title: String,
+    pub discard_prompt: String,
+    pub discard: String,
+    pub keep_editing: String,
+    /// Prompt when a form's draft is left from last time.
+    pub restore_title: String,
+    pub restore_prompt: String,
+    pub restore: String,
+    /// Shown with the path once a form is saved to or loaded from its file.
+    pub saved: String,
+    pub loaded: String,
+    /// After a form field's help when there's more of it than fits.
+    pub help_hint: String,
+    /// Buttons and progress of a multi-page form wizard.
+    pub back: String,
+    pub next: String,
+    pub finish: String,
+    pub step: String,
+    /// Hint in the search box of a select field with many options, and
+    /// what it shows when nothing matches.
+    pub filter_hint: String,
+    pub no_matches: String,
+    /// Hints on fixing the address in an IP, socket address or URL field.
+    /// `{}` stands for the address as it could be written instead.
+    pub ip_no_port: String,
+    pub ip_not_host: String,
+    pub ip_invalid: String,
+    pub socket_add_port: String,
+    pub socket_brackets: String,
+    pub socket_invalid: String,
+    pub port_invalid: String,
+    pub url_add_scheme: String,
+    /// `{}` stands for the scheme typed.
+    pub url_bad_scheme: String,
+    pub url_spaces: String,
+    pub url_bad_ipv6: String,
+    pub url_add_host: String,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: ',',
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            time_format: DEFAULT_TIME_FORMAT.to_string(),
+            submit: "Submit".to_string(),
+            cancel: "Cancel".to_string(),
+            reset: "Reset".to_string(),
+            ok: "OK".to_string(),
+            add: "+ Add".to_string(),
+            edit: "Edit".to_string(),
+            delete: "Delete".to_string(),
+            delete_confirm: "Delete? y/n".to_string(),
+            unset: "unset".to_string(),
+            readonly: "read-only".to_string(),
+            variant: "Kind".to_string(),
+            search: "Search".to_string(),
+            search_hint: "Search (-C N to filter, Enter to find, Ctrl+Enter to pin, Esc to cancel)"
+                .to_string(),
+            quit_title: "Quit?".to_string(),
+            quit_prompt: "Quit anyway?".to_string(),
+            quit_anyway: "Quit anyway".to_string(),
+            wait: "Wait".to_string(),
+            working: "Working".to_string(),
+            submit_failed: "Submission failed".to_string(),
+            required: "Required".to_string(),
+            invalid: "Invalid value".to_string(),
+            at_least: "At least".to_string(),
+            at_most: "At most".to_string(),
+            characters: "characters".to_string(),
+            checking: "Checking".to_string(),
+            check_failed: "Check failed".to_string(),
+            show: "show".to_string(),
+            hide: "hide".to_string(),
+            discard_title: "Discard changes?".to_string(),
+            discard_prompt: "This form has unsaved changes.".to_string(),
+            discard: "Discard".to_string(),
+            keep_editing: "Keep editing".to_string(),
+            restore_title: "Restore draft?".to_string(),
+            restore_prompt: "Unsaved changes were found.".to_string(),
+            restore: "Restore".to_string(),
+            saved: "Saved".to_string(),
+            loaded: "Loaded".to_string(),
+            help_hint: "F1 for more".to_string(),
+            back: "Back".to_string(),
+            next: "Next".to_string(),
+            finish: "Finish".to_string(),
+            step: "Step".to_string(),
+            filter_hint: "Type to filter".to_string(),
+            no_matches: "No matches".to_string(),
+            ip_no_port: "No port here: use {}".to_string(),
+            ip_not_host: "Needs an IP address, not a host name".to_string(),
+            ip_invalid: "Not an IP address, e.g. 192.168.1.10 or ::1".to_string(),
+            socket_add_port: "Add a port, e.g. {}".to_string(),
+            socket_brackets: "Put the IPv6 address in brackets: {}".to_string(),
+            socket_invalid: "Not an address, e.g. 127.0.0.1:8080 or [::1]:8080".to_string(),
+            port_invalid: "The port must be a number from 0 to 65535".to_string(),
+            url_add_scheme: "Add a scheme, e.g. {}".to_string(),
+            url_bad_scheme: "\"{}\" isn't a URL scheme, e.g. https".to_string(),
+            url_spaces: "A URL can't have spaces; write them as %20".to_string(),
+            url_bad_ipv6: "Not an IPv6 address in the brackets".to_string(),
+            url_add_host: "Add a host, e.g. {}".to_string(),
+        }
+    }
+}
+
+static DEFAULT: LazyLock<Arc<Locale>> = LazyLock::new(Arc::default);
+static CURRENT: RwLock<Option<Arc<Locale>>> = RwLock::new(None);
+
+thread_local! {
+    // Locale of the widget drawing or handling input on this thread, over
+    // the app-wide one
+    static SCOPED: RefCell<Option<Arc<Locale>>> = const { RefCell::new(None) };
+}
+
+/// Keeps a widget's own locale current on this thread until dropped; see
+/// [`Locale::enter`].
+#[must_use = "the locale is only current until the scope is dropped"]
+pub struct LocaleScope {
+    outer: Option<Option<Arc<Locale>>>,
+}
+
+impl Drop for LocaleScope {
+    fn drop(&mut self) {
+        if let Some(outer) = self.outer.take() {
+            SCOPED.set(outer);
+        }
+    }
+}
+
+impl Locale {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The locale of the widget being drawn, if it has its own, else the
+    /// app-wide one, or the English defaults if none was installed.
+    pub fn current() -> Arc<Self> {
+        if let Some(locale) = SCOPED.with_borrow(Option::clone) {
+            return locale;
+        }
+        CURRENT
+            .read()
+            .ok()
+            .and_then(|locale| locale.clone())
+            .unwrap_or_else(|| DEFAULT.clone())
+    }
+
+    /// Make this locale the app-wide one.
+    pub fn install(self) {
+        if let Ok(mut locale) = CURRENT.write() {
+            *locale = Some(Arc::new(self));
+        }
+    }
+
+    /// Make `locale` the [`current`](Self::current) one on this thread until
+    /// the scope is dropped, as widgets with their own locale do while they
+    /// draw and handle input. `None` leaves the current one as it is.
+    pub fn enter(locale: Option<&Arc<Locale>>) -> LocaleScope {
+        LocaleScope {
+            outer: locale.map(|locale| SCOPED.replace(Some(locale.clone()))),
+        }
+    }
+
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    pub fn with_date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = format.into();
+        self
+    }
+
+    pub fn with_time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = format.into();
+        self
+    }
+
+    /// `value` with `decimals` digits after the decimal separator.
+    pub fn format_decimal(&self, value: f64, decimals: usize) -> String {
+        let text = format!("{value:.decimals$}");
+        if self.decimal_separator == '.' {
+            text
+        } else {
+            text.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    /// Parse a number written with this locale's separators; thousands
+    /// separators are ignored.
+    pub fn parse_decimal(&self, input: &str) -> Option<f64> {
+        self.normalize_decimal(input).trim().parse().ok()
+    }
+
+    /// `input` with this locale's separators swapped for the ones Rust's
+    /// number parsing expects.
+    pub fn normalize_decimal(&self, input: &str) -> String {
+        input
+            .chars()
+            .filter(|ch| *ch != self.thousands_separator || *ch == self.decimal_separator)
+            .map(|ch| {
+                if ch == self.decimal_separator {
+                    '.'
+                } else {
+                    ch
+                }
+            })
+            .collect()
+    }
+
+    /// `value` with its digits grouped in threes.
+    pub fn group_thousands(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (idx, ch) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                out.push(self.thousands_separator);
+            }
+            out.push(ch);
+        }
+        out
+    }
+
+    pub fn format_date<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        format_or(time, &self.date_format, DEFAULT_DATE_FORMAT)
+    }
+
+    pub fn format_time<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: Display,
+    {
+        format_or(time, &self.time_format, DEFAULT_TIME_FORMAT)
+    }
+}
+
+// `time` in `format`, or in `fallback` when `format` isn't a valid `chrono`
+// format string.
+fn format_or<Tz: TimeZone>(time: &DateTime<Tz>, format: &str, fallback: &str) -> String
+where
+    Tz::Offset: Display,
+{
+    let mut out = String::new();
+    if write!(out, "{}", time.format(format)).is_err() {
+        out.clear();
+        let _ = write!(out, "{}", time.format(fallback));
+    }
+    out
+}
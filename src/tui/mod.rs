@@ -5,5 +5,26 @@ pub use tui_app::*;
 mod input_backend;
 pub use input_backend::*;
 
+mod focus_manager;
+pub use focus_manager::*;
+
+mod interaction;
+pub use interaction::*;
+
+mod frame_budget;
+pub use frame_budget::*;
+
+mod glyphs;
+pub use glyphs::*;
+
 mod mode_layout;
 pub use mode_layout::*;
+
+mod locale;
+pub use locale::*;
+
+mod quit_guard;
+pub use quit_guard::*;
+
+mod widget_config;
+pub use widget_config::*;
@@ -0,0 +1,422 @@
+// tokio-tui/src/tui/quit_guard.rs
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{ButtonsWidget, GlyphSet, Locale, TuiWidget, tui_theme};
+
+/// Something that should hold up quitting, reported by
+/// [`TuiWidget::quit_concerns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuitConcern {
+    /// Edits that would be lost, e.g. a form that wasn't submitted.
+    Unsaved(String),
+    /// Work still in progress; the user may choose to wait for it.
+    Running(String),
+    /// Quitting isn't allowed right now, for the given reason.
+    Veto(String),
+}
+
+/// The concerns gathered from an app's widgets when it is asked to quit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuitConcerns(Vec<QuitConcern>);
+
+impl QuitConcerns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, concern: QuitConcern) {
+        self.0.push(concern);
+    }
+
+    pub fn unsaved(&mut self, what: impl Into<String>) {
+        self.push(QuitConcern::Unsaved(what.into()));
+    }
+
+    pub fn running(&mut self, what: impl Into<String>) {
+        self.push(QuitConcern::Running(what.into()));
+    }
+
+    pub fn veto(&mut self, reason: impl Into<String>) {
+        self.push(QuitConcern::Veto(reason.into()));
+    }
+
+    /// Add the concerns of each widget.
+    pub fn collect<'a>(mut self, widgets: impl IntoIterator<Item = &'a dyn TuiWidget>) -> Self {
+        for widget in widgets {
+            widget.quit_concerns(&mut self);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &QuitConcern> {
+        self.0.iter()
+    }
+
+    pub fn unsaved_count(&self) -> usize {
+        self.count(|c| matches!(c, QuitConcern::Unsaved(_)))
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.count(|c| matches!(c, QuitConcern::Running(_)))
+    }
+
+    pub fn is_vetoed(&self) -> bool {
+        self.count(|c| matches!(c, QuitConcern::Veto(_))) > 0
+    }
+
+    fn count(&self, pred: impl Fn(&QuitConcern) -> bool) -> usize {
+        self.0.iter().filter(|c| pred(c)).count()
+    }
+
+    /// One sentence covering everything, e.g. "You have unsaved changes in
+    /// Profile and 2 running jobs."
+    pub fn summary(&self) -> String {
+        if self.is_vetoed() {
+            return "You can't quit right now.".to_string();
+        }
+        let mut parts = Vec::new();
+        match self.unsaved_count() {
+            0 => {}
+            1 => {
+                let what = self.0.iter().find_map(|c| match c {
+                    QuitConcern::Unsaved(what) => Some(what),
+                    _ => None,
+                });
+                parts.push(format!("unsaved changes in {}", what.unwrap()));
+            }
+            n => parts.push(format!("unsaved changes in {n} places")),
+        }
+        match self.running_count() {
+            0 => {}
+            1 => parts.push("1 running job".to_string()),
+            n => parts.push(format!("{n} running jobs")),
+        }
+        format!("You have {}.", parts.join(" and "))
+    }
+}
+
+impl FromIterator<QuitConcern> for QuitConcerns {
+    fn from_iter<I: IntoIterator<Item = QuitConcern>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuitAction {
+    Quit,
+    Wait,
+    Stay,
+}
+
+/// Confirms quitting when widgets report unsaved work or running jobs.
+///
+/// Owns the app's run token: call [`request`](Self::request) where the app
+/// would cancel it (e.g. on Ctrl+Q) with the concerns gathered from its
+/// widgets. With none, the token is cancelled right away; otherwise a modal
+/// lists them and asks "quit anyway?". When jobs are running the user may
+/// also choose to wait, in which case the app should keep passing fresh
+/// concerns to [`update`](Self::update) (e.g. from
+/// [`TuiApp::before_frame`](crate::TuiApp::before_frame)) and the guard
+/// quits once they finish.
+///
+/// Draw it last, over everything else, and give it keys first: while the
+/// modal is open it consumes every key.
+pub struct QuitGuard {
+    run_token: CancellationToken,
+    concerns: QuitConcerns,
+    open: bool,
+    waiting: bool,
+    actions: Vec<QuitAction>,
+    buttons: ButtonsWidget,
+    needs_redraw: bool,
+}
+
+impl std::fmt::Debug for QuitGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuitGuard")
+            .field("concerns", &self.concerns)
+            .field("open", &self.open)
+            .field("waiting", &self.waiting)
+            .finish()
+    }
+}
+
+impl QuitGuard {
+    pub fn new(run_token: CancellationToken) -> Self {
+        Self {
+            run_token,
+            concerns: QuitConcerns::default(),
+            open: false,
+            waiting: false,
+            actions: Vec::new(),
+            buttons: ButtonsWidget::new(),
+            needs_redraw: false,
+        }
+    }
+
+    pub fn run_token(&self) -> &CancellationToken {
+        &self.run_token
+    }
+
+    /// Quit now if nothing holds it up, otherwise ask. Returns true when
+    /// the run token was cancelled.
+    pub fn request(&mut self, concerns: QuitConcerns) -> bool {
+        self.waiting = false;
+        if concerns.is_empty() {
+            self.quit_now();
+            return true;
+        }
+        self.open_modal(concerns);
+        false
+    }
+
+    /// Refresh the concerns while waiting for running jobs; quits once none
+    /// are left, or asks again if only unsaved work remains.
+    pub fn update(&mut self, concerns: QuitConcerns) {
+        if !self.waiting {
+            return;
+        }
+        if concerns.is_empty() {
+            self.quit_now();
+        } else if concerns.running_count() == 0 {
+            self.waiting = false;
+            self.open_modal(concerns);
+        } else if concerns != self.concerns {
+            self.concerns = concerns;
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Cancel the run token without asking.
+    pub fn quit_now(&mut self) {
+        self.open = false;
+        self.waiting = false;
+        self.run_token.cancel();
+    }
+
+    /// Close the modal, or stop waiting, and carry on.
+    pub fn dismiss(&mut self) {
+        if self.open || self.waiting {
+            self.open = false;
+            self.waiting = false;
+            self.needs_redraw = true;
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        self.waiting
+    }
+
+    pub fn concerns(&self) -> &QuitConcerns {
+        &self.concerns
+    }
+
+    fn open_modal(&mut self, concerns: QuitConcerns) {
+        self.actions = if concerns.is_vetoed() {
+            vec![QuitAction::Stay]
+        } else if concerns.running_count() > 0 {
+            vec![QuitAction::Quit, QuitAction::Wait, QuitAction::Stay]
+        } else {
+            vec![QuitAction::Quit, QuitAction::Stay]
+        };
+        let locale = Locale::current();
+        let mut buttons = ButtonsWidget::new().with_padding(2);
+        for action in &self.actions {
+            let (label, color) = match action {
+                QuitAction::Quit => (&locale.quit_anyway, Color::Red),
+                QuitAction::Wait => (&locale.wait, Color::Yellow),
+                QuitAction::Stay if concerns.is_vetoed() => (&locale.ok, Color::Green),
+                QuitAction::Stay => (&locale.cancel, Color::Green),
+            };
+            buttons = buttons.add_button(
+                label,
+                Style::default().fg(color),
+                Style::default().fg(Color::Black).bg(color),
+            );
+        }
+        // Default to the harmless choice
+        buttons.set_selected(self.actions.len() - 1);
+        buttons.focus();
+        self.buttons = buttons;
+        self.concerns = concerns;
+        self.open = true;
+        self.needs_redraw = true;
+    }
+
+    fn perform(&mut self, action: QuitAction) {
+        match action {
+            QuitAction::Quit => self.quit_now(),
+            QuitAction::Wait => {
+                self.open = false;
+                self.waiting = true;
+                self.needs_redraw = true;
+            }
+            QuitAction::Stay => self.dismiss(),
+        }
+    }
+
+    fn has_action(&self, action: QuitAction) -> bool {
+        self.actions.contains(&action)
+    }
+
+    fn draw_modal(&mut self, area: Rect, buf: &mut Buffer) {
+        let locale = Locale::current();
+        let bullet = GlyphSet::current().pick("•", "-");
+        let mut lines = vec![Line::from(self.concerns.summary()), Line::default()];
+        for concern in self.concerns.iter() {
+            let (what, style) = match concern {
+                QuitConcern::Unsaved(what) => (what, Style::default().fg(tui_theme::WARNING_FG)),
+                QuitConcern::Running(what) => (what, Style::default().fg(tui_theme::ACTIVE_FG)),
+                QuitConcern::Veto(what) => (what, Style::default().fg(tui_theme::ERROR_FG)),
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!(" {bullet} ")),
+                Span::styled(what.as_str(), style),
+            ]));
+        }
+        if !self.concerns.is_vetoed() {
+            lines.push(Line::default());
+            lines.push(Line::from(locale.quit_prompt.as_str()));
+        }
+
+        let text_width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let width = (text_width + 4).max(30).min(area.width);
+        let height = (lines.len() as u16 + 4).min(area.height);
+        let modal = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width: width.min(area.width),
+            height,
+        };
+
+        Clear.render(modal, buf);
+        let block = Block::default()
+            .title(format!(" {} ", locale.quit_title))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(tui_theme::WARNING_FG));
+        let inner = block.inner(modal);
+        block.render(modal, buf);
+
+        let text_area = Rect {
+            x: inner.x + 1,
+            width: inner.width.saturating_sub(2),
+            height: inner.height.saturating_sub(2),
+            ..inner
+        };
+        Paragraph::new(lines).render(text_area, buf);
+        if inner.height > 0 {
+            self.buttons.draw(
+                Rect {
+                    y: inner.bottom() - 1,
+                    height: 1,
+                    ..inner
+                },
+                buf,
+            );
+        }
+    }
+
+    fn draw_waiting(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+        let jobs = match self.concerns.running_count() {
+            1 => "1 running job finishes".to_string(),
+            n => format!("{n} running jobs finish"),
+        };
+        let row = Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..area
+        };
+        let style = Style::default()
+            .fg(tui_theme::TOOLTIP_FG)
+            .bg(tui_theme::WARNING_FG);
+        Clear.render(row, buf);
+        Line::from(format!(" Quitting once {jobs} (Esc to stay) "))
+            .style(style)
+            .centered()
+            .render(row, buf);
+    }
+}
+
+impl TuiWidget for QuitGuard {
+    fn need_draw(&self) -> bool {
+        self.needs_redraw || self.open || self.waiting
+    }
+
+    fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        if self.open {
+            self.draw_modal(area, buf);
+        } else if self.waiting {
+            self.draw_waiting(area, buf);
+        }
+        self.needs_redraw = false;
+    }
+
+    fn key_event(&mut self, key: KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return self.open;
+        }
+        if self.waiting {
+            if key.code == KeyCode::Esc {
+                self.dismiss();
+                return true;
+            }
+            return false;
+        }
+        if !self.open {
+            return false;
+        }
+
+        match key.code {
+            // Asking to quit again while asked means it
+            KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if self.has_action(QuitAction::Quit) {
+                    self.quit_now();
+                }
+            }
+            KeyCode::Char('y') if self.has_action(QuitAction::Quit) => self.quit_now(),
+            KeyCode::Char('w') if self.has_action(QuitAction::Wait) => {
+                self.perform(QuitAction::Wait)
+            }
+            KeyCode::Char('n') | KeyCode::Esc => self.dismiss(),
+            KeyCode::Enter => {
+                let action = self.actions[self.buttons.selected()];
+                self.perform(action);
+            }
+            KeyCode::Tab => self.buttons.next_button(),
+            KeyCode::BackTab => self.buttons.prev_button(),
+            _ => {
+                self.buttons.key_event(key);
+            }
+        }
+        self.needs_redraw = true;
+        // Modal: nothing gets past it
+        true
+    }
+
+    fn focus(&mut self) {}
+
+    fn unfocus(&mut self) {}
+
+    fn is_focused(&self) -> bool {
+        self.open
+    }
+}
@@ -1,32 +1,100 @@
 // tokio-tui/src/tui/tui_app.rs
 use anyhow::Result;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyEvent, MouseEvent},
+    cursor::Show,
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, prelude::CrosstermBackend};
 use std::{
+    future::{Future, pending},
     io::stdout,
+    pin::pin,
+    sync::Once,
     time::{Duration, Instant},
 };
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+};
+use tokio_util::sync::CancellationToken;
 
-use crate::tui::input_backend::InputHandler;
+use crate::tui::{
+    FrameBudget, GlyphSet, InteractionConfig, Locale, QuitConcerns,
+    input_backend::{Coalesced, InputBackendOpts, InputHandler},
+};
 
 pub trait TuiApp {
     fn render(&mut self, frame: &mut TerminalFrame);
     #[allow(unused)]
     fn handle_mouse_events(&mut self, mouse_events: Vec<MouseEvent>) {}
     fn handle_key_events(&mut self, keys_events: Vec<KeyEvent>);
+    /// Receives key events with runs of identical keys collapsed. Override to
+    /// handle repeats in one step; by default they are expanded again.
+    fn handle_coalesced_key_events(&mut self, key_events: Vec<Coalesced<KeyEvent>>) {
+        self.handle_key_events(Coalesced::expand(key_events));
+    }
+    /// Receives mouse events with wheel bursts collapsed. Override to scroll
+    /// by `count` at once; by default they are expanded again.
+    fn handle_coalesced_mouse_events(&mut self, mouse_events: Vec<Coalesced<MouseEvent>>) {
+        self.handle_mouse_events(Coalesced::expand(mouse_events));
+    }
+    /// Receives a bracketed paste as a single string, after the keys typed
+    /// before it. By default it is fed through as key presses, with any
+    /// newline kept by [`PasteNewlines`](crate::PasteNewlines) sent as
+    /// Enter.
+    fn handle_paste(&mut self, text: String) {
+        let keys = text
+            .chars()
+            .map(|ch| match ch {
+                '\n' => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+                ch => KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE),
+            })
+            .collect();
+        self.handle_key_events(keys);
+    }
     fn before_frame(&mut self, #[allow(unused)] terminal: &TerminalBackend) {}
     fn after_frame(&mut self, #[allow(unused)] terminal: &TerminalBackend) {}
-    fn should_quit(&self) -> bool;
+    /// Checked every frame; the run also ends once the
+    /// [`Tui`]'s run token is cancelled.
+    fn should_quit(&self) -> bool {
+        false
+    }
     fn should_draw(&mut self) -> bool {
         true
     }
     fn quit_requested(&mut self) {}
+    /// Asked when [`Tui::run_until`]'s signal fires or the quit key is
+    /// pressed. Return false to keep running, e.g. after opening a
+    /// [`QuitGuard`](crate::QuitGuard) that cancels the run token itself
+    /// once the user agrees.
+    fn confirm_quit(&mut self) -> bool {
+        true
+    }
+    /// Shorthand for `Tui::new()?.run_until(self, signal)`.
+    fn run_until<F>(self, signal: F) -> Result<Self>
+    where
+        Self: Sized,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Tui::new()?.run_until(self, signal)
+    }
+    /// Where to show the terminal's own cursor after [`render`](Self::render),
+    /// usually the focused widget's
+    /// [`TuiWidget::cursor_position`]. IMEs place their candidate window
+    /// there and screen readers follow it; `None` keeps it hidden.
+    fn cursor_position(&self) -> Option<Position> {
+        None
+    }
 }
-pub use ratatui::{buffer::Buffer, layout::Rect};
+pub use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+};
 
 // Widget trait that all renderable components must implement
 pub trait TuiWidget: Send + Sync {
@@ -37,6 +105,11 @@ pub trait TuiWidget: Send + Sync {
     fn mouse_event(&mut self, event: MouseEvent) -> bool {
         false
     }
+    /// Bracketed paste text, to insert in one edit. Return true if handled.
+    #[allow(unused)]
+    fn paste_event(&mut self, text: &str) -> bool {
+        false
+    }
     fn focus(&mut self);
     fn unfocus(&mut self);
     fn is_focused(&self) -> bool;
@@ -46,6 +119,14 @@ pub trait TuiWidget: Send + Sync {
     fn need_visibility(&self) -> Option<bool> {
         None
     }
+    /// Screen cell of the text cursor as of the last draw, while focused.
+    fn cursor_position(&self) -> Option<Position> {
+        None
+    }
+    /// Report anything that should hold up quitting, for a
+    /// [`QuitGuard`](crate::QuitGuard) to confirm.
+    #[allow(unused)]
+    fn quit_concerns(&self, concerns: &mut QuitConcerns) {}
 }
 
 pub type TerminalBackend = ratatui::DefaultTerminal;
@@ -56,6 +137,11 @@ pub struct Tui {
     key_handler: Option<InputHandler>,
     frame_sync: bool,
     frame_length: Duration,
+    frame_budget: Option<FrameBudget>,
+    run_token: CancellationToken,
+    quit_key: Option<KeyEvent>,
+    ctrl_c_quit: bool,
+    interaction: Option<InteractionConfig>,
 }
 
 impl Tui {
@@ -64,9 +150,43 @@ impl Tui {
             key_handler: Some(InputHandler::new()),
             frame_sync: true,
             frame_length: DEFAULT_FRAME_TIME,
+            frame_budget: None,
+            run_token: CancellationToken::new(),
+            quit_key: None,
+            ctrl_c_quit: false,
+            interaction: None,
         })
     }
 
+    /// Replace the input backend options (coalescing, paste handling, ...).
+    pub fn with_input_opts(mut self, opts: InputBackendOpts) -> Self {
+        if self.key_handler.is_some() {
+            self.key_handler = Some(InputHandler::with_opts(opts));
+        }
+        self
+    }
+
+    /// Interaction timing (drag-scroll, key chords, ...) for the widgets
+    /// this `Tui` runs.
+    pub fn with_interaction(mut self, config: InteractionConfig) -> Self {
+        self.interaction = Some(config);
+        self
+    }
+
+    /// Restrict which symbols widgets draw, e.g. to plain ASCII on
+    /// terminals without a suitable font. See [`GlyphSet::from_env`].
+    pub fn with_glyphs(self, glyphs: GlyphSet) -> Self {
+        glyphs.install();
+        self
+    }
+
+    /// Translate the labels widgets draw on their own and set number and
+    /// date formats. See [`Locale`].
+    pub fn with_locale(self, locale: Locale) -> Self {
+        locale.install();
+        self
+    }
+
     pub fn without_key_capture(mut self) -> Self {
         self.key_handler = None;
         self
@@ -82,47 +202,177 @@ impl Tui {
         self
     }
 
-    pub fn run<A: TuiApp>(mut self, mut app: A) -> Result<A> {
-        // Set up the terminal
-        enable_raw_mode()?;
-        execute!(
-            stdout(),
-            EnterAlternateScreen,
-            EnableMouseCapture // Enable mouse events
-        )?;
+    /// Degrade expensive rendering when frames keep exceeding `budget`.
+    pub fn with_frame_budget(mut self, budget: FrameBudget) -> Self {
+        budget.install();
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Quit when this token is cancelled, whatever the app's
+    /// [`should_quit`](TuiApp::should_quit) says. Share it with the app's
+    /// background tasks so they stop along with it.
+    pub fn with_run_token(mut self, run_token: CancellationToken) -> Self {
+        self.run_token = run_token;
+        self
+    }
+
+    pub fn run_token(&self) -> &CancellationToken {
+        &self.run_token
+    }
+
+    /// Treat presses of `key` (e.g. Ctrl+Q) as a request to quit, asking
+    /// [`TuiApp::confirm_quit`] first. The app never sees the key.
+    pub fn with_quit_key(mut self, key: KeyEvent) -> Self {
+        self.quit_key = Some(key);
+        self
+    }
+
+    /// Treat Ctrl+C as a request to quit, as SIGINT would be outside raw
+    /// mode. Off by default, since widgets bind Ctrl+C to copy; when on,
+    /// the app never sees the key.
+    pub fn with_ctrl_c_quit(mut self) -> Self {
+        self.ctrl_c_quit = true;
+        self
+    }
+
+    /// Run `app` until it quits. The terminal is restored however the run
+    /// ends, including by an error or a panic.
+    pub fn run<A: TuiApp>(self, app: A) -> Result<A> {
+        self.run_with(app, None)
+    }
+
+    /// Like [`run`](Self::run), but also ask the app to quit when `signal`
+    /// completes and on SIGINT or SIGTERM. The app's
+    /// [`confirm_quit`](TuiApp::confirm_quit) decides; when it agrees the
+    /// run token is cancelled. The terminal is in raw mode while the app
+    /// runs, so Ctrl+C arrives as a key press rather than SIGINT; see
+    /// [`with_ctrl_c_quit`](Self::with_ctrl_c_quit).
+    ///
+    /// Must be called from within a tokio runtime. The run blocks the
+    /// calling thread, so on a current-thread runtime `signal` is polled on
+    /// a thread and runtime of its own; it mustn't depend on resources of
+    /// the caller's runtime there.
+    ///
+    /// ```ignore
+    /// let run_token = CancellationToken::new();
+    /// let app = MyApp::new(run_token.clone());
+    /// Tui::new()?
+    ///     .with_run_token(run_token)
+    ///     .with_quit_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL))
+    ///     .run_until(app, std::future::pending())?;
+    /// ```
+    pub fn run_until<A, F>(self, app: A, signal: F) -> Result<A>
+    where
+        A: TuiApp,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (quit_tx, mut quit_rx) = unbounded_channel();
+        let stop = CancellationToken::new();
+        let watcher = watch_quit_signals(signal, quit_tx, stop.clone());
+        if Handle::current().runtime_flavor() == RuntimeFlavor::CurrentThread {
+            // The event loop blocks the runtime's only thread
+            std::thread::spawn(move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build();
+                match runtime {
+                    Ok(runtime) => runtime.block_on(watcher),
+                    Err(error) => tracing::warn!("can't watch for quit signals: {error}"),
+                }
+            });
+        } else {
+            tokio::spawn(watcher);
+        }
+        let result = self.run_with(app, Some(&mut quit_rx));
+        stop.cancel();
+        result
+    }
+
+    fn run_with<A: TuiApp>(
+        mut self,
+        mut app: A,
+        quit_requests: Option<&mut UnboundedReceiver<()>>,
+    ) -> Result<A> {
+        install_panic_hook();
+        let terminal_guard = TerminalGuard::enter()?;
         let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
         // Start the key handler if we have one
         if let Some(handler) = &mut self.key_handler {
             handler.start()?;
         }
+        let result = self.event_loop(&mut app, &mut terminal, quit_requests);
+
+        // Stop the key handler if we have one
+        if let Some(handler) = &mut self.key_handler {
+            handler.stop();
+        }
+        drop(terminal_guard);
+
+        result.map(|()| app)
+    }
+
+    fn event_loop<A: TuiApp>(
+        &mut self,
+        app: &mut A,
+        terminal: &mut TerminalBackend,
+        mut quit_requests: Option<&mut UnboundedReceiver<()>>,
+    ) -> Result<()> {
         let mut last_width = 0u16;
         let mut last_height = 0u16;
+        let _interaction = InteractionConfig::enter(self.interaction);
         // Main event loop
         loop {
             let frame_start = Instant::now();
 
             // Check if we should quit
-            if app.should_quit() {
+            if app.should_quit() || self.run_token.is_cancelled() {
                 break;
             }
 
             // Pre-frame processing
-            app.before_frame(&terminal);
+            app.before_frame(terminal);
+
+            let mut quit_requested = false;
+            if let Some(rx) = quit_requests.as_deref_mut() {
+                while rx.try_recv().is_ok() {
+                    quit_requested = true;
+                }
+            }
 
             // Process key events from handler if any
             if let Some(handler) = &mut self.key_handler {
                 // Poll for new keys if needed (non-threaded handlers)
 
                 // Process any available keys
-                if let Some((key_events, mouse_events)) = handler.flush_events() {
-                    if let Some(events) = key_events {
-                        app.handle_key_events(events);
+                if let Some((key_events, mouse_events, paste)) = handler.flush_events() {
+                    if let Some(mut events) = key_events {
+                        let ctrl_c = self
+                            .ctrl_c_quit
+                            .then(|| KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+                        for quit_key in [self.quit_key, ctrl_c].into_iter().flatten() {
+                            let before = events.len();
+                            events.retain(|key| !is_quit_key(&key.event, &quit_key));
+                            quit_requested |= events.len() != before;
+                        }
+                        if !events.is_empty() {
+                            app.handle_coalesced_key_events(events);
+                        }
                     }
                     if let Some(events) = mouse_events {
-                        app.handle_mouse_events(events);
+                        app.handle_coalesced_mouse_events(events);
+                    }
+                    if let Some(text) = paste {
+                        app.handle_paste(text);
                     }
                 }
             }
+
+            if quit_requested && app.confirm_quit() {
+                self.run_token.cancel();
+                break;
+            }
+
             let frame_size = terminal
                 .size()
                 .unwrap_or_else(|_| ratatui::layout::Size::new(last_width, last_height));
@@ -133,11 +383,20 @@ impl Tui {
                 last_height = frame_size.height;
 
                 // Render the UI
-                terminal.draw(|frame| app.render(frame))?;
+                terminal.draw(|frame| {
+                    app.render(frame);
+                    if let Some(position) = app.cursor_position() {
+                        frame.set_cursor_position(position);
+                    }
+                })?;
             }
 
             // Post-frame processing
-            app.after_frame(&terminal);
+            app.after_frame(terminal);
+
+            if let Some(budget) = &self.frame_budget {
+                budget.record(frame_start.elapsed());
+            }
 
             if self.frame_sync {
                 // If we processed the frame too quickly, sleep for the remainder of the frame time
@@ -147,20 +406,104 @@ impl Tui {
                 }
             }
         }
+        Ok(())
+    }
+}
 
-        // Stop the key handler if we have one
-        if let Some(handler) = &mut self.key_handler {
-            handler.stop();
+fn is_quit_key(key: &KeyEvent, quit_key: &KeyEvent) -> bool {
+    key.kind == KeyEventKind::Press
+        && key.code == quit_key.code
+        && key.modifiers == quit_key.modifiers
+}
+
+// Forward every quit signal until the run is over and `stop` cancelled.
+// `signal` counts once; SIGINT and SIGTERM may come again.
+async fn watch_quit_signals<F: Future<Output = ()>>(
+    signal: F,
+    quit_tx: UnboundedSender<()>,
+    stop: CancellationToken,
+) {
+    let mut signal = pin!(signal);
+    let mut signal_done = false;
+    loop {
+        tokio::select! {
+            () = stop.cancelled() => break,
+            () = &mut signal, if !signal_done => signal_done = true,
+            () = interrupted() => {}
+            () = terminated() => {}
         }
+        if quit_tx.send(()).is_err() {
+            break;
+        }
+    }
+}
+
+async fn interrupted() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        pending::<()>().await;
+    }
+}
+
+#[cfg(unix)]
+async fn terminated() {
+    use tokio::signal::unix::{SignalKind, signal};
+    match signal(SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            sigterm.recv().await;
+        }
+        Err(_) => pending::<()>().await,
+    }
+}
+
+#[cfg(not(unix))]
+async fn terminated() {
+    pending::<()>().await
+}
+
+// Raw mode and the alternate screen for as long as it lives.
+struct TerminalGuard;
 
-        // Clean up the terminal
-        disable_raw_mode()?;
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        // Created first so that a half-done setup is undone too
+        let guard = TerminalGuard;
+        enable_raw_mode()?;
         execute!(
-            terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture // Disable mouse capture when done
+            stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture, // Enable mouse events
+            EnableBracketedPaste
         )?;
+        Ok(guard)
+    }
+}
 
-        Ok(app)
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
     }
 }
+
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture, // Disable mouse capture when done
+        DisableBracketedPaste,
+        Show
+    );
+}
+
+// Restore the terminal before the panic message is printed, so it isn't
+// lost on the alternate screen.
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore_terminal();
+            previous(info);
+        }));
+    });
+}
@@ -0,0 +1,116 @@
+// tokio-tui/src/tui/widget_config.rs
+use ratatui::style::Style;
+use tokio::sync::watch;
+
+use crate::AnsiPalette;
+
+/// Settings that can be changed on a live widget. Every field is optional:
+/// `None` leaves the widget's own setting alone, and each widget ignores
+/// the fields that don't apply to it.
+///
+/// Send new values through a [`watch`] channel attached with e.g.
+/// [`ScrollbackWidget::watch_config`](crate::ScrollbackWidget::watch_config)
+/// to change widgets while the app runs, from a settings form or a file
+/// watcher, without rebuilding them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WidgetConfig {
+    /// Lines a scrollback keeps; older lines are dropped when it shrinks.
+    pub capacity: Option<usize>,
+    pub wrap_lines: Option<bool>,
+    pub wrap_indent: Option<usize>,
+    /// Base style of tab titles and status lines.
+    pub style: Option<Style>,
+    /// Style of the selected tab.
+    pub highlight_style: Option<Style>,
+    /// Background of every other scrollback line.
+    pub zebra_stripes: Option<Style>,
+    pub ansi_palette: Option<AnsiPalette>,
+    /// Reported through [`TuiWidget::need_visibility`](crate::TuiWidget::need_visibility);
+    /// hidden widgets also skip drawing.
+    pub visible: Option<bool>,
+}
+
+impl WidgetConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    pub fn with_wrap_lines(mut self, wrap: bool) -> Self {
+        self.wrap_lines = Some(wrap);
+        self
+    }
+
+    pub fn with_wrap_indent(mut self, indent: usize) -> Self {
+        self.wrap_indent = Some(indent);
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn with_highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    pub fn with_zebra_stripes(mut self, style: Style) -> Self {
+        self.zebra_stripes = Some(style);
+        self
+    }
+
+    pub fn with_ansi_palette(mut self, palette: AnsiPalette) -> Self {
+        self.ansi_palette = Some(palette);
+        self
+    }
+
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+}
+
+/// Widgets whose settings a [`WidgetConfig`] can change.
+pub trait ApplyConfig {
+    fn apply_config(&mut self, config: &WidgetConfig);
+}
+
+/// The receiving end of a [`WidgetConfig`] channel. Widgets that take one
+/// poll it as they preprocess; for others, call [`apply_to`](Self::apply_to)
+/// once per frame.
+#[derive(Debug)]
+pub struct ConfigWatch {
+    rx: watch::Receiver<WidgetConfig>,
+}
+
+impl ConfigWatch {
+    /// The current value counts as a change, so the first
+    /// [`apply_to`](Self::apply_to) applies it.
+    pub fn new(mut rx: watch::Receiver<WidgetConfig>) -> Self {
+        rx.mark_changed();
+        Self { rx }
+    }
+
+    /// Apply the latest config to `widget` if it changed since the last
+    /// call. Once the sender is gone the last config simply stays.
+    pub fn apply_to(&mut self, widget: &mut impl ApplyConfig) -> bool {
+        if !self.rx.has_changed().unwrap_or(false) {
+            return false;
+        }
+        let config = self.rx.borrow_and_update().clone();
+        widget.apply_config(&config);
+        true
+    }
+}
+
+impl From<watch::Receiver<WidgetConfig>> for ConfigWatch {
+    fn from(rx: watch::Receiver<WidgetConfig>) -> Self {
+        Self::new(rx)
+    }
+}
@@ -5,6 +5,14 @@ use ratatui::style::Color;
 pub const THUMB_SYMBOL: &str = "▃";
 #[cfg(not(windows))]
 pub const THUMB_SYMBOL: &str = "🬋";
+pub const THUMB_SYMBOL_ASCII: &str = "=";
+pub const V_THUMB_SYMBOL: &str = "█";
+pub const V_TRACK_SYMBOL_ASCII: &str = "|";
+pub const H_TRACK_SYMBOL_ASCII: &str = "-";
+pub const MARKER_SYMBOL: &str = "┃";
+pub const MARKER_SYMBOL_ASCII: &str = "#";
+pub const OVERFLOW_LEFT_SYMBOL: &str = "<";
+pub const OVERFLOW_RIGHT_SYMBOL: &str = ">";
 
 pub const BORDER_DEFAULT: Color = Color::Rgb(100, 100, 100);
 pub const SCROLLBAR_DEFAULT: Color = Color::Rgb(200, 200, 200);
@@ -12,8 +20,14 @@ pub const BORDER_RESET: Color = Color::Rgb(101, 101, 101);
 pub const BORDER_FOCUSED: Color = Color::Yellow;
 pub const BORDER_ACTIVE: Color = Color::White;
 pub const BORDER_UNFOCUSED: Color = Color::Rgb(70, 70, 70);
+pub const FOCUS_RETURN_FG: Color = Color::LightCyan;
 pub const SEARCH_HIGHLIGHT_COLOR: Color = Color::Rgb(240, 180, 0);
 pub const CURRENT_MATCH_COLOR: Color = Color::Rgb(255, 100, 0);
+pub const PINNED_MATCH_FG: Color = Color::Black;
+pub const MARKER_ERROR_FG: Color = Color::Red;
+pub const MARKER_BOOKMARK_FG: Color = Color::Cyan;
+pub const MARKER_SEARCH_FG: Color = Color::Yellow;
+pub const PINNED_MATCH_BGS: [Color; 4] = [Color::Cyan, COLOR_LIME, COLOR_PINK, Color::LightBlue];
 
 pub const COLOR_ORANGE: Color = Color::Rgb(255, 165, 0);
 pub const COLOR_PURPLE: Color = Color::Rgb(128, 0, 128);
@@ -34,6 +48,13 @@ pub const SELECTED_FG: Color = Color::Black;
 pub const SELECTED_BG: Color = Color::Yellow;
 pub const UNFOCUSED_FG: Color = Color::Rgb(170, 170, 170);
 pub const HINT_FG: Color = Color::Rgb(70, 70, 70);
+pub const TOOLTIP_FG: Color = Color::Black;
+pub const TOOLTIP_BG: Color = Color::Rgb(200, 200, 200);
+pub const PAUSED_FG: Color = COLOR_ORANGE;
+pub const WARNING_FG: Color = COLOR_ORANGE;
+pub const ERROR_FG: Color = Color::Red;
+pub const ZEBRA_BG: Color = Color::Rgb(28, 28, 28);
+pub const FOCUSED_ROW_BG: Color = Color::Rgb(40, 40, 64);
 
 const HOUR: u8 = 120;
 const MINUTE: u8 = 150;
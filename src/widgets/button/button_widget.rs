@@ -91,6 +91,25 @@ impl ButtonsWidget {
         self
     }
 
+    /// Change the text of the button at `index`
+    pub fn set_label(&mut self, index: usize, text: impl AsRef<str>) {
+        if let Some((label, _, _)) = self.buttons.get_mut(index)
+            && label != text.as_ref()
+        {
+            *label = text.as_ref().to_string();
+        }
+    }
+
+    /// Width of the whole row of buttons
+    pub fn width(&self) -> u16 {
+        let labels: u16 = self
+            .buttons
+            .iter()
+            .map(|(text, _, _)| text.chars().count() as u16 + 2)
+            .sum();
+        labels + self.padding * (self.buttons.len() as u16).saturating_sub(1)
+    }
+
     /// Get the number of buttons
     pub fn button_count(&self) -> usize {
         self.buttons.len()
@@ -148,7 +167,7 @@ impl TuiWidget for ButtonsWidget {
         let button_widths: Vec<u16> = self
             .buttons
             .iter()
-            .map(|(text, _, _)| text.len() as u16 + 2) // +2 for padding inside button
+            .map(|(text, _, _)| text.chars().count() as u16 + 2) // +2 for padding inside button
             .collect();
 
         let total_width: u16 =
@@ -1,8 +1,16 @@
 // tokio-tui/src/widgets/form/form_data.rs
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
 
-use super::{FormFieldType, FormFieldWidget, FormWidget};
+use crate::Locale;
+
+use super::{
+    FieldEditor, FieldUnit, FormFieldType, FormFieldWidget, FormWidget, SliderRange, parse_duration,
+};
 
 /// Trait representing a field value that can be used in a form
 pub trait FormValue: Clone {
@@ -22,6 +30,8 @@ impl FormValue for String {
     fn from_field_widget(field: &FormFieldWidget) -> Self {
         match &field.inner {
             FormFieldType::Text(text_field) => text_field.value.clone(),
+            FormFieldType::Password(password_field) => password_field.value.clone(),
+            FormFieldType::Path(path_field) => path_field.path.to_string_lossy().into_owned(),
             _ => String::new(), // Fallback
         }
     }
@@ -41,6 +51,173 @@ impl FormValue for Vec<String> {
     }
 }
 
+/// Implementation for HashMap<String, String> values (map fields, sorted by
+/// key)
+impl FormValue for HashMap<String, String> {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        let mut entries: Vec<(String, String)> = self.clone().into_iter().collect();
+        entries.sort();
+        FormFieldWidget::map(label, entries, required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Map(map_field) => map_field.entries.iter().cloned().collect(),
+            _ => HashMap::new(), // Fallback
+        }
+    }
+}
+
+/// Implementation for BTreeMap<String, String> values (map fields)
+impl FormValue for BTreeMap<String, String> {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::map(label, self.clone(), required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Map(map_field) => map_field.entries.iter().cloned().collect(),
+            _ => BTreeMap::new(), // Fallback
+        }
+    }
+}
+
+/// Implementation for bool values (checkbox fields)
+impl FormValue for bool {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::checkbox(label, *self, required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Checkbox(checkbox) => checkbox.checked,
+            _ => false, // Fallback
+        }
+    }
+}
+
+/// Implementation for PathBuf values (path fields)
+impl FormValue for PathBuf {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::path(label, self.clone(), required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Path(path_field) => path_field.path.clone(),
+            _ => PathBuf::new(), // Fallback
+        }
+    }
+}
+
+/// Implementation for Duration values (duration fields, to the millisecond)
+impl FormValue for Duration {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::duration(label, *self, required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Text(text_field) => {
+                parse_duration(&text_field.value).unwrap_or_default()
+            }
+            _ => Duration::ZERO, // Fallback
+        }
+    }
+}
+
+/// Implementation for IpAddr values (IP address fields)
+impl FormValue for IpAddr {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::ip_addr(label, self.to_string(), required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Text(text_field) => text_field.value.trim().parse().ok(),
+            _ => None,
+        }
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED)) // Fallback
+    }
+}
+
+/// Implementation for SocketAddr values (IP address and port fields)
+impl FormValue for SocketAddr {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        FormFieldWidget::socket_addr(label, self.to_string(), required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::Text(text_field) => text_field.value.trim().parse().ok(),
+            _ => None,
+        }
+        .unwrap_or(SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)) // Fallback
+    }
+}
+
+/// Implementation for Option values (fields that can be left unset)
+impl<T: FormValue + Default> FormValue for Option<T> {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        let value = self.clone().unwrap_or_default();
+        value
+            .to_field_widget(label, required)
+            .with_unset(self.is_none())
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        (!field.is_unset()).then(|| T::from_field_widget(field))
+    }
+}
+
+/// Implementations for integer values (exact number fields limited to the
+/// type's range)
+macro_rules! integer_form_value {
+    ($($ty:ty),*) => {$(
+        impl FormValue for $ty {
+            fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+                FormFieldWidget::integer(label, *self as i128, required)
+                    .with_integer_range(<$ty>::MIN as i128, <$ty>::MAX as i128)
+            }
+
+            fn from_field_widget(field: &FormFieldWidget) -> Self {
+                match &field.inner {
+                    FormFieldType::Number(number_field) => number_field
+                        .integer
+                        .and_then(|value| <$ty>::try_from(value).ok())
+                        .unwrap_or(number_field.value as $ty),
+                    FormFieldType::Slider(slider_field) => slider_field.value as $ty,
+                    _ => 0, // Fallback
+                }
+            }
+        }
+    )*};
+}
+integer_form_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Implementations for floating point values (number fields)
+macro_rules! float_form_value {
+    ($($ty:ty),*) => {$(
+        impl FormValue for $ty {
+            fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+                // Through the shortest decimal form, so an f32 0.1 doesn't
+                // come out as 0.10000000149011612
+                let value = self.to_string().parse().unwrap_or_default();
+                FormFieldWidget::number(label, value, required)
+            }
+
+            fn from_field_widget(field: &FormFieldWidget) -> Self {
+                match &field.inner {
+                    FormFieldType::Number(number_field) => number_field.value as $ty,
+                    FormFieldType::Slider(slider_field) => slider_field.value as $ty,
+                    _ => 0.0, // Fallback
+                }
+            }
+        }
+    )*};
+}
+float_form_value!(f32, f64);
+
 /// Trait for enum types that can be used in select fields
 pub trait EnumFormValue: Clone + PartialEq + Debug {
     /// Get all possible options of this enum
@@ -59,17 +236,67 @@ pub trait EnumFormValue: Clone + PartialEq + Debug {
             .position(|opt| opt.to_string() == self.to_string())
             .unwrap_or(0)
     }
+
+    /// A form of the data this option holds, edited below the select while
+    /// the option is chosen; `None` for an option without data.
+    fn payload_form(&self) -> Option<FormWidget> {
+        None
+    }
+
+    /// This option with its data read back from the form
+    /// [`payload_form`](Self::payload_form) made.
+    fn with_payload(self, _form: &FormWidget) -> Self {
+        self
+    }
 }
 
-/// Implementation for EnumFormValue types
+// Key of the select in the subform of an enum whose options hold data; the
+// data of each option is under the option's name.
+const VARIANT_KEY: &str = "variant";
+
+/// Implementation for EnumFormValue types: a select field, or for options
+/// holding data, a subform of the select and the chosen option's data
 impl<T: EnumFormValue> FormValue for T {
     fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
-        let options = T::all_options()
+        let all = T::all_options();
+        let options = all
             .iter()
             .map(|option| option.to_string())
             .collect::<Vec<_>>();
+        let mut select = FormFieldWidget::select(label, options, self.get_index(), required);
+        if all.iter().all(|option| option.payload_form().is_none()) {
+            return select;
+        }
 
-        FormFieldWidget::select(label, options, self.get_index(), required)
+        select.label = Locale::current().variant.clone();
+        let mut fields = vec![(VARIANT_KEY.to_string(), select)];
+        let chosen = self.to_string();
+        for option in all {
+            let name = option.to_string();
+            // The chosen option's own data; the others start from their
+            // defaults
+            let payload = if name == chosen {
+                self.payload_form()
+            } else {
+                option.payload_form()
+            };
+            let Some(form) = payload else {
+                continue;
+            };
+            let shown = name.clone();
+            let field =
+                FormFieldWidget::subform(name.as_str(), form, true).with_show_if(move |values| {
+                    values
+                        .get(VARIANT_KEY)
+                        .is_some_and(|chosen| chosen == shown)
+                });
+            fields.push((name, field));
+        }
+        FormFieldWidget::subform(
+            label,
+            FormWidget::new_nested().with_fields(fields),
+            required,
+        )
     }
 
     fn from_field_widget(field: &FormFieldWidget) -> Self {
@@ -81,17 +308,101 @@ impl<T: EnumFormValue> FormValue for T {
                 // Fallback to first option
                 T::all_options().first().unwrap().clone()
             }
+            FormFieldType::SubForm(subform) => {
+                let form = &subform.form_widget;
+                let option = form
+                    .get_field(VARIANT_KEY)
+                    .and_then(|select| T::from_string(&select.get_value_as_string()))
+                    .unwrap_or_else(|| T::all_options().first().unwrap().clone());
+                match form.get_field(&option.to_string()).map(|data| &data.inner) {
+                    Some(FormFieldType::SubForm(data)) => option.with_payload(&data.form_widget),
+                    _ => option,
+                }
+            }
             _ => T::all_options().first().unwrap().clone(), // Fallback
         }
     }
 }
 
+/// Implementation for Vec<EnumFormValue> values (multi-select fields)
+impl<T: EnumFormValue> FormValue for Vec<T> {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        let options = T::all_options()
+            .iter()
+            .map(|option| option.to_string())
+            .collect::<Vec<_>>();
+        // By name, so a variant holding data still matches its option
+        let chosen = self
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        let checked = options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| chosen.contains(option))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        FormFieldWidget::multi_select(label, options, checked, required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        match &field.inner {
+            FormFieldType::MultiSelect(multi_select) => {
+                let all = T::all_options();
+                multi_select
+                    .checked_indices()
+                    .into_iter()
+                    .filter_map(|idx| all.get(idx).cloned())
+                    .collect()
+            }
+            _ => Vec::new(), // Fallback
+        }
+    }
+}
+
+/// Implementation for HashSet<EnumFormValue> values, in option order
+impl<T: EnumFormValue + Eq + Hash> FormValue for HashSet<T> {
+    fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
+        let values: Vec<T> = self.iter().cloned().collect();
+        values.to_field_widget(label, required)
+    }
+
+    fn from_field_widget(field: &FormFieldWidget) -> Self {
+        Vec::<T>::from_field_widget(field).into_iter().collect()
+    }
+}
+
 /// Field metadata for a form data struct
 pub struct FieldMeta {
     pub id: &'static str,
     pub label: &'static str,
     pub required: bool,
     pub help_text: Option<&'static str>,
+    pub unit: Option<FieldUnit>,
+    /// Edit the number with a slider over this range.
+    pub slider: Option<SliderRange>,
+    /// Title of a section starting at this field.
+    pub section: Option<&'static str>,
+    /// Edit the text in this instead of a text field.
+    pub editor: Option<FieldEditor>,
+    /// Hint shown in the field while it's empty.
+    pub placeholder: Option<&'static str>,
+    /// Show the value without letting it be edited.
+    pub readonly: bool,
+}
+
+/// Settings for the form of a form data struct as a whole, from
+/// `#[tui_edit(...)]` on the struct. A form only takes the ones it hasn't
+/// been given already.
+#[derive(Debug, Clone, Default)]
+pub struct FormMeta {
+    /// Title of the form's border.
+    pub title: Option<&'static str>,
+    /// Columns the fields are laid out in.
+    pub columns: Option<u16>,
+    /// Shown on the Submit button instead of the locale's label.
+    pub submit_label: Option<&'static str>,
 }
 
 /// Trait for a struct that can be used as form data
@@ -104,6 +415,18 @@ pub trait FormData: Default + Sized {
 
     /// Create form data from field widgets
     fn from_fields(fields: &HashMap<String, FormFieldWidget>) -> Self;
+
+    /// The data a new form starts from: `Default`, unless fields give their
+    /// own with `#[field(default = ...)]`.
+    fn form_default() -> Self {
+        Self::default()
+    }
+
+    /// Settings for the form as a whole: none, unless the struct gives
+    /// them with `#[tui_edit(...)]`.
+    fn form_meta() -> FormMeta {
+        FormMeta::default()
+    }
 }
 
 // Add trait for nested forms
@@ -0,0 +1,145 @@
+// tokio-tui/src/widgets/form/form_draft.rs
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::sync::mpsc;
+
+use crate::FormWidget;
+
+use super::{FormData, FormFieldWidget, FormFileFormat};
+
+/// A draft interval for [`FormWidget::with_draft`] that loses little typing
+/// to a crash.
+pub const DRAFT_INTERVAL: Duration = Duration::from_secs(5);
+
+type SaveFn = Box<dyn Fn(&FormWidget) -> Result<String> + Send + Sync>;
+type ReadFn = Box<dyn Fn(&str) -> Result<HashMap<String, FormFieldWidget>> + Send + Sync>;
+
+enum DraftCommand {
+    Write(String),
+    Remove,
+}
+
+/// The file a form keeps its unsubmitted changes in, set with
+/// [`FormWidget::with_draft`] so that they aren't lost to a crash. Password
+/// fields are left blank in it.
+pub struct FormDraft {
+    pub path: PathBuf,
+    pub format: FormFileFormat,
+    pub interval: Duration,
+    save: SaveFn,
+    read: ReadFn,
+    // Draft left from last time, until it's restored or discarded
+    found: Option<String>,
+    // What was last written or thrown away, and when the form was last
+    // looked at
+    written: Option<String>,
+    checked_at: Instant,
+    tx: mpsc::UnboundedSender<DraftCommand>,
+}
+
+impl FormDraft {
+    /// Look for a draft left at `path`, and start the task that writes it.
+    pub async fn open<T: FormData + Serialize + DeserializeOwned>(
+        path: impl Into<PathBuf>,
+        interval: Duration,
+    ) -> Self {
+        let path = path.into();
+        let format = FormFileFormat::from_path(&path);
+        let found = tokio::fs::read_to_string(&path).await.ok();
+        Self {
+            tx: spawn_writer(path.clone()),
+            path,
+            format,
+            interval,
+            save: Box::new(move |form| format.write(&form.data_without_secrets::<T>())),
+            read: Box::new(move |text| {
+                let data: T = format.read(text)?;
+                Ok(data.to_fields())
+            }),
+            written: found.clone(),
+            found,
+            checked_at: Instant::now(),
+        }
+    }
+
+    /// Whether a draft from last time is waiting to be restored or
+    /// discarded.
+    pub fn is_found(&self) -> bool {
+        self.found.is_some()
+    }
+
+    // The fields of the draft from last time, which is kept as the current
+    // draft from here on.
+    pub(crate) fn take_found(&mut self) -> Option<Result<HashMap<String, FormFieldWidget>>> {
+        let text = self.found.take()?;
+        self.checked_at = Instant::now();
+        Some((self.read)(&text).with_context(|| format!("parsing {}", self.path.display())))
+    }
+
+    // Write the form's changes once the interval is up, if they changed
+    // since the last write. A form without changes has no draft.
+    pub(crate) fn autosave(&mut self, form: &FormWidget) {
+        if self.found.is_some() || self.checked_at.elapsed() < self.interval {
+            return;
+        }
+        self.checked_at = Instant::now();
+        if !form.is_dirty() {
+            if self.written.is_some() {
+                self.remove();
+            }
+            return;
+        }
+        match (self.save)(form) {
+            Ok(text) if self.written.as_ref() != Some(&text) => {
+                self.written = Some(text.clone());
+                let _ = self.tx.send(DraftCommand::Write(text));
+            }
+            Ok(_) => {}
+            Err(error) => tracing::debug!("draft of {}: {error:#}", self.path.display()),
+        }
+    }
+
+    /// Delete the draft, as a submit or cancel does.
+    pub fn remove(&mut self) {
+        self.found = None;
+        self.written = None;
+        let _ = self.tx.send(DraftCommand::Remove);
+    }
+
+    // Delete the draft of a cancelled form, without writing its changes
+    // again until they change further.
+    pub(crate) fn discard(&mut self, form: &FormWidget) {
+        self.remove();
+        self.written = (self.save)(form).ok();
+    }
+}
+
+// Writes and removes the draft from a background task, in order.
+fn spawn_writer(path: PathBuf) -> mpsc::UnboundedSender<DraftCommand> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(command) = rx.recv().await {
+            let _ = match command {
+                DraftCommand::Write(text) => tokio::fs::write(&path, text).await,
+                DraftCommand::Remove => tokio::fs::remove_file(&path).await,
+            };
+        }
+    });
+    tx
+}
+
+impl std::fmt::Debug for FormDraft {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormDraft")
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
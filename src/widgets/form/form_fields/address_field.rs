@@ -0,0 +1,155 @@
+// tokio-tui/src/widgets/form/form_fields/address_field.rs
+use std::{
+    fmt::Display,
+    net::{IpAddr, SocketAddr},
+};
+
+use crate::Locale;
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Network address a text field's value must be. The field shows what's
+/// wrong with the address beside it, and fails validation until it's
+/// fixed. Set one with [`FormFieldWidget::with_address`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    /// An IPv4 or IPv6 address: `192.168.1.10`, `::1`.
+    Ip,
+    /// An IP address and port: `127.0.0.1:8080`, `[::1]:8080`.
+    Socket,
+    /// A URL with a scheme and host: `https://example.com:8443/api`.
+    Url,
+}
+
+impl FormFieldWidget {
+    /// Creates a text field for an IP address.
+    pub fn ip_addr(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
+        Self::text(label, value, required).with_address(AddressKind::Ip)
+    }
+
+    /// Creates a text field for an IP address and port.
+    pub fn socket_addr(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
+        Self::text(label, value, required).with_address(AddressKind::Socket)
+    }
+
+    /// Creates a text field for a URL.
+    pub fn url(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
+        Self::text(label, value, required).with_address(AddressKind::Url)
+    }
+
+    /// Only accept `kind` of address in the text field; has no effect on
+    /// other field types.
+    pub fn with_address(mut self, kind: AddressKind) -> Self {
+        if let FormFieldType::Text(field) = &mut self.inner {
+            field.address = Some(kind);
+        }
+        self
+    }
+}
+
+impl AddressKind {
+    /// What's wrong with `input` as this kind of address, as a hint on
+    /// fixing it. Surrounding spaces are ignored.
+    pub fn check(&self, input: &str) -> Result<(), String> {
+        let input = input.trim();
+        match self {
+            Self::Ip => check_ip(input),
+            Self::Socket => check_socket(input),
+            Self::Url => check_url(input),
+        }
+    }
+}
+
+// A locale's hint with `{}` filled in.
+fn hint(template: &str, fill: impl Display) -> String {
+    template.replacen("{}", &fill.to_string(), 1)
+}
+
+fn check_ip(input: &str) -> Result<(), String> {
+    if input.parse::<IpAddr>().is_ok() {
+        return Ok(());
+    }
+    let locale = Locale::current();
+    if let Ok(addr) = input.parse::<SocketAddr>() {
+        return Err(hint(&locale.ip_no_port, addr.ip()));
+    }
+    if input.contains(|c: char| c.is_ascii_alphabetic() && !c.is_ascii_hexdigit()) {
+        return Err(locale.ip_not_host.clone());
+    }
+    Err(locale.ip_invalid.clone())
+}
+
+fn check_socket(input: &str) -> Result<(), String> {
+    if input.parse::<SocketAddr>().is_ok() {
+        return Ok(());
+    }
+    let locale = Locale::current();
+    if let Ok(ip) = input.parse::<IpAddr>() {
+        return Err(match ip {
+            IpAddr::V4(ip) => hint(&locale.socket_add_port, format!("{ip}:8080")),
+            IpAddr::V6(ip) => hint(&locale.socket_add_port, format!("[{ip}]:8080")),
+        });
+    }
+    // An IPv6 address needs brackets around it before its port
+    if let Some((host, port)) = input.rsplit_once(':')
+        && host.contains(':')
+        && !host.starts_with('[')
+        && host.parse::<IpAddr>().is_ok()
+    {
+        return Err(hint(&locale.socket_brackets, format!("[{host}]:{port}")));
+    }
+    match input.rsplit_once(':') {
+        Some((_, port)) if port.parse::<u16>().is_err() => Err(locale.port_invalid.clone()),
+        Some((host, _)) if host.parse::<IpAddr>().is_err() && !host.starts_with('[') => {
+            Err(locale.ip_not_host.clone())
+        }
+        _ => Err(locale.socket_invalid.clone()),
+    }
+}
+
+fn check_url(input: &str) -> Result<(), String> {
+    let locale = Locale::current();
+    let Some((scheme, rest)) = input.split_once("://") else {
+        return Err(hint(&locale.url_add_scheme, format!("https://{input}")));
+    };
+    let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !scheme_ok {
+        return Err(hint(&locale.url_bad_scheme, scheme));
+    }
+    if input.contains(char::is_whitespace) {
+        return Err(locale.url_spaces.clone());
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let (host, port) = match host_port.strip_prefix('[') {
+        // An IPv6 host is bracketed, its port after the brackets
+        Some(bracketed) => match bracketed.split_once(']') {
+            Some((ip, after))
+                if ip.parse::<IpAddr>().is_ok() && (after.is_empty() || after.starts_with(':')) =>
+            {
+                (ip, after.strip_prefix(':'))
+            }
+            _ => return Err(locale.url_bad_ipv6.clone()),
+        },
+        None => match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        },
+    };
+    if host.is_empty() {
+        return Err(hint(
+            &locale.url_add_host,
+            format!("{scheme}://example.com"),
+        ));
+    }
+    if port.is_some_and(|port| port.parse::<u16>().is_err()) {
+        return Err(locale.port_invalid.clone());
+    }
+    Ok(())
+}
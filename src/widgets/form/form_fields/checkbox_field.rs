@@ -0,0 +1,92 @@
+// tokio-tui/src/widgets/form/form_fields/checkbox_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use super::{FormFieldType, FormFieldWidget};
+
+#[derive(Debug)]
+pub struct CheckboxField {
+    pub checked: bool,
+    pub active: bool, // Whether focus is on the checkbox
+}
+
+impl FormFieldWidget {
+    /// Creates a checkbox toggled with Space or Enter. It always has a value,
+    /// so `required` only shows in the label; add a validator to insist on
+    /// it being checked.
+    pub fn checkbox(label: impl Into<String>, checked: bool, required: bool) -> Self {
+        Self::new(
+            label,
+            FormFieldType::Checkbox(CheckboxField {
+                checked,
+                active: false,
+            }),
+            required,
+        )
+    }
+}
+
+impl CheckboxField {
+    pub fn get_value(&self) -> String {
+        self.checked.to_string()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        true
+    }
+
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        3
+    }
+
+    // There's no editing mode: the checkbox toggles in place while focus is
+    // on it.
+    pub fn enter(&mut self) {
+        self.active = true;
+    }
+
+    pub fn leave(&mut self) {
+        self.active = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.toggle();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn render(&self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
+        let style = if self.active {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let mark = if self.checked { "[x]" } else { "[ ]" };
+        Paragraph::new(mark).style(style).render(content_area, buf);
+    }
+}
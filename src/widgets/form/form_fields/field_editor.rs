@@ -0,0 +1,32 @@
+// tokio-tui/src/widgets/form/form_fields/field_editor.rs
+use super::{FormFieldType, FormFieldWidget};
+
+/// Editor a text field's value is edited in instead of a plain text field,
+/// e.g. from `#[field(widget = "textarea")]`. Set one with
+/// [`FormFieldWidget::with_editor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldEditor {
+    /// Several lines of text, as [`FormFieldWidget::textarea`].
+    TextArea,
+    /// Masked text, as [`FormFieldWidget::password`].
+    Password,
+    /// A path picked from a file browser, as [`FormFieldWidget::path`].
+    Path,
+}
+
+impl FormFieldWidget {
+    /// Edit the text field's value in `editor` instead, keeping the label,
+    /// help and validators; has no effect on other field types.
+    pub fn with_editor(mut self, editor: FieldEditor) -> Self {
+        let FormFieldType::Text(field) = &self.inner else {
+            return self;
+        };
+        let value = field.value.clone();
+        self.inner = match editor {
+            FieldEditor::TextArea => return self.with_rows(super::TEXTAREA_ROWS),
+            FieldEditor::Password => Self::password("", value, false).inner,
+            FieldEditor::Path => Self::path("", value, false).inner,
+        };
+        self
+    }
+}
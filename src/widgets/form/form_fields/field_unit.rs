@@ -0,0 +1,202 @@
+// tokio-tui/src/widgets/form/form_fields/field_unit.rs
+use std::time::Duration;
+
+use crate::Locale;
+
+/// Unit a text field's value is measured in.
+///
+/// Fields with a unit accept several spellings of the same amount
+/// (`1.5G`, `1536M`, `1610612736`), rewrite the input in a normal form when
+/// editing ends, and show the canonical value next to it. Set one with
+/// [`FormFieldWidget::with_unit`](super::FormFieldWidget::with_unit) or the
+/// `#[field(unit = "...")]` derive attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldUnit {
+    /// Byte sizes with binary multipliers: `512`, `64K`, `1.5G`, `2TiB`.
+    /// Canonical value is a byte count.
+    Bytes,
+    /// Durations built from `ms`, `s`, `m`, `h` and `d` parts: `90s`,
+    /// `1m30s`, `1.5h`. A bare number is seconds. Canonical value is in
+    /// milliseconds.
+    Duration,
+}
+
+const BYTE_UNITS: [(&str, u64); 6] = [
+    ("P", 1 << 50),
+    ("T", 1 << 40),
+    ("G", 1 << 30),
+    ("M", 1 << 20),
+    ("K", 1 << 10),
+    ("B", 1),
+];
+
+const DURATION_UNITS: [(&str, u64); 5] = [
+    ("d", 86_400_000),
+    ("h", 3_600_000),
+    ("m", 60_000),
+    ("s", 1_000),
+    ("ms", 1),
+];
+
+impl FieldUnit {
+    /// Look a unit up by the name used in derive attributes.
+    pub const fn from_name(name: &str) -> Option<Self> {
+        match name.as_bytes() {
+            b"bytes" | b"size" => Some(Self::Bytes),
+            b"duration" => Some(Self::Duration),
+            _ => None,
+        }
+    }
+
+    // The unit named in `#[field(unit = "...")]`. The derive evaluates this
+    // at compile time, so an unknown name fails the build.
+    #[doc(hidden)]
+    pub const fn named(name: &str) -> Self {
+        match Self::from_name(name) {
+            Some(unit) => unit,
+            None => panic!("unknown unit, expected \"bytes\", \"size\" or \"duration\""),
+        }
+    }
+
+    /// Parse `input` to the canonical value.
+    pub fn parse(&self, input: &str) -> Option<u64> {
+        match self {
+            Self::Bytes => parse_bytes(input),
+            Self::Duration => parse_duration(input).map(|d| d.as_millis() as u64),
+        }
+    }
+
+    /// The normal spelling of a canonical value, e.g. `1.5G` or `1h30m`.
+    pub fn format(&self, value: u64) -> String {
+        match self {
+            Self::Bytes => format_bytes(value),
+            Self::Duration => format_duration(Duration::from_millis(value)),
+        }
+    }
+
+    /// The canonical value as shown beside the field.
+    pub fn describe(&self, value: u64) -> String {
+        let locale = Locale::current();
+        match self {
+            Self::Bytes => format!("{} bytes", locale.group_thousands(value)),
+            Self::Duration if value.is_multiple_of(1000) => {
+                format!("{}s", locale.group_thousands(value / 1000))
+            }
+            Self::Duration => format!("{}ms", locale.group_thousands(value)),
+        }
+    }
+
+    /// `input` rewritten in the normal form, if it parses.
+    pub fn normalize(&self, input: &str) -> Option<String> {
+        self.parse(input).map(|value| self.format(value))
+    }
+
+    pub(crate) fn noun(&self) -> &'static str {
+        match self {
+            Self::Bytes => "size",
+            Self::Duration => "duration",
+        }
+    }
+}
+
+/// Parse a byte size such as `1.5G`, `1536M`, `64 KiB` or `4096`. The
+/// [`Locale`]'s decimal separator is accepted as well as `.`.
+pub fn parse_bytes(input: &str) -> Option<u64> {
+    let input = decimal_point(&input.trim().replace('_', ""));
+    let split = input
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    let (number, suffix) = input.split_at(split);
+    let number: f64 = number.trim().parse().ok()?;
+    if !number.is_finite() || number < 0.0 {
+        return None;
+    }
+
+    let suffix = suffix.trim().to_ascii_uppercase();
+    let prefix = suffix
+        .strip_suffix("IB")
+        .or_else(|| suffix.strip_suffix('B').filter(|p| !p.is_empty()))
+        .unwrap_or(&suffix);
+    let multiplier = match prefix {
+        "" | "B" => 1,
+        _ => BYTE_UNITS.iter().find(|(unit, _)| *unit == prefix)?.1,
+    };
+    Some((number * multiplier as f64).round() as u64)
+}
+
+/// Parse a duration such as `90s`, `1m30s`, `1.5h` or `250ms`. A bare
+/// number is taken as seconds. The [`Locale`]'s decimal separator is
+/// accepted as well as `.`.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let input = decimal_point(&input.trim().to_ascii_lowercase().replace([' ', '_'], ""));
+    if input.is_empty() {
+        return None;
+    }
+    if let Ok(secs) = input.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).ok();
+    }
+
+    let mut millis = 0f64;
+    let mut rest = input.as_str();
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_len].parse().ok()?;
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_len];
+        rest = &rest[unit_len..];
+
+        let scale = DURATION_UNITS.iter().find(|(name, _)| *name == unit)?.1;
+        millis += number * scale as f64;
+    }
+    // Too long a duration doesn't fit the milliseconds
+    let millis = millis.round();
+    (millis < u64::MAX as f64).then(|| Duration::from_millis(millis as u64))
+}
+
+// `input` with the locale's decimal separator swapped for `.`.
+fn decimal_point(input: &str) -> String {
+    let separator = Locale::current().decimal_separator;
+    if separator == '.' {
+        input.to_string()
+    } else {
+        input.replace(separator, ".")
+    }
+}
+
+// Largest unit that shows the size exactly with at most two decimals.
+fn format_bytes(bytes: u64) -> String {
+    let point = Locale::current().decimal_separator;
+    for (unit, size) in BYTE_UNITS {
+        if bytes >= size && (bytes as u128 * 100).is_multiple_of(size as u128) {
+            let whole = bytes / size;
+            let hundredths = (bytes % size) as u128 * 100 / size as u128;
+            return match hundredths {
+                0 => format!("{whole}{unit}"),
+                h if h % 10 == 0 => format!("{whole}{point}{}{unit}", h / 10),
+                h => format!("{whole}{point}{h:02}{unit}"),
+            };
+        }
+    }
+    "0B".to_string()
+}
+
+fn format_duration(duration: Duration) -> String {
+    let mut millis = duration.as_millis() as u64;
+    if millis == 0 {
+        return "0s".to_string();
+    }
+    let mut out = String::new();
+    for (unit, size) in DURATION_UNITS {
+        if millis >= size {
+            out.push_str(&format!("{}{unit}", millis / size));
+            millis %= size;
+        }
+    }
+    out
+}
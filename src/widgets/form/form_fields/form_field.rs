@@ -1,55 +1,457 @@
 // tokio-tui/src/widgets/form/form_fields/form_field.rs
+use std::future::Future;
+
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
-    layout::Rect,
-    style::Style,
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent},
+    layout::{Position, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders},
+    widgets::{Block, Borders, Widget as _},
 };
 
-use crate::{tui_theme, TabsWidget};
+use crate::{
+    AsyncFieldValidator, FieldValidator, FieldValues, FormWidget, Locale, PendingCheck, ShowIf,
+    TabsWidget, TuiWidget as _, spinner_frame, tui_theme,
+};
 
-use super::{ListField, SelectFormField, SubFormField, SubFormListField, TextFormField};
+use super::{
+    CheckboxField, ListAction, ListField, MapField, MultiSelectField, NumberField, PasswordField,
+    PathField, SelectFormField, SliderField, SubFormField, SubFormListField, TextFormField,
+};
 
 /// Represents a field in the form with its label and type
-#[derive(Debug)]
 pub struct FormFieldWidget {
     pub label: String,
     pub inner: FormFieldType,
     pub required: bool,
     pub help_text: Option<String>,
     pub is_focused: bool,
+    pub validators: Vec<FieldValidator>,
+    pub async_validator: Option<AsyncFieldValidator>,
+    pub show_if: Option<ShowIf>,
+    // Whether a field that can be left unset is, as an `Option`'s `None`
+    unset: Option<bool>,
+    readonly: bool,
+    // Message from the last validation that failed
+    error: Option<String>,
+    pending_check: Option<PendingCheck>,
+    // Outcome of the last async check, and the value it was for
+    checked: Option<(String, Result<(), String>)>,
 }
 
 #[derive(Debug)]
 pub enum FormFieldType {
     Text(TextFormField),
+    Password(PasswordField),
+    Number(NumberField),
+    Slider(SliderField),
     Select(SelectFormField),
+    MultiSelect(MultiSelectField),
+    Checkbox(CheckboxField),
+    Path(PathField),
     List(ListField),
+    Map(MapField),
     SubForm(SubFormField),         // For 1:1 nested form
     SubFormList(SubFormListField), // For Vec<SubForm>
 }
 
+impl std::fmt::Debug for FormFieldWidget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Async checks hold the value they checked, which for a password is
+        // the password itself
+        let masked = matches!(self.inner, FormFieldType::Password(_));
+        let pending = self
+            .pending_check
+            .as_ref()
+            .map(|pending| if masked { "<masked>" } else { &pending.value });
+        let checked = self.checked.as_ref().map(|(value, result)| {
+            let value = if masked { "<masked>" } else { value.as_str() };
+            (value, result)
+        });
+        f.debug_struct("FormFieldWidget")
+            .field("label", &self.label)
+            .field("inner", &self.inner)
+            .field("required", &self.required)
+            .field("help_text", &self.help_text)
+            .field("is_focused", &self.is_focused)
+            .field("validators", &self.validators)
+            .field("async_validator", &self.async_validator)
+            .field("show_if", &self.show_if)
+            .field("unset", &self.unset)
+            .field("readonly", &self.readonly)
+            .field("error", &self.error)
+            .field("pending_check", &pending)
+            .field("checked", &checked)
+            .finish()
+    }
+}
+
 impl FormFieldWidget {
+    pub fn new(label: impl Into<String>, inner: FormFieldType, required: bool) -> Self {
+        Self {
+            label: label.into(),
+            inner,
+            required,
+            help_text: None,
+            is_focused: false,
+            validators: Vec::new(),
+            async_validator: None,
+            show_if: None,
+            unset: None,
+            readonly: false,
+            error: None,
+            pending_check: None,
+            checked: None,
+        }
+    }
+
     /// Adds help text to this field
     pub fn with_help_text(mut self, text: impl Into<String>) -> Self {
         self.help_text = Some(text.into());
         self
     }
 
+    /// Show `hint` in the text or password field while it's empty, e.g.
+    /// `0.0.0.0:8080`; has no effect on other field types.
+    pub fn with_placeholder(mut self, hint: impl AsRef<str>) -> Self {
+        match &mut self.inner {
+            FormFieldType::Text(field) => field.input_box.set_hint(hint),
+            FormFieldType::Password(field) => field.input_box.set_hint(hint),
+            _ => {}
+        }
+        self
+    }
+
     // In the get_value_as_string method
     pub fn get_value_as_string(&self) -> String {
+        if self.is_unset() {
+            return String::new();
+        }
         self.inner.get_value_as_string()
     }
 
-    // In the is_valid method
+    /// Let the field be left unset, as an `Option` field's `None`, starting
+    /// out `unset` or not. A checkbox before the label shows whether it's
+    /// set; Ctrl+Space flips it, and editing the value sets it.
+    pub fn with_unset(mut self, unset: bool) -> Self {
+        self.unset = Some(unset);
+        self
+    }
+
+    /// Whether the field is left unset; see [`with_unset`](Self::with_unset).
+    pub fn is_unset(&self) -> bool {
+        self.unset == Some(true)
+    }
+
+    /// Set or unset a field that can be left unset; has no effect on other
+    /// fields.
+    pub fn set_unset(&mut self, unset: bool) {
+        if self.unset.is_some() {
+            self.unset = Some(unset);
+        }
+    }
+
+    /// Show the field's value without letting it be edited. It can still
+    /// be focused for its help, and is never checked.
+    pub fn with_readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    pub fn set_readonly(&mut self, readonly: bool) {
+        self.readonly = readonly;
+        if readonly {
+            self.leave();
+        }
+    }
+
+    /// Check the field's value with `check` when the form is validated.
+    /// Optional fields left empty skip their validators.
+    pub fn with_validator(
+        mut self,
+        check: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.add_validator(check);
+        self
+    }
+
+    pub fn add_validator(
+        &mut self,
+        check: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) {
+        self.validators.push(FieldValidator::new(check));
+    }
+
+    pub fn with_async_validator<F, Fut>(mut self, check: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.set_async_validator(check);
+        self
+    }
+
+    /// Check the field's value with `check` on the tokio runtime once the
+    /// other checks pass: when the field is left after an edit, and when the
+    /// form is validated. A spinner shows on the field meanwhile, and a
+    /// submit waits for the outcome. Each value is only checked once.
+    pub fn set_async_validator<F, Fut>(&mut self, check: F)
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.async_validator = Some(AsyncFieldValidator::new(check));
+        self.pending_check = None;
+        self.checked = None;
+    }
+
+    /// Show the field only while `predicate` holds for the form's current
+    /// values, e.g. a proxy URL only while "Use proxy" is checked. Hidden
+    /// fields are skipped when moving between fields and when validating.
+    pub fn with_show_if(
+        mut self,
+        predicate: impl Fn(&FieldValues) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.set_show_if(predicate);
+        self
+    }
+
+    pub fn set_show_if(
+        &mut self,
+        predicate: impl Fn(&FieldValues) -> bool + Send + Sync + 'static,
+    ) {
+        self.show_if = Some(ShowIf::new(predicate));
+    }
+
+    pub fn is_visible(&self, values: &FieldValues) -> bool {
+        self.show_if
+            .as_ref()
+            .is_none_or(|show_if| show_if.check(values))
+    }
+
+    /// Whether an async validator is still checking the value.
+    pub fn is_checking(&self) -> bool {
+        self.pending_check.is_some()
+    }
+
+    // Start the async check of the current value, unless it has been
+    // checked or is being checked already.
+    fn start_check(&mut self) {
+        let Some(validator) = &self.async_validator else {
+            return;
+        };
+        let value = self.inner.validation_value();
+        let known = self
+            .checked
+            .as_ref()
+            .is_some_and(|(checked, _)| *checked == value)
+            || self
+                .pending_check
+                .as_ref()
+                .is_some_and(|pending| pending.value == value);
+        if !known {
+            self.pending_check = Some(validator.spawn(value));
+        }
+    }
+
+    /// Pick up the outcome of a finished async check. Returns true if one
+    /// finished.
+    pub(crate) fn poll_check(&mut self) -> bool {
+        let Some(result) = self.pending_check.as_mut().and_then(PendingCheck::poll) else {
+            return false;
+        };
+        let Some(pending) = self.pending_check.take() else {
+            return false;
+        };
+        // An outcome for a value since edited away is no use
+        if pending.value == self.inner.validation_value() {
+            self.error = result.clone().err();
+        }
+        self.checked = Some((pending.value, result));
+        true
+    }
+
     pub fn is_valid(&self) -> bool {
-        if !self.required {
-            return true;
+        self.check().is_ok()
+    }
+
+    // The first problem with the value: a unit that doesn't parse, a missing
+    // required value, then whatever the validators say.
+    fn check(&self) -> Result<(), String> {
+        // No value is a fine value for a field left unset, and a read-only
+        // value can't be fixed anyway
+        if self.is_unset() || self.readonly {
+            return Ok(());
+        }
+        let locale = Locale::current();
+        if let FormFieldType::Text(field) = &self.inner
+            && !field.unit_is_valid()
+        {
+            return Err(locale.invalid.clone());
+        }
+        if let FormFieldType::Text(field) = &self.inner {
+            field.check_address(&field.value)?;
+        }
+
+        if !self.inner.is_valid() {
+            return if self.required {
+                Err(locale.required.clone())
+            } else {
+                Ok(())
+            };
+        }
+
+        let value = self.inner.validation_value();
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.check(&value))?;
+
+        // An async check that hasn't finished doesn't count against the value
+        match &self.checked {
+            Some((checked, result)) if *checked == value => result.clone(),
+            _ => Ok(()),
         }
+    }
+
+    /// Check the value now, keeping the message to show on the field until
+    /// it passes. Fields of nested forms are left to
+    /// [`FormWidget::validate`](crate::FormWidget::validate). A value that
+    /// passes the other checks is handed to the async validator, if there is
+    /// one and it hasn't seen the value; see [`is_checking`](Self::is_checking).
+    pub fn validate(&mut self) -> Result<(), String> {
+        let result = self.check();
+        self.error = result.clone().err();
+        if result.is_ok() {
+            self.start_check();
+        }
+        result
+    }
 
-        self.inner.is_valid()
+    /// The message from the last failed validation, if the field is invalid.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    /// Take `other`'s value, keeping this field's focus, label and
+    /// settings. A field being edited keeps the user's edit, and a field of
+    /// another type can't take the value; returns whether it was taken.
+    pub fn set_value_from(&mut self, other: &FormFieldWidget) -> bool {
+        !self.is_active() && self.take_value(other)
+    }
+
+    /// Go back to `initial`'s value, dropping an edit in progress.
+    pub fn revert_to(&mut self, initial: &FormFieldWidget) -> bool {
+        let editing = self.is_active();
+        if editing {
+            self.inner.leave();
+        }
+        let reverted = self.take_value(initial);
+        if editing {
+            self.inner.enter_start();
+        }
+        reverted
+    }
+
+    fn take_value(&mut self, other: &FormFieldWidget) -> bool {
+        if !self.inner.set_value_from(&other.inner) {
+            return false;
+        }
+        if self.unset.is_some() && other.unset.is_some() {
+            self.unset = other.unset;
+        }
+        // A shown error follows the new value
+        if self.error.is_some() {
+            let _ = self.validate();
+        }
+        true
+    }
+
+    // Clear password values, here and in nested forms.
+    pub(crate) fn blank_secrets(&mut self) {
+        match &mut self.inner {
+            FormFieldType::Password(field) => field.value.clear(),
+            FormFieldType::SubForm(field) => field
+                .form_widget
+                .fields_mut()
+                .values_mut()
+                .for_each(Self::blank_secrets),
+            FormFieldType::SubFormList(field) => {
+                for form in &mut field.form_widgets {
+                    form.fields_mut().values_mut().for_each(Self::blank_secrets);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Take back `other`'s password values, here and in nested forms.
+    pub(crate) fn keep_secrets_of(&mut self, other: &FormFieldWidget) {
+        match (&mut self.inner, &other.inner) {
+            (FormFieldType::Password(field), FormFieldType::Password(other)) => {
+                field.value.clone_from(&other.value)
+            }
+            (FormFieldType::SubForm(field), FormFieldType::SubForm(other)) => {
+                field.form_widget.keep_secrets_of(&other.form_widget)
+            }
+            (FormFieldType::SubFormList(field), FormFieldType::SubFormList(other)) => {
+                for (form, other) in field.form_widgets.iter_mut().zip(&other.form_widgets) {
+                    form.keep_secrets_of(other);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // A field of the same type holding only this one's value, for a form
+    // to go back to with `revert_to`.
+    pub(crate) fn value_copy(&self) -> FormFieldWidget {
+        let label = self.label.clone();
+        let required = self.required;
+        let mut copy = match &self.inner {
+            FormFieldType::Text(field) => Self::text(label, field.value.clone(), required),
+            FormFieldType::Password(field) => Self::password(label, field.value.clone(), required),
+            FormFieldType::Number(field) => match field.integer {
+                Some(value) => Self::integer(label, value, required),
+                None => Self::number(label, field.value, required),
+            },
+            FormFieldType::Slider(field) => Self::slider(label, field.value, field.range, required),
+            FormFieldType::Select(field) => {
+                Self::select(label, field.options.clone(), field.selected, required)
+            }
+            FormFieldType::MultiSelect(field) => Self::multi_select(
+                label,
+                field.options.clone(),
+                field.checked_indices(),
+                required,
+            ),
+            FormFieldType::Checkbox(field) => Self::checkbox(label, field.checked, required),
+            FormFieldType::Path(field) => Self::path(label, field.path.clone(), required),
+            FormFieldType::List(field) => Self::string_list(label, field.items.clone(), required),
+            FormFieldType::Map(field) => Self::map(label, field.entries.clone(), required),
+            FormFieldType::SubForm(field) => {
+                Self::subform(label, field.form_widget.value_copy(), required)
+            }
+            FormFieldType::SubFormList(field) => {
+                let mut list = SubFormListField::new(FormWidget::new_nested);
+                list.form_widgets = field
+                    .form_widgets
+                    .iter()
+                    .map(FormWidget::value_copy)
+                    .collect();
+                Self::new(label, FormFieldType::SubFormList(list), required)
+            }
+        };
+        copy.unset = self.unset;
+        copy
     }
 
     pub fn inner(&self) -> &FormFieldType {
@@ -61,12 +463,32 @@ impl FormFieldWidget {
 
     // In the enter method
     pub fn enter(&mut self) {
-        self.inner.enter();
+        if !self.readonly {
+            self.inner.enter();
+        }
+    }
+
+    // Start editing as focus arrives from above, or from below when
+    // `forward` is false.
+    pub(crate) fn enter_from(&mut self, forward: bool) {
+        if self.readonly {
+            return;
+        }
+        if forward {
+            self.inner.enter_end();
+        } else {
+            self.inner.enter_start();
+        }
     }
 
     // In the leave method
     pub fn leave(&mut self) {
         self.inner.leave();
+        // Once shown, an error follows the edits that fix it; async checks
+        // start as soon as there's a value worth checking
+        if self.error.is_some() || (self.async_validator.is_some() && self.check().is_ok()) {
+            let _ = self.validate();
+        }
     }
 
     // In the is_active method
@@ -93,10 +515,18 @@ impl FormFieldWidget {
 
     // In the handle_key_event method
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
-        if key.kind != KeyEventKind::Press {
+        if key.kind != KeyEventKind::Press || self.readonly {
             return false;
         }
 
+        if self.unset.is_some()
+            && key.code == KeyCode::Char(' ')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.unset = self.unset.map(|unset| !unset);
+            return true;
+        }
+
         // If Escape is pressed and we're in an active inner widget
         if key.code == KeyCode::Esc && self.is_active() {
             self.leave();
@@ -110,36 +540,160 @@ impl FormFieldWidget {
         }
 
         // Pass the event to the inner field if active
-        if self.is_active() {
-            self.inner.handle_key_event(key)
+        let before = self.unset_value();
+        if self.is_active() && self.inner.handle_key_event(key) {
+            self.set_if_edited(before);
+            self.clear_fixed_error();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Pasted text goes to the inner field only while it's being edited.
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        let before = self.unset_value();
+        if self.is_active() && self.inner.handle_paste(text) {
+            self.set_if_edited(before);
+            self.clear_fixed_error();
+            true
         } else {
             false
         }
     }
 
+    // The value under a field left unset, to tell when it's edited.
+    fn unset_value(&self) -> Option<String> {
+        self.is_unset().then(|| self.inner.validation_value())
+    }
+
+    // Editing the value of a field left unset sets it.
+    fn set_if_edited(&mut self, before: Option<String>) {
+        if let Some(before) = before
+            && (self.inner.has_pending_edit() || self.inner.validation_value() != before)
+        {
+            self.unset = Some(false);
+        }
+    }
+
+    // Drop the error shown as soon as an edit fixes the value. A new
+    // problem waits for the field to be left, so it isn't flagged mid-word.
+    fn clear_fixed_error(&mut self) {
+        if self.error.is_none() {
+            return;
+        }
+        let fixed = match self.inner.typed_text() {
+            // Text is stored as the field is left, so check what's typed
+            Some(text) if text.trim().is_empty() => !self.required,
+            Some(text) => {
+                let address_ok = match &self.inner {
+                    FormFieldType::Text(field) => field.check_address(text).is_ok(),
+                    _ => true,
+                };
+                address_ok
+                    && self
+                        .validators
+                        .iter()
+                        .all(|validator| validator.check(text).is_ok())
+            }
+            None => self.check().is_ok(),
+        };
+        if fixed {
+            self.error = None;
+        }
+    }
+
     pub fn render(&mut self, buf: &mut Buffer, area: Rect, _tabs_widget: Option<&mut TabsWidget>) {
-        let mut block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(if self.is_focused {
-                Style::default().fg(tui_theme::BORDER_FOCUSED)
-            } else {
-                Style::default().fg(tui_theme::BORDER_DEFAULT)
-            });
+        let mut block =
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(if self.error.is_some() {
+                    Style::default().fg(tui_theme::ERROR_FG)
+                } else if self.is_focused {
+                    Style::default().fg(tui_theme::BORDER_FOCUSED)
+                } else {
+                    Style::default().fg(tui_theme::BORDER_DEFAULT)
+                });
 
         // Add label to top-left of block
-        let mut label = self.label.clone();
+        let mut label = match self.unset {
+            Some(true) => format!("[ ] {}", self.label),
+            Some(false) => format!("[x] {}", self.label),
+            None => self.label.clone(),
+        };
         if !self.required {
             label.push_str(" [optional]");
         }
+        if self.readonly {
+            label.push_str(&format!(" [{}]", Locale::current().readonly));
+        }
         block = block.title_top(Line::from(Span::raw(label)).left_aligned());
+        if let Some(pending) = &self.pending_check {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(
+                        " {} {} ",
+                        spinner_frame(pending.started.elapsed()),
+                        Locale::current().checking
+                    ),
+                    Style::default().fg(tui_theme::ACTIVE_FG),
+                ))
+                .left_aligned(),
+            );
+        } else if let Some(error) = &self.error {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {error} "),
+                    Style::default().fg(tui_theme::ERROR_FG),
+                ))
+                .left_aligned(),
+            );
+        }
 
         match &mut self.inner {
             FormFieldType::Text(field) => field.render(buf, area, block),
+            FormFieldType::Password(field) => field.render(buf, area, block),
+            FormFieldType::Number(field) => field.render(buf, area, block),
+            FormFieldType::Slider(field) => field.render(buf, area, block),
+            FormFieldType::Path(field) => field.render(buf, area, block),
             FormFieldType::Select(field) => field.render(buf, area, block),
+            FormFieldType::MultiSelect(field) => field.render(buf, area, block),
+            FormFieldType::Checkbox(field) => field.render(buf, area, block),
             FormFieldType::List(field) => field.render(buf, area, block),
+            FormFieldType::Map(field) => field.render(buf, area, block),
             FormFieldType::SubForm(field) => field.render(buf, area, block),
             FormFieldType::SubFormList(field) => field.render(buf, area, block),
         }
+
+        // An unset field shows no value until it's edited
+        let edited = self.is_active() && self.inner.has_pending_edit();
+        if self.is_unset() && !edited && area.height > 2 {
+            let content = Rect {
+                x: area.x + 1,
+                y: area.y + 1,
+                width: area.width.saturating_sub(2),
+                height: 1,
+            };
+            buf.set_string(
+                content.x,
+                content.y,
+                " ".repeat(content.width as usize),
+                Style::default(),
+            );
+            Line::styled(
+                Locale::current().unset.as_str(),
+                Style::default()
+                    .fg(tui_theme::HINT_FG)
+                    .add_modifier(Modifier::ITALIC),
+            )
+            .render(content, buf);
+        }
+
+        // A read-only value is dimmed
+        if self.readonly {
+            let inner = Block::default().borders(Borders::ALL).inner(area);
+            buf.set_style(inner, Style::default().add_modifier(Modifier::DIM));
+        }
     }
 }
 
@@ -147,18 +701,229 @@ impl FormFieldType {
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
         match self {
             FormFieldType::Text(field) => field.handle_key_event(key),
+            FormFieldType::Password(field) => field.handle_key_event(key),
+            FormFieldType::Number(field) => field.handle_key_event(key),
+            FormFieldType::Slider(field) => field.handle_key_event(key),
+            FormFieldType::Path(field) => field.handle_key_event(key),
             FormFieldType::Select(field) => field.handle_key_event(key),
+            FormFieldType::MultiSelect(field) => field.handle_key_event(key),
+            FormFieldType::Checkbox(field) => field.handle_key_event(key),
             FormFieldType::List(field) => field.handle_key_event(key),
+            FormFieldType::Map(field) => field.handle_key_event(key),
             FormFieldType::SubForm(field) => field.handle_key_event(key),
             FormFieldType::SubFormList(field) => field.handle_key_event(key),
         }
     }
+    pub(crate) fn has_pending_edit(&self) -> bool {
+        match self {
+            FormFieldType::Text(field) => field.input_box.text() != field.value,
+            FormFieldType::Password(field) => field.input_box.text() != field.value,
+            FormFieldType::Number(field) => field.input_box.text() != field.get_value(),
+            FormFieldType::Slider(_) => false,
+            FormFieldType::Path(_) => false,
+            FormFieldType::Select(_) => false,
+            FormFieldType::MultiSelect(_) => false,
+            FormFieldType::Checkbox(_) => false,
+            FormFieldType::List(field) => {
+                matches!(field.action, ListAction::Edit | ListAction::Add)
+            }
+            FormFieldType::Map(field) => field.is_editing(),
+            FormFieldType::SubForm(field) => field.form_widget.has_pending_edits(),
+            FormFieldType::SubFormList(field) => field
+                .editing_index
+                .and_then(|idx| field.form_widgets.get(idx))
+                .is_some_and(|form| form.has_pending_edits()),
+        }
+    }
+
+    // What's typed into a text, password or number field being edited,
+    // before it's stored as the value.
+    fn typed_text(&self) -> Option<&str> {
+        let input = match self {
+            FormFieldType::Text(field) => &field.input_box,
+            FormFieldType::Password(field) => &field.input_box,
+            FormFieldType::Number(field) => &field.input_box,
+            _ => return None,
+        };
+        input.is_focused().then(|| input.text())
+    }
+
+    /// Screen cell of the text cursor of whatever is being typed into.
+    pub(crate) fn cursor_position(&self) -> Option<Position> {
+        match self {
+            FormFieldType::Text(field) => field.input_box.cursor_position(),
+            FormFieldType::Password(field) => field.input_box.cursor_position(),
+            FormFieldType::Number(field) => field.input_box.cursor_position(),
+            FormFieldType::Slider(_) => None,
+            FormFieldType::Path(_) => None,
+            FormFieldType::Select(field) => field.cursor_position(),
+            FormFieldType::MultiSelect(_) => None,
+            FormFieldType::Checkbox(_) => None,
+            FormFieldType::List(field) => field.input_box.cursor_position(),
+            FormFieldType::Map(field) => field.input().cursor_position(),
+            FormFieldType::SubForm(field) => field.form_widget.cursor_position(),
+            FormFieldType::SubFormList(field) => field
+                .editing_index
+                .and_then(|idx| field.form_widgets.get(idx))
+                .and_then(|form| form.cursor_position()),
+        }
+    }
+
+    /// Leave a nested form's innermost edit, if this field has one open.
+    /// Returns false when leaving this field itself is the next level.
+    pub(crate) fn pop_nested(&mut self) -> bool {
+        match self {
+            FormFieldType::SubForm(field) => field.active && field.form_widget.pop_level(),
+            FormFieldType::SubFormList(field) if field.deletes.confirming => {
+                field.deletes.confirming = false;
+                true
+            }
+            FormFieldType::SubFormList(field) => field
+                .editing_index
+                .and_then(|idx| field.form_widgets.get_mut(idx))
+                .is_some_and(|form| form.pop_level()),
+            // Esc answers "Delete? y/n" with no
+            FormFieldType::List(field) if field.deletes.confirming => {
+                field.deletes.confirming = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Bring back a list's last deleted item. True for any list being
+    // edited, even with nothing to bring back, so Ctrl+Z doesn't go on to
+    // revert the whole list.
+    pub(crate) fn undo_delete(&mut self) -> bool {
+        match self {
+            FormFieldType::List(field) if field.active => {
+                field.undo_delete();
+                true
+            }
+            FormFieldType::SubFormList(field) if field.active => {
+                field.undo_delete();
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Mouse events go to sliders, and into the nested forms being edited.
+    pub fn handle_mouse_event(&mut self, event: MouseEvent) -> bool {
+        match self {
+            FormFieldType::Slider(field) => field.handle_mouse_event(event),
+            FormFieldType::SubForm(field) => field.active && field.form_widget.mouse_event(event),
+            FormFieldType::SubFormList(field) => field
+                .editing_index
+                .and_then(|idx| field.form_widgets.get_mut(idx))
+                .is_some_and(|form| form.mouse_event(event)),
+            _ => false,
+        }
+    }
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        match self {
+            FormFieldType::Text(field) => field.handle_paste(text),
+            FormFieldType::Password(field) => field.handle_paste(text),
+            FormFieldType::Number(field) => field.handle_paste(text),
+            FormFieldType::Slider(_) => false,
+            FormFieldType::Path(_) => false,
+            FormFieldType::Select(_) => false,
+            FormFieldType::MultiSelect(_) => false,
+            FormFieldType::Checkbox(_) => false,
+            FormFieldType::List(field) => field.handle_paste(text),
+            FormFieldType::Map(field) => field.handle_paste(text),
+            FormFieldType::SubForm(field) => field.handle_paste(text),
+            FormFieldType::SubFormList(field) => field.handle_paste(text),
+        }
+    }
+    // Copy the value, and nothing else, from a field of the same type.
+    fn set_value_from(&mut self, other: &FormFieldType) -> bool {
+        match (self, other) {
+            (FormFieldType::Text(field), FormFieldType::Text(other)) => {
+                field.value.clone_from(&other.value)
+            }
+            (FormFieldType::Password(field), FormFieldType::Password(other)) => {
+                field.value.clone_from(&other.value)
+            }
+            (FormFieldType::Number(field), FormFieldType::Number(other)) => {
+                field.value = other.value;
+                field.integer = other.integer;
+            }
+            (FormFieldType::Slider(field), FormFieldType::Slider(other)) => {
+                field.value = other.value
+            }
+            (FormFieldType::Select(field), FormFieldType::Select(other)) => {
+                field.options.clone_from(&other.options);
+                field.selected = other.selected.min(field.options.len().saturating_sub(1));
+            }
+            (FormFieldType::MultiSelect(field), FormFieldType::MultiSelect(other)) => {
+                field.options.clone_from(&other.options);
+                field.checked.clone_from(&other.checked);
+            }
+            (FormFieldType::Checkbox(field), FormFieldType::Checkbox(other)) => {
+                field.checked = other.checked
+            }
+            (FormFieldType::Path(field), FormFieldType::Path(other)) => {
+                field.path.clone_from(&other.path)
+            }
+            (FormFieldType::List(field), FormFieldType::List(other)) => {
+                field.items.clone_from(&other.items);
+                field.selected = field.selected.filter(|&idx| idx < field.items.len());
+                // Deleted items belonged to the old value
+                field.deletes = Default::default();
+            }
+            (FormFieldType::Map(field), FormFieldType::Map(other)) => {
+                field.entries.clone_from(&other.entries);
+                field.selected = field.selected.filter(|&idx| idx < field.entries.len());
+            }
+            (FormFieldType::SubForm(field), FormFieldType::SubForm(other)) => {
+                field.form_widget.copy_values_from(&other.form_widget);
+            }
+            (FormFieldType::SubFormList(field), FormFieldType::SubFormList(other)) => {
+                // Items are replaced whole, so not while one is edited
+                if field.editing_index.is_some() {
+                    return false;
+                }
+                field.form_widgets = other
+                    .form_widgets
+                    .iter()
+                    .map(|form| {
+                        let mut copy = (field.template_creator)();
+                        copy.copy_values_from(form);
+                        copy
+                    })
+                    .collect();
+                field.selected_form = field
+                    .selected_form
+                    .filter(|&idx| idx < field.form_widgets.len());
+                field.deletes = Default::default();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// The value validators check: [`get_value_as_string`](Self::get_value_as_string),
+    /// but with passwords unmasked.
+    pub(crate) fn validation_value(&self) -> String {
+        match self {
+            FormFieldType::Password(field) => field.value.clone(),
+            _ => self.get_value_as_string(),
+        }
+    }
+
     // In the get_value_as_string method
     pub fn get_value_as_string(&self) -> String {
         match self {
             FormFieldType::Text(field) => field.get_value(),
+            FormFieldType::Password(field) => field.get_value(),
+            FormFieldType::Number(field) => field.get_value(),
+            FormFieldType::Slider(field) => field.get_value(),
+            FormFieldType::Path(field) => field.get_value(),
             FormFieldType::Select(field) => field.get_value(),
+            FormFieldType::MultiSelect(field) => field.get_value(),
+            FormFieldType::Checkbox(field) => field.get_value(),
             FormFieldType::List(field) => field.get_value(),
+            FormFieldType::Map(field) => field.get_value(),
             FormFieldType::SubForm(field) => field.get_value(),
             FormFieldType::SubFormList(field) => field.get_value(),
         }
@@ -168,8 +933,15 @@ impl FormFieldType {
     pub fn is_valid(&self) -> bool {
         match self {
             FormFieldType::Text(field) => field.is_valid(),
+            FormFieldType::Password(field) => field.is_valid(),
+            FormFieldType::Number(field) => field.is_valid(),
+            FormFieldType::Slider(field) => field.is_valid(),
+            FormFieldType::Path(field) => field.is_valid(),
             FormFieldType::Select(field) => field.is_valid(),
+            FormFieldType::MultiSelect(field) => field.is_valid(),
+            FormFieldType::Checkbox(field) => field.is_valid(),
             FormFieldType::List(field) => field.is_valid(),
+            FormFieldType::Map(field) => field.is_valid(),
             FormFieldType::SubForm(field) => field.is_valid(),
             FormFieldType::SubFormList(field) => field.is_valid(),
         }
@@ -179,8 +951,15 @@ impl FormFieldType {
     pub fn enter_end(&mut self) {
         match self {
             FormFieldType::Text(field) => field.enter(),
-            FormFieldType::Select(field) => field.enter(),
+            FormFieldType::Password(field) => field.enter_end(),
+            FormFieldType::Number(field) => field.enter_end(),
+            FormFieldType::Slider(field) => field.enter(),
+            FormFieldType::Path(field) => field.enter_end(),
+            FormFieldType::Select(field) => field.enter_end(),
+            FormFieldType::MultiSelect(field) => field.enter_end(),
+            FormFieldType::Checkbox(field) => field.enter(),
             FormFieldType::List(field) => field.enter_end(),
+            FormFieldType::Map(field) => field.enter_end(),
             FormFieldType::SubForm(field) => field.enter_end(),
             FormFieldType::SubFormList(field) => field.enter_end(),
         }
@@ -190,8 +969,15 @@ impl FormFieldType {
     pub fn enter_start(&mut self) {
         match self {
             FormFieldType::Text(field) => field.enter(),
-            FormFieldType::Select(field) => field.enter(),
+            FormFieldType::Password(field) => field.enter_start(),
+            FormFieldType::Number(field) => field.enter_start(),
+            FormFieldType::Slider(field) => field.enter(),
+            FormFieldType::Path(field) => field.enter_start(),
+            FormFieldType::Select(field) => field.enter_start(),
+            FormFieldType::MultiSelect(field) => field.enter_start(),
+            FormFieldType::Checkbox(field) => field.enter(),
             FormFieldType::List(field) => field.enter_start(),
+            FormFieldType::Map(field) => field.enter_start(),
             FormFieldType::SubForm(field) => field.enter_start(),
             FormFieldType::SubFormList(field) => field.enter_start(),
         }
@@ -201,8 +987,15 @@ impl FormFieldType {
     pub fn enter(&mut self) {
         match self {
             FormFieldType::Text(field) => field.enter(),
+            FormFieldType::Password(field) => field.enter(),
+            FormFieldType::Number(field) => field.enter(),
+            FormFieldType::Slider(field) => field.enter(),
+            FormFieldType::Path(field) => field.enter(),
             FormFieldType::Select(field) => field.enter(),
+            FormFieldType::MultiSelect(field) => field.enter(),
+            FormFieldType::Checkbox(field) => field.enter(),
             FormFieldType::List(field) => field.enter(),
+            FormFieldType::Map(field) => field.enter(),
             FormFieldType::SubForm(field) => field.enter(),
             FormFieldType::SubFormList(field) => field.enter(),
         }
@@ -212,8 +1005,15 @@ impl FormFieldType {
     pub fn leave(&mut self) {
         match self {
             FormFieldType::Text(field) => field.leave(),
+            FormFieldType::Password(field) => field.leave(),
+            FormFieldType::Number(field) => field.leave(),
+            FormFieldType::Slider(field) => field.leave(),
+            FormFieldType::Path(field) => field.leave(),
             FormFieldType::Select(field) => field.leave(),
+            FormFieldType::MultiSelect(field) => field.leave(),
+            FormFieldType::Checkbox(field) => field.leave(),
             FormFieldType::List(field) => field.leave(),
+            FormFieldType::Map(field) => field.leave(),
             FormFieldType::SubForm(field) => field.leave(),
             FormFieldType::SubFormList(field) => field.leave(),
         }
@@ -223,8 +1023,15 @@ impl FormFieldType {
     pub fn is_active(&self) -> bool {
         match self {
             FormFieldType::Text(field) => field.is_active(),
+            FormFieldType::Password(field) => field.is_active(),
+            FormFieldType::Number(field) => field.is_active(),
+            FormFieldType::Slider(field) => field.is_active(),
+            FormFieldType::Path(field) => field.is_active(),
             FormFieldType::Select(field) => field.is_open(),
+            FormFieldType::MultiSelect(field) => field.is_active(),
+            FormFieldType::Checkbox(field) => field.is_active(),
             FormFieldType::List(field) => field.is_active(),
+            FormFieldType::Map(field) => field.is_active(),
             FormFieldType::SubForm(field) => field.is_active(),
             FormFieldType::SubFormList(field) => field.is_active(),
         }
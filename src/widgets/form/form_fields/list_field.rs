@@ -1,13 +1,14 @@
 // tokio-tui/src/widgets/form/form_fields/list_field.rs
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
+    text::Line,
     widgets::{Block, Paragraph, Widget},
 };
 
-use crate::{ButtonsWidget, InputWidget, TuiWidget};
+use crate::{ButtonsWidget, GlyphSet, InputWidget, Locale, TuiWidget};
 
 use super::{FormFieldType, FormFieldWidget};
 
@@ -20,6 +21,8 @@ pub struct ListField {
     pub action: ListAction,      // Current action (None, Edit, Delete, Add)
     pub action_buttons: ButtonsWidget, // Buttons for item actions
     pub max_display: Option<usize>, // Maximum number of items to display when not active
+    pub delete_policy: DeletePolicy, // Whether to ask before deleting, and how much to undo
+    pub(crate) deletes: DeleteBuffer<String>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -31,35 +34,176 @@ pub enum ListAction {
 impl FormFieldWidget {
     /// Creates a string list field
     pub fn string_list(label: impl Into<String>, items: Vec<String>, required: bool) -> Self {
-        Self {
-            label: label.into(),
-            inner: FormFieldType::List(ListField {
+        Self::new(
+            label,
+            FormFieldType::List(ListField {
                 input_box: InputWidget::new(),
                 items,
                 selected: None,
                 action: ListAction::None,
                 active: false,
-                action_buttons: ButtonsWidget::new()
-                    .add_button(
-                        "Edit",
-                        Style::default().fg(Color::Blue),
-                        Style::default().fg(Color::Black).bg(Color::Blue),
-                    )
-                    .add_button(
-                        "Delete",
-                        Style::default().fg(Color::Red),
-                        Style::default().fg(Color::Black).bg(Color::Red),
-                    )
-                    .with_padding(2),
+                action_buttons: reorder_buttons(),
                 max_display: None,
+                delete_policy: DeletePolicy::default(),
+                deletes: DeleteBuffer::default(),
             }),
             required,
-            help_text: None,
-            is_focused: false,
+        )
+    }
+
+    /// Set how a list or subform list field deletes items; has no effect
+    /// on other field types.
+    pub fn with_delete_policy(mut self, policy: DeletePolicy) -> Self {
+        match &mut self.inner {
+            FormFieldType::List(field) => field.delete_policy = policy,
+            FormFieldType::SubFormList(field) => field.delete_policy = policy,
+            _ => {}
+        }
+        self
+    }
+}
+
+// Edit/Delete buttons shown next to the selected item of a list.
+pub(crate) fn item_buttons() -> ButtonsWidget {
+    let locale = Locale::current();
+    ButtonsWidget::new()
+        .add_button(
+            &locale.edit,
+            Style::default().fg(Color::Blue),
+            Style::default().fg(Color::Black).bg(Color::Blue),
+        )
+        .add_button(
+            &locale.delete,
+            Style::default().fg(Color::Red),
+            Style::default().fg(Color::Black).bg(Color::Red),
+        )
+        .with_padding(2)
+}
+
+// Edit/Delete buttons followed by Move up/Move down, for lists whose order
+// matters.
+pub(crate) fn reorder_buttons() -> ButtonsWidget {
+    let (up, down) = move_labels();
+    let style = Style::default().fg(Color::Cyan);
+    let selected_style = Style::default().fg(Color::Black).bg(Color::Cyan);
+    item_buttons()
+        .add_button(up, style, selected_style)
+        .add_button(down, style, selected_style)
+}
+
+fn move_labels() -> (&'static str, &'static str) {
+    let glyphs = GlyphSet::current();
+    (glyphs.pick("▲", "^"), glyphs.pick("▼", "v"))
+}
+
+// Keep the buttons' labels in step with the installed locale.
+pub(crate) fn relabel_item_buttons(buttons: &mut ButtonsWidget, locale: &Locale) {
+    buttons.set_label(0, &locale.edit);
+    buttons.set_label(1, &locale.delete);
+    if buttons.button_count() > 2 {
+        let (up, down) = move_labels();
+        buttons.set_label(2, up);
+        buttons.set_label(3, down);
+    }
+}
+
+// Ctrl+Up or Ctrl+Down, which move the selected item of a list.
+pub(crate) fn is_move_key(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Up | KeyCode::Down)
+}
+
+/// Deleted items kept for Ctrl+Z to bring back, unless set with
+/// [`DeletePolicy::with_undo_depth`].
+pub const DELETE_UNDO_DEPTH: usize = 10;
+
+/// How a list field deletes items: whether it asks "Delete? y/n" first,
+/// and how many deleted items Ctrl+Z can bring back, latest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletePolicy {
+    pub confirm: bool,
+    /// Zero turns undo off.
+    pub undo_depth: usize,
+}
+
+impl Default for DeletePolicy {
+    fn default() -> Self {
+        Self {
+            confirm: false,
+            undo_depth: DELETE_UNDO_DEPTH,
         }
     }
 }
 
+impl DeletePolicy {
+    /// Ask "Delete? y/n" before deleting an item.
+    pub fn confirmed() -> Self {
+        Self {
+            confirm: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_undo_depth(mut self, depth: usize) -> Self {
+        self.undo_depth = depth;
+        self
+    }
+}
+
+// Items deleted from a list, oldest first with the index each was at, and
+// whether the selected item is waiting on "Delete? y/n".
+#[derive(Debug)]
+pub(crate) struct DeleteBuffer<T> {
+    pub(crate) confirming: bool,
+    deleted: Vec<(usize, T)>,
+}
+
+impl<T> Default for DeleteBuffer<T> {
+    fn default() -> Self {
+        Self {
+            confirming: false,
+            deleted: Vec::new(),
+        }
+    }
+}
+
+impl<T> DeleteBuffer<T> {
+    // Whether to delete now; a policy that confirms asks first, and the
+    // request that answers yes goes ahead.
+    pub(crate) fn should_delete(&mut self, policy: DeletePolicy) -> bool {
+        if policy.confirm && !self.confirming {
+            self.confirming = true;
+            return false;
+        }
+        self.confirming = false;
+        true
+    }
+
+    // Take the item at `idx` out of `items`, keeping it to undo.
+    pub(crate) fn delete(&mut self, items: &mut Vec<T>, idx: usize, policy: DeletePolicy) {
+        let item = items.remove(idx);
+        if policy.undo_depth == 0 {
+            return;
+        }
+        self.deleted.push((idx, item));
+        let excess = self.deleted.len().saturating_sub(policy.undo_depth);
+        self.deleted.drain(..excess);
+    }
+
+    // Put the last deleted item back where it was; returns its index.
+    pub(crate) fn undo(&mut self, items: &mut Vec<T>) -> Option<usize> {
+        let (idx, item) = self.deleted.pop()?;
+        let idx = idx.min(items.len());
+        items.insert(idx, item);
+        Some(idx)
+    }
+}
+
+// "Delete? y/n" in place of a list item's buttons.
+pub(crate) fn render_delete_prompt(area: Rect, buf: &mut Buffer) {
+    let style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    Line::styled(Locale::current().delete_confirm.as_str(), style).render(area, buf);
+}
+
 impl Default for ListField {
     fn default() -> Self {
         Self {
@@ -68,19 +212,10 @@ impl Default for ListField {
             selected: None,
             active: false,
             action: ListAction::None,
-            action_buttons: ButtonsWidget::new()
-                .add_button(
-                    "Edit",
-                    Style::default().fg(Color::Blue),
-                    Style::default().fg(Color::Black).bg(Color::Blue),
-                )
-                .add_button(
-                    "Delete",
-                    Style::default().fg(Color::Red),
-                    Style::default().fg(Color::Black).bg(Color::Red),
-                )
-                .with_padding(2),
+            action_buttons: reorder_buttons(),
             max_display: None,
+            delete_policy: DeletePolicy::default(),
+            deletes: DeleteBuffer::default(),
         }
     }
 }
@@ -95,6 +230,11 @@ impl ListField {
         self
     }
 
+    pub fn with_delete_policy(mut self, policy: DeletePolicy) -> Self {
+        self.delete_policy = policy;
+        self
+    }
+
     pub fn get_value(&self) -> String {
         if self.items.is_empty() {
             String::new()
@@ -136,6 +276,7 @@ impl ListField {
         self.active = false;
         self.selected = None;
         self.action = ListAction::None;
+        self.deletes.confirming = false;
         self.input_box.unfocus();
         self.action_buttons.unfocus();
     }
@@ -154,6 +295,53 @@ impl ListField {
         self.action_buttons.set_selected(1);
     }
 
+    // Swap the selected item with the one above or below, the selection
+    // going with it.
+    fn move_selected(&mut self, down: bool) {
+        let Some(idx) = self.selected else {
+            return;
+        };
+        let other = if down { idx + 1 } else { idx.wrapping_sub(1) };
+        if idx < self.items.len() && other < self.items.len() {
+            self.items.swap(idx, other);
+            self.selected = Some(other);
+        }
+    }
+
+    // Delete the selected item, once confirmed if the policy asks for that.
+    fn delete_selected(&mut self) {
+        let Some(idx) = self.selected.filter(|&idx| idx < self.items.len()) else {
+            return;
+        };
+        if !self.deletes.should_delete(self.delete_policy) {
+            return;
+        }
+        self.deletes
+            .delete(&mut self.items, idx, self.delete_policy);
+        if self.items.is_empty() {
+            self.selected = None;
+        } else if idx >= self.items.len() {
+            self.selected = Some(self.items.len() - 1);
+        }
+        self.action_buttons.unfocus();
+    }
+
+    /// Bring back the last deleted item where it was; Ctrl+Z does this
+    /// while the list is being edited. Returns whether there was one.
+    pub fn undo_delete(&mut self) -> bool {
+        let Some(idx) = self.deletes.undo(&mut self.items) else {
+            return false;
+        };
+        self.selected = Some(idx);
+        self.deletes.confirming = false;
+        true
+    }
+
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        let editing = self.action == ListAction::Edit || self.action == ListAction::Add;
+        self.active && editing && self.input_box.paste_event(text)
+    }
+
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
         // If not active, don't handle keys
         if !self.active {
@@ -196,6 +384,25 @@ impl ListField {
                 }
             }
         } else {
+            // "Delete? y/n" takes the next key, y deleting the selected item
+            if self.deletes.confirming {
+                if key.code == KeyCode::Char('y') {
+                    self.delete_selected();
+                } else {
+                    self.deletes.confirming = false;
+                }
+                return true;
+            }
+
+            // Ctrl+Up/Down move the selected item, staying on the list's ends
+            if is_move_key(&key) {
+                if self.selected.is_none() {
+                    return false;
+                }
+                self.move_selected(key.code == KeyCode::Down);
+                return true;
+            }
+
             // If we're focused on the action buttons
             if self.action_buttons.is_focused() {
                 if key.code == KeyCode::Esc {
@@ -215,17 +422,10 @@ impl ListField {
                             }
                         } else if selected_button == 1 {
                             // Delete button
-                            if let Some(idx) = self.selected {
-                                if idx < self.items.len() {
-                                    self.items.remove(idx);
-                                    if self.items.is_empty() {
-                                        self.selected = None;
-                                    } else if idx >= self.items.len() {
-                                        self.selected = Some(self.items.len() - 1);
-                                    }
-                                }
-                            }
-                            self.action_buttons.unfocus();
+                            self.delete_selected();
+                        } else {
+                            // Move up and Move down buttons
+                            self.move_selected(selected_button == 3);
                         }
                     }
                     return true;
@@ -296,16 +496,7 @@ impl ListField {
                 }
                 KeyCode::Delete => {
                     // Shortcut to delete the selected item
-                    if let Some(idx) = self.selected {
-                        if idx < self.items.len() {
-                            self.items.remove(idx);
-                            if self.items.is_empty() {
-                                self.selected = None;
-                            } else if idx >= self.items.len() {
-                                self.selected = Some(self.items.len() - 1);
-                            }
-                        }
-                    }
+                    self.delete_selected();
                 }
                 _ => return false,
             }
@@ -380,6 +571,10 @@ impl ListField {
             .saturating_sub(if self.active { 1 } else { 0 })
             as usize; // Reserve space for Add button
         let items_to_show = self.items.len().min(max_visible_items);
+        // Leave space for the buttons
+        let text_width = content_area
+            .width
+            .saturating_sub(self.action_buttons.width());
 
         for i in 0..items_to_show {
             let y = content_area.y + i as u16;
@@ -392,7 +587,7 @@ impl ListField {
                     Rect {
                         x: content_area.x,
                         y,
-                        width: content_area.width.saturating_sub(20), // Leave space for buttons
+                        width: text_width,
                         height: 1,
                     },
                     buf,
@@ -410,7 +605,7 @@ impl ListField {
                     Rect {
                         x: content_area.x,
                         y,
-                        width: content_area.width.saturating_sub(20), // Leave space for buttons
+                        width: text_width,
                         height: 1,
                     },
                     buf,
@@ -420,14 +615,20 @@ impl ListField {
             // Render action buttons for selected item when active
             if is_selected && self.action != ListAction::Edit && self.active {
                 // Configure buttons if this is the selected row
+                relabel_item_buttons(&mut self.action_buttons, &Locale::current());
+                let width = self.action_buttons.width().min(content_area.width);
                 let button_area = Rect {
-                    x: content_area.x + content_area.width - 19,
+                    x: content_area.x + content_area.width - width,
                     y,
-                    width: 19,
+                    width,
                     height: 1,
                 };
 
-                self.action_buttons.draw(button_area, buf);
+                if self.deletes.confirming {
+                    render_delete_prompt(button_area, buf);
+                } else {
+                    self.action_buttons.draw(button_area, buf);
+                }
             }
         }
 
@@ -458,15 +659,17 @@ impl ListField {
                     Style::default().fg(Color::Green)
                 };
 
-                Paragraph::new("+ Add").style(add_style).render(
-                    Rect {
-                        x: content_area.x,
-                        y: add_y,
-                        width: content_area.width,
-                        height: 1,
-                    },
-                    buf,
-                );
+                Paragraph::new(Locale::current().add.as_str())
+                    .style(add_style)
+                    .render(
+                        Rect {
+                            x: content_area.x,
+                            y: add_y,
+                            width: content_area.width,
+                            height: 1,
+                        },
+                        buf,
+                    );
             }
 
             // If there are more items than can be shown, indicate scrolling is possible
@@ -0,0 +1,422 @@
+// tokio-tui/src/widgets/form/form_fields/map_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{ButtonsWidget, InputWidget, Locale, TuiWidget};
+
+use super::{FormFieldType, FormFieldWidget, ListAction, item_buttons, relabel_item_buttons};
+
+/// Separator drawn between an entry's key and its value.
+const SEPARATOR: &str = " = ";
+
+/// Key-value pairs, edited with a key and a value input side by side. Keys
+/// are unique: adding one that's there already replaces its value.
+#[derive(Debug)]
+pub struct MapField {
+    pub entries: Vec<(String, String)>,
+    pub key_input: InputWidget,
+    // Boxed so a map field is no bigger than a list field
+    pub value_input: Box<InputWidget>,
+    pub selected: Option<usize>, // Selected entry index or None for Add button
+    pub active: bool,            // Whether the map field is in active mode
+    pub action: ListAction,      // Current action (None, Edit, Add)
+    pub action_buttons: ButtonsWidget, // Buttons for entry actions
+}
+
+impl FormFieldWidget {
+    /// Creates a key-value map field, its entries in the order given.
+    pub fn map(
+        label: impl Into<String>,
+        entries: impl IntoIterator<Item = (String, String)>,
+        required: bool,
+    ) -> Self {
+        Self::new(
+            label,
+            FormFieldType::Map(MapField {
+                entries: entries.into_iter().collect(),
+                ..MapField::default()
+            }),
+            required,
+        )
+    }
+}
+
+impl Default for MapField {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            key_input: InputWidget::new(),
+            value_input: Box::new(InputWidget::new()),
+            selected: None,
+            active: false,
+            action: ListAction::None,
+            action_buttons: item_buttons(),
+        }
+    }
+}
+
+impl MapField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn get_value(&self) -> String {
+        self.entries
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        self.entries.len() as u16 + if self.active { 3 } else { 2 }
+    }
+
+    pub fn enter(&mut self) {
+        self.active = true;
+        self.selected = if self.entries.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.action = ListAction::None;
+        self.action_buttons.unfocus();
+    }
+    pub fn enter_start(&mut self) {
+        self.enter();
+    }
+    pub fn enter_end(&mut self) {
+        self.enter();
+        self.selected = None;
+    }
+    pub fn leave(&mut self) {
+        self.active = false;
+        self.selected = None;
+        self.action = ListAction::None;
+        self.key_input.unfocus();
+        self.value_input.unfocus();
+        self.action_buttons.unfocus();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Whether a key or value is being typed.
+    pub fn is_editing(&self) -> bool {
+        matches!(self.action, ListAction::Edit | ListAction::Add)
+    }
+
+    // The input being typed into.
+    fn input_mut(&mut self) -> &mut InputWidget {
+        if self.value_input.is_focused() {
+            &mut self.value_input
+        } else {
+            &mut self.key_input
+        }
+    }
+
+    pub fn input(&self) -> &InputWidget {
+        if self.value_input.is_focused() {
+            &self.value_input
+        } else {
+            &self.key_input
+        }
+    }
+
+    fn start_edit(&mut self, action: ListAction) {
+        let (key, value) = match (&action, self.selected) {
+            (ListAction::Edit, Some(idx)) => self.entries[idx].clone(),
+            _ => Default::default(),
+        };
+        self.action = action;
+        self.action_buttons.unfocus();
+        self.value_input.unfocus();
+        self.value_input.set_text(value);
+        self.key_input.focus_and_set_text(key);
+    }
+
+    fn switch_input(&mut self) {
+        if self.value_input.is_focused() {
+            let key = self.key_input.text().to_string();
+            self.value_input.unfocus();
+            self.key_input.focus_and_set_text(key);
+        } else {
+            let value = self.value_input.text().to_string();
+            self.key_input.unfocus();
+            self.value_input.focus_and_set_text(value);
+        }
+    }
+
+    // Store the pair being typed; an empty key keeps the inputs open.
+    fn finish_edit(&mut self) -> bool {
+        let key = self.key_input.text().trim().to_string();
+        if key.is_empty() {
+            return false;
+        }
+        let value = self.value_input.text().to_string();
+
+        let existing = self
+            .entries
+            .iter()
+            .position(|(entry_key, _)| *entry_key == key);
+        let editing = self.selected.filter(|_| self.action == ListAction::Edit);
+        let idx = match (editing, existing) {
+            // Renamed onto another entry's key: the two merge
+            (Some(idx), Some(other)) if idx != other => {
+                self.entries[other].1 = value;
+                self.entries.remove(idx);
+                if other > idx { other - 1 } else { other }
+            }
+            (Some(idx), _) | (None, Some(idx)) => {
+                self.entries[idx] = (key, value);
+                idx
+            }
+            (None, None) => {
+                self.entries.push((key, value));
+                self.entries.len() - 1
+            }
+        };
+        self.selected = Some(idx);
+        self.cancel_edit();
+        true
+    }
+
+    fn cancel_edit(&mut self) {
+        self.key_input.unfocus();
+        self.value_input.unfocus();
+        self.action = ListAction::None;
+    }
+
+    fn delete_selected(&mut self) {
+        if let Some(idx) = self.selected.filter(|&idx| idx < self.entries.len()) {
+            self.entries.remove(idx);
+            self.selected = if self.entries.is_empty() {
+                None
+            } else {
+                Some(idx.min(self.entries.len() - 1))
+            };
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.active && self.is_editing() && self.input_mut().paste_event(text)
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        if !self.active {
+            return false;
+        }
+
+        // Typing a key or value: Tab moves between them, Enter moves on from
+        // the key and stores the pair from the value
+        if self.is_editing() {
+            match key.code {
+                KeyCode::Tab | KeyCode::BackTab => self.switch_input(),
+                KeyCode::Enter if self.key_input.is_focused() => self.switch_input(),
+                KeyCode::Enter => {
+                    if !self.finish_edit() {
+                        self.switch_input();
+                    }
+                }
+                KeyCode::Esc => self.cancel_edit(),
+                _ => return self.input_mut().key_event(key),
+            }
+            return true;
+        }
+
+        if self.action_buttons.is_focused() {
+            if key.code == KeyCode::Esc {
+                self.action_buttons.unfocus();
+                return true;
+            }
+            if self.action_buttons.key_event(key) {
+                if key.code == KeyCode::Enter {
+                    match self.action_buttons.selected() {
+                        0 => self.start_edit(ListAction::Edit),
+                        _ => {
+                            self.delete_selected();
+                            self.action_buttons.unfocus();
+                        }
+                    }
+                }
+                return true;
+            }
+        }
+
+        // The buttons stay with the entry they were focused on
+        let selected = match (key.code, self.selected) {
+            (KeyCode::Up, Some(idx)) if idx > 0 => Some(Some(idx - 1)),
+            (KeyCode::Up, None) if !self.entries.is_empty() => Some(Some(self.entries.len() - 1)),
+            (KeyCode::Down, Some(idx)) => Some((idx + 1 < self.entries.len()).then_some(idx + 1)),
+            _ => None,
+        };
+        if let Some(selected) = selected {
+            self.selected = selected;
+            self.action_buttons.unfocus();
+            return true;
+        }
+
+        match (key.code, self.selected) {
+            (KeyCode::Left | KeyCode::Enter, Some(_)) => {
+                self.action_buttons.focus();
+                self.action_buttons.set_selected(0);
+            }
+            (KeyCode::Right, Some(_)) => {
+                self.action_buttons.focus();
+                self.action_buttons.set_selected(1);
+            }
+            (KeyCode::Enter, None) => self.start_edit(ListAction::Add),
+            (KeyCode::Delete, Some(_)) => self.delete_selected(),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+        let row = |idx: usize| Rect {
+            y: content_area.y + idx as u16,
+            height: 1,
+            ..content_area
+        };
+
+        if !self.active && self.entries.is_empty() {
+            Paragraph::new("[Empty]")
+                .style(Style::default().fg(Color::White))
+                .render(content_area, buf);
+            return;
+        }
+
+        // Keys line up in a column as wide as the longest, up to a third of
+        // the field
+        let key_width = self
+            .entries
+            .iter()
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(8)
+            .min(usize::from(content_area.width / 3)) as u16;
+        // Leave space for the buttons while active
+        let text_width = if self.active {
+            content_area.width.saturating_sub(20)
+        } else {
+            content_area.width
+        };
+        let shown = self.entries.len().min(usize::from(
+            content_area.height.saturating_sub(u16::from(self.active)),
+        ));
+
+        for idx in 0..shown {
+            let is_selected = self.active && self.selected == Some(idx);
+            let area = Rect {
+                width: text_width,
+                ..row(idx)
+            };
+            if is_selected && self.action == ListAction::Edit {
+                self.render_inputs(area, key_width, buf);
+            } else {
+                let style = Style::default().fg(if is_selected {
+                    Color::Yellow
+                } else {
+                    Color::White
+                });
+                let (key, value) = &self.entries[idx];
+                let key: String = key.chars().take(usize::from(key_width)).collect();
+                Paragraph::new(format!(
+                    "{key:width$}{SEPARATOR}{value}",
+                    width = usize::from(key_width)
+                ))
+                .style(style)
+                .render(area, buf);
+            }
+
+            if is_selected && self.action != ListAction::Edit {
+                relabel_item_buttons(&mut self.action_buttons, &Locale::current());
+                let width = self.action_buttons.width().min(content_area.width);
+                self.action_buttons.draw(
+                    Rect {
+                        x: content_area.right() - width,
+                        width,
+                        ..row(idx)
+                    },
+                    buf,
+                );
+            }
+        }
+
+        if !self.active {
+            return;
+        }
+        // Add button, or the inputs for the entry being added, last
+        let add_area = row(shown);
+        if self.action == ListAction::Add {
+            self.render_inputs(add_area, key_width, buf);
+        } else {
+            let add_style = if self.selected.is_none() {
+                Style::default().fg(Color::Black).bg(Color::Green)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            Paragraph::new(Locale::current().add.as_str())
+                .style(add_style)
+                .render(add_area, buf);
+        }
+    }
+
+    // The key and value inputs side by side, the key's as wide as the key
+    // column.
+    fn render_inputs(&mut self, area: Rect, key_width: u16, buf: &mut Buffer) {
+        let separator_width = SEPARATOR.len() as u16;
+        let key_area = Rect {
+            width: key_width.min(area.width),
+            ..area
+        };
+        let value_x = key_area.right() + separator_width;
+        Paragraph::new(SEPARATOR)
+            .style(Style::default().fg(Color::DarkGray))
+            .render(
+                Rect {
+                    x: key_area.right(),
+                    width: separator_width.min(area.right().saturating_sub(key_area.right())),
+                    ..area
+                },
+                buf,
+            );
+        self.key_input.no_border();
+        self.key_input.draw(key_area, buf);
+        self.value_input.no_border();
+        self.value_input.draw(
+            Rect {
+                x: value_x.min(area.right()),
+                width: area.right().saturating_sub(value_x),
+                ..area
+            },
+            buf,
+        );
+    }
+}
@@ -1,13 +1,33 @@
 // tokio-tui/src/widgets/form/form_fields/mod.rs
+mod address_field;
+mod checkbox_field;
+mod field_editor;
+mod field_unit;
 mod form_field;
 mod list_field;
+mod map_field;
+mod multi_select_field;
+mod number_field;
+mod password_field;
+mod path_field;
 mod select_field;
+mod slider_field;
 mod subform_field;
 mod subform_list_field;
 mod text_field;
+pub use address_field::*;
+pub use checkbox_field::*;
+pub use field_editor::*;
+pub use field_unit::*;
 pub use form_field::*;
 pub use list_field::*;
+pub use map_field::*;
+pub use multi_select_field::*;
+pub use number_field::*;
+pub use password_field::*;
+pub use path_field::*;
 pub use select_field::*;
+pub use slider_field::*;
 pub use subform_field::*;
 pub use subform_list_field::*;
 pub use text_field::*;
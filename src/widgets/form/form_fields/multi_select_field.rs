@@ -0,0 +1,211 @@
+// tokio-tui/src/widgets/form/form_fields/multi_select_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::GlyphSet;
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Options the checklist shows at once; longer lists scroll.
+const LIST_ROWS: usize = 8;
+
+#[derive(Debug)]
+pub struct MultiSelectField {
+    pub options: Vec<String>,
+    /// Checked state of each option.
+    pub checked: Vec<bool>,
+    /// The option Space toggles, while toggling.
+    pub highlighted: Option<usize>,
+    pub focused: bool, // Whether focus is on the field, toggling or not
+}
+
+impl FormFieldWidget {
+    /// Creates a checklist where any number of options can be checked. Enter
+    /// starts toggling them with Space; moving past either end of the list
+    /// moves on to the next field.
+    pub fn multi_select(
+        label: impl Into<String>,
+        options: Vec<String>,
+        checked: impl IntoIterator<Item = usize>,
+        required: bool,
+    ) -> Self {
+        let mut flags = vec![false; options.len()];
+        for idx in checked {
+            if let Some(flag) = flags.get_mut(idx) {
+                *flag = true;
+            }
+        }
+        Self::new(
+            label,
+            FormFieldType::MultiSelect(MultiSelectField {
+                options,
+                checked: flags,
+                highlighted: None,
+                focused: false,
+            }),
+            required,
+        )
+    }
+}
+
+impl MultiSelectField {
+    /// Indices of the checked options, in option order.
+    pub fn checked_indices(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .enumerate()
+            .filter(|(_, checked)| **checked)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    pub fn toggle(&mut self, idx: usize) {
+        if let Some(flag) = self.checked.get_mut(idx) {
+            *flag = !*flag;
+        }
+    }
+
+    /// The checked options joined with commas.
+    pub fn get_value(&self) -> String {
+        self.checked_indices()
+            .iter()
+            .map(|idx| self.options[*idx].as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.checked.contains(&true)
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        2 + self.options.len().clamp(1, LIST_ROWS) as u16
+    }
+
+    pub fn enter(&mut self) {
+        self.focused = true;
+        if !self.options.is_empty() {
+            self.highlighted = Some(0);
+        }
+    }
+
+    // Moving onto the field doesn't start toggling, so Up and Down keep
+    // moving between fields until Enter.
+    pub fn enter_start(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn enter_end(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn leave(&mut self) {
+        self.highlighted = None;
+        self.focused = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.highlighted.is_some()
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        let Some(idx) = self.highlighted else {
+            return false;
+        };
+        let last = self.options.len().saturating_sub(1);
+        match key.code {
+            // Past either end, the form moves on to the next field
+            KeyCode::Up if idx == 0 => return false,
+            KeyCode::Down if idx == last => return false,
+            KeyCode::Up => self.highlighted = Some(idx - 1),
+            KeyCode::Down => self.highlighted = Some(idx + 1),
+            KeyCode::Home => self.highlighted = Some(0),
+            KeyCode::End => self.highlighted = Some(last),
+            KeyCode::Char(' ') => self.toggle(idx),
+            KeyCode::Enter => self.highlighted = None,
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn render(&self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        let glyphs = GlyphSet::current();
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+        if self.options.is_empty() {
+            Paragraph::new("(none)")
+                .style(Style::default().add_modifier(Modifier::DIM))
+                .render(content_area, buf);
+            return;
+        }
+
+        let rows = content_area.height as usize;
+        // Scroll so the highlighted option stays visible
+        let first = self
+            .highlighted
+            .map_or(0, |idx| (idx + 1).saturating_sub(rows));
+        for (row, (idx, option)) in self
+            .options
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .enumerate()
+        {
+            let is_highlighted = self.highlighted == Some(idx);
+            let style = if is_highlighted {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else if self.focused {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let marker = if is_highlighted {
+                glyphs.pick("▶", ">")
+            } else {
+                " "
+            };
+            let mark = if self.checked[idx] { "[x]" } else { "[ ]" };
+            Paragraph::new(format!("{marker} {mark} {option}"))
+                .style(style)
+                .render(
+                    Rect {
+                        y: content_area.y + row as u16,
+                        height: 1,
+                        ..content_area
+                    },
+                    buf,
+                );
+        }
+
+        // Hint at options scrolled out of view
+        let hint_style = Style::default().fg(Color::DarkGray);
+        let hint_area = |y| Rect {
+            x: content_area.right().saturating_sub(7),
+            y,
+            width: 7.min(content_area.width),
+            height: 1,
+        };
+        if first > 0 {
+            Paragraph::new(glyphs.pick("▲ more", "^ more"))
+                .style(hint_style)
+                .render(hint_area(content_area.y), buf);
+        }
+        if first + rows < self.options.len() {
+            Paragraph::new(glyphs.pick("▼ more", "v more"))
+                .style(hint_style)
+                .render(hint_area(content_area.bottom().saturating_sub(1)), buf);
+        }
+    }
+}
@@ -0,0 +1,313 @@
+// tokio-tui/src/widgets/form/form_fields/number_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{GlyphSet, InputFilter, InputWidget, Locale, TuiWidget};
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Steps PageUp and PageDown move by.
+const PAGE_STEPS: f64 = 10.0;
+
+#[derive(Debug)]
+pub struct NumberField {
+    pub value: f64,
+    /// The exact value of a field made with [`FormFieldWidget::integer`],
+    /// which `value` only approximates past 2^53.
+    pub integer: Option<i128>,
+    // Bounds of `integer`, e.g. the range of the type it's edited as
+    integer_range: (i128, i128),
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// How far Up and Down move the value.
+    pub step: f64,
+    /// Digits after the decimal separator; `Some(0)` for integers, `None` to
+    /// show the value as it is.
+    pub decimals: Option<usize>,
+    pub input_box: InputWidget,
+    pub focused: bool, // Whether focus is on the field, editing or not
+}
+
+impl FormFieldWidget {
+    /// Creates a number field, typed into after Enter and stepped with
+    /// Up/Down (PageUp/PageDown for ten steps) while editing.
+    pub fn number(label: impl Into<String>, value: f64, required: bool) -> Self {
+        let mut field = NumberField {
+            value,
+            integer: None,
+            integer_range: (i128::MIN, i128::MAX),
+            min: None,
+            max: None,
+            step: 1.0,
+            decimals: None,
+            input_box: InputWidget::new().without_history(),
+            focused: false,
+        };
+        field.update_filter();
+        Self::new(label, FormFieldType::Number(field), required)
+    }
+
+    /// Creates a number field for whole numbers, kept exact however large
+    /// they are rather than going through `f64`.
+    pub fn integer(label: impl Into<String>, value: i128, required: bool) -> Self {
+        let mut field = Self::number(label, value as f64, required).with_decimals(0);
+        if let FormFieldType::Number(number) = &mut field.inner {
+            number.set_integer(value);
+        }
+        field
+    }
+
+    /// Keep the number field's value between `min` and `max`; has no effect
+    /// on other field types.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        if let FormFieldType::Number(field) = &mut self.inner {
+            field.min = Some(min);
+            field.max = Some(max);
+            field.update_filter();
+            match field.integer {
+                // `as` saturates past either end of the range
+                Some(value) => {
+                    field.integer_range = (min.ceil() as i128, max.floor() as i128);
+                    field.set_integer(value);
+                }
+                None => field.value = field.constrain(field.value),
+            }
+        }
+        self
+    }
+
+    /// Keep an [`integer`](Self::integer) field's value between `min` and
+    /// `max` exactly; has no effect on other field types.
+    pub fn with_integer_range(mut self, min: i128, max: i128) -> Self {
+        if let FormFieldType::Number(field) = &mut self.inner
+            && let Some(value) = field.integer
+        {
+            field.min = Some(min as f64);
+            field.max = Some(max as f64);
+            field.integer_range = (min, max);
+            field.update_filter();
+            field.set_integer(value);
+        }
+        self
+    }
+
+    /// How far Up and Down move the number field's value; has no effect on
+    /// other field types.
+    pub fn with_step(mut self, step: f64) -> Self {
+        if let FormFieldType::Number(field) = &mut self.inner {
+            field.step = step;
+        }
+        self
+    }
+
+    /// Round the number field's value to `decimals` digits, 0 making it an
+    /// integer field; has no effect on other field types.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        if let FormFieldType::Number(field) = &mut self.inner {
+            field.decimals = Some(decimals);
+            field.update_filter();
+            field.value = field.constrain(field.value);
+        }
+        self
+    }
+}
+
+impl NumberField {
+    pub fn get_value(&self) -> String {
+        match self.integer {
+            Some(value) => value.to_string(),
+            None => self.format(self.value),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        true
+    }
+
+    /// Set the value, clamped to the range and rounded to the decimals.
+    pub fn set_value(&mut self, value: f64) {
+        match self.integer {
+            Some(_) => self.set_integer(value.round() as i128),
+            None => self.value = self.constrain(value),
+        }
+    }
+
+    /// Set an [`integer`](FormFieldWidget::integer) field's value exactly,
+    /// clamped to its range.
+    pub fn set_integer(&mut self, value: i128) {
+        let (min, max) = self.integer_range;
+        let value = value.clamp(min, max.max(min));
+        self.integer = Some(value);
+        self.value = value as f64;
+    }
+
+    fn constrain(&self, value: f64) -> f64 {
+        let mut value = value;
+        if let Some(decimals) = self.decimals {
+            let scale = 10f64.powi(decimals as i32);
+            value = (value * scale).round() / scale;
+        }
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    fn format(&self, value: f64) -> String {
+        let locale = Locale::current();
+        match self.decimals {
+            Some(decimals) => locale.format_decimal(value, decimals),
+            None => value
+                .to_string()
+                .replace('.', &locale.decimal_separator.to_string()),
+        }
+    }
+
+    // Only what could make a number in range can be typed.
+    fn update_filter(&mut self) {
+        let negative = self.min.is_none_or(|min| min < 0.0);
+        let filter = match (self.decimals, negative) {
+            (Some(0), false) => InputFilter::Numeric,
+            (Some(0), true) => InputFilter::Integer,
+            _ => InputFilter::Float,
+        };
+        self.input_box.set_filter_mode(filter);
+    }
+
+    // Move the number being edited by `steps` steps.
+    fn step_by(&mut self, steps: f64) {
+        if let Some(value) = self.integer {
+            let current = self.parse_integer().unwrap_or(value);
+            let (min, max) = self.integer_range;
+            let stepped = current
+                .saturating_add((steps * self.step).round() as i128)
+                .clamp(min, max.max(min));
+            self.input_box.set_text(stepped.to_string());
+            return;
+        }
+        let current = Locale::current()
+            .parse_decimal(self.input_box.text())
+            .unwrap_or(self.value);
+        let mut stepped = self.constrain(current + steps * self.step);
+        if self.decimals.is_none() {
+            // Shed the float noise of repeated steps like 0.1
+            stepped = (stepped * 1e9).round() / 1e9;
+        }
+        self.input_box.set_text(self.format(stepped));
+    }
+
+    // Take the edited number; text that doesn't parse leaves the value as
+    // it was.
+    fn commit_input(&mut self) {
+        if let Some(value) = self.parse_integer() {
+            self.set_integer(value);
+        } else if let Some(value) = Locale::current().parse_decimal(self.input_box.text()) {
+            self.set_value(value);
+        }
+    }
+
+    // The edited text as a whole number, for an integer field.
+    fn parse_integer(&self) -> Option<i128> {
+        self.integer?;
+        Locale::current()
+            .normalize_decimal(self.input_box.text())
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    pub fn enter(&mut self) {
+        self.focused = true;
+        self.input_box.focus_and_set_text(self.get_value());
+    }
+
+    // Moving onto the field doesn't start editing, so Up and Down keep
+    // moving between fields until Enter.
+    pub fn enter_start(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn enter_end(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn leave(&mut self) {
+        if self.input_box.is_focused() {
+            self.commit_input();
+        }
+        self.input_box.unfocus();
+        self.focused = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.input_box.is_focused()
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        if !self.input_box.is_focused() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Enter => {
+                self.commit_input();
+                self.input_box.unfocus();
+            }
+            KeyCode::Up => self.step_by(1.0),
+            KeyCode::Down => self.step_by(-1.0),
+            KeyCode::PageUp => self.step_by(PAGE_STEPS),
+            KeyCode::PageDown => self.step_by(-PAGE_STEPS),
+            _ => return self.input_box.key_event(key),
+        }
+        true
+    }
+
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.input_box.paste_event(text)
+    }
+
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
+
+        if self.input_box.is_focused() {
+            self.input_box.no_border();
+            self.input_box.draw(content_area, buf);
+            return;
+        }
+
+        let value_style = if self.focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let mut spans = vec![Span::raw(self.get_value())];
+        if self.focused {
+            spans.push(Span::styled(
+                format!("  {}", GlyphSet::current().pick("▲▼", "+/-")),
+                Style::default().add_modifier(Modifier::DIM),
+            ));
+        }
+        Paragraph::new(Line::from(spans))
+            .style(value_style)
+            .render(content_area, buf);
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        3
+    }
+}
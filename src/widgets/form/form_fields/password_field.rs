@@ -0,0 +1,216 @@
+// tokio-tui/src/widgets/form/form_fields/password_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{GlyphSet, InputWidget, Locale, TuiWidget, tui_theme};
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Mask characters shown for a password however long it is, so its length
+/// doesn't show either.
+const MASK_LEN: usize = 8;
+
+pub struct PasswordField {
+    pub value: String,
+    pub input_box: InputWidget,
+    /// Whether Ctrl+R shows the password.
+    pub reveal_toggle: bool,
+    pub revealed: bool,
+    pub focused: bool, // Whether focus is on the field, editing or not
+}
+
+impl FormFieldWidget {
+    /// Creates a password field, masked while typed and when shown. Its
+    /// value only comes out of [`PasswordField::value`]: summaries such as
+    /// [`get_value_as_string`](Self::get_value_as_string) mask it.
+    pub fn password(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
+        Self::new(
+            label,
+            FormFieldType::Password(PasswordField {
+                value: value.into(),
+                input_box: InputWidget::new().without_history().with_mask(mask_glyph()),
+                reveal_toggle: false,
+                revealed: false,
+                focused: false,
+            }),
+            required,
+        )
+    }
+
+    /// Let Ctrl+R show and hide the password field's value; has no effect
+    /// on other field types.
+    pub fn with_reveal_toggle(mut self) -> Self {
+        if let FormFieldType::Password(field) = &mut self.inner {
+            field.reveal_toggle = true;
+        }
+        self
+    }
+}
+
+impl std::fmt::Debug for PasswordField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PasswordField")
+            .field("value", &"<masked>")
+            .field("reveal_toggle", &self.reveal_toggle)
+            .field("revealed", &self.revealed)
+            .field("focused", &self.focused)
+            .finish()
+    }
+}
+
+fn mask_glyph() -> char {
+    GlyphSet::current()
+        .pick("•", "*")
+        .chars()
+        .next()
+        .unwrap_or('*')
+}
+
+impl PasswordField {
+    /// The masked value, empty if there's no password.
+    pub fn get_value(&self) -> String {
+        if self.value.is_empty() {
+            String::new()
+        } else {
+            mask_glyph().to_string().repeat(MASK_LEN)
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn toggle_reveal(&mut self) {
+        self.revealed = !self.revealed;
+        self.input_box.set_mask((!self.revealed).then(mask_glyph));
+    }
+
+    pub fn enter(&mut self) {
+        self.focused = true;
+        self.input_box.focus_and_set_text(&self.value);
+    }
+
+    pub fn enter_start(&mut self) {
+        self.enter();
+    }
+
+    pub fn enter_end(&mut self) {
+        self.enter();
+    }
+
+    pub fn leave(&mut self) {
+        if self.input_box.is_focused() {
+            self.value = self.input_box.text().to_string();
+        }
+        self.input_box.unfocus();
+        // Nothing stays on screen once focus moves on
+        if self.revealed {
+            self.toggle_reveal();
+        }
+        self.focused = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.input_box.is_focused()
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('r')
+                if self.reveal_toggle && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                self.toggle_reveal();
+                true
+            }
+            KeyCode::Enter if self.input_box.is_focused() => {
+                self.value = self.input_box.text().to_string();
+                self.input_box.unfocus();
+                true
+            }
+            _ => self.input_box.key_event(key),
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.input_box.paste_event(text)
+    }
+
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
+
+        // The toggle's hint goes at the right end while editing
+        let hint = (self.reveal_toggle && self.is_active()).then(|| {
+            let locale = Locale::current();
+            format!(
+                " ^R {}",
+                if self.revealed {
+                    &locale.hide
+                } else {
+                    &locale.show
+                }
+            )
+        });
+        let hint_width = hint.as_ref().map_or(0, |hint| hint.chars().count() as u16);
+        let value_area = Rect {
+            width: content_area.width.saturating_sub(hint_width),
+            ..content_area
+        };
+        if let Some(hint) = hint {
+            Paragraph::new(Line::from(Span::styled(
+                hint,
+                Style::default().add_modifier(Modifier::DIM),
+            )))
+            .render(
+                Rect {
+                    x: value_area.right(),
+                    width: hint_width,
+                    ..content_area
+                },
+                buf,
+            );
+        }
+
+        if self.input_box.is_focused() {
+            self.input_box.no_border();
+            self.input_box.draw(value_area, buf);
+            return;
+        }
+
+        let value_style = if self.focused {
+            Style::default().fg(tui_theme::BORDER_FOCUSED)
+        } else {
+            Style::default().fg(tui_theme::TEXT_FG)
+        };
+        let shown = if self.revealed {
+            self.value.clone()
+        } else {
+            self.get_value()
+        };
+        if shown.is_empty() && !self.input_box.hint().is_empty() {
+            Paragraph::new(self.input_box.hint())
+                .style(Style::default().fg(tui_theme::HINT_FG))
+                .render(value_area, buf);
+            return;
+        }
+        Paragraph::new(shown)
+            .style(value_style)
+            .render(value_area, buf);
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        3
+    }
+}
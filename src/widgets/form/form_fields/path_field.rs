@@ -0,0 +1,357 @@
+// tokio-tui/src/widgets/form/form_fields/path_field.rs
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::GlyphSet;
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Entries the browser lists at once; longer directories scroll.
+const BROWSER_ROWS: usize = 10;
+
+#[derive(Debug)]
+pub struct PathField {
+    pub path: PathBuf,
+    /// Extensions of the files the browser lists, without the dot; every
+    /// file when empty. Directories are always listed.
+    pub extensions: Vec<String>,
+    pub browser: Option<PathBrowser>,
+    pub focused: bool, // Whether focus is on the field, browsing or not
+}
+
+/// The open file browser of a [`PathField`].
+#[derive(Debug)]
+pub struct PathBrowser {
+    pub dir: PathBuf,
+    pub entries: Vec<BrowserEntry>,
+    pub selected: usize,
+    pub show_hidden: bool,
+    // Why the directory couldn't be listed
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+impl FormFieldWidget {
+    /// Creates a path field; Enter opens a file browser to pick the path
+    /// with.
+    pub fn path(label: impl Into<String>, path: impl Into<PathBuf>, required: bool) -> Self {
+        Self::new(
+            label,
+            FormFieldType::Path(PathField {
+                path: path.into(),
+                extensions: Vec::new(),
+                browser: None,
+                focused: false,
+            }),
+            required,
+        )
+    }
+
+    /// List only files with these extensions in the path field's browser;
+    /// has no effect on other field types.
+    pub fn with_extensions(
+        mut self,
+        extensions: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        if let FormFieldType::Path(field) = &mut self.inner {
+            field.extensions = extensions
+                .into_iter()
+                .map(|ext| ext.as_ref().trim_start_matches('.').to_lowercase())
+                .collect();
+        }
+        self
+    }
+}
+
+impl PathBrowser {
+    fn open(dir: PathBuf, extensions: &[String], show_hidden: bool) -> Self {
+        let mut browser = Self {
+            dir,
+            entries: Vec::new(),
+            selected: 0,
+            show_hidden,
+            error: None,
+        };
+        browser.list(extensions);
+        browser
+    }
+
+    // Read the directory: `..` first, then directories, then the files
+    // that pass the extension filter, each sorted by name.
+    fn list(&mut self, extensions: &[String]) {
+        self.entries.clear();
+        self.selected = 0;
+        self.error = None;
+        if self.dir.parent().is_some() {
+            self.entries.push(BrowserEntry {
+                name: "..".to_string(),
+                is_dir: true,
+            });
+        }
+
+        let read = match fs::read_dir(&self.dir) {
+            Ok(read) => read,
+            Err(err) => {
+                self.error = Some(err.to_string());
+                return;
+            }
+        };
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+        for entry in read.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with('.') && !self.show_hidden {
+                continue;
+            }
+            // Follows symlinks, so a link to a directory can be entered
+            if entry.path().is_dir() {
+                dirs.push(name);
+            } else if extensions.is_empty() || has_extension(&name, extensions) {
+                files.push(name);
+            }
+        }
+        dirs.sort_unstable();
+        files.sort_unstable();
+        self.entries.extend(
+            dirs.into_iter()
+                .map(|name| BrowserEntry { name, is_dir: true }),
+        );
+        self.entries
+            .extend(files.into_iter().map(|name| BrowserEntry {
+                name,
+                is_dir: false,
+            }));
+    }
+
+    fn select_name(&mut self, name: &str) {
+        if let Some(idx) = self.entries.iter().position(|entry| entry.name == name) {
+            self.selected = idx;
+        }
+    }
+
+    // Go up a directory, keeping the one just left selected.
+    fn up(&mut self, extensions: &[String]) {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let left = self
+            .dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        self.dir = parent;
+        self.list(extensions);
+        if let Some(left) = left {
+            self.select_name(&left);
+        }
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let last = self.entries.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+}
+
+fn has_extension(name: &str, extensions: &[String]) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|ext| extensions.contains(&ext.to_string_lossy().to_lowercase()))
+}
+
+impl PathField {
+    pub fn get_value(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.path.as_os_str().is_empty()
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        match &self.browser {
+            // The directory row and its entries
+            Some(browser) => 3 + browser.entries.len().clamp(1, BROWSER_ROWS) as u16,
+            None => 3,
+        }
+    }
+
+    /// Open the browser in the directory of the current path (or the working
+    /// directory), with the current file selected.
+    pub fn enter(&mut self) {
+        self.focused = true;
+        let (dir, file) = if self.path.is_dir() {
+            (self.path.clone(), None)
+        } else {
+            let dir = self
+                .path
+                .parent()
+                .filter(|dir| dir.is_dir())
+                .map(Path::to_path_buf)
+                .or_else(|| std::env::current_dir().ok())
+                .unwrap_or_default();
+            (dir, self.path.file_name())
+        };
+        let mut browser = PathBrowser::open(dir, &self.extensions, false);
+        if let Some(file) = file {
+            browser.select_name(&file.to_string_lossy());
+        }
+        self.browser = Some(browser);
+    }
+
+    // Moving onto the field doesn't open the browser; Enter does.
+    pub fn enter_start(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn enter_end(&mut self) {
+        self.focused = true;
+    }
+
+    pub fn leave(&mut self) {
+        self.browser = None;
+        self.focused = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.browser.is_some()
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        let Some(browser) = &mut self.browser else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Up => browser.move_by(-1),
+            KeyCode::Down => browser.move_by(1),
+            KeyCode::PageUp => browser.move_by(-(BROWSER_ROWS as isize)),
+            KeyCode::PageDown => browser.move_by(BROWSER_ROWS as isize),
+            KeyCode::Home => browser.selected = 0,
+            KeyCode::End => browser.move_by(isize::MAX),
+            KeyCode::Backspace | KeyCode::Left => browser.up(&self.extensions),
+            KeyCode::Char('.') => {
+                browser.show_hidden = !browser.show_hidden;
+                let selected = browser.entries.get(browser.selected).cloned();
+                browser.list(&self.extensions);
+                if let Some(selected) = selected {
+                    browser.select_name(&selected.name);
+                }
+            }
+            KeyCode::Enter | KeyCode::Right => {
+                let Some(entry) = browser.entries.get(browser.selected).cloned() else {
+                    return true;
+                };
+                if entry.name == ".." {
+                    browser.up(&self.extensions);
+                } else if entry.is_dir {
+                    browser.dir.push(&entry.name);
+                    browser.list(&self.extensions);
+                } else if key.code == KeyCode::Enter {
+                    // Pick the file and close the browser
+                    self.path = browser.dir.join(&entry.name);
+                    self.browser = None;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn render(&self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        let glyphs = GlyphSet::current();
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+        let row = |idx: u16| Rect {
+            y: content_area.y + idx,
+            height: 1,
+            ..content_area
+        };
+
+        let Some(browser) = &self.browser else {
+            let value_style = if self.focused {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let line = if self.path.as_os_str().is_empty() {
+                Line::from(Span::styled(
+                    "(none)",
+                    Style::default().add_modifier(Modifier::DIM),
+                ))
+            } else {
+                Line::from(self.get_value())
+            };
+            Paragraph::new(line).style(value_style).render(row(0), buf);
+            return;
+        };
+
+        // The directory being browsed, or why it can't be
+        let dir_line = match &browser.error {
+            Some(error) => Line::from(vec![
+                Span::raw(browser.dir.display().to_string()),
+                Span::styled(format!("  {error}"), Style::default().fg(Color::Red)),
+            ]),
+            None => Line::from(browser.dir.display().to_string()),
+        };
+        Paragraph::new(dir_line)
+            .style(Style::default().fg(Color::Yellow))
+            .render(row(0), buf);
+
+        let rows = (content_area.height as usize).saturating_sub(1);
+        if rows == 0 {
+            return;
+        }
+        // Scroll so the selection stays visible
+        let first = (browser.selected + 1).saturating_sub(rows);
+        for (idx, (entry_idx, entry)) in browser
+            .entries
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .enumerate()
+        {
+            let is_selected = entry_idx == browser.selected;
+            let style = if is_selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else if entry.is_dir {
+                Style::default().fg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let marker = if is_selected {
+                glyphs.pick("▶", ">")
+            } else {
+                " "
+            };
+            let suffix = if entry.is_dir && entry.name != ".." {
+                "/"
+            } else {
+                ""
+            };
+            Paragraph::new(format!("{marker} {}{suffix}", entry.name))
+                .style(style)
+                .render(row(idx as u16 + 1), buf);
+        }
+    }
+}
@@ -1,19 +1,36 @@
 // tokio-tui/src/widgets/form/form_fields/select_field.rs
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent},
-    layout::Rect,
-    style::{Color, Style},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
     widgets::{Block, Paragraph, Widget},
 };
 
 use super::{FormFieldType, FormFieldWidget};
+use crate::{GlyphSet, Locale, tui_theme};
+
+/// Selects with more options than this get a search box in their dropdown.
+pub const SEARCH_THRESHOLD: usize = 12;
+/// Options a searchable dropdown shows at once.
+const SEARCH_ROWS: usize = 8;
 
 #[derive(Debug)]
 pub struct SelectFormField {
     pub options: Vec<String>,
     pub selected: usize,
     pub dropdown_open: bool,
+    /// Whether the dropdown has a search box; by default, when there are
+    /// more than [`SEARCH_THRESHOLD`] options.
+    pub search: Option<bool>,
+    /// Typed into the search box, narrowing the options.
+    pub query: String,
+    // Indices of the options matching the query, best match first
+    matches: Vec<usize>,
+    // Position in `matches` of the highlighted option
+    highlighted: usize,
+    // Where the search box's cursor was last drawn
+    cursor: Option<Position>,
 }
 
 impl FormFieldWidget {
@@ -24,29 +41,49 @@ impl FormFieldWidget {
         selected: usize,
         required: bool,
     ) -> Self {
-        Self {
-            label: label.into(),
-            inner: FormFieldType::Select(SelectFormField {
+        Self::new(
+            label,
+            FormFieldType::Select(SelectFormField {
                 options,
                 selected,
                 dropdown_open: false,
+                search: None,
+                query: String::new(),
+                matches: Vec::new(),
+                highlighted: 0,
+                cursor: None,
             }),
             required,
-            help_text: None,
-            is_focused: false,
+        )
+    }
+
+    /// Give the select field's dropdown a search box, or take it away,
+    /// whatever its number of options; has no effect on other field types.
+    pub fn with_search(mut self, search: bool) -> Self {
+        if let FormFieldType::Select(field) = &mut self.inner {
+            field.search = Some(search);
         }
+        self
     }
 }
 
 impl SelectFormField {
     pub fn calculate_height(&self) -> u16 {
-        if self.dropdown_open {
+        if !self.dropdown_open {
+            3
+        } else if self.is_searchable() {
+            // The search box, then a window of the matches, or a line
+            // saying there are none
+            4 + self.matches.len().clamp(1, SEARCH_ROWS) as u16
+        } else {
             // When dropdown is open, show all options + field itself
             3 + self.options.len() as u16
-        } else {
-            3
         }
     }
+
+    pub fn is_searchable(&self) -> bool {
+        self.search.unwrap_or(self.options.len() > SEARCH_THRESHOLD)
+    }
     pub fn get_value(&self) -> String {
         if self.selected < self.options.len() {
             self.options[self.selected].clone()
@@ -65,10 +102,86 @@ impl SelectFormField {
 
     pub fn enter(&mut self) {
         self.dropdown_open = true;
+        if self.is_searchable() {
+            self.set_query(String::new());
+        }
+    }
+
+    // A searchable dropdown waits for Enter, rather than opening as focus
+    // moves through it.
+    pub fn enter_start(&mut self) {
+        if !self.is_searchable() {
+            self.enter();
+        }
+    }
+
+    pub fn enter_end(&mut self) {
+        self.enter_start();
     }
 
     pub fn leave(&mut self) {
         self.dropdown_open = false;
+        self.query.clear();
+        self.cursor = None;
+    }
+
+    pub fn cursor_position(&self) -> Option<Position> {
+        self.cursor.filter(|_| self.dropdown_open)
+    }
+
+    // Narrow the options down to those matching `query`, highlighting the
+    // selected one if it's among them, or else the best match.
+    fn set_query(&mut self, query: String) {
+        let mut scored: Vec<(i32, usize)> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, option)| Some((fuzzy_score(option, &query)?, idx)))
+            .collect();
+        // Best first; ties keep the options' order
+        scored.sort_by_key(|&(score, idx)| (-score, idx));
+        self.matches = scored.into_iter().map(|(_, idx)| idx).collect();
+        self.highlighted = if query.is_empty() {
+            self.matches
+                .iter()
+                .position(|&idx| idx == self.selected)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        self.query = query;
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Up => self.highlighted = self.highlighted.saturating_sub(1),
+            KeyCode::Down => {
+                self.highlighted = (self.highlighted + 1).min(self.matches.len().saturating_sub(1))
+            }
+            KeyCode::PageUp => self.highlighted = self.highlighted.saturating_sub(SEARCH_ROWS),
+            KeyCode::PageDown => {
+                self.highlighted =
+                    (self.highlighted + SEARCH_ROWS).min(self.matches.len().saturating_sub(1))
+            }
+            KeyCode::Enter => {
+                // Nothing matching leaves the selection as it was
+                if let Some(&idx) = self.matches.get(self.highlighted) {
+                    self.selected = idx;
+                }
+                self.leave();
+            }
+            KeyCode::Backspace => {
+                let mut query = std::mem::take(&mut self.query);
+                query.pop();
+                self.set_query(query);
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let query = format!("{}{c}", self.query);
+                self.set_query(query);
+            }
+            _ => return false,
+        }
+        true
     }
 
     pub fn is_open(&self) -> bool {
@@ -79,6 +192,9 @@ impl SelectFormField {
         if !self.dropdown_open {
             return false;
         }
+        if self.is_searchable() {
+            return self.handle_search_key(key);
+        }
 
         match key.code {
             KeyCode::Up => {
@@ -99,7 +215,8 @@ impl SelectFormField {
         true
     }
 
-    pub fn render(&self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        let glyphs = GlyphSet::current();
         // Render the block
         block.render(area, buf);
 
@@ -111,6 +228,11 @@ impl SelectFormField {
             height: area.height.saturating_sub(2),
         };
 
+        if self.dropdown_open && self.is_searchable() {
+            self.render_search(buf, content_area);
+            return;
+        }
+
         // When dropdown is closed, just show the selected value
         if !self.dropdown_open {
             let selected_value = if self.selected < self.options.len() {
@@ -125,7 +247,7 @@ impl SelectFormField {
                 Style::default().fg(Color::White)
             };
 
-            let value_display = format!("{selected_value} ▼");
+            let value_display = format!("{selected_value} {}", glyphs.pick("▼", "v"));
             Paragraph::new(value_display)
                 .style(value_style)
                 .render(content_area, buf);
@@ -140,7 +262,7 @@ impl SelectFormField {
             };
 
             let value_style = Style::default().fg(Color::Yellow);
-            let value_display = format!("{selected_value} ▲");
+            let value_display = format!("{selected_value} {}", glyphs.pick("▲", "^"));
 
             Paragraph::new(value_display)
                 .style(value_style)
@@ -188,11 +310,12 @@ impl SelectFormField {
                 };
 
                 // Prefix selected option with a marker
-                let display_text = if is_selected {
-                    format!("▶ {option}")
+                let marker = if is_selected {
+                    glyphs.pick("▶", ">")
                 } else {
-                    format!("  {option}")
+                    " "
                 };
+                let display_text = format!("{marker} {option}");
 
                 Paragraph::new(display_text).style(option_style).render(
                     Rect {
@@ -208,29 +331,134 @@ impl SelectFormField {
             // If we're showing a subset of options, show scroll indicators
             if start_idx > 0 {
                 let indicator_style = Style::default().fg(Color::DarkGray);
-                Paragraph::new("▲ more").style(indicator_style).render(
-                    Rect {
-                        x: dropdown_area.x,
-                        y: dropdown_area.y,
-                        width: dropdown_area.width,
-                        height: 1,
-                    },
-                    buf,
-                );
+                Paragraph::new(glyphs.pick("▲ more", "^ more"))
+                    .style(indicator_style)
+                    .render(
+                        Rect {
+                            x: dropdown_area.x,
+                            y: dropdown_area.y,
+                            width: dropdown_area.width,
+                            height: 1,
+                        },
+                        buf,
+                    );
             }
 
             if end_idx < total_options {
                 let indicator_style = Style::default().fg(Color::DarkGray);
-                Paragraph::new("▼ more").style(indicator_style).render(
-                    Rect {
-                        x: dropdown_area.x,
-                        y: dropdown_area.y + (end_idx - start_idx) as u16,
-                        width: dropdown_area.width,
-                        height: 1,
-                    },
-                    buf,
-                );
+                Paragraph::new(glyphs.pick("▼ more", "v more"))
+                    .style(indicator_style)
+                    .render(
+                        Rect {
+                            x: dropdown_area.x,
+                            y: dropdown_area.y + (end_idx - start_idx) as u16,
+                            width: dropdown_area.width,
+                            height: 1,
+                        },
+                        buf,
+                    );
+            }
+        }
+    }
+
+    // The selected value, the search box under it, and the matches in a
+    // window that follows the highlight.
+    fn render_search(&mut self, buf: &mut Buffer, area: Rect) {
+        let glyphs = GlyphSet::current();
+        let locale = Locale::current();
+        let line = |offset: u16| Rect {
+            y: area.y + offset,
+            height: 1,
+            ..area
+        };
+        if area.height == 0 {
+            return;
+        }
+
+        let selected_value = self.options.get(self.selected).map_or("", String::as_str);
+        Paragraph::new(format!("{selected_value} {}", glyphs.pick("▲", "^")))
+            .style(Style::default().fg(Color::Yellow))
+            .render(line(0), buf);
+
+        if area.height < 2 {
+            return;
+        }
+        let prompt = format!("{} ", glyphs.pick("⌕", "/"));
+        let prompt_width = prompt.chars().count() as u16;
+        let (text, style) = if self.query.is_empty() {
+            (
+                locale.filter_hint.as_str(),
+                Style::default()
+                    .fg(tui_theme::HINT_FG)
+                    .add_modifier(Modifier::DIM),
+            )
+        } else {
+            (self.query.as_str(), Style::default().fg(tui_theme::TEXT_FG))
+        };
+        Paragraph::new(format!("{prompt}{text}"))
+            .style(style)
+            .render(line(1), buf);
+        let cursor_x = area.x + prompt_width + self.query.chars().count() as u16;
+        self.cursor = (cursor_x < area.right()).then(|| Position::new(cursor_x, area.y + 1));
+
+        let rows = usize::from(area.height - 2).min(SEARCH_ROWS);
+        if self.matches.is_empty() {
+            if rows > 0 {
+                Paragraph::new(locale.no_matches.as_str())
+                    .style(Style::default().fg(Color::DarkGray))
+                    .render(line(2), buf);
             }
+            return;
+        }
+        let start = self
+            .highlighted
+            .saturating_sub(rows.saturating_sub(1))
+            .min(self.matches.len().saturating_sub(rows));
+        for (row, &idx) in self.matches.iter().enumerate().skip(start).take(rows) {
+            let is_highlighted = row == self.highlighted;
+            let style = if is_highlighted {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let marker = if is_highlighted {
+                glyphs.pick("▶", ">")
+            } else {
+                " "
+            };
+            Paragraph::new(format!("{marker} {}", self.options[idx]))
+                .style(style)
+                .render(line(2 + (row - start) as u16), buf);
         }
     }
 }
+
+// How well `query` matches `option`: its characters in order anywhere in
+// the option, ignoring case. Characters right after the one before, and at
+// the start of a word, count for more. None if it doesn't match at all.
+fn fuzzy_score(option: &str, query: &str) -> Option<i32> {
+    let chars: Vec<char> = option.chars().collect();
+    let mut score = 0;
+    let mut from = 0;
+    let mut last = None;
+    for wanted in query.chars() {
+        let idx = (from..chars.len()).find(|&idx| same_letter(chars[idx], wanted))?;
+        score += 1;
+        if idx > 0 && last == Some(idx - 1) {
+            score += 4;
+        }
+        let word_start = idx == 0
+            || !chars[idx - 1].is_alphanumeric()
+            || (chars[idx].is_uppercase() && chars[idx - 1].is_lowercase());
+        if word_start {
+            score += 3;
+        }
+        last = Some(idx);
+        from = idx + 1;
+    }
+    Some(score)
+}
+
+fn same_letter(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
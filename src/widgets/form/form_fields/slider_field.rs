@@ -0,0 +1,236 @@
+// tokio-tui/src/widgets/form/form_fields/slider_field.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{GlyphSet, Locale};
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Bounds and step of a [`SliderField`], e.g. from
+/// `#[field(slider(min = 0, max = 100, step = 5))]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl SliderRange {
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            step: 1.0,
+        }
+    }
+
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// Steps PageUp and PageDown move by.
+const PAGE_STEPS: f64 = 10.0;
+
+#[derive(Debug)]
+pub struct SliderField {
+    pub value: f64,
+    pub range: SliderRange,
+    /// Digits after the decimal separator of the value shown next to the bar.
+    pub decimals: usize,
+    pub active: bool, // Whether focus is on the slider
+    // Where the bar was last drawn, for the mouse
+    bar_area: Option<Rect>,
+    dragging: bool,
+}
+
+impl FormFieldWidget {
+    /// Creates a slider moved with Left/Right (PageUp/PageDown for ten steps,
+    /// Home/End for the ends) or by clicking and dragging along the bar.
+    pub fn slider(
+        label: impl Into<String>,
+        value: f64,
+        range: SliderRange,
+        required: bool,
+    ) -> Self {
+        let mut field = SliderField {
+            value,
+            range,
+            decimals: decimals_of(range.step),
+            active: false,
+            bar_area: None,
+            dragging: false,
+        };
+        field.set_value(value);
+        Self::new(label, FormFieldType::Slider(field), required)
+    }
+
+    /// Edit a number field with a slider over `range` instead; has no effect
+    /// on other field types.
+    pub fn with_slider(mut self, range: SliderRange) -> Self {
+        if let FormFieldType::Number(number) = &self.inner {
+            let decimals = number.decimals.unwrap_or(decimals_of(range.step));
+            let mut field = SliderField {
+                value: number.value,
+                range,
+                decimals,
+                active: false,
+                bar_area: None,
+                dragging: false,
+            };
+            field.set_value(number.value);
+            self.inner = FormFieldType::Slider(field);
+        }
+        self
+    }
+}
+
+// Decimals needed to show multiples of `step`, e.g. 2 for 0.25.
+fn decimals_of(step: f64) -> usize {
+    let text = step.abs().to_string();
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+impl SliderField {
+    pub fn get_value(&self) -> String {
+        Locale::current().format_decimal(self.value, self.decimals)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        true
+    }
+
+    /// Set the value, snapped to the step and clamped to the range.
+    pub fn set_value(&mut self, value: f64) {
+        let SliderRange { min, max, step } = self.range;
+        let snapped = if step > 0.0 {
+            min + ((value - min) / step).round() * step
+        } else {
+            value
+        };
+        let scale = 10f64.powi(self.decimals as i32);
+        self.value = ((snapped * scale).round() / scale).clamp(min, max.max(min));
+    }
+
+    fn step_by(&mut self, steps: f64) {
+        self.set_value(self.value + steps * self.range.step);
+    }
+
+    // Fraction of the way from min to max the value is.
+    fn ratio(&self) -> f64 {
+        let span = self.range.max - self.range.min;
+        if span <= 0.0 {
+            0.0
+        } else {
+            (self.value - self.range.min) / span
+        }
+    }
+
+    pub fn calculate_height(&self) -> u16 {
+        3
+    }
+
+    pub fn enter(&mut self) {
+        self.active = true;
+    }
+
+    pub fn leave(&mut self) {
+        self.active = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Left => self.step_by(-1.0),
+            KeyCode::Right => self.step_by(1.0),
+            KeyCode::PageDown => self.step_by(-PAGE_STEPS),
+            KeyCode::PageUp => self.step_by(PAGE_STEPS),
+            KeyCode::Home => self.set_value(self.range.min),
+            KeyCode::End => self.set_value(self.range.max),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Clicking on the bar moves the value there, and so does dragging from
+    /// such a click, even off the bar.
+    pub fn handle_mouse_event(&mut self, event: MouseEvent) -> bool {
+        let Some(bar) = self.bar_area else {
+            return false;
+        };
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if bar.contains(Position::new(event.column, event.row)) =>
+            {
+                self.dragging = true;
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {}
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                return true;
+            }
+            _ => return false,
+        }
+        let cells = bar.width.saturating_sub(1).max(1) as f64;
+        let offset = event.column.saturating_sub(bar.x).min(bar.width) as f64;
+        let ratio = (offset / cells).min(1.0);
+        self.set_value(self.range.min + ratio * (self.range.max - self.range.min));
+        true
+    }
+
+    pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
+        let glyphs = GlyphSet::current();
+        block.render(area, buf);
+
+        let content_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: 1,
+        };
+
+        // The value goes right of the bar, as wide as the widest it can be
+        let label = format!(" {}", self.get_value());
+        let widest = [self.range.min, self.range.max]
+            .map(|end| Locale::current().format_decimal(end, self.decimals).len() + 1)
+            .into_iter()
+            .max()
+            .unwrap_or_default() as u16;
+        let bar_width = content_area.width.saturating_sub(widest);
+        let bar = Rect {
+            width: bar_width,
+            ..content_area
+        };
+        self.bar_area = Some(bar).filter(|bar| !bar.is_empty());
+
+        let knob = (self.ratio() * bar_width.saturating_sub(1) as f64).round() as usize;
+        let filled_style = if self.active {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let line = Line::from(vec![
+            Span::styled(glyphs.pick("━", "=").repeat(knob), filled_style),
+            Span::styled(glyphs.pick("●", "O"), filled_style),
+            Span::styled(
+                glyphs
+                    .pick("─", "-")
+                    .repeat((bar_width as usize).saturating_sub(knob + 1)),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(label, filled_style),
+        ]);
+        Paragraph::new(line).render(content_area, buf);
+    }
+}
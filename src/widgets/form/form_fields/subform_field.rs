@@ -6,13 +6,13 @@ use ratatui::{
     style::{Color, Style},
     widgets::{Block, Paragraph, Widget as _},
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{FormValue, FormWidget, SubFormData, TuiWidget as _};
 
 use super::{FormFieldType, FormFieldWidget};
 
-#[derive(Clone, Serialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct TuiForm<T: SubFormData + Serialize + std::fmt::Debug + Default>(pub T);
 
 impl<T: SubFormData + Serialize + std::fmt::Debug + Default> FormValue for TuiForm<T> {
@@ -26,7 +26,7 @@ impl<T: SubFormData + Serialize + std::fmt::Debug + Default> FormValue for TuiFo
             FormFieldType::SubForm(subform_field) => {
                 TuiForm(T::from_form_widget(&subform_field.form_widget))
             }
-            _ => TuiForm(T::default()), // Fallback
+            _ => TuiForm(T::form_default()), // Fallback
         }
     }
 }
@@ -37,16 +37,14 @@ pub struct SubFormField {
 impl FormFieldWidget {
     /// Creates a subform field (1:1 relationship)
     pub fn subform(label: impl Into<String>, form_widget: FormWidget, required: bool) -> Self {
-        Self {
-            label: label.into(),
-            inner: FormFieldType::SubForm(SubFormField {
+        Self::new(
+            label,
+            FormFieldType::SubForm(SubFormField {
                 form_widget,
                 active: false,
             }),
             required,
-            help_text: None,
-            is_focused: false,
-        }
+        )
     }
 }
 
@@ -61,7 +59,7 @@ impl std::fmt::Debug for SubFormField {
 // Implementation for SubFormField (1:1 relationship)
 impl SubFormField {
     pub fn get_value(&self) -> String {
-        format!("[{}]", self.form_widget.title.clone())
+        format!("[{}]", self.form_widget.display_title())
     }
     pub fn calculate_height(&self) -> u16 {
         if self.active {
@@ -69,11 +67,8 @@ impl SubFormField {
             let nested_form = &self.form_widget;
             let mut total_height = 3; // Base height for the form container
 
-            // Add height for each child field
-            for child_key in nested_form.keys() {
-                total_height += nested_form.calculate_field_height(child_key.as_str()) + 1;
-                // +1 for spacing
-            }
+            // Add height for the child fields as laid out, +1 for spacing
+            total_height += nested_form.content_lines() + 1;
 
             // Add height for the buttons area
             total_height += 3;
@@ -85,7 +80,7 @@ impl SubFormField {
             let mut total_height = 1;
 
             // Add 1 line for each field in the subform
-            total_height += self.form_widget.get_fields().len() as u16;
+            total_height += self.form_widget.visible_keys().count() as u16;
 
             // Add 1 for help text/hint
             total_height += 1;
@@ -94,7 +89,12 @@ impl SubFormField {
         }
     }
     pub fn is_valid(&self) -> bool {
-        for field in self.form_widget.get_fields().values() {
+        let fields = self.form_widget.get_fields();
+        for field in self
+            .form_widget
+            .visible_keys()
+            .filter_map(|key| fields.get(key))
+        {
             if field.required && !field.is_valid() {
                 return false;
             }
@@ -123,6 +123,10 @@ impl SubFormField {
         self.active
     }
 
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.active && self.form_widget.paste_event(text)
+    }
+
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
         if self.active {
             if key.code == KeyCode::Esc && key.kind == KeyEventKind::Press {
@@ -167,7 +171,7 @@ impl SubFormField {
             let mut y_offset = 1;
 
             // Maintain field order using field_keys
-            for key in self.form_widget.keys() {
+            for key in self.form_widget.visible_keys() {
                 if let Some(field) = self.form_widget.get_fields().get(key) {
                     // Get field value
                     let value = field.get_value_as_string();
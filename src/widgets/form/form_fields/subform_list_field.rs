@@ -6,13 +6,16 @@ use ratatui::{
     style::{Color, Style},
     widgets::{Block, Paragraph, Widget as _},
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{ButtonsWidget, FormValue, FormWidget, SubFormData, TuiWidget as _};
+use crate::{ButtonsWidget, FormValue, FormWidget, GlyphSet, Locale, SubFormData, TuiWidget as _};
 
-use super::{FormFieldType, FormFieldWidget};
+use super::{
+    DeleteBuffer, DeletePolicy, FormFieldType, FormFieldWidget, is_move_key, relabel_item_buttons,
+    render_delete_prompt, reorder_buttons,
+};
 
-#[derive(Clone, Serialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct TuiList<T: SubFormData + Serialize + std::fmt::Debug + Default>(pub Vec<T>);
 
 impl<T: SubFormData + Serialize + std::fmt::Debug + Default> TuiList<T> {
@@ -24,7 +27,7 @@ impl<T: SubFormData + Serialize + std::fmt::Debug + Default> TuiList<T> {
 // Implement FormValue for the SubFormListWrapper
 impl<T: SubFormData + Serialize + std::fmt::Debug + Default> FormValue for TuiList<T> {
     fn to_field_widget(&self, label: &str, required: bool) -> FormFieldWidget {
-        let template_creator = || T::default().to_form_widget();
+        let template_creator = || T::form_default().to_form_widget();
 
         let mut field = FormFieldWidget::subform_list(label, template_creator, required);
 
@@ -51,6 +54,10 @@ impl<T: SubFormData + Serialize + std::fmt::Debug + Default> FormValue for TuiLi
     }
 }
 
+/// Height a subform list field grows to before it scrolls, unless set with
+/// [`SubFormListField::with_max_height`].
+pub const SUBFORM_LIST_MAX_HEIGHT: u16 = 20;
+
 // SubFormListField for Vec<SubForm> relationships
 pub struct SubFormListField {
     pub form_widgets: Vec<FormWidget>,
@@ -59,6 +66,11 @@ pub struct SubFormListField {
     pub active: bool,
     pub editing_index: Option<usize>,
     pub edit_buttons: ButtonsWidget,
+    pub max_height: u16, // Height past which the items scroll
+    pub delete_policy: DeletePolicy,
+    pub(crate) deletes: DeleteBuffer<FormWidget>,
+    scroll: usize,    // Index of the first item shown
+    page_size: usize, // Items shown on the last draw, for PageUp/PageDown
 }
 impl FormFieldWidget {
     /// Creates a subform list field (Vec<SubForm> relationship)
@@ -66,13 +78,11 @@ impl FormFieldWidget {
     where
         F: Fn() -> FormWidget + Send + Sync + 'static,
     {
-        Self {
-            label: label.as_ref().to_string(),
-            inner: FormFieldType::SubFormList(SubFormListField::new(template_creator)),
+        Self::new(
+            label.as_ref().to_string(),
+            FormFieldType::SubFormList(SubFormListField::new(template_creator)),
             required,
-            help_text: None,
-            is_focused: false,
-        }
+        )
     }
 }
 impl std::fmt::Debug for SubFormListField {
@@ -83,6 +93,9 @@ impl std::fmt::Debug for SubFormListField {
             .field("active", &self.active)
             .field("editing_index", &self.editing_index)
             .field("action_buttons", &self.edit_buttons)
+            .field("max_height", &self.max_height)
+            .field("delete_policy", &self.delete_policy)
+            .field("scroll", &self.scroll)
             .finish()
     }
 }
@@ -98,20 +111,24 @@ impl SubFormListField {
             selected_form: None,
             active: false,
             editing_index: None,
-            edit_buttons: ButtonsWidget::new()
-                .add_button(
-                    "Edit",
-                    Style::default().fg(Color::Blue),
-                    Style::default().fg(Color::Black).bg(Color::Blue),
-                )
-                .add_button(
-                    "Delete",
-                    Style::default().fg(Color::Red),
-                    Style::default().fg(Color::Black).bg(Color::Red),
-                )
-                .with_padding(2),
+            edit_buttons: reorder_buttons(),
+            max_height: SUBFORM_LIST_MAX_HEIGHT,
+            delete_policy: DeletePolicy::default(),
+            deletes: DeleteBuffer::default(),
+            scroll: 0,
+            page_size: 1,
         }
     }
+
+    pub fn with_max_height(mut self, max_height: u16) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    pub fn with_delete_policy(mut self, policy: DeletePolicy) -> Self {
+        self.delete_policy = policy;
+        self
+    }
     pub fn calculate_height(&self) -> u16 {
         if self.active {
             if let Some(idx) = self.editing_index {
@@ -120,11 +137,8 @@ impl SubFormListField {
                     let nested_form = &self.form_widgets[idx];
                     let mut total_height = 3; // Base height
 
-                    // Add height for each child field
-                    for child_key in nested_form.keys() {
-                        total_height += nested_form.calculate_field_height(child_key.as_str()) + 1;
-                        // +1 for spacing
-                    }
+                    // Add height for the child fields as laid out, +1 for spacing
+                    total_height += nested_form.content_lines() + 1;
 
                     // Add height for the buttons area
                     total_height += 3;
@@ -134,46 +148,80 @@ impl SubFormListField {
                     8 // Fallback height if index is invalid
                 }
             } else {
-                // When in navigation mode but not editing, show all forms with all fields
-                let mut total_height = 0;
-
-                for form in &self.form_widgets {
-                    // Each form needs:
-                    // 1 line for title
-                    // 1 line per field
-                    // 1 line for spacing
-                    total_height += 1 + form.get_fields().len() as u16 + 1;
-                }
-
-                // Add 1 for the Add button
-                if self.active {
-                    total_height += 2;
-                }
-
-                // Add 1 for help text if any
-                total_height += 1;
-
-                // Minimum height of 3
-                total_height.max(3)
+                // In navigation mode, the items and the Add button
+                self.list_height() + 2
             }
         } else {
-            // When not active, still show all forms with all fields
-            let mut total_height = 0;
-
-            for form in &self.form_widgets {
-                // Each form needs:
-                // 1 line for title
-                // 1 line per field
-                // 1 line for spacing
-                total_height += 1 + form.get_fields().len() as u16 + 1;
-            }
+            self.list_height()
+        }
+    }
 
-            // Add 1 for help text if any
-            total_height += 1;
+    // Height of every item's summary, up to the max height past which the
+    // items scroll.
+    fn list_height(&self) -> u16 {
+        let items: u16 = (0..self.form_widgets.len())
+            .map(|idx| self.item_height(idx))
+            .sum();
+        // Add 1 for help text if any
+        (items + 1).max(3).min(self.max_height.max(3))
+    }
+
+    // Each item needs 1 line for its title, 1 line per field and 1 line for
+    // spacing.
+    fn item_height(&self, idx: usize) -> u16 {
+        self.form_widgets
+            .get(idx)
+            .map_or(0, |form| 2 + form.visible_keys().count() as u16)
+    }
 
-            // Minimum height of 3
-            total_height.max(3)
+    // Lines from the first item's title to the last's fields.
+    fn items_height(&self, first: usize, last: usize) -> u16 {
+        (first..=last)
+            .map(|idx| self.item_height(idx))
+            .sum::<u16>()
+            .saturating_sub(1)
+    }
+
+    // Scroll as little as it takes to show the selected item (the last one
+    // while on the Add button) in `rows` lines, without leaving space below
+    // the last item.
+    fn scroll_into_view(&mut self, rows: u16) {
+        let len = self.form_widgets.len();
+        if len == 0 {
+            self.scroll = 0;
+            return;
+        }
+        self.scroll = self.scroll.min(len - 1);
+        if self.active {
+            let target = self.selected_form.unwrap_or(len - 1).min(len - 1);
+            self.scroll = self.scroll.min(target);
+            while self.scroll < target && self.items_height(self.scroll, target) > rows {
+                self.scroll += 1;
+            }
         }
+        while self.scroll > 0 && self.items_height(self.scroll - 1, len - 1) <= rows {
+            self.scroll -= 1;
+        }
+    }
+
+    // Move the selection a page of items up or down.
+    fn select_page(&mut self, down: bool) -> bool {
+        let len = self.form_widgets.len();
+        let Some(idx) = self.selected_form else {
+            if down || len == 0 {
+                return false;
+            }
+            self.selected_form = Some(len.saturating_sub(self.page_size));
+            self.focus_edit();
+            return true;
+        };
+        self.selected_form = Some(if down {
+            (idx + self.page_size).min(len - 1)
+        } else {
+            idx.saturating_sub(self.page_size)
+        });
+        self.focus_edit();
+        true
     }
     pub fn get_value(&self) -> String {
         if self.form_widgets.is_empty() {
@@ -190,7 +238,7 @@ impl SubFormListField {
             }
 
             // Add form title
-            result.push_str(&form.title);
+            result.push_str(form.display_title());
 
             // Try to add the first field value for context
             if let Some((key, field)) = form.get_fields().iter().next() {
@@ -296,19 +344,54 @@ impl SubFormListField {
         self.editing_index = None;
     }
 
+    // Delete the selected item, once confirmed if the policy asks for that.
     fn delete_selected_item(&mut self) {
-        if let Some(idx) = self.selected_form {
-            if idx < self.form_widgets.len() {
-                self.form_widgets.remove(idx);
+        let Some(idx) = self
+            .selected_form
+            .filter(|&idx| idx < self.form_widgets.len())
+        else {
+            return;
+        };
+        if !self.deletes.should_delete(self.delete_policy) {
+            return;
+        }
+        self.deletes
+            .delete(&mut self.form_widgets, idx, self.delete_policy);
 
-                if self.form_widgets.is_empty() {
-                    self.selected_form = None;
-                } else if idx >= self.form_widgets.len() {
-                    self.selected_form = Some(self.form_widgets.len() - 1);
-                }
+        if self.form_widgets.is_empty() {
+            self.selected_form = None;
+        } else if idx >= self.form_widgets.len() {
+            self.selected_form = Some(self.form_widgets.len() - 1);
+        }
 
-                self.edit_buttons.unfocus();
-            }
+        self.edit_buttons.unfocus();
+    }
+
+    /// Bring back the last deleted item where it was; Ctrl+Z does this
+    /// while the list is focused. Returns whether there was one.
+    pub fn undo_delete(&mut self) -> bool {
+        if self.editing_index.is_some() {
+            return false;
+        }
+        let Some(idx) = self.deletes.undo(&mut self.form_widgets) else {
+            return false;
+        };
+        self.selected_form = Some(idx);
+        self.deletes.confirming = false;
+        self.focus_edit();
+        true
+    }
+
+    // Swap the selected item with the one above or below, the selection
+    // going with it.
+    fn move_selected_item(&mut self, down: bool) {
+        let Some(idx) = self.selected_form else {
+            return;
+        };
+        let other = if down { idx + 1 } else { idx.wrapping_sub(1) };
+        if idx < self.form_widgets.len() && other < self.form_widgets.len() {
+            self.form_widgets.swap(idx, other);
+            self.selected_form = Some(other);
         }
     }
 
@@ -333,6 +416,15 @@ impl SubFormListField {
         self.edit_buttons.unfocus();
     }
 
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        if !self.active {
+            return false;
+        }
+        self.editing_index
+            .and_then(|idx| self.form_widgets.get_mut(idx))
+            .is_some_and(|form| form.paste_event(text))
+    }
+
     // Key event handling (unchanged)
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
         // Previous implementation...
@@ -369,10 +461,34 @@ impl SubFormListField {
             return false;
         }
 
+        // "Delete? y/n" takes the next key, y deleting the selected item
+        if self.deletes.confirming {
+            if key.code == KeyCode::Char('y') {
+                self.delete_selected_item();
+            } else {
+                self.deletes.confirming = false;
+            }
+            return true;
+        }
+
+        // Ctrl+Up/Down move the selected item, staying on the list's ends
+        if is_move_key(&key) {
+            if self.selected_form.is_none() {
+                return false;
+            }
+            self.move_selected_item(key.code == KeyCode::Down);
+            return true;
+        }
+
         // Handle main navigation
         match key.code {
             KeyCode::Up => self.select_up(),
             KeyCode::Down => self.select_down(),
+            KeyCode::PageUp => self.select_page(false),
+            KeyCode::PageDown => self.select_page(true),
+            KeyCode::Left | KeyCode::Right if self.edit_buttons.is_focused() => {
+                self.edit_buttons.key_event(key)
+            }
             KeyCode::Left => {
                 if self.selected_form.is_some() {
                     self.focus_edit();
@@ -402,6 +518,9 @@ impl SubFormListField {
                     } else if selected_button == 1 {
                         // Delete button
                         self.delete_selected_item();
+                    } else {
+                        // Move up and Move down buttons
+                        self.move_selected_item(selected_button == 3);
                     }
                 } else {
                     // Add button selected - create new item
@@ -443,36 +562,48 @@ impl SubFormListField {
                 .style(Style::default().fg(Color::White))
                 .render(content_area, buf);
 
-            // Show Add button if active
-            if self.active {
+            // Show Add button if active, and if it fits above the border
+            if self.active && content_area.height > 2 {
                 let add_style = if self.selected_form.is_none() {
                     Style::default().fg(Color::Black).bg(Color::Green)
                 } else {
                     Style::default().fg(Color::Green)
                 };
 
-                Paragraph::new("+ Add").style(add_style).render(
-                    Rect {
-                        x: content_area.x,
-                        y: content_area.y + 2,
-                        width: content_area.width,
-                        height: 1,
-                    },
-                    buf,
-                );
+                Paragraph::new(Locale::current().add.as_str())
+                    .style(add_style)
+                    .render(
+                        Rect {
+                            x: content_area.x,
+                            y: content_area.y + 2,
+                            width: content_area.width,
+                            height: 1,
+                        },
+                        buf,
+                    );
             }
             return;
         }
 
-        // For all other cases, always show ALL forms with their fields
+        // Show the items that fit from the scroll position, keeping the last
+        // line for the Add button and the position indicator
+        let all_fit = self.items_height(0, self.form_widgets.len() - 1) + u16::from(self.active)
+            <= content_area.height;
+        let footer = self.active || !all_fit;
+        let rows = content_area.height.saturating_sub(u16::from(footer));
+        self.scroll_into_view(rows);
+        let max_y = content_area.y + rows;
         let mut current_y = content_area.y;
-        let max_y = area.y + area.height - 1;
+        let mut shown = 0;
 
-        for (form_idx, form) in self.form_widgets.iter().enumerate() {
-            // Stop rendering if we run out of space
-            if current_y >= max_y {
+        for (form_idx, form) in self.form_widgets.iter().enumerate().skip(self.scroll) {
+            // Stop at the first item whose fields don't all fit, unless it's
+            // the only one shown
+            let fits = current_y + self.item_height(form_idx) - 1 <= max_y;
+            if current_y >= max_y || (shown > 0 && !fits) {
                 break;
             }
+            shown += 1;
 
             // Form header with special styling for selected item in navigation mode
             let is_selected = self.selected_form == Some(form_idx) && self.active;
@@ -483,7 +614,7 @@ impl SubFormListField {
             };
 
             // Form header
-            Paragraph::new(format!("{}. {}", form_idx + 1, form.title))
+            Paragraph::new(format!("{}. {}", form_idx + 1, form.display_title()))
                 .style(title_style)
                 .render(
                     Rect {
@@ -499,19 +630,27 @@ impl SubFormListField {
 
             // If item is selected in navigation mode, show action buttons
             if is_selected {
+                relabel_item_buttons(&mut self.edit_buttons, &Locale::current());
                 let button_area = Rect {
                     x: content_area.x + 3,
                     y: current_y,
-                    width: 19,
+                    width: self
+                        .edit_buttons
+                        .width()
+                        .min(content_area.width.saturating_sub(3)),
                     height: 1,
                 };
-                self.edit_buttons.draw(button_area, buf);
+                if self.deletes.confirming {
+                    render_delete_prompt(button_area, buf);
+                } else {
+                    self.edit_buttons.draw(button_area, buf);
+                }
             }
 
             current_y += 1;
 
             // Use the field_keys vector to maintain proper field order
-            for key in form.keys() {
+            for key in form.visible_keys() {
                 if let Some(field) = form.get_fields().get(key) {
                     // Check if we have space
                     if current_y >= max_y {
@@ -538,32 +677,65 @@ impl SubFormListField {
                 }
             }
 
-            // Add a blank line between forms if we have space
-            if current_y < max_y {
-                current_y += 1;
-            }
+            // Add a blank line between forms
+            current_y += 1;
         }
+        self.page_size = shown.max(1);
+
+        // With no rows at all the footer would land on the border
+        if !footer || content_area.height == 0 {
+            return;
+        }
+        let footer_area = Rect {
+            x: content_area.x,
+            y: max_y,
+            width: content_area.width,
+            height: 1,
+        };
 
         // If in active navigation mode, always render the Add button at the bottom
         if self.active {
-            // Only render if we have space
-            if current_y < max_y {
-                let add_style = if self.selected_form.is_none() {
-                    Style::default().fg(Color::Black).bg(Color::Green)
-                } else {
-                    Style::default().fg(Color::Green)
-                };
+            let add_style = if self.selected_form.is_none() {
+                Style::default().fg(Color::Black).bg(Color::Green)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+
+            Paragraph::new(Locale::current().add.as_str())
+                .style(add_style)
+                .render(footer_area, buf);
+        }
 
-                Paragraph::new("+ Add").style(add_style).render(
+        // Which items are shown, with arrows for those scrolled out of view
+        if !all_fit {
+            let glyphs = GlyphSet::current();
+            let last = self.scroll + shown;
+            let above = if self.scroll > 0 {
+                glyphs.pick("↑", "^")
+            } else {
+                " "
+            };
+            let below = if last < self.form_widgets.len() {
+                glyphs.pick("↓", "v")
+            } else {
+                " "
+            };
+            let indicator = format!(
+                "{above}{below} {}-{last}/{}",
+                self.scroll + 1,
+                self.form_widgets.len()
+            );
+            let width = (indicator.chars().count() as u16).min(footer_area.width);
+            Paragraph::new(indicator)
+                .style(Style::default().fg(Color::DarkGray))
+                .render(
                     Rect {
-                        x: content_area.x,
-                        y: current_y,
-                        width: content_area.width,
-                        height: 1,
+                        x: footer_area.right() - width,
+                        width,
+                        ..footer_area
                     },
                     buf,
                 );
-            }
         }
     }
     pub fn enter(&mut self) {
@@ -581,6 +753,8 @@ impl SubFormListField {
         } else {
             self.active = false;
             self.selected_form = None;
+            self.scroll = 0;
+            self.deletes.confirming = false;
             self.edit_buttons.unfocus();
         }
     }
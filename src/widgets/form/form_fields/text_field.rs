@@ -1,37 +1,49 @@
 // tokio-tui/src/widgets/form/form_fields/text_field.rs
+use std::time::Duration;
+
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     layout::Rect,
-    style::Style,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Paragraph, Widget},
 };
 
-use crate::{tui_theme, InputWidget, TuiWidget};
+use crate::{GlyphSet, InputFilter, InputLines, InputWidget, TuiWidget, tui_theme};
 
-use super::{FormFieldType, FormFieldWidget};
+use super::{AddressKind, FieldUnit, FormFieldType, FormFieldWidget};
 
 #[derive(Debug)]
 pub struct TextFormField {
     pub value: String,
     pub input_box: InputWidget,
     pub max_length: Option<usize>,
+    pub unit: Option<FieldUnit>,
+    pub address: Option<AddressKind>,
+    /// Lines of text shown and edited at once; more than one makes a text
+    /// area.
+    pub rows: u16,
 }
 
+/// Lines a [`FormFieldWidget::textarea`] shows.
+pub const TEXTAREA_ROWS: u16 = 5;
+
 impl FormFieldWidget {
     /// Creates a new text input field
     pub fn text(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
-        Self {
-            label: label.into(),
-            inner: FormFieldType::Text(TextFormField {
+        Self::new(
+            label,
+            FormFieldType::Text(TextFormField {
                 input_box: InputWidget::new().without_history(),
                 value: value.into(),
                 max_length: None,
+                unit: None,
+                address: None,
+                rows: 1,
             }),
             required,
-            help_text: None,
-            is_focused: false,
-        }
+        )
     }
 
     /// Creates a new text input field with a maximum length
@@ -41,17 +53,66 @@ impl FormFieldWidget {
         max_length: usize,
         required: bool,
     ) -> Self {
-        Self {
-            label: label.into(),
-            inner: FormFieldType::Text(TextFormField {
+        Self::new(
+            label,
+            FormFieldType::Text(TextFormField {
                 input_box: InputWidget::new(),
                 value: value.into(),
                 max_length: Some(max_length),
+                unit: None,
+                address: None,
+                rows: 1,
             }),
             required,
-            help_text: None,
-            is_focused: false,
+        )
+    }
+
+    /// Creates a text area: a text field of several lines, where
+    /// Shift+Enter (or Alt+Enter) starts a new line and Enter finishes
+    /// editing.
+    pub fn textarea(label: impl Into<String>, value: impl Into<String>, required: bool) -> Self {
+        Self::text(label, value, required).with_rows(TEXTAREA_ROWS)
+    }
+
+    /// Creates a duration field: a text field taking `90s`, `1h30m`,
+    /// `250ms` and the like, shown in the normal spelling with the total
+    /// beside it. Anything else fails validation.
+    pub fn duration(label: impl Into<String>, value: Duration, required: bool) -> Self {
+        let unit = FieldUnit::Duration;
+        Self::text(label, unit.format(value.as_millis() as u64), required).with_unit(unit)
+    }
+
+    /// Accept the text field's value in any spelling of `unit`; has no
+    /// effect on other field types.
+    pub fn with_unit(mut self, unit: FieldUnit) -> Self {
+        if let FormFieldType::Text(field) = &mut self.inner {
+            field.unit = Some(unit);
+            field.normalize();
         }
+        self
+    }
+
+    /// Show and edit the text field's value over `rows` lines; has no effect
+    /// on other field types.
+    pub fn with_rows(mut self, rows: u16) -> Self {
+        if let FormFieldType::Text(field) = &mut self.inner {
+            field.rows = rows.max(1);
+            field.input_box.set_lines(if field.rows > 1 {
+                InputLines::Fixed(field.rows)
+            } else {
+                InputLines::Single
+            });
+        }
+        self
+    }
+
+    /// Reject characters `filter` doesn't allow as they're typed into the
+    /// text field; has no effect on other field types.
+    pub fn with_input_filter(mut self, filter: InputFilter) -> Self {
+        if let FormFieldType::Text(field) = &mut self.inner {
+            field.input_box.set_filter_mode(filter);
+        }
+        self
     }
 }
 
@@ -65,6 +126,42 @@ impl TextFormField {
         !self.value.trim().is_empty()
     }
 
+    /// The value in canonical units, if the field has a unit and it parses.
+    pub fn unit_value(&self) -> Option<u64> {
+        self.unit?.parse(&self.value)
+    }
+
+    // False only for a non-empty value that doesn't parse in the field's
+    // unit, which fails validation even on optional fields.
+    pub(crate) fn unit_is_valid(&self) -> bool {
+        self.value.trim().is_empty() || self.unit.is_none() || self.unit_value().is_some()
+    }
+
+    // What's wrong with the value as the field's kind of address; an empty
+    // value is left to the required check.
+    pub(crate) fn check_address(&self, value: &str) -> Result<(), String> {
+        match self.address {
+            Some(kind) if !value.trim().is_empty() => kind.check(value),
+            _ => Ok(()),
+        }
+    }
+
+    // Take the edited text, clipped to the max length and normalized to the
+    // field's unit.
+    fn commit_input(&mut self) {
+        self.value = self.input_box.text().to_string();
+        if let Some(max) = self.max_length {
+            self.value = self.value.chars().take(max).collect();
+        }
+        self.normalize();
+    }
+
+    fn normalize(&mut self) {
+        if let Some(normal) = self.unit.and_then(|unit| unit.normalize(&self.value)) {
+            self.value = normal;
+        }
+    }
+
     pub fn enter(&mut self) {
         self.input_box.focus_and_set_text(&self.value);
     }
@@ -72,10 +169,7 @@ impl TextFormField {
     pub fn leave(&mut self) {
         // Save current value before unfocusing
         if self.input_box.is_focused() {
-            self.value = self.input_box.text().to_string();
-            if let Some(max) = self.max_length {
-                self.value = self.value.chars().take(max).collect();
-            }
+            self.commit_input();
         }
         self.input_box.unfocus();
     }
@@ -85,14 +179,16 @@ impl TextFormField {
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> bool {
+        // Shift+Enter and Alt+Enter start a new line in a text area
+        let newline = self.rows > 1
+            && key
+                .modifiers
+                .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT);
         match key.code {
-            KeyCode::Enter => {
+            KeyCode::Enter if !newline => {
                 if self.input_box.is_focused() {
                     // Complete text editing
-                    self.value = self.input_box.text().to_string();
-                    if let Some(max) = self.max_length {
-                        self.value = self.value.chars().take(max).collect();
-                    }
+                    self.commit_input();
                     self.input_box.unfocus();
                     return true;
                 }
@@ -105,6 +201,10 @@ impl TextFormField {
         }
     }
 
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.input_box.paste_event(text)
+    }
+
     pub fn render(&mut self, buf: &mut Buffer, area: Rect, block: Block<'_>) {
         // Render the block
         block.render(area, buf);
@@ -114,7 +214,7 @@ impl TextFormField {
             x: area.x + 1,
             y: area.y + 1,
             width: area.width.saturating_sub(2),
-            height: 1,
+            height: self.rows.min(area.height.saturating_sub(2)),
         };
 
         // Handle value rendering
@@ -130,13 +230,54 @@ impl TextFormField {
                 Style::default().fg(tui_theme::TEXT_FG)
             };
 
-            Paragraph::new(self.value.as_str())
+            // The placeholder stands in for an empty value
+            if self.value.is_empty() && !self.input_box.hint().is_empty() {
+                Paragraph::new(self.input_box.hint())
+                    .style(Style::default().fg(tui_theme::HINT_FG))
+                    .render(content_area, buf);
+                return;
+            }
+            if self.rows > 1 {
+                Paragraph::new(self.value.as_str())
+                    .style(value_style)
+                    .render(content_area, buf);
+                return;
+            }
+
+            let mut spans = vec![Span::raw(self.value.as_str())];
+            if let Some(unit) = self.unit
+                && !self.value.trim().is_empty()
+            {
+                // Canonical value, or a note that the input didn't parse
+                let suffix = match unit.parse(&self.value) {
+                    Some(value) => Span::styled(
+                        format!("  = {}", unit.describe(value)),
+                        Style::default().add_modifier(Modifier::DIM),
+                    ),
+                    None => Span::styled(
+                        format!(
+                            "  {} not a valid {}",
+                            GlyphSet::current().pick("✗", "x"),
+                            unit.noun()
+                        ),
+                        Style::default().fg(Color::Red),
+                    ),
+                };
+                spans.push(suffix);
+            } else if let Err(hint) = self.check_address(&self.value) {
+                spans.push(Span::styled(
+                    format!("  {} {hint}", GlyphSet::current().pick("✗", "x")),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+
+            Paragraph::new(Line::from(spans))
                 .style(value_style)
                 .render(content_area, buf);
         }
     }
 
     pub fn calculate_height(&self) -> u16 {
-        3
+        self.rows + 2
     }
 }
@@ -0,0 +1,131 @@
+// tokio-tui/src/widgets/form/form_file.rs
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::FormWidget;
+
+use super::FormData;
+
+/// Text format of a file a form's data is saved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormFileFormat {
+    Json,
+    Toml,
+}
+
+impl FormFileFormat {
+    /// TOML for a `.toml` path, JSON otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn write<T: Serialize>(self, data: &T) -> Result<String> {
+        Ok(match self {
+            Self::Json => serde_json::to_string_pretty(data)?,
+            Self::Toml => toml::to_string_pretty(data)?,
+        })
+    }
+
+    pub fn read<T: DeserializeOwned>(self, text: &str) -> Result<T> {
+        Ok(match self {
+            Self::Json => serde_json::from_str(text)?,
+            Self::Toml => toml::from_str(text)?,
+        })
+    }
+}
+
+impl FormWidget {
+    /// The form's data as `T`, in pretty-printed JSON.
+    pub fn to_json<T: FormData + Serialize>(&self) -> Result<String> {
+        FormFileFormat::Json.write(&self.get_data::<T>())
+    }
+
+    /// Replace the form's data with `T` read from JSON, as
+    /// [`set_data`](Self::set_data) does.
+    pub fn load_json<T: FormData + DeserializeOwned>(&mut self, json: &str) -> Result<()> {
+        let data: T = FormFileFormat::Json.read(json)?;
+        self.set_data(&data);
+        Ok(())
+    }
+
+    /// The form's data as `T`, in TOML.
+    pub fn to_toml<T: FormData + Serialize>(&self) -> Result<String> {
+        FormFileFormat::Toml.write(&self.get_data::<T>())
+    }
+
+    /// Replace the form's data with `T` read from TOML, as
+    /// [`set_data`](Self::set_data) does.
+    pub fn load_toml<T: FormData + DeserializeOwned>(&mut self, toml: &str) -> Result<()> {
+        let data: T = FormFileFormat::Toml.read(toml)?;
+        self.set_data(&data);
+        Ok(())
+    }
+}
+
+type SaveFn = Box<dyn Fn(&FormWidget) -> Result<String> + Send + Sync>;
+type LoadFn = Box<dyn Fn(&mut FormWidget, &str) -> Result<()> + Send + Sync>;
+
+/// The file a form saves to and loads from, set with
+/// [`FormWidget::with_file`].
+pub struct FormFile {
+    pub path: PathBuf,
+    pub format: FormFileFormat,
+    save: SaveFn,
+    load: LoadFn,
+}
+
+impl FormFile {
+    pub fn new<T: FormData + Serialize + DeserializeOwned>(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let format = FormFileFormat::from_path(&path);
+        Self {
+            path,
+            format,
+            save: Box::new(move |form| format.write(&form.get_data::<T>())),
+            load: Box::new(move |form, text| {
+                let data: T = format.read(text)?;
+                form.set_data(&data);
+                Ok(())
+            }),
+        }
+    }
+
+    /// Write the form's data to the file, through a temporary file next
+    /// to it so that a crash midway leaves the old file whole.
+    pub fn save(&self, form: &FormWidget) -> Result<()> {
+        let text = (self.save)(form)?;
+        write_replacing(&self.path, &text)
+            .with_context(|| format!("writing {}", self.path.display()))
+    }
+
+    pub fn load(&self, form: &mut FormWidget) -> Result<()> {
+        let text = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("reading {}", self.path.display()))?;
+        (self.load)(form, &text).with_context(|| format!("parsing {}", self.path.display()))
+    }
+}
+
+// Write `text` to a temporary file beside `path`, then move it over `path`.
+fn write_replacing(path: &Path, text: &str) -> std::io::Result<()> {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    let temp = path.with_file_name(name);
+    std::fs::write(&temp, text)?;
+    std::fs::rename(&temp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&temp);
+    })
+}
+
+impl std::fmt::Debug for FormFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormFile")
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish()
+    }
+}
@@ -0,0 +1,86 @@
+// tokio-tui/src/widgets/form/form_section.rs
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::{GlyphSet, tui_theme};
+
+use super::FieldMeta;
+
+/// A titled run of form fields under a header that collapses it. It starts
+/// at the field `first_key` and runs to the next section or the end of the
+/// form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormSection {
+    pub title: String,
+    pub first_key: String,
+    pub collapsed: bool,
+}
+
+impl FormSection {
+    pub fn new(title: impl Into<String>, first_key: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            first_key: first_key.into(),
+            collapsed: false,
+        }
+    }
+
+    /// Sections of a `#[field(section = "...")]` form, each starting at the
+    /// field with the attribute.
+    pub(crate) fn from_definitions(definitions: &[FieldMeta]) -> Vec<Self> {
+        definitions
+            .iter()
+            .filter_map(|def| Some(Self::new(def.section?, def.id)))
+            .collect()
+    }
+
+    /// Draw the header: an arrow showing whether the section is open, its
+    /// title and, when collapsed, how many fields it hides.
+    pub(crate) fn render_header(&self, hidden: usize, focused: bool, area: Rect, buf: &mut Buffer) {
+        let glyphs = GlyphSet::current();
+        let arrow = if self.collapsed {
+            glyphs.pick("▶", ">")
+        } else {
+            glyphs.pick("▼", "v")
+        };
+        let title_style = if focused {
+            Style::default()
+                .fg(tui_theme::SELECTED_FG)
+                .bg(tui_theme::SELECTED_BG)
+        } else {
+            Style::default()
+                .fg(tui_theme::ACTIVE_FG)
+                .add_modifier(Modifier::BOLD)
+        };
+        let mut spans = vec![Span::styled(format!("{arrow} {}", self.title), title_style)];
+        if self.collapsed {
+            spans.push(Span::styled(
+                format!(" ({hidden})"),
+                Style::default().fg(tui_theme::HINT_FG),
+            ));
+        }
+        let used: usize = spans.iter().map(Span::width).sum();
+        let rule = (area.width as usize).saturating_sub(used + 1);
+        if rule > 0 {
+            spans.push(Span::styled(
+                format!(" {}", glyphs.pick("─", "-").repeat(rule)),
+                Style::default().fg(tui_theme::BORDER_DEFAULT),
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+}
+
+/// A row of a form's layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormRow {
+    /// Header of the section at this index.
+    Header(usize),
+    /// The field at this index of the form's keys.
+    Field(usize),
+}
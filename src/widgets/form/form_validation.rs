@@ -0,0 +1,161 @@
+// tokio-tui/src/widgets/form/form_validation.rs
+use std::{fmt, future::Future, pin::Pin, time::Instant};
+
+use regex::Regex;
+use tokio::sync::oneshot;
+
+use crate::Locale;
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Checks a form field's value, as
+/// [`FormFieldWidget::get_value_as_string`](crate::FormFieldWidget::get_value_as_string)
+/// gives it (passwords unmasked), returning the message to show when it isn't acceptable. Add one
+/// with [`FormFieldWidget::with_validator`](crate::FormFieldWidget::with_validator).
+pub struct FieldValidator(Check);
+
+type Check = Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+impl FieldValidator {
+    pub fn new(check: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Box::new(check))
+    }
+
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+impl fmt::Debug for FieldValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FieldValidator")
+    }
+}
+
+impl FormFieldWidget {
+    /// Keep a number field's value, or the length of a text or password
+    /// field's, from `min` to `max`; either bound can be left open. Has no
+    /// effect on other field types.
+    pub fn with_bounds(mut self, min: Option<f64>, max: Option<f64>) -> Self {
+        let locale = Locale::current();
+        let (at_least, at_most) = (locale.at_least.clone(), locale.at_most.clone());
+        let bounds = move |value: f64, unit: &str| match (min, max) {
+            (Some(min), _) if value < min => Err(format!("{at_least} {min}{unit}")),
+            (_, Some(max)) if value > max => Err(format!("{at_most} {max}{unit}")),
+            _ => Ok(()),
+        };
+        match &self.inner {
+            FormFieldType::Number(_) | FormFieldType::Slider(_) => {
+                self.add_validator(move |value| match Locale::current().parse_decimal(value) {
+                    Some(value) => bounds(value, ""),
+                    None => Err(Locale::current().invalid.clone()),
+                })
+            }
+            FormFieldType::Text(_) | FormFieldType::Password(_) => {
+                let unit = format!(" {}", locale.characters);
+                self.add_validator(move |value| bounds(value.chars().count() as f64, &unit));
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Only accept values the regular expression `pattern` matches; anchor
+    /// it with `^` and `$` to match the whole value. A pattern that doesn't
+    /// compile fails every value, saying why.
+    pub fn with_pattern(self, pattern: &str) -> Self {
+        match Regex::new(pattern) {
+            Ok(regex) => self.with_validator(move |value| {
+                if regex.is_match(value) {
+                    Ok(())
+                } else {
+                    Err(Locale::current().invalid.clone())
+                }
+            }),
+            Err(error) => {
+                let message = format!("Bad pattern: {error}");
+                self.with_validator(move |_| Err(message.clone()))
+            }
+        }
+    }
+}
+
+/// Checks a form field's value in the background, e.g. against a remote
+/// service, resolving to the message to show when it isn't acceptable. Set
+/// one with [`FormFieldWidget::with_async_validator`](crate::FormFieldWidget::with_async_validator).
+pub struct AsyncFieldValidator(AsyncCheck);
+
+type AsyncCheck =
+    Box<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> + Send + Sync>;
+
+impl AsyncFieldValidator {
+    pub fn new<F, Fut>(check: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        Self(Box::new(move |value| Box::pin(check(value))))
+    }
+
+    // Run the check on the tokio runtime.
+    pub(crate) fn spawn(&self, value: String) -> PendingCheck {
+        let future = (self.0)(value.clone());
+        let (tx, result) = oneshot::channel();
+        tokio::spawn(async move {
+            let _ = tx.send(future.await);
+        });
+        PendingCheck {
+            value,
+            result,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl fmt::Debug for AsyncFieldValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AsyncFieldValidator")
+    }
+}
+
+// An async check still running, for the value it was started with.
+#[derive(Debug)]
+pub(crate) struct PendingCheck {
+    pub(crate) value: String,
+    result: oneshot::Receiver<Result<(), String>>,
+    pub(crate) started: Instant,
+}
+
+impl PendingCheck {
+    // The outcome, once the check is done.
+    pub(crate) fn poll(&mut self) -> Option<Result<(), String>> {
+        match self.result.try_recv() {
+            Ok(result) => Some(result),
+            Err(oneshot::error::TryRecvError::Empty) => None,
+            // The check panicked
+            Err(oneshot::error::TryRecvError::Closed) => {
+                Some(Err(Locale::current().check_failed.clone()))
+            }
+        }
+    }
+}
+
+/// A field that failed validation, as reported by
+/// [`FormWidget::validate`](crate::FormWidget::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Key of the field. Fields of nested forms are joined to their parent's
+    /// key with dots, with the item index in between for subform lists:
+    /// `address.city`, `contacts.0.email`.
+    pub key: String,
+    pub label: String,
+    pub message: String,
+    /// An async validator is still checking the field; `message` says so.
+    pub pending: bool,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.message)
+    }
+}
@@ -0,0 +1,61 @@
+// tokio-tui/src/widgets/form/form_visibility.rs
+use std::{collections::HashMap, fmt};
+
+use super::{FormFieldType, FormFieldWidget};
+
+/// Decides from the other fields' current values whether a form field is
+/// shown. Set one with
+/// [`FormFieldWidget::with_show_if`](crate::FormFieldWidget::with_show_if).
+pub struct ShowIf(Predicate);
+
+type Predicate = Box<dyn Fn(&FieldValues) -> bool + Send + Sync>;
+
+impl ShowIf {
+    pub fn new(predicate: impl Fn(&FieldValues) -> bool + Send + Sync + 'static) -> Self {
+        Self(Box::new(predicate))
+    }
+
+    pub fn check(&self, values: &FieldValues) -> bool {
+        (self.0)(values)
+    }
+}
+
+impl fmt::Debug for ShowIf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ShowIf")
+    }
+}
+
+/// The fields of a form, as a [`ShowIf`] predicate sees them.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldValues<'a> {
+    fields: &'a HashMap<String, FormFieldWidget>,
+}
+
+impl<'a> FieldValues<'a> {
+    pub fn new(fields: &'a HashMap<String, FormFieldWidget>) -> Self {
+        Self { fields }
+    }
+
+    pub fn field(&self, key: &str) -> Option<&'a FormFieldWidget> {
+        self.fields.get(key)
+    }
+
+    /// The field's value, as
+    /// [`FormFieldWidget::get_value_as_string`] gives it.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.field(key).map(FormFieldWidget::get_value_as_string)
+    }
+
+    /// Whether the field is a checked checkbox.
+    pub fn is_checked(&self, key: &str) -> bool {
+        self.field(key).is_some_and(
+            |field| matches!(&field.inner, FormFieldType::Checkbox(checkbox) if checkbox.checked),
+        )
+    }
+
+    /// Whether the field has a value, as a required field needs.
+    pub fn is_set(&self, key: &str) -> bool {
+        self.field(key).is_some_and(|field| field.inner.is_valid())
+    }
+}
@@ -1,20 +1,40 @@
 // tokio-tui/src/widgets/form/form_widget.rs
 
+use anyhow::{Result, bail};
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
-    layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, Borders, Widget},
+    crossterm::event::{
+        KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    symbols::line,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        StatefulWidget as _, Widget, Wrap,
+    },
+};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
 };
-use std::collections::HashMap;
 use tracing::debug;
 
-use crate::{tui_theme, ButtonsWidget, TuiWidget};
+use crate::{
+    ButtonsWidget, GlyphSet, InteractionConfig, Locale, QuitConcerns, TuiWidget, tui_theme,
+};
 
-use super::{FormData, FormFieldType, FormFieldWidget};
+use super::{
+    FieldError, FieldValues, FormData, FormDraft, FormFieldType, FormFieldWidget, FormFile,
+    FormMeta, FormRow, FormSection, FormValue,
+};
 
 pub type FormWidgetCallback = Box<dyn Fn(&mut FormWidget) + Send + Sync>;
+pub type FieldChangeCallback = Box<dyn Fn(&str, &FormFieldWidget) + Send + Sync>;
 
 pub struct FormWidget {
     pub title: String,
@@ -25,35 +45,168 @@ pub struct FormWidget {
 
     // Option<usize> where None means buttons are selected
     active_field_index: Option<usize>,
+    // Where each visible field was last drawn, for mouse clicks
+    field_areas: Vec<(usize, Rect)>,
+
+    sections: Vec<FormSection>,
+    // Index of the section whose header has focus, instead of a field or
+    // the buttons
+    active_section: Option<usize>,
+    header_areas: Vec<(usize, Rect)>,
+    // Columns the fields are laid out in, left to right, if set
+    columns: Option<u16>,
+    // Settings from the form data's `#[tui_edit(...)]`, for when the form
+    // wasn't given its own
+    meta_title: Option<&'static str>,
+    meta_columns: Option<u16>,
+    meta_submit_label: Option<&'static str>,
+    // Used instead of the app-wide locale, if set
+    locale: Option<Arc<Locale>>,
+
+    // Lines of the form's content scrolled above the top
+    scroll_offset: u16,
+    // Lines of content shown at once, as last drawn
+    viewport_height: u16,
+    v_scrollbar: ScrollbarState,
+    // Keys of the fields Tab moves through, in order, if not all of them
+    tab_order: Option<Vec<String>>,
+    // Set while the focused field is cut off at an edge, where its cursor
+    // would be drawn out of place
+    cursor_hidden: bool,
 
     is_focused: bool,
     on_cancel: Option<FormWidgetCallback>,
     on_submit: Option<FormWidgetCallback>,
+    on_change: Option<FieldChangeCallback>,
+    // Field values as last reported to `on_change`
+    reported_values: HashMap<String, String>,
 
     submit_buttons: ButtonsWidget,
+    button_actions: Vec<FormAction>,
+    // Buttons added with `with_action`, by `FormAction::Custom` index
+    custom_actions: Vec<CustomAction>,
+    // Shown on the Submit button instead of the locale's label
+    submit_label: Option<String>,
     nested: bool,
 
     status: FormWidgetStatus,
+    // Submit once the async validators are done
+    submit_when_checked: bool,
+
+    // When Esc last returned focus to a field of this form
+    returned_at: Option<Instant>,
+
+    // Fields as loaded, for Reset and Ctrl+Z to go back to
+    initial_fields: HashMap<String, FormFieldWidget>,
+    // Field values as loaded or last submitted, to tell edits apart
+    saved_values: HashMap<String, String>,
+    // Ask before cancelling a form with changes
+    confirm_discard: bool,
+    // Discard and Keep editing buttons, while asking, and what to do once
+    // the changes are discarded
+    discard_prompt: Option<ButtonsWidget>,
+    after_discard: AfterDiscard,
+    // The focused field's help, opened with F1
+    help_open: bool,
+
+    file: Option<FormFile>,
+    draft: Option<FormDraft>,
+    // Restore and Discard buttons, while asking about a draft from last time
+    restore_prompt: Option<ButtonsWidget>,
+    // Outcome of the last save or load, and whether it failed
+    file_message: Option<(String, bool)>,
 }
-#[derive(PartialEq, Eq)]
+
+/// How long a field stays highlighted after Esc returns focus to it.
+pub const FOCUS_RETURN_FLASH: Duration = Duration::from_millis(600);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FormWidgetStatus {
     None,
     Submit,
     Cancel,
+    /// The button added with [`FormWidget::with_back_button`] was pressed.
+    Back,
+    /// A button added with [`FormWidget::with_action`] was pressed; holds
+    /// its label.
+    Action(String),
+}
+
+// What a button at the bottom of the form does, in the buttons' order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FormAction {
+    Back,
+    Submit,
+    Custom(usize),
+    Cancel,
+    Reset,
+}
+
+// What the discard prompt goes on to do once the user agrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AfterDiscard {
+    Cancel,
+    LoadFile,
+}
+
+// A button added with `FormWidget::with_action`.
+struct CustomAction {
+    label: String,
+    color: Color,
+    callback: FormWidgetCallback,
 }
 
-fn make_buttons(with_cancel: bool) -> ButtonsWidget {
+impl FormAction {
+    fn label<'a>(self, locale: &'a Locale, custom: &'a [CustomAction]) -> &'a str {
+        match self {
+            FormAction::Back => &locale.back,
+            FormAction::Submit => &locale.submit,
+            FormAction::Custom(idx) => &custom[idx].label,
+            FormAction::Cancel => &locale.cancel,
+            FormAction::Reset => &locale.reset,
+        }
+    }
+
+    fn color(self, custom: &[CustomAction]) -> Color {
+        match self {
+            FormAction::Back => Color::Blue,
+            FormAction::Submit => Color::Green,
+            FormAction::Custom(idx) => custom[idx].color,
+            FormAction::Cancel => Color::Red,
+            FormAction::Reset => Color::Yellow,
+        }
+    }
+}
+
+// Lines `line` takes once word-wrapped to `width`.
+fn wrapped_height(line: &str, width: usize) -> usize {
+    let mut lines = 1;
+    let mut used = 0;
+    for word in line.split_whitespace() {
+        let len = word.chars().count();
+        if used > 0 && used + 1 + len > width {
+            lines += 1;
+            used = 0;
+        }
+        used += usize::from(used > 0) + len;
+        // A word longer than the line breaks across lines
+        while used > width {
+            lines += 1;
+            used -= width;
+        }
+    }
+    lines
+}
+
+fn make_buttons(actions: &[FormAction], custom: &[CustomAction]) -> ButtonsWidget {
+    let locale = Locale::current();
     let mut buttons = ButtonsWidget::new();
-    buttons = buttons.add_button(
-        "Submit",
-        Style::default().fg(Color::Green),
-        Style::default().fg(Color::Black).bg(Color::Green),
-    );
-    if with_cancel {
+    for action in actions {
+        let color = action.color(custom);
         buttons = buttons.add_button(
-            "Cancel",
-            Style::default().fg(Color::Red),
-            Style::default().fg(Color::Black).bg(Color::Red),
+            action.label(&locale, custom),
+            Style::default().fg(color),
+            Style::default().fg(Color::Black).bg(color),
         );
     }
     buttons
@@ -69,12 +222,43 @@ impl FormWidget {
             field_keys: Vec::new(),
             border_style: Style::default().fg(tui_theme::BORDER_DEFAULT),
             active_field_index: None, // Buttons selected by default
+            field_areas: Vec::new(),
+            sections: Vec::new(),
+            active_section: None,
+            header_areas: Vec::new(),
+            columns: None,
+            meta_title: None,
+            meta_columns: None,
+            meta_submit_label: None,
+            locale: None,
+            scroll_offset: 0,
+            viewport_height: 0,
+            v_scrollbar: ScrollbarState::default(),
+            tab_order: None,
+            cursor_hidden: false,
             is_focused: false,
             on_cancel: None,
             on_submit: None,
-            submit_buttons: make_buttons(false),
+            on_change: None,
+            reported_values: HashMap::new(),
+            submit_buttons: make_buttons(&[FormAction::Submit], &[]),
+            button_actions: vec![FormAction::Submit],
+            custom_actions: Vec::new(),
+            submit_label: None,
             nested: false,
             status: FormWidgetStatus::None,
+            submit_when_checked: false,
+            returned_at: None,
+            initial_fields: HashMap::new(),
+            saved_values: HashMap::new(),
+            confirm_discard: false,
+            discard_prompt: None,
+            after_discard: AfterDiscard::Cancel,
+            help_open: false,
+            file: None,
+            draft: None,
+            restore_prompt: None,
+            file_message: None,
         }
     }
 
@@ -91,24 +275,398 @@ impl FormWidget {
 
             self.on_cancel = Some(callback)
         }
+        if let Some(mut draft) = self.draft.take() {
+            draft.discard(self);
+            self.draft = Some(draft);
+        }
         if !self.nested {
             self.status = FormWidgetStatus::Cancel
         }
     }
 
-    // Submit the form
+    // Cancel the form, first asking whether to throw away its changes if
+    // the form asks that.
+    fn request_cancel(&mut self) {
+        if !self.confirm_discard || self.nested || !self.is_dirty() {
+            self.cancel_form();
+            return;
+        }
+        self.open_discard_prompt(AfterDiscard::Cancel);
+    }
+
+    // Load the form's file, first asking whether to throw away its changes
+    // if it has any.
+    fn request_load_file(&mut self) {
+        if self.is_dirty() {
+            self.open_discard_prompt(AfterDiscard::LoadFile);
+        } else {
+            self.load_file_and_show();
+        }
+    }
+
+    fn load_file_and_show(&mut self) {
+        let result = self.load_file();
+        self.show_file_result(result, &Locale::current().loaded);
+    }
+
+    // Ask "Discard changes?", going on to `after` if the user agrees.
+    fn open_discard_prompt(&mut self, after: AfterDiscard) {
+        let locale = Locale::current();
+        let mut buttons = ButtonsWidget::new()
+            .with_padding(2)
+            .add_button(
+                &locale.discard,
+                Style::default().fg(Color::Red),
+                Style::default().fg(Color::Black).bg(Color::Red),
+            )
+            .add_button(
+                &locale.keep_editing,
+                Style::default().fg(Color::Green),
+                Style::default().fg(Color::Black).bg(Color::Green),
+            );
+        // Default to the harmless choice
+        buttons.set_selected(1);
+        buttons.focus();
+        self.discard_prompt = Some(buttons);
+        self.after_discard = after;
+    }
+
+    // Keys go to the discard prompt while it's open.
+    fn discard_prompt_key(&mut self, key: KeyEvent) {
+        let Some(buttons) = &mut self.discard_prompt else {
+            return;
+        };
+        let discard = match key.code {
+            KeyCode::Char('y') => true,
+            KeyCode::Char('n') | KeyCode::Esc => false,
+            KeyCode::Enter => buttons.selected() == 0,
+            KeyCode::Tab => {
+                buttons.next_button();
+                return;
+            }
+            KeyCode::BackTab => {
+                buttons.prev_button();
+                return;
+            }
+            _ => {
+                buttons.key_event(key);
+                return;
+            }
+        };
+        self.discard_prompt = None;
+        if discard {
+            match self.after_discard {
+                AfterDiscard::Cancel => self.cancel_form(),
+                AfterDiscard::LoadFile => self.load_file_and_show(),
+            }
+        }
+    }
+
+    fn draw_discard_prompt(&mut self, area: Rect, buf: &mut Buffer) {
+        let locale = Locale::current();
+        if let Some(buttons) = &mut self.discard_prompt {
+            draw_prompt(
+                buttons,
+                &locale.discard_title,
+                &locale.discard_prompt,
+                area,
+                buf,
+            );
+        }
+        if let Some(buttons) = &mut self.restore_prompt {
+            draw_prompt(
+                buttons,
+                &locale.restore_title,
+                &locale.restore_prompt,
+                area,
+                buf,
+            );
+        }
+    }
+
+    // Keys go to the restore prompt while it's open.
+    fn restore_prompt_key(&mut self, key: KeyEvent) {
+        let Some(buttons) = &mut self.restore_prompt else {
+            return;
+        };
+        let restore = match key.code {
+            KeyCode::Char('y') => true,
+            KeyCode::Char('n') | KeyCode::Esc => false,
+            KeyCode::Enter => buttons.selected() == 0,
+            KeyCode::Tab => {
+                buttons.next_button();
+                return;
+            }
+            KeyCode::BackTab => {
+                buttons.prev_button();
+                return;
+            }
+            _ => {
+                buttons.key_event(key);
+                return;
+            }
+        };
+        self.restore_prompt = None;
+        if restore {
+            self.restore_draft();
+        } else if let Some(draft) = &mut self.draft {
+            draft.remove();
+        }
+    }
+
+    /// Label and help text of the focused field, the innermost one when a
+    /// nested form is being edited.
+    pub fn focused_help(&self) -> Option<(&str, &str)> {
+        let field = self
+            .active_field_index
+            .and_then(|idx| self.field_keys.get(idx))
+            .and_then(|key| self.fields.get(key))?;
+        let nested = match &field.inner {
+            FormFieldType::SubForm(subform) if subform.active => subform.form_widget.focused_help(),
+            FormFieldType::SubFormList(list) if list.active => list
+                .editing_index
+                .and_then(|idx| list.form_widgets.get(idx))
+                .and_then(FormWidget::focused_help),
+            _ => None,
+        };
+        nested.or_else(|| Some((field.label.as_str(), field.help_text.as_deref()?)))
+    }
+
+    // The focused field's help on one line under the buttons, with a hint
+    // to open the rest when it doesn't fit.
+    fn draw_help_footer(&self, area: Rect, buf: &mut Buffer) {
+        let Some((_, help)) = self.focused_help() else {
+            return;
+        };
+        let locale = Locale::current();
+        let first = help.lines().next().unwrap_or_default();
+        let hint = format!(" ({})", locale.help_hint);
+        let hint_width = hint.chars().count();
+        let width = area.width as usize;
+        let cut = first.chars().count() > width || help.lines().nth(1).is_some();
+        let shown: String = if cut {
+            first
+                .chars()
+                .take(width.saturating_sub(hint_width))
+                .collect()
+        } else {
+            first.to_string()
+        };
+        let mut spans = vec![Span::styled(shown, Style::default().fg(tui_theme::HINT_FG))];
+        if cut {
+            spans.push(Span::styled(
+                hint,
+                Style::default()
+                    .fg(tui_theme::HINT_FG)
+                    .add_modifier(Modifier::DIM),
+            ));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    // The focused field's whole help text in a box over the form, while F1
+    // has it open.
+    fn draw_help_popup(&self, area: Rect, buf: &mut Buffer) {
+        if !self.help_open {
+            return;
+        }
+        let Some((label, help)) = self.focused_help() else {
+            return;
+        };
+        let width = 60.min(area.width.saturating_sub(4));
+        let text_width = usize::from(width.saturating_sub(4)).max(1);
+        let lines: usize = help
+            .lines()
+            .map(|line| wrapped_height(line, text_width))
+            .sum();
+        let height = (lines as u16 + 2).min(area.height);
+        let modal = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        Clear.render(modal, buf);
+        let block = Block::default()
+            .title(format!(" {} ", label))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(tui_theme::BORDER_FOCUSED));
+        let inner = block.inner(modal);
+        block.render(modal, buf);
+        Paragraph::new(help).wrap(Wrap { trim: false }).render(
+            Rect {
+                x: inner.x + 1,
+                width: inner.width.saturating_sub(2),
+                ..inner
+            },
+            buf,
+        );
+    }
+
+    // Submit the form, unless a field doesn't pass validation. While async
+    // validators are still checking, the submit waits for them.
     fn submit_form(&mut self) {
+        self.submit_when_checked = false;
+        if let Err(errors) = self.validate() {
+            self.submit_when_checked = errors.iter().all(|error| error.pending);
+            if !self.submit_when_checked {
+                self.focus_first_invalid();
+            }
+            return;
+        }
         if let Some(callback) = self.on_submit.take() {
             callback(self);
 
             self.on_submit = Some(callback);
         }
+        self.mark_saved();
+        if let Some(draft) = &mut self.draft {
+            draft.remove();
+        }
 
         if !self.nested {
             self.status = FormWidgetStatus::Submit
         }
     }
 
+    /// Validate every field, nested forms included, in form order. Invalid
+    /// fields show their error until a later validation passes.
+    pub fn validate(&mut self) -> Result<(), Vec<FieldError>> {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let mut errors = Vec::new();
+        // Hidden fields don't count
+        let keys: Vec<String> = self.visible_keys().cloned().collect();
+        for key in &keys {
+            let Some(field) = self.fields.get_mut(key) else {
+                continue;
+            };
+            let mut nested = Vec::new();
+            match &mut field.inner {
+                FormFieldType::SubForm(subform) => {
+                    if let Err(errors) = subform.form_widget.validate() {
+                        nested.extend(errors.into_iter().map(|error| FieldError {
+                            key: format!("{key}.{}", error.key),
+                            ..error
+                        }));
+                    }
+                }
+                FormFieldType::SubFormList(list) => {
+                    for (idx, form) in list.form_widgets.iter_mut().enumerate() {
+                        if let Err(errors) = form.validate() {
+                            nested.extend(errors.into_iter().map(|error| FieldError {
+                                key: format!("{key}.{idx}.{}", error.key),
+                                ..error
+                            }));
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            match field.validate() {
+                Err(message) => errors.push(FieldError {
+                    key: key.clone(),
+                    label: field.label.clone(),
+                    message,
+                    pending: false,
+                }),
+                Ok(()) if field.is_checking() => errors.push(FieldError {
+                    key: key.clone(),
+                    label: field.label.clone(),
+                    message: Locale::current().checking.clone(),
+                    pending: true,
+                }),
+                // A nested form's first problem shows on its field here
+                Ok(()) => field.set_error(
+                    nested
+                        .iter()
+                        .find(|error| !error.pending)
+                        .map(ToString::to_string),
+                ),
+            }
+            errors.extend(nested);
+        }
+        // Open any section with a problem so it can be seen
+        for error in &errors {
+            let key = error.key.split('.').next().unwrap_or(&error.key);
+            let section = self
+                .field_keys
+                .iter()
+                .position(|k| k == key)
+                .and_then(|idx| self.section_of(idx));
+            if let Some(section) = section {
+                self.sections[section].collapsed = false;
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Focus the first field showing a validation error, scrolling it into
+    /// view; a failed submit does this. Returns whether there was one.
+    pub fn focus_first_invalid(&mut self) -> bool {
+        self.focus_invalid_after(None)
+    }
+
+    // Focus the first field showing an error after the row at `pos` in
+    // layout order, wrapping around.
+    fn focus_invalid_after(&mut self, pos: Option<usize>) -> bool {
+        let rows = self.layout_rows();
+        let start = pos.map_or(0, |pos| pos + 1);
+        let invalid = (0..rows.len())
+            .map(|offset| rows[(start + offset) % rows.len()])
+            .find(|row| match row {
+                FormRow::Field(idx) => self
+                    .field_keys
+                    .get(*idx)
+                    .and_then(|key| self.fields.get(key))
+                    .is_some_and(|field| field.error().is_some()),
+                FormRow::Header(_) => false,
+            });
+        if invalid.is_some() {
+            self.focus_row(invalid, true);
+        }
+        invalid.is_some()
+    }
+
+    /// Whether an async validator of a field, nested forms included, is
+    /// still checking its value.
+    pub fn is_checking(&self) -> bool {
+        self.fields.values().any(|field| {
+            field.is_checking()
+                || match &field.inner {
+                    FormFieldType::SubForm(subform) => subform.form_widget.is_checking(),
+                    FormFieldType::SubFormList(list) => {
+                        list.form_widgets.iter().any(FormWidget::is_checking)
+                    }
+                    _ => false,
+                }
+        })
+    }
+
+    // Pick up the outcomes of finished async checks, finishing a submit
+    // that was waiting for them.
+    fn poll_checks(&mut self) {
+        for field in self.fields.values_mut() {
+            field.poll_check();
+            match &mut field.inner {
+                FormFieldType::SubForm(subform) => subform.form_widget.poll_checks(),
+                FormFieldType::SubFormList(list) => list
+                    .form_widgets
+                    .iter_mut()
+                    .for_each(FormWidget::poll_checks),
+                _ => {}
+            }
+        }
+        if self.submit_when_checked && !self.is_checking() {
+            self.submit_form();
+        }
+    }
+
     pub fn reset_submit(&mut self) -> bool {
         if self.status == FormWidgetStatus::Submit {
             self.status = FormWidgetStatus::None;
@@ -119,7 +677,19 @@ impl FormWidget {
     }
 
     pub fn reset_closed(&mut self) -> bool {
-        if self.status != FormWidgetStatus::None {
+        if matches!(
+            self.status,
+            FormWidgetStatus::Submit | FormWidgetStatus::Cancel
+        ) {
+            self.status = FormWidgetStatus::None;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn reset_back(&mut self) -> bool {
+        if self.status == FormWidgetStatus::Back {
             self.status = FormWidgetStatus::None;
             true
         } else {
@@ -127,10 +697,444 @@ impl FormWidget {
         }
     }
 
+    /// The label of the [`with_action`](Self::with_action) button pressed
+    /// since the last call, if any.
+    pub fn take_action(&mut self) -> Option<String> {
+        match std::mem::replace(&mut self.status, FormWidgetStatus::None) {
+            FormWidgetStatus::Action(label) => Some(label),
+            status => {
+                self.status = status;
+                None
+            }
+        }
+    }
+
+    /// Whether a field differs from the data the form was loaded with, or
+    /// last submitted.
+    pub fn is_dirty(&self) -> bool {
+        self.has_pending_edits() || self.values() != self.saved_values
+    }
+
+    /// Take the fields' current values as the saved ones, e.g. once the app
+    /// has stored them.
+    pub fn mark_saved(&mut self) {
+        self.saved_values = self.values();
+    }
+
+    // Take the fields' values as loaded: saved, and not changes to report.
+    fn mark_loaded(&mut self) {
+        self.mark_saved();
+        self.reported_values = self.saved_values.clone();
+    }
+
+    /// Ask "Discard changes?" before Cancel or Esc closes the form with
+    /// changes; see [`is_dirty`](Self::is_dirty).
+    pub fn with_discard_confirmation(mut self) -> Self {
+        self.confirm_discard = true;
+        self
+    }
+
+    /// Whether the field under `key` differs from the data the form was
+    /// loaded with, or last submitted.
+    pub fn is_field_dirty(&self, key: &str) -> bool {
+        let Some(field) = self.fields.get(key) else {
+            return false;
+        };
+        let mut values = HashMap::new();
+        field_values(key, field, &mut values);
+        (field.is_active() && field.inner.has_pending_edit())
+            || values_differ(key, &values, &self.saved_values)
+    }
+
+    /// Set the field under `key` to `value`, leaving focus and the other
+    /// fields as they are. A field being edited keeps the user's edit and
+    /// isn't set; returns whether it was. Like loaded data, the new value
+    /// doesn't count as a change (see [`is_dirty`](Self::is_dirty)) and is
+    /// what a reset goes back to.
+    pub fn set_field_value<V: FormValue>(&mut self, key: &str, value: &V) -> bool {
+        let Some(field) = self.fields.get_mut(key) else {
+            return false;
+        };
+        let update = value.to_field_widget(&field.label, field.required);
+        if !field.set_value_from(&update) {
+            return false;
+        }
+        if let Some(initial) = self.initial_fields.get_mut(key) {
+            initial.set_value_from(&update);
+        }
+        self.save_field(key);
+        true
+    }
+
+    /// Update the form from `data`, like [`set_data`](Self::set_data) but
+    /// leaving focus, sections and scrolling alone, and skipping fields the
+    /// user has changed or is editing: e.g. for defaults a background task
+    /// detects after the form opened.
+    pub fn patch_data<T: FormData>(&mut self, data: &T) {
+        for (key, update) in data.to_fields() {
+            if self.is_field_dirty(&key) {
+                continue;
+            }
+            let Some(field) = self.fields.get_mut(&key) else {
+                continue;
+            };
+            if field.set_value_from(&update) {
+                if let Some(initial) = self.initial_fields.get_mut(&key) {
+                    initial.set_value_from(&update);
+                }
+                self.save_field(&key);
+            }
+        }
+    }
+
+    // Take the values of another form's fields under the same keys.
+    pub(crate) fn copy_values_from(&mut self, other: &FormWidget) {
+        for (key, update) in &other.fields {
+            if let Some(field) = self.fields.get_mut(key) {
+                field.set_value_from(update);
+            }
+        }
+    }
+
+    // A nested form holding only the values of this one's fields.
+    pub(crate) fn value_copy(&self) -> FormWidget {
+        let mut copy = FormWidget::new_nested();
+        for key in &self.field_keys {
+            if let Some(field) = self.fields.get(key) {
+                copy.fields.insert(key.clone(), field.value_copy());
+                copy.field_keys.push(key.clone());
+            }
+        }
+        copy
+    }
+
+    // The form's data with password values left blank, for writing where
+    // the user didn't ask for it to be kept.
+    pub(crate) fn data_without_secrets<T: FormData>(&self) -> T {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let mut fields = self.value_copy().fields;
+        fields.values_mut().for_each(FormFieldWidget::blank_secrets);
+        T::from_fields(&fields)
+    }
+
+    // Take back `other`'s password values for the fields under the same
+    // keys.
+    pub(crate) fn keep_secrets_of(&mut self, other: &FormWidget) {
+        for (key, field) in &mut self.fields {
+            if let Some(other) = other.fields.get(key) {
+                field.keep_secrets_of(other);
+            }
+        }
+    }
+
+    // Take the current value of the field under `key` as its saved one.
+    fn save_field(&mut self, key: &str) {
+        self.saved_values
+            .retain(|saved, _| !is_value_of(saved, key));
+        if let Some(field) = self.fields.get(key) {
+            field_values(key, field, &mut self.saved_values);
+        }
+    }
+
+    // Each field's value, with nested forms' fields under dotted keys.
+    fn values(&self) -> HashMap<String, String> {
+        let mut values = HashMap::new();
+        for (key, field) in &self.fields {
+            field_values(key, field, &mut values);
+        }
+        values
+    }
+
     pub fn keys(&self) -> &Vec<String> {
         &self.field_keys
     }
 
+    /// Keys of the fields shown, in form order; see
+    /// [`FormFieldWidget::with_show_if`].
+    pub fn visible_keys(&self) -> impl Iterator<Item = &String> {
+        self.field_keys
+            .iter()
+            .filter(|key| self.is_field_visible(key))
+    }
+
+    pub fn is_field_visible(&self, key: &str) -> bool {
+        self.fields
+            .get(key)
+            .is_some_and(|field| field.is_visible(&FieldValues::new(&self.fields)))
+    }
+
+    /// Show the field under `key` only while `predicate` holds; see
+    /// [`FormFieldWidget::with_show_if`].
+    pub fn with_show_if(
+        mut self,
+        key: &str,
+        predicate: impl Fn(&FieldValues) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(field) = self.fields.get_mut(key) {
+            field.set_show_if(predicate);
+        }
+        self
+    }
+
+    fn is_visible_at(&self, idx: usize) -> bool {
+        self.field_keys
+            .get(idx)
+            .is_some_and(|key| self.is_field_visible(key))
+    }
+
+    /// Start a section titled `title` at the field `first_key`; it runs to
+    /// the next section or the end of the form. Enter or Space on its header
+    /// collapses and expands it.
+    pub fn with_section(mut self, title: impl Into<String>, first_key: impl Into<String>) -> Self {
+        self.sections.push(FormSection::new(title, first_key));
+        self
+    }
+
+    /// Like [`with_section`](Self::with_section), but collapsed to start
+    /// with.
+    pub fn with_collapsed_section(
+        mut self,
+        title: impl Into<String>,
+        first_key: impl Into<String>,
+    ) -> Self {
+        let mut section = FormSection::new(title, first_key);
+        section.collapsed = true;
+        self.sections.push(section);
+        self
+    }
+
+    pub fn sections(&self) -> &[FormSection] {
+        &self.sections
+    }
+
+    pub fn set_section_collapsed(&mut self, title: &str, collapsed: bool) {
+        if let Some(section) = self.sections.iter().position(|s| s.title == title) {
+            self.set_collapsed(section, collapsed);
+        }
+    }
+
+    fn set_collapsed(&mut self, section: usize, collapsed: bool) {
+        self.sections[section].collapsed = collapsed;
+        // Focus can't stay on a field that folds away
+        if collapsed
+            && self
+                .active_field_index
+                .is_some_and(|idx| self.section_of(idx) == Some(section))
+        {
+            self.unfocus_all();
+            self.set_focused(Some(FormRow::Header(section)));
+        }
+    }
+
+    // Where each section starts in the form's keys, with its index.
+    fn section_starts(&self) -> Vec<(usize, usize)> {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter_map(|(section, s)| {
+                let start = self.field_keys.iter().position(|key| *key == s.first_key)?;
+                Some((start, section))
+            })
+            .collect()
+    }
+
+    // The section the field at `idx` is in, if any.
+    fn section_of(&self, idx: usize) -> Option<usize> {
+        self.section_starts()
+            .into_iter()
+            .filter(|(start, _)| *start <= idx)
+            .max_by_key(|(start, _)| *start)
+            .map(|(_, section)| section)
+    }
+
+    // Section headers and the fields shown under them, in form order.
+    fn layout_rows(&self) -> Vec<FormRow> {
+        let starts = self.section_starts();
+        let mut rows = Vec::new();
+        let mut collapsed = false;
+        for idx in 0..self.field_keys.len() {
+            for &(_, section) in starts.iter().filter(|(start, _)| *start == idx) {
+                rows.push(FormRow::Header(section));
+                collapsed = self.sections[section].collapsed;
+            }
+            if !collapsed && self.is_visible_at(idx) {
+                rows.push(FormRow::Field(idx));
+            }
+        }
+        rows
+    }
+
+    fn row_height(&self, row: FormRow) -> u16 {
+        match row {
+            FormRow::Header(_) => 1,
+            FormRow::Field(idx) => self
+                .field_keys
+                .get(idx)
+                .map_or(0, |key| self.calculate_field_height(key)),
+        }
+    }
+
+    // The focused row; None while the buttons have focus.
+    fn focused(&self) -> Option<FormRow> {
+        match (self.active_section, self.active_field_index) {
+            (Some(section), _) => Some(FormRow::Header(section)),
+            (None, Some(idx)) => Some(FormRow::Field(idx)),
+            (None, None) => None,
+        }
+    }
+
+    // Position of the focused row in `rows`.
+    fn focused_row(&self, rows: &[FormRow]) -> Option<usize> {
+        let focused = self.focused()?;
+        rows.iter().position(|row| *row == focused)
+    }
+
+    // Each row with the line of the form's content it starts on, its height
+    // and its column; rows are a line apart. Fields fill the columns left
+    // to right, and a section header spans them all, as column None.
+    fn row_layout(&self) -> Vec<(FormRow, u16, u16, Option<u16>)> {
+        let mut placed = Vec::new();
+        let mut top = 0;
+        let mut column = 0;
+        // Height of the tallest field on the current line
+        let mut line_height = 0;
+        for row in self.layout_rows() {
+            let height = self.row_height(row);
+            match row {
+                FormRow::Header(_) => {
+                    if column > 0 {
+                        top += line_height + 1;
+                        (column, line_height) = (0, 0);
+                    }
+                    placed.push((row, top, height, None));
+                    top += height + 1;
+                }
+                FormRow::Field(_) => {
+                    placed.push((row, top, height, Some(column)));
+                    line_height = line_height.max(height);
+                    column += 1;
+                    if column == self.columns() {
+                        top += line_height + 1;
+                        (column, line_height) = (0, 0);
+                    }
+                }
+            }
+        }
+        placed
+    }
+
+    // Lines of the form's content, as laid out.
+    fn content_height(layout: &[(FormRow, u16, u16, Option<u16>)]) -> u16 {
+        layout
+            .iter()
+            .map(|(_, top, height, _)| top + height)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // Lines the fields and section headers shown take, as laid out.
+    pub(crate) fn content_lines(&self) -> u16 {
+        Self::content_height(&self.row_layout())
+    }
+
+    // Whether a row counts as in view at `offset`: all of it, or any of it
+    // for a row taller than the view.
+    fn in_view(&self, top: u16, height: u16, offset: u16) -> bool {
+        let view_end = offset + self.viewport_height;
+        if height > self.viewport_height {
+            top < view_end && top + height > offset
+        } else {
+            top >= offset && top + height <= view_end
+        }
+    }
+
+    /// Scroll the form by `lines`, up for negative. When the focused field
+    /// scrolls out of view, focus moves to the nearest one in view.
+    pub fn scroll_by(&mut self, lines: i32) -> bool {
+        let layout = self.row_layout();
+        let content_height = Self::content_height(&layout);
+        let max_offset = content_height.saturating_sub(self.viewport_height);
+        let offset = (i32::from(self.scroll_offset) + lines).clamp(0, i32::from(max_offset)) as u16;
+        if offset == self.scroll_offset {
+            return false;
+        }
+        self.scroll_offset = offset;
+
+        let focused = self.focused();
+        let focus_in_view = layout.iter().any(|&(row, top, height, _)| {
+            Some(row) == focused && self.in_view(top, height, offset)
+        });
+        if focused.is_some() && !focus_in_view {
+            let mut shown = layout
+                .iter()
+                .filter(|&&(_, top, height, _)| self.in_view(top, height, offset));
+            let target = if lines > 0 {
+                shown.next()
+            } else {
+                shown.next_back()
+            };
+            if let Some(&(row, ..)) = target {
+                self.focus_row(Some(row), lines > 0);
+            }
+        }
+        true
+    }
+
+    // Scroll just enough to bring the row at `top` into view.
+    fn scroll_into_view(&mut self, top: u16, height: u16) {
+        if self.in_view(top, height, self.scroll_offset) {
+            return;
+        }
+        self.scroll_offset = if top < self.scroll_offset || height > self.viewport_height {
+            top
+        } else {
+            top + height - self.viewport_height
+        };
+    }
+
+    fn render_row(&mut self, row: FormRow, area: Rect, buf: &mut Buffer) {
+        match row {
+            FormRow::Header(section) => {
+                let hidden = (0..self.field_keys.len())
+                    .filter(|&idx| self.section_of(idx) == Some(section) && self.is_visible_at(idx))
+                    .count();
+                let focused = self.is_focused && self.active_section == Some(section);
+                self.sections[section].render_header(hidden, focused, area, buf);
+            }
+            FormRow::Field(field_idx) => {
+                let flashing = self.is_flashing();
+                if let Some(field) = self.field_mut(field_idx) {
+                    field.render(buf, area, None);
+
+                    // Highlight the field Esc just returned to
+                    if flashing && self.active_field_index == Some(field_idx) {
+                        highlight_border(area, buf);
+                    }
+                }
+            }
+        }
+    }
+
+    fn set_focused(&mut self, row: Option<FormRow>) {
+        (self.active_section, self.active_field_index) = match row {
+            Some(FormRow::Header(section)) => (Some(section), None),
+            Some(FormRow::Field(idx)) => (None, Some(idx)),
+            None => (None, None),
+        };
+    }
+
+    // Move focus to `row`, or to the buttons for None. Moving forward enters
+    // fields with `enter_end`, backward with `enter_start`.
+    fn focus_row(&mut self, row: Option<FormRow>, forward: bool) {
+        self.unfocus_all();
+        self.set_focused(row);
+        if row.is_none() {
+            self.submit_buttons.focus();
+        } else if let Some(field) = self.active_mut() {
+            field.enter_from(forward);
+        }
+    }
+
     pub fn field_mut(&mut self, idx: usize) -> Option<&mut FormFieldWidget> {
         self.field_keys
             .get(idx)
@@ -152,55 +1156,431 @@ impl FormWidget {
     }
 
     pub fn buttons_have_focus(&self) -> bool {
-        self.active_field_index.is_none() && self.submit_buttons.is_focused()
+        self.active_field_index.is_none()
+            && self.active_section.is_none()
+            && self.submit_buttons.is_focused()
     }
 
     // Initialize the form with a FormData struct
     pub fn with_data<T: FormData>(mut self, data: &T) -> Self {
+        let _locale = Locale::enter(self.locale.as_ref());
         self.fields = data.to_fields();
-        self.field_keys = T::field_definitions()
-            .iter()
-            .map(|def| def.id.to_string())
-            .collect();
+        self.initial_fields = data.to_fields();
+        let definitions = T::field_definitions();
+        self.field_keys = definitions.iter().map(|def| def.id.to_string()).collect();
+        self.sections = FormSection::from_definitions(&definitions);
         self.active_field_index = if self.field_keys.is_empty() {
             None
         } else {
             Some(0)
         };
+        self.apply_meta(T::form_meta());
+        self.mark_loaded();
         self
     }
     pub fn with_default<T: FormData>(mut self) -> Self {
-        let data = T::default();
+        let _locale = Locale::enter(self.locale.as_ref());
+        let data = T::form_default();
         self.fields = data.to_fields();
-        self.field_keys = T::field_definitions()
-            .iter()
-            .map(|def| def.id.to_string())
-            .collect();
+        self.initial_fields = data.to_fields();
+        let definitions = T::field_definitions();
+        self.field_keys = definitions.iter().map(|def| def.id.to_string()).collect();
+        self.sections = FormSection::from_definitions(&definitions);
         self.active_field_index = if self.field_keys.is_empty() {
             None
         } else {
             Some(0)
         };
+        self.apply_meta(T::form_meta());
+        self.mark_loaded();
         self
     }
 
-    // Sets the fields for this form using a HashMap
-    pub fn with_fields(mut self, fields: HashMap<String, FormFieldWidget>) -> Self {
-        self.field_keys = fields.keys().cloned().collect();
-        self.fields = fields;
+    /// Sets the fields for this form, laid out in the order given. A
+    /// `HashMap` has no order of its own, so pass a `Vec` or array of
+    /// pairs, or fix the order with [`reorder_fields`](Self::reorder_fields).
+    pub fn with_fields<K: Into<String>>(
+        mut self,
+        fields: impl IntoIterator<Item = (K, FormFieldWidget)>,
+    ) -> Self {
+        self.replace_fields(fields);
+        self.mark_loaded();
         self
     }
 
+    // Swap in new fields in iteration order; a repeated key keeps its
+    // first place and its last field. Their values are what a reset goes
+    // back to.
+    fn replace_fields<K: Into<String>>(
+        &mut self,
+        fields: impl IntoIterator<Item = (K, FormFieldWidget)>,
+    ) {
+        self.fields.clear();
+        self.field_keys.clear();
+        for (key, field) in fields {
+            let key = key.into();
+            if self.fields.insert(key.clone(), field).is_none() {
+                self.field_keys.push(key);
+            }
+        }
+        self.initial_fields = self
+            .fields
+            .iter()
+            .map(|(key, field)| (key.clone(), field.value_copy()))
+            .collect();
+    }
+
+    /// Put the fields under `keys` first, in that order, and the others
+    /// after them as they were. Keys of no field are ignored.
+    pub fn reorder_fields(&mut self, keys: &[&str]) {
+        let focused = self
+            .active_field_index
+            .and_then(|idx| self.field_keys.get(idx))
+            .cloned();
+        let mut ordered: Vec<String> = Vec::with_capacity(self.field_keys.len());
+        for key in keys {
+            if self.fields.contains_key(*key) && !ordered.iter().any(|done| done == key) {
+                ordered.push(key.to_string());
+            }
+        }
+        for key in &self.field_keys {
+            if !ordered.contains(key) {
+                ordered.push(key.clone());
+            }
+        }
+        self.field_keys = ordered;
+        // Focus stays on the same field
+        self.active_field_index =
+            focused.and_then(|focused| self.field_keys.iter().position(|key| *key == focused));
+    }
+
+    /// Have Tab and Shift+Tab move through the fields under `keys` in that
+    /// order, rather than as they're laid out. Fields left out are skipped
+    /// by Tab but can still be reached with the arrow keys or a click.
+    pub fn set_tab_order(&mut self, keys: &[&str]) {
+        let mut order: Vec<String> = Vec::with_capacity(keys.len());
+        for key in keys {
+            if !order.iter().any(|done| done == key) {
+                order.push(key.to_string());
+            }
+        }
+        self.tab_order = Some(order);
+    }
+
+    /// Tab through the fields under `keys`; see
+    /// [`set_tab_order`](Self::set_tab_order).
+    pub fn with_tab_order(mut self, keys: &[&str]) -> Self {
+        self.set_tab_order(keys);
+        self
+    }
+
+    /// Go back to tabbing through every field as laid out.
+    pub fn clear_tab_order(&mut self) {
+        self.tab_order = None;
+    }
+
+    /// Back the form with the file at `path`, holding a `T` in TOML for a
+    /// `.toml` path and JSON otherwise. Ctrl+S saves the form to it and
+    /// Ctrl+O loads it again, asking first if that would discard changes;
+    /// the form starts out with the file's data if it exists.
+    pub fn with_file<T: FormData + Serialize + DeserializeOwned>(
+        mut self,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        self.file = Some(FormFile::new::<T>(path));
+        if self.file.as_ref().is_some_and(|file| file.path.exists()) {
+            let result = self.load_file();
+            self.show_file_result(result, &Locale::current().loaded);
+        }
+        self
+    }
+
+    pub fn file(&self) -> Option<&FormFile> {
+        self.file.as_ref()
+    }
+
+    /// Keep the form's changes in a draft at `path` until it's submitted or
+    /// cancelled, as a `T` in TOML for a `.toml` path and JSON otherwise.
+    /// The draft is written every `interval` while there are changes, so
+    /// they survive a crash; if one is left from last time, the form asks
+    /// whether to restore it. Call it after the form's data is set.
+    ///
+    /// Password fields, `#[field(secret)]` ones included, are written blank
+    /// so that their values don't end up on disk; restoring a draft leaves
+    /// them as they are.
+    pub async fn with_draft<T: FormData + Serialize + DeserializeOwned>(
+        mut self,
+        path: impl Into<PathBuf>,
+        interval: Duration,
+    ) -> Self {
+        let draft = FormDraft::open::<T>(path, interval).await;
+        if draft.is_found() {
+            let locale = Locale::current();
+            let mut buttons = ButtonsWidget::new()
+                .with_padding(2)
+                .add_button(
+                    &locale.restore,
+                    Style::default().fg(Color::Green),
+                    Style::default().fg(Color::Black).bg(Color::Green),
+                )
+                .add_button(
+                    &locale.discard,
+                    Style::default().fg(Color::Red),
+                    Style::default().fg(Color::Black).bg(Color::Red),
+                );
+            buttons.focus();
+            self.restore_prompt = Some(buttons);
+        }
+        self.draft = Some(draft);
+        self
+    }
+
+    pub fn draft(&self) -> Option<&FormDraft> {
+        self.draft.as_ref()
+    }
+
+    /// Take the values of the draft left from last time as changes to the
+    /// form's data; see [`with_draft`](Self::with_draft).
+    pub fn restore_draft(&mut self) {
+        self.restore_prompt = None;
+        let Some(found) = self.draft.as_mut().and_then(FormDraft::take_found) else {
+            return;
+        };
+        match found {
+            Ok(fields) => {
+                for (key, restored) in &fields {
+                    if let Some(field) = self.fields.get_mut(key) {
+                        // The draft has no passwords to restore
+                        let kept = field.value_copy();
+                        field.revert_to(restored);
+                        field.keep_secrets_of(&kept);
+                    }
+                }
+            }
+            Err(error) => self.show_file_result(Err(error), ""),
+        }
+    }
+
+    // Write the draft if it's time to.
+    fn autosave(&mut self) {
+        if let Some(mut draft) = self.draft.take() {
+            draft.autosave(self);
+            self.draft = Some(draft);
+        }
+    }
+
+    /// Write the form's data to its file; see [`with_file`](Self::with_file).
+    pub fn save_file(&mut self) -> Result<()> {
+        let Some(file) = &self.file else {
+            bail!("the form has no file");
+        };
+        file.save(self)?;
+        self.mark_saved();
+        Ok(())
+    }
+
+    /// Replace the form's data with its file's; see
+    /// [`with_file`](Self::with_file).
+    pub fn load_file(&mut self) -> Result<()> {
+        let Some(file) = self.file.take() else {
+            bail!("the form has no file");
+        };
+        let result = file.load(self);
+        self.file = Some(file);
+        result
+    }
+
+    // Show how a save or load went on the form's bottom border.
+    fn show_file_result(&mut self, result: Result<()>, done: &str) {
+        self.file_message = Some(match (result, &self.file) {
+            (Ok(()), Some(file)) => (format!("{done} {}", file.path.display()), false),
+            (Ok(()), None) => (done.to_string(), false),
+            // Parse errors run on with a snippet of the file
+            (Err(error), _) => {
+                let message = format!("{error:#}");
+                (message.lines().next().unwrap_or_default().to_string(), true)
+            }
+        });
+    }
+
     // Sets the callback for when the form is cancelled
     pub fn with_cancel<F>(mut self, callback: F) -> Self
     where
         F: Fn(&mut FormWidget) + Send + Sync + 'static,
     {
         self.on_cancel = Some(Box::new(callback));
-        self.submit_buttons = make_buttons(true);
+        self.add_action(FormAction::Cancel);
+        self
+    }
+
+    /// Add a Reset button, which puts every field back to the data the
+    /// form was loaded with; see [`reset_to_initial`](Self::reset_to_initial).
+    pub fn with_reset_button(mut self) -> Self {
+        self.add_action(FormAction::Reset);
         self
     }
 
+    /// Add a Back button before Submit, reported through
+    /// [`reset_back`](Self::reset_back).
+    pub fn with_back_button(mut self) -> Self {
+        self.add_action(FormAction::Back);
+        self
+    }
+
+    /// Show `label` on the Submit button in place of the locale's.
+    pub fn set_submit_label(&mut self, label: impl Into<String>) {
+        self.submit_label = Some(label.into());
+    }
+
+    /// Use `locale` for this widget, and the fields in it, instead of the
+    /// app-wide one.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.set_locale(Some(locale));
+        self
+    }
+
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        self.locale = locale.map(Arc::new);
+    }
+
+    /// Lay the fields out in `columns` side by side, filled left to right;
+    /// focus still moves through them in order. Section headers span all
+    /// the columns.
+    pub fn with_columns(mut self, columns: u16) -> Self {
+        self.set_columns(columns);
+        self
+    }
+
+    pub fn set_columns(&mut self, columns: u16) {
+        self.columns = Some(columns.max(1));
+    }
+
+    /// Columns the fields are laid out in: the ones set, else those from
+    /// the form data's `#[tui_edit(columns)]`, else 1.
+    pub fn columns(&self) -> u16 {
+        self.columns.or(self.meta_columns).unwrap_or(1)
+    }
+
+    /// The title drawn on the border: [`title`](Self::title), or the one
+    /// from the form data's `#[tui_edit(title)]` while that's empty.
+    pub fn display_title(&self) -> &str {
+        match self.meta_title {
+            Some(title) if self.title.is_empty() => title,
+            _ => &self.title,
+        }
+    }
+
+    // Take the settings a form data struct gives its form, where the form
+    // hasn't been given its own.
+    fn apply_meta(&mut self, meta: FormMeta) {
+        self.meta_title = meta.title;
+        self.meta_columns = meta.columns.map(|columns| columns.max(1));
+        self.meta_submit_label = meta.submit_label;
+    }
+
+    /// Add a button between Submit and Cancel that runs `callback` when
+    /// pressed, then reports its label through
+    /// [`take_action`](Self::take_action). Buttons go in the order added.
+    pub fn with_action<F>(self, label: impl Into<String>, callback: F) -> Self
+    where
+        F: Fn(&mut FormWidget) + Send + Sync + 'static,
+    {
+        self.with_colored_action(label, Color::Cyan, callback)
+    }
+
+    /// [`with_action`](Self::with_action), with a button in `color`.
+    pub fn with_colored_action<F>(
+        mut self,
+        label: impl Into<String>,
+        color: Color,
+        callback: F,
+    ) -> Self
+    where
+        F: Fn(&mut FormWidget) + Send + Sync + 'static,
+    {
+        self.custom_actions.push(CustomAction {
+            label: label.into(),
+            color,
+            callback: Box::new(callback),
+        });
+        self.add_action(FormAction::Custom(self.custom_actions.len() - 1));
+        self
+    }
+
+    fn add_action(&mut self, action: FormAction) {
+        if !self.button_actions.contains(&action) {
+            self.button_actions.push(action);
+            self.button_actions.sort();
+            self.submit_buttons = make_buttons(&self.button_actions, &self.custom_actions);
+        }
+    }
+
+    // Run the callback of a `with_action` button and report it pressed.
+    fn run_action(&mut self, idx: usize) {
+        // Taken out while it runs, as the submit and cancel callbacks are
+        let placeholder: FormWidgetCallback = Box::new(|_| {});
+        let callback = std::mem::replace(&mut self.custom_actions[idx].callback, placeholder);
+        callback(self);
+        self.custom_actions[idx].callback = callback;
+
+        if !self.nested {
+            self.status = FormWidgetStatus::Action(self.custom_actions[idx].label.clone());
+        }
+    }
+
+    /// Put every field back to the data the form was loaded with (by
+    /// [`with_data`](Self::with_data), [`with_fields`](Self::with_fields),
+    /// [`set_data`](Self::set_data) and the like), dropping edits in
+    /// progress.
+    pub fn reset_to_initial(&mut self) {
+        for (key, initial) in &self.initial_fields {
+            if let Some(field) = self.fields.get_mut(key) {
+                field.revert_to(initial);
+            }
+        }
+    }
+
+    /// Put the field under `key` back to the data the form was loaded with;
+    /// Ctrl+Z does this for the focused field. Returns whether it could.
+    pub fn revert_field(&mut self, key: &str) -> bool {
+        match (self.fields.get_mut(key), self.initial_fields.get(key)) {
+            (Some(field), Some(initial)) => field.revert_to(initial),
+            _ => false,
+        }
+    }
+
+    /// Call `callback` with the key and field of each field whose value
+    /// changes, however it changed: typing, Reset, a nested form's edit or
+    /// [`set_field_value`](Self::set_field_value). Changes are picked up as
+    /// the form draws, once each; data the form is loaded with isn't one.
+    pub fn on_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, &FormFieldWidget) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self.reported_values = self.values();
+        self
+    }
+
+    // Report the fields changed since the last call to the change callback.
+    fn report_changes(&mut self) {
+        let Some(callback) = &self.on_change else {
+            return;
+        };
+        let values = self.values();
+        if values == self.reported_values {
+            return;
+        }
+        for key in &self.field_keys {
+            if values_differ(key, &values, &self.reported_values)
+                && let Some(field) = self.fields.get(key)
+            {
+                callback(key, field);
+            }
+        }
+        self.reported_values = values;
+    }
+
     // Sets the callback for when the form is submitted
     pub fn with_submit<F>(mut self, callback: F) -> Self
     where
@@ -210,21 +1590,38 @@ impl FormWidget {
         self
     }
 
-    // Sets the fields in this form
-    pub fn set_fields(&mut self, fields: HashMap<String, FormFieldWidget>) {
-        self.field_keys = fields.keys().cloned().collect();
-        self.fields = fields;
+    /// Sets the fields in this form, in the order given; see
+    /// [`with_fields`](Self::with_fields).
+    pub fn set_fields<K: Into<String>>(
+        &mut self,
+        fields: impl IntoIterator<Item = (K, FormFieldWidget)>,
+    ) {
+        self.replace_fields(fields);
         self.active_field_index = None; // Reset to buttons
+        self.active_section = None;
+        self.mark_loaded();
     }
 
     // Sets the form data
     pub fn set_data<T: FormData>(&mut self, data: &T) {
+        let _locale = Locale::enter(self.locale.as_ref());
         self.fields = data.to_fields();
-        self.field_keys = T::field_definitions()
-            .iter()
-            .map(|def| def.id.to_string())
-            .collect();
+        self.initial_fields = data.to_fields();
+        let definitions = T::field_definitions();
+        self.field_keys = definitions.iter().map(|def| def.id.to_string()).collect();
+        // Sections stay collapsed across new data
+        let mut sections = FormSection::from_definitions(&definitions);
+        for section in &mut sections {
+            section.collapsed = self
+                .sections
+                .iter()
+                .any(|old| old.title == section.title && old.collapsed);
+        }
+        self.sections = sections;
         self.active_field_index = None; // Reset to buttons
+        self.active_section = None;
+        self.apply_meta(T::form_meta());
+        self.mark_loaded();
     }
 
     // Returns a clone of the current fields in the form
@@ -232,8 +1629,29 @@ impl FormWidget {
         &self.fields
     }
 
+    pub(crate) fn fields_mut(&mut self) -> &mut HashMap<String, FormFieldWidget> {
+        &mut self.fields
+    }
+
+    /// Drop every field but those under `keys`, and the sections, for a
+    /// form showing part of its data.
+    pub(crate) fn retain_fields(mut self, keys: &[String]) -> Self {
+        self.field_keys.retain(|key| keys.contains(key));
+        self.fields.retain(|key, _| keys.contains(key));
+        self.initial_fields.retain(|key, _| keys.contains(key));
+        self.sections.clear();
+        self.active_field_index = if self.field_keys.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        self.mark_saved();
+        self
+    }
+
     // Get the form data
     pub fn get_data<T: FormData>(&self) -> T {
+        let _locale = Locale::enter(self.locale.as_ref());
         T::from_fields(&self.fields)
     }
 
@@ -251,25 +1669,171 @@ impl FormWidget {
         });
     }
 
-    // Unfocus all fields
-    fn unfocus_all(&mut self) {
-        for field in self.fields.values_mut() {
-            field.unfocus();
-        }
-        self.submit_buttons.unfocus();
+    // Unfocus all fields
+    fn unfocus_all(&mut self) {
+        for field in self.fields.values_mut() {
+            field.unfocus();
+        }
+        self.submit_buttons.unfocus();
+    }
+
+    // Get the index of the currently active field (if any)
+    fn active_field(&self) -> Option<usize> {
+        for (i, key) in self.field_keys.iter().enumerate() {
+            if let Some(field) = self.fields.get(key) {
+                if field.is_focused() {
+                    return Some(i);
+                }
+            }
+        }
+        None
+    }
+    /// Titles of the nested forms being edited, outermost first, starting
+    /// with this form's own title. Subform list items show as `Label #n`.
+    pub fn breadcrumb(&self) -> Vec<String> {
+        let mut path = vec![self.display_title().to_string()];
+        self.push_nested_path(&mut path);
+        path
+    }
+
+    fn push_nested_path(&self, path: &mut Vec<String>) {
+        let Some(field) = self
+            .active_field_index
+            .and_then(|idx| self.field_keys.get(idx))
+            .and_then(|key| self.fields.get(key))
+        else {
+            return;
+        };
+        match &field.inner {
+            FormFieldType::SubForm(subform) if subform.active => {
+                path.push(field.label.clone());
+                subform.form_widget.push_nested_path(path);
+            }
+            FormFieldType::SubFormList(list) if list.active => {
+                if let Some(idx) = list.editing_index
+                    && let Some(form) = list.form_widgets.get(idx)
+                {
+                    path.push(format!("{} #{}", field.label, idx + 1));
+                    form.push_nested_path(path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Leave the innermost field being edited, exactly one level down from
+    /// wherever focus is, and flash the field focus returns to. Returns false
+    /// if no field of this form is being edited.
+    pub(crate) fn pop_level(&mut self) -> bool {
+        let Some(field) = self.active_mut().filter(|field| field.is_active()) else {
+            return false;
+        };
+        if !field.inner.pop_nested() {
+            field.leave();
+            self.returned_at = Some(Instant::now());
+        }
+        true
+    }
+
+    // Whether the focused field is a nested form with a field of its own
+    // being edited.
+    fn editing_nested(&self) -> bool {
+        self.active_field_index
+            .and_then(|idx| self.field_keys.get(idx))
+            .and_then(|key| self.fields.get(key))
+            .is_some_and(|field| match &field.inner {
+                FormFieldType::SubForm(subform) => subform.active,
+                FormFieldType::SubFormList(list) => list.editing_index.is_some(),
+                _ => false,
+            })
+    }
+
+    fn is_flashing(&self) -> bool {
+        self.returned_at
+            .is_some_and(|at| at.elapsed() < FOCUS_RETURN_FLASH)
     }
 
-    // Get the index of the currently active field (if any)
-    fn active_field(&self) -> Option<usize> {
-        for (i, key) in self.field_keys.iter().enumerate() {
-            if let Some(field) = self.fields.get(key) {
-                if field.is_focused() {
-                    return Some(i);
-                }
+    // Breadcrumb on the header row, trimmed from the outermost end to fit,
+    // with a hint of where Esc leads on the right
+    fn render_breadcrumb(&self, area: Rect, buf: &mut Buffer) {
+        let path = self.breadcrumb();
+        if path.len() < 2 || area.width == 0 {
+            return;
+        }
+        let glyphs = GlyphSet::current();
+        let separator = glyphs.pick(" › ", " > ");
+        let ellipsis = glyphs.pick("…", "...");
+
+        let parent = &path[path.len() - 2];
+        let hint = format!("Esc: {parent}");
+        let hint_width = hint.chars().count() as u16 + 2;
+        let crumb_width = if area.width > hint_width * 2 {
+            area.width - hint_width
+        } else {
+            area.width
+        };
+
+        // Drop outer titles (behind an ellipsis) until the rest fits
+        let separator_width = separator.chars().count();
+        let width = |first: usize| -> usize {
+            let parts = &path[first..];
+            let trimmed = if first > 0 {
+                ellipsis.chars().count() + separator_width
+            } else {
+                0
+            };
+            parts.iter().map(|p| p.chars().count()).sum::<usize>()
+                + (parts.len() - 1) * separator_width
+                + trimmed
+        };
+        let mut first = 0;
+        while first + 1 < path.len() && width(first) > crumb_width as usize {
+            first += 1;
+        }
+
+        let dim = Style::default().fg(tui_theme::UNFOCUSED_FG);
+        let sep_style = Style::default().fg(tui_theme::HINT_FG);
+        let mut spans = Vec::new();
+        if first > 0 {
+            spans.push(Span::styled(ellipsis, dim));
+            spans.push(Span::styled(separator, sep_style));
+        }
+        for (i, part) in path[first..].iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(separator, sep_style));
             }
+            let style = if first + i + 1 == path.len() {
+                Style::default()
+                    .fg(tui_theme::ACTIVE_FG)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                dim
+            };
+            spans.push(Span::styled(part.as_str(), style));
+        }
+        Line::from(spans).render(
+            Rect {
+                width: crumb_width,
+                ..area
+            },
+            buf,
+        );
+
+        if crumb_width < area.width {
+            Line::from(Span::styled(hint, sep_style))
+                .right_aligned()
+                .render(area, buf);
         }
-        None
     }
+
+    /// Whether a field (or a nested form's field) is being edited with text
+    /// that hasn't been committed to the form yet.
+    pub fn has_pending_edits(&self) -> bool {
+        self.fields
+            .values()
+            .any(|field| field.is_active() && field.inner.has_pending_edit())
+    }
+
     /// Check if any field in this form is currently active (in edit mode)
     pub fn has_active_fields(&self) -> bool {
         for field in self.fields.values() {
@@ -282,99 +1846,282 @@ impl FormWidget {
     // Calculate the height needed for a field
     pub fn calculate_field_height(&self, field_key: &str) -> u16 {
         match self.fields.get(field_key) {
-            Some(field) => match &field.inner {
-                FormFieldType::Text(field) => field.calculate_height(),
-                FormFieldType::Select(field) => field.calculate_height(),
-                FormFieldType::List(field) => field.calculate_height(),
-                FormFieldType::SubForm(field) => field.calculate_height(),
-                FormFieldType::SubFormList(field) => field.calculate_height(),
-            },
-            None => 0, // Default height if field not found
+            Some(field) if field.is_visible(&FieldValues::new(&self.fields)) => {
+                match &field.inner {
+                    FormFieldType::Text(field) => field.calculate_height(),
+                    FormFieldType::Password(field) => field.calculate_height(),
+                    FormFieldType::Number(field) => field.calculate_height(),
+                    FormFieldType::Slider(field) => field.calculate_height(),
+                    FormFieldType::Path(field) => field.calculate_height(),
+                    FormFieldType::Select(field) => field.calculate_height(),
+                    FormFieldType::MultiSelect(field) => field.calculate_height(),
+                    FormFieldType::Checkbox(field) => field.calculate_height(),
+                    FormFieldType::List(field) => field.calculate_height(),
+                    FormFieldType::Map(field) => field.calculate_height(),
+                    FormFieldType::SubForm(field) => field.calculate_height(),
+                    FormFieldType::SubFormList(field) => field.calculate_height(),
+                }
+            }
+            // Hidden or not found
+            _ => 0,
         }
     }
     fn activate_prev(&mut self) -> bool {
-        self.unfocus_all();
-
         debug!(
             "FormWidget activate_prev start {:?}",
             self.active_field_index
         );
 
-        if let Some(idx) = self.active_field_index {
-            if idx > 0 {
-                self.active_field_index = Some(idx - 1);
-                if let Some(field) = self.active_mut() {
-                    field.inner_mut().enter_start();
-                }
-            } else {
-                self.active_field_index = None;
-                self.submit_buttons.focus();
-            };
-            true
-        } else if !self.fields.is_empty() {
-            self.active_field_index = Some(self.fields.len() - 1);
-            if let Some(field) = self.active_mut() {
-                field.inner_mut().enter_start();
+        let rows = self.layout_rows();
+        let prev = match self.focused_row(&rows) {
+            Some(0) => None,
+            Some(pos) => Some(rows[pos - 1]),
+            None if rows.is_empty() => {
+                self.unfocus_all();
+                return !self.nested;
             }
-            true
-        } else {
-            !self.nested
-        }
+            None => rows.last().copied(),
+        };
+        self.focus_row(prev, false);
+        true
     }
     fn activate_next(&mut self) -> bool {
-        self.unfocus_all();
+        let rows = self.layout_rows();
+        let next = match self.focused_row(&rows) {
+            Some(pos) => rows.get(pos + 1).copied(),
+            None if rows.is_empty() => {
+                self.unfocus_all();
+                return !self.nested;
+            }
+            None => rows.first().copied(),
+        };
+        self.focus_row(next, true);
+        true
+    }
+    // Rows Tab moves through: those of the tab order's fields that are
+    // shown, or every row as laid out.
+    fn tab_rows(&self) -> Vec<FormRow> {
+        let rows = self.layout_rows();
+        let Some(order) = &self.tab_order else {
+            return rows;
+        };
+        order
+            .iter()
+            .filter_map(|key| {
+                let row = FormRow::Field(self.field_keys.iter().position(|k| k == key)?);
+                rows.contains(&row).then_some(row)
+            })
+            .collect()
+    }
 
-        if let Some(idx) = self.active_field_index {
-            if idx + 1 < self.field_keys.len() {
-                self.active_field_index = Some(idx + 1);
-                if let Some(field) = self.active_mut() {
-                    field.inner_mut().enter_end();
-                }
-            } else {
-                self.active_field_index = None;
+    // Tab order is every tab row followed by each submit button (top-level
+    // forms only), wrapping around so focus never leaves the form. From a
+    // field outside the tab order, Tab goes to the first tab row and
+    // Shift+Tab to the last.
+    fn tab_next(&mut self) -> bool {
+        let button_count = if self.nested {
+            0
+        } else {
+            self.submit_buttons.button_count()
+        };
+
+        let rows = self.tab_rows();
+        let on_buttons = self.focused().is_none();
+        match self.focused_row(&rows) {
+            Some(pos) if pos + 1 < rows.len() => self.focus_row(Some(rows[pos + 1]), true),
+            Some(_) if button_count > 0 => {
+                self.focus_row(None, true);
+                self.submit_buttons.set_selected(0);
             }
-            true
-        } else if !self.field_keys.is_empty() {
-            self.active_field_index = Some(0);
-            if let Some(field) = self.active_mut() {
-                field.inner_mut().enter_end();
+            None if on_buttons && self.submit_buttons.selected() + 1 < button_count => {
+                self.submit_buttons.next_button();
             }
-            true
+            _ if !rows.is_empty() => self.focus_row(rows.first().copied(), true),
+            _ => {
+                self.focus_row(None, true);
+                self.submit_buttons.set_selected(0);
+            }
+        }
+        true
+    }
+
+    fn tab_prev(&mut self) -> bool {
+        let button_count = if self.nested {
+            0
         } else {
-            !self.nested
+            self.submit_buttons.button_count()
+        };
+
+        let rows = self.tab_rows();
+        let on_buttons = self.focused().is_none();
+        match self.focused_row(&rows) {
+            Some(pos) if pos > 0 => self.focus_row(Some(rows[pos - 1]), false),
+            None if on_buttons && self.submit_buttons.selected() > 0 && button_count > 0 => {
+                self.submit_buttons.prev_button();
+            }
+            None if !rows.is_empty() => self.focus_row(rows.last().copied(), false),
+            _ if button_count > 0 => {
+                self.focus_row(None, false);
+                self.submit_buttons.set_selected(button_count - 1);
+            }
+            _ if !rows.is_empty() => self.focus_row(rows.last().copied(), false),
+            _ => {}
         }
+        true
     }
+
     fn apply_focus(&mut self) {
         self.unfocus_all();
 
         // When form gets focus, either focus the button widget or selected field
         if let Some(field) = self.active_mut() {
             field.focus();
-        } else {
+        } else if self.active_section.is_none() {
             self.submit_buttons.focus();
         }
     }
 
     pub fn focus_start(&mut self) {
-        self.active_field_index = if !self.field_keys.is_empty() {
-            Some(0)
-        } else {
-            None
-        };
+        let first = self.layout_rows().first().copied();
+        self.set_focused(first);
         self.apply_focus();
     }
     pub fn focus_end(&mut self) {
-        self.active_field_index = if !self.field_keys.is_empty() {
-            Some(self.field_keys.len() - 1)
-        } else {
-            None
-        };
+        let last = self.layout_rows().last().copied();
+        self.set_focused(last);
         self.apply_focus();
     }
 }
 
+// Add the field's value under `key`, or a nested form's under `key.field`.
+fn field_values(key: &str, field: &FormFieldWidget, values: &mut HashMap<String, String>) {
+    match &field.inner {
+        FormFieldType::SubForm(subform) => {
+            for (nested, value) in subform.form_widget.values() {
+                values.insert(format!("{key}.{nested}"), value);
+            }
+        }
+        FormFieldType::SubFormList(list) => {
+            values.insert(key.to_string(), list.form_widgets.len().to_string());
+            for (idx, form) in list.form_widgets.iter().enumerate() {
+                for (nested, value) in form.values() {
+                    values.insert(format!("{key}.{idx}.{nested}"), value);
+                }
+            }
+        }
+        // An unset field has no value, which tells it apart from an empty one
+        _ if field.is_unset() => {}
+        inner => {
+            values.insert(key.to_string(), inner.validation_value());
+        }
+    }
+}
+
+// Whether a key from `field_values` belongs to the field under `key`.
+fn is_value_of(value_key: &str, key: &str) -> bool {
+    value_key
+        .strip_prefix(key)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+// A small modal over the middle of `area` asking `prompt`, with buttons to
+// answer it.
+fn draw_prompt(
+    buttons: &mut ButtonsWidget,
+    title: &str,
+    prompt: &str,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let width = 34.min(area.width);
+    let height = 6.min(area.height);
+    let modal = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    Clear.render(modal, buf);
+    let block = Block::default()
+        .title(format!(" {title} "))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tui_theme::WARNING_FG));
+    let inner = block.inner(modal);
+    block.render(modal, buf);
+
+    Paragraph::new(prompt).render(
+        Rect {
+            x: inner.x + 1,
+            width: inner.width.saturating_sub(2),
+            height: 1,
+            ..inner
+        },
+        buf,
+    );
+    if inner.height > 0 {
+        buttons.draw(
+            Rect {
+                y: inner.bottom() - 1,
+                height: 1,
+                ..inner
+            },
+            buf,
+        );
+    }
+}
+
+// Whether two sets of `field_values` differ for the field under `key`.
+fn values_differ(
+    key: &str,
+    values: &HashMap<String, String>,
+    other: &HashMap<String, String>,
+) -> bool {
+    let count = |values: &HashMap<String, String>| {
+        values
+            .keys()
+            .filter(|value_key| is_value_of(value_key, key))
+            .count()
+    };
+    count(values) != count(other)
+        || values
+            .iter()
+            .filter(|(value_key, _)| is_value_of(value_key, key))
+            .any(|(value_key, value)| other.get(value_key) != Some(value))
+}
+
+fn highlight_border(area: Rect, buf: &mut Buffer) {
+    let style = Style::default().fg(tui_theme::FOCUS_RETURN_FG);
+    let edges = [
+        Rect { height: 1, ..area },
+        Rect {
+            y: area.bottom().saturating_sub(1),
+            height: 1,
+            ..area
+        },
+        Rect { width: 1, ..area },
+        Rect {
+            x: area.right().saturating_sub(1),
+            width: 1,
+            ..area
+        },
+    ];
+    for edge in edges {
+        buf.set_style(edge.intersection(buf.area), style);
+    }
+}
+
 impl TuiWidget for FormWidget {
+    fn preprocess(&mut self) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        self.poll_checks();
+        self.report_changes();
+        self.autosave();
+    }
+
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        self.poll_checks();
         self.update_border_style();
 
         // Calculate inner area for form content
@@ -388,12 +2135,21 @@ impl TuiWidget for FormWidget {
         } else {
             // Create outer block
             let block = Block::default()
-                .title(self.title.clone())
+                .title(self.display_title().to_string())
                 .borders(Borders::ALL)
                 .border_style(self.border_style);
 
             // Render outer block
             block.render(area, buf);
+            self.render_breadcrumb(
+                Rect {
+                    x: area.x + 2,
+                    y: area.y + 1,
+                    width: area.width.saturating_sub(4),
+                    height: 1,
+                },
+                buf,
+            );
             Rect {
                 x: area.x + 2,
                 y: area.y + 2,
@@ -402,83 +2158,127 @@ impl TuiWidget for FormWidget {
             }
         };
 
-        // Calculate heights for all fields
-        let mut field_positions = Vec::new();
-        let mut current_y = inner_area.y;
+        // Lay out the section headers and the fields shown, and scroll the
+        // focused one into view
+        let layout = self.row_layout();
         let button_height = 3; // Space reserved for buttons at bottom
-
-        // First pass: calculate positions and heights
-        for key in &self.field_keys {
-            let height = self.calculate_field_height(key);
-            field_positions.push((current_y, height));
-            current_y += height + 1; // Add 1 for spacing between fields
-        }
-
-        // Determine visible fields based on height constraints
         let buttons_y = inner_area.y + inner_area.height - button_height;
-        let mut visible_field_indices = Vec::new();
-
-        // Find the range of visible fields
-        let mut first_visible = 0;
-
-        // If selected field would be off-screen, adjust the scroll position
-        if let Some(selected_idx) = self.active_field_index {
-            if selected_idx < self.field_keys.len() {
-                let (selected_y, selected_height) = field_positions[selected_idx];
-
-                // If selected field is above visible area, scroll up
-                if selected_y < inner_area.y {
-                    // Find how many fields to scroll up
-                    while first_visible < selected_idx {
-                        first_visible += 1;
-                    }
-                }
-
-                // If selected field is below visible area or doesn't fit, scroll down
-                let selected_bottom = selected_y + selected_height;
-                if selected_bottom > buttons_y {
-                    // Scroll down until the selected field fits
-                    while first_visible < selected_idx && selected_y + selected_height > buttons_y {
-                        first_visible += 1;
+        self.viewport_height = buttons_y.saturating_sub(inner_area.y);
+        let viewport = self.viewport_height;
+        let content_height = Self::content_height(&layout);
+        let focused = self.focused();
+        if let Some(&(_, top, height, _)) = layout.iter().find(|(row, ..)| Some(*row) == focused) {
+            self.scroll_into_view(top, height);
+        }
+        self.scroll_offset = self
+            .scroll_offset
+            .min(content_height.saturating_sub(viewport));
+        let offset = self.scroll_offset;
+
+        // Leave a column for the scrollbar when the content doesn't fit
+        let overflows = content_height > viewport;
+        let content_width = inner_area.width.saturating_sub(u16::from(overflows));
+        // Columns are two spaces apart
+        let gap: u16 = 2;
+        let columns = self.columns();
+        let column_width = content_width.saturating_sub(gap.saturating_mul(columns - 1)) / columns;
+
+        self.field_areas.clear();
+        self.header_areas.clear();
+        self.cursor_hidden = false;
+
+        for (row, top, height, column) in layout {
+            let bottom = top + height;
+            if bottom <= offset || top >= offset + viewport {
+                continue;
+            }
+            let (x, width) = match column {
+                Some(column) => (
+                    inner_area
+                        .x
+                        .saturating_add(column.saturating_mul(column_width + gap)),
+                    column_width,
+                ),
+                None => (inner_area.x, content_width),
+            };
+            // More columns than fit leave none of them any width
+            if width == 0 {
+                continue;
+            }
+            let y = inner_area.y + top.saturating_sub(offset);
+            let skipped = offset.saturating_sub(top);
+            let shown = bottom.min(offset + viewport) - top.max(offset);
+            if shown == height {
+                self.render_row(
+                    row,
+                    Rect {
+                        x,
+                        y,
+                        width,
+                        height,
+                    },
+                    buf,
+                );
+            } else {
+                // Draw a row cut off at an edge whole, off to the side, and
+                // copy in the part that shows
+                let scratch_y = if skipped == 0 { y } else { 0 };
+                let mut scratch = Buffer::empty(Rect {
+                    x,
+                    y: scratch_y,
+                    width,
+                    height,
+                });
+                self.render_row(row, scratch.area, &mut scratch);
+                for line in 0..shown {
+                    for x in x..x + width {
+                        if let Some(cell) = buf.cell_mut(Position::new(x, y + line)) {
+                            *cell = scratch[(x, scratch_y + skipped + line)].clone();
+                        }
                     }
                 }
+                self.cursor_hidden |= Some(row) == focused;
             }
-        }
-
-        // Determine which fields are visible
-        let mut current_y = inner_area.y;
-        for i in first_visible..self.field_keys.len() {
-            let height = self.calculate_field_height(&self.field_keys[i]);
 
-            // Check if field fits in the visible area
-            if current_y + height <= buttons_y {
-                visible_field_indices.push(i);
-                current_y += height + 1;
-            } else {
-                break;
+            let shown_area = Rect {
+                x,
+                y,
+                width,
+                height: shown,
+            };
+            match row {
+                FormRow::Header(section) => self.header_areas.push((section, shown_area)),
+                FormRow::Field(field_idx) => self.field_areas.push((field_idx, shown_area)),
             }
         }
 
-        // When rendering fields, don't pass tabs_widget for select fields
-        for &field_idx in &visible_field_indices {
-            let (y_pos, height) = field_positions[field_idx];
-            let y = y_pos - (field_positions[first_visible].0 - inner_area.y);
-
-            if let Some(field) = self.field_mut(field_idx) {
-                let field_area = Rect {
-                    x: inner_area.x,
-                    y,
-                    width: inner_area.width,
-                    height,
-                };
-
-                // Render field
-                field.render(buf, field_area, None);
-            }
+        if overflows {
+            self.v_scrollbar = self
+                .v_scrollbar
+                .content_length(usize::from(content_height - viewport))
+                .position(usize::from(offset));
+            let glyphs = GlyphSet::current();
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .thumb_symbol(glyphs.pick(tui_theme::V_THUMB_SYMBOL, tui_theme::THUMB_SYMBOL_ASCII))
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_symbol(Some(
+                    glyphs.pick(line::VERTICAL, tui_theme::V_TRACK_SYMBOL_ASCII),
+                ))
+                .track_style(Style::default().fg(tui_theme::BORDER_DEFAULT))
+                .thumb_style(Style::default().fg(tui_theme::SCROLLBAR_DEFAULT))
+                .render(
+                    Rect {
+                        height: viewport,
+                        ..inner_area
+                    },
+                    buf,
+                    &mut self.v_scrollbar,
+                );
         }
 
         // Update button selection based on current mode
-        if self.active_field_index.is_none() {
+        if self.active_field_index.is_none() && self.active_section.is_none() {
             self.submit_buttons.focus();
         } else {
             self.submit_buttons.unfocus();
@@ -486,6 +2286,15 @@ impl TuiWidget for FormWidget {
 
         if !self.nested {
             // Render buttons at the bottom
+            let locale = Locale::current();
+            for (idx, action) in self.button_actions.iter().enumerate() {
+                let submit_label = self.submit_label.as_deref().or(self.meta_submit_label);
+                let label = match (action, submit_label) {
+                    (FormAction::Submit, Some(label)) => label,
+                    _ => action.label(&locale, &self.custom_actions),
+                };
+                self.submit_buttons.set_label(idx, label);
+            }
             self.submit_buttons.draw(
                 Rect {
                     x: inner_area.x,
@@ -495,16 +2304,116 @@ impl TuiWidget for FormWidget {
                 },
                 buf,
             );
+            if buttons_y + 2 < inner_area.bottom() {
+                self.draw_help_footer(
+                    Rect {
+                        x: inner_area.x,
+                        y: buttons_y + 2,
+                        width: inner_area.width,
+                        height: 1,
+                    },
+                    buf,
+                );
+            }
+        }
+
+        if !self.nested
+            && let Some((message, failed)) = &self.file_message
+        {
+            let style = Style::default().fg(if *failed {
+                tui_theme::ERROR_FG
+            } else {
+                tui_theme::HINT_FG
+            });
+            Line::from(Span::styled(format!(" {message} "), style)).render(
+                Rect {
+                    x: area.x + 2,
+                    y: area.bottom().saturating_sub(1),
+                    width: area.width.saturating_sub(4),
+                    height: 1,
+                },
+                buf,
+            );
         }
+
+        self.draw_help_popup(area, buf);
+        self.draw_discard_prompt(area, buf);
+        self.report_changes();
+        self.autosave();
     }
 
     fn key_event(&mut self, key: KeyEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
         if key.kind != KeyEventKind::Press {
             return false;
         }
+        if self.discard_prompt.is_some() {
+            self.discard_prompt_key(key);
+            return true;
+        }
+        if self.restore_prompt.is_some() {
+            self.restore_prompt_key(key);
+            return true;
+        }
+        // Any key closes the help
+        if self.help_open {
+            self.help_open = false;
+            return true;
+        }
+        self.file_message = None;
+
+        if key.code == KeyCode::F(1) && !self.nested && self.focused_help().is_some() {
+            self.help_open = true;
+            return true;
+        }
+
+        // F8 goes to the next field with an error; a nested form being
+        // edited goes through its own
+        if key.code == KeyCode::F(8) && !self.editing_nested() {
+            let rows = self.layout_rows();
+            let focused = self.focused_row(&rows);
+            return self.focus_invalid_after(focused);
+        }
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            let locale = Locale::current();
+            match key.code {
+                KeyCode::Char('s') if self.file.is_some() => {
+                    let result = self.save_file();
+                    self.show_file_result(result, &locale.saved);
+                    return true;
+                }
+                KeyCode::Char('o') if self.file.is_some() => {
+                    self.request_load_file();
+                    return true;
+                }
+                // A nested form being edited reverts its own field, and a
+                // list being edited brings back its last deleted item
+                // instead
+                KeyCode::Char('z') if !self.editing_nested() => {
+                    if self
+                        .active_mut()
+                        .is_some_and(|field| field.inner.undo_delete())
+                    {
+                        return true;
+                    }
+                    let key = self
+                        .active_field_index
+                        .and_then(|idx| self.field_keys.get(idx))
+                        .cloned();
+                    return key.is_some_and(|key| self.revert_field(&key));
+                }
+                _ => {}
+            }
+        }
 
         // Handle escape key specially - it should always move "up" one level
         if key.code == KeyCode::Esc {
+            // Leave the innermost nested edit first, one level per press
+            if self.pop_level() {
+                return true;
+            }
+
             // If any field is active (inner editing mode), exit that mode first
             for field in self.fields.values_mut() {
                 if field.is_active() {
@@ -519,9 +2428,13 @@ impl TuiWidget for FormWidget {
                 self.unfocus_all();
                 return true;
             }
+            if self.active_section.take().is_some() {
+                self.submit_buttons.focus();
+                return true;
+            }
 
             // Otherwise, escape from the form itself
-            self.cancel_form();
+            self.request_cancel();
             return true;
         }
 
@@ -540,7 +2453,7 @@ impl TuiWidget for FormWidget {
                         false
                     }
                 }
-                KeyCode::Down => {
+                KeyCode::Down if !handled => {
                     if let Some(field) = self.active_mut() {
                         if !field.handle_key_event(key) {
                             return self.activate_next();
@@ -558,29 +2471,126 @@ impl TuiWidget for FormWidget {
             }
         }
 
+        // A focused section header opens and closes its section
+        if let Some(section) = self.active_section {
+            let collapse = match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => Some(!self.sections[section].collapsed),
+                KeyCode::Left => Some(true),
+                KeyCode::Right => Some(false),
+                _ => None,
+            };
+            if let Some(collapse) = collapse {
+                self.set_collapsed(section, collapse);
+                return true;
+            }
+        }
+
         match key.code {
             KeyCode::Up => self.activate_prev(),
             KeyCode::Down => self.activate_next(),
-            KeyCode::Tab => self.activate_next(),
-            KeyCode::BackTab => self.activate_prev(),
+            KeyCode::Tab => self.tab_next(),
+            KeyCode::BackTab => self.tab_prev(),
+            KeyCode::PageUp => self.scroll_by(-i32::from(self.viewport_height)),
+            KeyCode::PageDown => self.scroll_by(i32::from(self.viewport_height)),
             KeyCode::Enter => {
                 // Activate the currently focused field
                 if let Some(field) = self.active_mut() {
                     field.enter();
                 } else {
-                    match self.submit_buttons.selected() {
-                        0 => self.submit_form(), // Submit button
-                        1 => self.cancel_form(),
-                        _ => {}
+                    match self.button_actions.get(self.submit_buttons.selected()) {
+                        Some(FormAction::Back) if !self.nested => {
+                            self.status = FormWidgetStatus::Back
+                        }
+                        Some(FormAction::Submit) => self.submit_form(),
+                        Some(&FormAction::Custom(idx)) => self.run_action(idx),
+                        Some(FormAction::Cancel) => self.request_cancel(),
+                        Some(FormAction::Reset) => self.reset_to_initial(),
+                        Some(FormAction::Back) | None => {}
                     }
                 }
                 true
             }
+            _ if self.active_section.is_some() => return false,
             _ => return self.submit_buttons.key_event(key),
         };
         true
     }
 
+    // A click focuses the field under it; the field gets the click and the
+    // drag that may follow, so a slider can be dragged off its bar.
+    fn mouse_event(&mut self, event: MouseEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if self.discard_prompt.is_some() || self.restore_prompt.is_some() {
+            return true;
+        }
+        if self.help_open {
+            if let MouseEventKind::Down(_) = event.kind {
+                self.help_open = false;
+            }
+            return true;
+        }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let position = Position::new(event.column, event.row);
+                // Clicking a section header opens or closes it
+                if let Some(section) = self
+                    .header_areas
+                    .iter()
+                    .find(|(_, area)| area.contains(position))
+                    .map(|(section, _)| *section)
+                {
+                    self.unfocus_all();
+                    self.set_focused(Some(FormRow::Header(section)));
+                    let collapsed = self.sections[section].collapsed;
+                    self.set_collapsed(section, !collapsed);
+                    return true;
+                }
+                let Some(idx) = self
+                    .field_areas
+                    .iter()
+                    .find(|(_, area)| area.contains(position))
+                    .map(|(idx, _)| *idx)
+                else {
+                    return false;
+                };
+                if self.active_field_index != Some(idx) {
+                    self.unfocus_all();
+                    self.set_focused(Some(FormRow::Field(idx)));
+                    if let Some(field) = self.active_mut() {
+                        field.enter_from(false);
+                    }
+                }
+                if let Some(field) = self.active_mut()
+                    && !field.is_readonly()
+                {
+                    field.inner_mut().handle_mouse_event(event);
+                }
+                true
+            }
+            MouseEventKind::ScrollUp => {
+                self.scroll_by(-(InteractionConfig::current().wheel_vertical_step as i32))
+            }
+            MouseEventKind::ScrollDown => {
+                self.scroll_by(InteractionConfig::current().wheel_vertical_step as i32)
+            }
+            MouseEventKind::Drag(MouseButton::Left) | MouseEventKind::Up(MouseButton::Left) => {
+                self.active_mut().is_some_and(|field| {
+                    !field.is_readonly() && field.inner_mut().handle_mouse_event(event)
+                })
+            }
+            _ => false,
+        }
+    }
+
+    fn paste_event(&mut self, text: &str) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if self.discard_prompt.is_some() || self.restore_prompt.is_some() || self.help_open {
+            return true;
+        }
+        self.active_mut()
+            .is_some_and(|field| field.handle_paste(text))
+    }
+
     fn focus(&mut self) {
         self.is_focused = true;
     }
@@ -594,4 +2604,135 @@ impl TuiWidget for FormWidget {
     fn is_focused(&self) -> bool {
         self.is_focused
     }
+
+    fn cursor_position(&self) -> Option<Position> {
+        if !self.is_focused || self.cursor_hidden || self.help_open {
+            return None;
+        }
+        let key = self.field_keys.get(self.active_field_index?)?;
+        self.fields.get(key)?.inner.cursor_position()
+    }
+
+    fn quit_concerns(&self, concerns: &mut QuitConcerns) {
+        if self.is_dirty() {
+            concerns.unsaved(match self.display_title() {
+                "" => "a form",
+                title => title,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_value(form: &FormWidget, key: &str) -> String {
+        form.get_field(key).unwrap().get_value_as_string()
+    }
+
+    fn set_text(form: &mut FormWidget, key: &str, value: &str) {
+        if let FormFieldType::Text(field) = &mut form.fields_mut().get_mut(key).unwrap().inner {
+            field.value = value.to_string();
+        }
+    }
+
+    fn nested_form<'a>(form: &'a mut FormWidget, key: &str) -> &'a mut FormWidget {
+        match &mut form.fields_mut().get_mut(key).unwrap().inner {
+            FormFieldType::SubForm(subform) => &mut subform.form_widget,
+            _ => panic!("`{key}` isn't a subform"),
+        }
+    }
+
+    fn form_with_subform() -> FormWidget {
+        let inner = FormWidget::new_nested()
+            .with_fields([("name", FormFieldWidget::text("Name", "before", false))]);
+        FormWidget::new("Outer").with_fields([
+            ("title", FormFieldWidget::text("Title", "kept", false)),
+            ("inner", FormFieldWidget::subform("Inner", inner, false)),
+        ])
+    }
+
+    #[test]
+    fn reverts_a_field_of_a_subform() {
+        let mut form = form_with_subform();
+        let inner = nested_form(&mut form, "inner");
+        set_text(inner, "name", "after");
+        assert!(inner.revert_field("name"));
+        assert_eq!(text_value(inner, "name"), "before");
+    }
+
+    #[test]
+    fn resets_hand_built_fields_and_subforms() {
+        let mut form = form_with_subform();
+        set_text(&mut form, "title", "changed");
+        set_text(nested_form(&mut form, "inner"), "name", "after");
+        form.reset_to_initial();
+        assert_eq!(text_value(&form, "title"), "kept");
+        assert_eq!(
+            text_value(nested_form(&mut form, "inner"), "name"),
+            "before"
+        );
+    }
+
+    #[test]
+    fn undo_with_no_deletes_leaves_the_list_alone() {
+        let mut form = FormWidget::new("Tags").with_fields([(
+            "tags",
+            FormFieldWidget::string_list("Tags", vec!["a".into(), "b".into()], false),
+        )]);
+        form.active_field_index = Some(0);
+        let FormFieldType::List(list) = &mut form.active_mut().unwrap().inner else {
+            panic!("`tags` isn't a list");
+        };
+        list.items.pop();
+        list.active = true;
+
+        let undo = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+        assert!(form.key_event(undo));
+        let FormFieldType::List(list) = &form.get_field("tags").unwrap().inner else {
+            unreachable!();
+        };
+        assert_eq!(list.items, ["a"]);
+    }
+
+    #[derive(crate::TuiEdit, Default, Clone, Debug)]
+    #[tui_edit(title = "Server", columns = 3)]
+    struct Server {
+        host: String,
+    }
+
+    #[derive(crate::TuiEdit, Default, Clone, Debug)]
+    #[tui_edit(title = "Client")]
+    struct Client {
+        name: String,
+    }
+
+    #[test]
+    fn set_columns_win_over_the_data() {
+        let form = FormWidget::new("")
+            .with_columns(1)
+            .with_data(&Server::default());
+        assert_eq!(form.columns(), 1);
+        assert_eq!(form.display_title(), "Server");
+    }
+
+    #[test]
+    fn new_data_brings_its_own_title() {
+        let mut form = FormWidget::new("").with_data(&Server::default());
+        assert_eq!(form.columns(), 3);
+        form.set_data(&Client::default());
+        assert_eq!(form.display_title(), "Client");
+        assert_eq!(form.columns(), 1);
+
+        let named = FormWidget::new("Mine").with_data(&Client::default());
+        assert_eq!(named.display_title(), "Mine");
+    }
+
+    #[test]
+    fn draws_with_more_columns_than_fit() {
+        let mut form = form_with_subform().with_columns(u16::MAX);
+        let area = Rect::new(0, 0, 40, 12);
+        form.draw(area, &mut Buffer::empty(area));
+    }
 }
@@ -0,0 +1,421 @@
+// tokio-tui/src/widgets/form/form_wizard.rs
+use std::{collections::HashMap, sync::Arc};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyEvent, MouseEvent},
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::{GlyphSet, Locale, QuitConcerns, TuiWidget, tui_theme};
+
+use super::{FormData, FormWidget, FormWidgetStatus};
+
+pub type WizardCallback = Box<dyn Fn(&mut WizardWidget) + Send + Sync>;
+
+/// A form split into pages filled in one after another. Each page is a
+/// [`FormWidget`] whose Submit button moves on to the next page once its
+/// fields pass validation, and finishes the wizard on the last page.
+pub struct WizardWidget {
+    pub title: String,
+    pages: Vec<FormWidget>,
+    current: usize,
+    is_focused: bool,
+    on_finish: Option<WizardCallback>,
+    on_cancel: Option<WizardCallback>,
+    status: FormWidgetStatus,
+    // Whether a page was filled in and moved on from
+    advanced: bool,
+    // Set on finishing, until the wizard goes back a page
+    finished: bool,
+    // Used instead of the app-wide locale, if set
+    locale: Option<Arc<Locale>>,
+}
+
+impl WizardWidget {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            pages: Vec::new(),
+            current: 0,
+            is_focused: false,
+            on_finish: None,
+            on_cancel: None,
+            status: FormWidgetStatus::None,
+            advanced: false,
+            finished: false,
+            locale: None,
+        }
+    }
+
+    /// Use `locale` for the wizard and its pages instead of the
+    /// app-wide one.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.set_locale(Some(locale));
+        self
+    }
+
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        self.locale = locale.map(Arc::new);
+    }
+
+    /// Add a page after the others; its title names the step.
+    pub fn with_page(mut self, page: FormWidget) -> Self {
+        let mut page = page.with_cancel(|_| {});
+        if !self.pages.is_empty() {
+            page = page.with_back_button();
+        }
+        self.pages.push(page);
+        self
+    }
+
+    /// A page for each `#[field(section = "...")]` of `T`, titled with the
+    /// section. Fields before the first section get a page of their own,
+    /// titled with the wizard's title. Read the data back with
+    /// [`get_data`](Self::get_data).
+    pub fn with_data<T: FormData>(mut self, data: &T) -> Self {
+        let mut pages: Vec<(String, Vec<String>)> = Vec::new();
+        for def in T::field_definitions() {
+            match (def.section, pages.last_mut()) {
+                (None, Some((_, keys))) => keys.push(def.id.to_string()),
+                (section, _) => pages.push((
+                    section.map_or_else(|| self.title.clone(), str::to_string),
+                    vec![def.id.to_string()],
+                )),
+            }
+        }
+        for (title, keys) in pages {
+            self = self.with_page(FormWidget::new(title).with_data(data).retain_fields(&keys));
+        }
+        self
+    }
+
+    // Sets the callback for when the last page is submitted
+    pub fn with_finish<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut WizardWidget) + Send + Sync + 'static,
+    {
+        self.on_finish = Some(Box::new(callback));
+        self
+    }
+
+    // Sets the callback for when a page is cancelled
+    pub fn with_cancel<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut WizardWidget) + Send + Sync + 'static,
+    {
+        self.on_cancel = Some(Box::new(callback));
+        self
+    }
+
+    pub fn pages(&self) -> &[FormWidget] {
+        &self.pages
+    }
+
+    pub fn page(&self, idx: usize) -> Option<&FormWidget> {
+        self.pages.get(idx)
+    }
+
+    pub fn page_mut(&mut self, idx: usize) -> Option<&mut FormWidget> {
+        self.pages.get_mut(idx)
+    }
+
+    /// Index of the page shown.
+    pub fn current_page(&self) -> usize {
+        self.current
+    }
+
+    /// The data of a wizard made with [`with_data`](Self::with_data),
+    /// gathered from every page.
+    pub fn get_data<T: FormData>(&mut self) -> T {
+        let taken: Vec<HashMap<_, _>> = self
+            .pages
+            .iter_mut()
+            .map(|page| std::mem::take(page.fields_mut()))
+            .collect();
+        let mut fields = HashMap::new();
+        let mut keys = Vec::new();
+        for page_fields in taken {
+            keys.push(page_fields.keys().cloned().collect::<Vec<_>>());
+            fields.extend(page_fields);
+        }
+        let data = T::from_fields(&fields);
+        for (page, keys) in self.pages.iter_mut().zip(keys) {
+            let page_fields = page.fields_mut();
+            for key in keys {
+                if let Some(field) = fields.remove(&key) {
+                    page_fields.insert(key, field);
+                }
+            }
+        }
+        data
+    }
+
+    /// Move on to the next page if the current one passes validation, or
+    /// finish on the last page. Returns whether it did.
+    pub fn next_page(&mut self) -> bool {
+        let Some(page) = self.pages.get_mut(self.current) else {
+            return false;
+        };
+        if page.validate().is_err() {
+            return false;
+        }
+        page.mark_saved();
+        self.advance();
+        true
+    }
+
+    /// Go back a page, keeping what was filled in. Returns false on the
+    /// first page.
+    pub fn prev_page(&mut self) -> bool {
+        if self.current == 0 {
+            return false;
+        }
+        self.show_page(self.current - 1);
+        true
+    }
+
+    pub fn reset_submit(&mut self) -> bool {
+        if self.status == FormWidgetStatus::Submit {
+            self.status = FormWidgetStatus::None;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn reset_closed(&mut self) -> bool {
+        if self.status != FormWidgetStatus::None {
+            self.status = FormWidgetStatus::None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whether anything was filled in that the wizard hasn't finished with.
+    pub fn is_dirty(&self) -> bool {
+        self.pages.iter().any(FormWidget::is_dirty) || (self.advanced && !self.finished)
+    }
+
+    // Past the current page, whose fields have passed validation.
+    fn advance(&mut self) {
+        self.advanced = true;
+        if self.current + 1 < self.pages.len() {
+            self.show_page(self.current + 1);
+            return;
+        }
+        self.finished = true;
+        if let Some(callback) = self.on_finish.take() {
+            callback(self);
+            self.on_finish = Some(callback);
+        }
+        self.status = FormWidgetStatus::Submit;
+    }
+
+    fn cancel(&mut self) {
+        if let Some(callback) = self.on_cancel.take() {
+            callback(self);
+            self.on_cancel = Some(callback);
+        }
+        self.status = FormWidgetStatus::Cancel;
+    }
+
+    fn show_page(&mut self, idx: usize) {
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.unfocus();
+        }
+        self.current = idx;
+        self.finished = false;
+        if self.is_focused
+            && let Some(page) = self.pages.get_mut(idx)
+        {
+            page.focus();
+            page.focus_start();
+        }
+    }
+
+    // Act on the buttons the current page had pressed. A page submits
+    // from its async checks too, so this runs as it draws.
+    fn update(&mut self) {
+        let Some(page) = self.pages.get_mut(self.current) else {
+            return;
+        };
+        if page.reset_submit() {
+            self.advance();
+        } else if page.reset_back() {
+            self.prev_page();
+        } else if page.reset_closed() {
+            self.cancel();
+        }
+    }
+
+    // One step per page, checked off up to the current one, or just the
+    // current step's number when they don't all fit.
+    fn render_progress(&self, area: Rect, buf: &mut Buffer) {
+        let glyphs = GlyphSet::current();
+        let mut spans = Vec::new();
+        for (idx, page) in self.pages.iter().enumerate() {
+            if idx > 0 {
+                spans.push(Span::styled(
+                    format!(" {} ", glyphs.pick("›", ">")),
+                    Style::default().fg(tui_theme::BORDER_DEFAULT),
+                ));
+            }
+            let (marker, style) = if idx < self.current {
+                (
+                    glyphs.pick("●", "*"),
+                    Style::default().fg(tui_theme::ACTIVE_FG),
+                )
+            } else if idx == self.current {
+                (
+                    glyphs.pick("◉", "@"),
+                    Style::default()
+                        .fg(tui_theme::SELECTED_FG)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                (
+                    glyphs.pick("○", "o"),
+                    Style::default().fg(tui_theme::HINT_FG),
+                )
+            };
+            spans.push(Span::styled(format!("{marker} {}", page.title), style));
+        }
+
+        let width: usize = spans.iter().map(Span::width).sum();
+        let line = if width <= area.width as usize {
+            Line::from(spans)
+        } else {
+            let title = self.pages.get(self.current).map_or("", |page| &page.title);
+            Line::from(Span::styled(
+                format!(
+                    "{} {}/{}: {title}",
+                    Locale::current().step,
+                    self.current + 1,
+                    self.pages.len()
+                ),
+                Style::default()
+                    .fg(tui_theme::SELECTED_FG)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+        Paragraph::new(line).render(area, buf);
+    }
+}
+
+impl TuiWidget for WizardWidget {
+    fn preprocess(&mut self) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.preprocess();
+        }
+        self.update();
+    }
+
+    fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let block = Block::default()
+            .title(self.title.clone())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if self.is_focused {
+                tui_theme::BORDER_FOCUSED
+            } else {
+                tui_theme::BORDER_DEFAULT
+            }));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        self.render_progress(
+            Rect {
+                x: inner.x + 1,
+                width: inner.width.saturating_sub(2),
+                height: 1.min(inner.height),
+                ..inner
+            },
+            buf,
+        );
+
+        // The last page's Submit finishes the wizard
+        let locale = Locale::current();
+        let last = self.pages.len().saturating_sub(1);
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.set_submit_label(if self.current == last {
+                &locale.finish
+            } else {
+                &locale.next
+            });
+            page.draw(
+                Rect {
+                    y: inner.y + 1,
+                    height: inner.height.saturating_sub(1),
+                    ..inner
+                },
+                buf,
+            );
+        }
+        self.update();
+    }
+
+    fn key_event(&mut self, key: KeyEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let Some(page) = self.pages.get_mut(self.current) else {
+            return false;
+        };
+        let handled = page.key_event(key);
+        self.update();
+        handled
+    }
+
+    fn mouse_event(&mut self, event: MouseEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let Some(page) = self.pages.get_mut(self.current) else {
+            return false;
+        };
+        let handled = page.mouse_event(event);
+        self.update();
+        handled
+    }
+
+    fn paste_event(&mut self, text: &str) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        self.pages
+            .get_mut(self.current)
+            .is_some_and(|page| page.paste_event(text))
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.focus();
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.unfocus();
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        self.pages.get(self.current)?.cursor_position()
+    }
+
+    fn quit_concerns(&self, concerns: &mut QuitConcerns) {
+        if self.is_dirty() {
+            concerns.unsaved(if self.title.is_empty() {
+                "a form wizard"
+            } else {
+                &self.title
+            });
+        }
+    }
+}
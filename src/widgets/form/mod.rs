@@ -6,3 +6,21 @@ mod form_fields;
 pub use form_fields::*;
 mod form_data;
 pub use form_data::*;
+
+mod form_validation;
+pub use form_validation::*;
+
+mod form_visibility;
+pub use form_visibility::*;
+
+mod form_section;
+pub use form_section::*;
+
+mod form_file;
+pub use form_file::*;
+
+mod form_draft;
+pub use form_draft::*;
+
+mod form_wizard;
+pub use form_wizard::*;
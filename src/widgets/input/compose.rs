@@ -0,0 +1,41 @@
+// tokio-tui/src/widgets/input/compose.rs
+// Dead-key composition, for terminals that pass dead keys through as the
+// bare accent instead of composing them with the next key themselves.
+
+use unicode_normalization::UnicodeNormalization;
+
+// The combining mark a spacing accent stands for when typed as a dead key.
+pub(crate) fn combining_mark(dead: char) -> Option<char> {
+    Some(match dead {
+        '`' => '\u{300}',
+        '´' => '\u{301}',
+        '^' => '\u{302}',
+        '~' => '\u{303}',
+        '¯' => '\u{304}',
+        '˘' => '\u{306}',
+        '˙' => '\u{307}',
+        '¨' => '\u{308}',
+        '˚' => '\u{30A}',
+        '˝' => '\u{30B}',
+        'ˇ' => '\u{30C}',
+        '¸' => '\u{327}',
+        '˛' => '\u{328}',
+        _ => return None,
+    })
+}
+
+// What dead key `dead` followed by `ch` types: the accented character when
+// there is one, the accent alone after a space, and otherwise both as they
+// are, as desktop keyboards do.
+pub(crate) fn compose(dead: char, ch: char) -> String {
+    if ch == ' ' {
+        return dead.to_string();
+    }
+    if let Some(mark) = combining_mark(dead) {
+        let composed: String = [ch, mark].into_iter().nfc().collect();
+        if composed.chars().count() == 1 {
+            return composed;
+        }
+    }
+    [dead, ch].into_iter().collect()
+}
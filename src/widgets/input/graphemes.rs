@@ -0,0 +1,46 @@
+// tokio-tui/src/widgets/input/graphemes.rs
+// Cursor arithmetic over grapheme clusters. The cursor stays a byte offset
+// into the text, but only ever moves between cluster boundaries, so an
+// emoji or a letter with combining accents is one step and one cursor cell.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+// Start of the cluster before byte offset `at`.
+pub(crate) fn prev_boundary(text: &str, at: usize) -> usize {
+    text[..at]
+        .grapheme_indices(true)
+        .next_back()
+        .map_or(0, |(idx, _)| idx)
+}
+
+// End of the cluster starting at byte offset `at`.
+pub(crate) fn next_boundary(text: &str, at: usize) -> usize {
+    text[at..]
+        .graphemes(true)
+        .next()
+        .map_or(at, |cluster| at + cluster.len())
+}
+
+// The cluster starting at byte offset `at`, if any.
+pub(crate) fn cluster_at(text: &str, at: usize) -> Option<&str> {
+    text[at..].graphemes(true).next()
+}
+
+// Terminal cells `text` takes up.
+pub(crate) fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+// Byte offset of the last cluster boundary in `line` at most `column` cells
+// in.
+pub(crate) fn offset_at_column(line: &str, column: usize) -> usize {
+    let mut width = 0;
+    for (idx, cluster) in line.grapheme_indices(true) {
+        width += cluster.width();
+        if width > column {
+            return idx;
+        }
+    }
+    line.len()
+}
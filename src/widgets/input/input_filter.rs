@@ -0,0 +1,64 @@
+// tokio-tui/src/widgets/input/input_filter.rs
+use crate::Locale;
+
+/// Built-in character filters for [`InputWidget`](crate::InputWidget). They
+/// only stop characters that can never be valid where they're typed, so a
+/// half-typed value like `-` or `1.` still gets through; pair them with a
+/// validator to check the whole value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFilter {
+    /// Digits only.
+    Numeric,
+    /// Digits, with an optional leading `-`.
+    Integer,
+    /// Digits, at most one decimal separator (`.` unless the [`Locale`]
+    /// says otherwise), and an optional leading `-`.
+    Float,
+    /// Hexadecimal digits, either case.
+    Hex,
+}
+
+impl InputFilter {
+    /// Whether `ch` may be inserted into `text` at byte offset `at`.
+    pub fn accepts(self, text: &str, at: usize, ch: char) -> bool {
+        // Nothing goes before a leading sign
+        if at == 0 && text.starts_with('-') {
+            return false;
+        }
+        let leading_sign = || ch == '-' && at == 0;
+        match self {
+            InputFilter::Numeric => ch.is_ascii_digit(),
+            InputFilter::Integer => ch.is_ascii_digit() || leading_sign(),
+            InputFilter::Float => {
+                ch.is_ascii_digit()
+                    || leading_sign()
+                    || (ch == Locale::current().decimal_separator && !text.contains(ch))
+            }
+            InputFilter::Hex => ch.is_ascii_hexdigit(),
+        }
+    }
+}
+
+// What an `InputWidget` lets through as it's typed in.
+pub(crate) enum CharFilter {
+    Builtin(InputFilter),
+    Custom(Box<dyn Fn(char) -> bool + Send + Sync>),
+}
+
+impl CharFilter {
+    pub(crate) fn accepts(&self, text: &str, at: usize, ch: char) -> bool {
+        match self {
+            CharFilter::Builtin(filter) => filter.accepts(text, at, ch),
+            CharFilter::Custom(accepts) => accepts(ch),
+        }
+    }
+}
+
+impl std::fmt::Debug for CharFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CharFilter::Builtin(filter) => f.debug_tuple("Builtin").field(filter).finish(),
+            CharFilter::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
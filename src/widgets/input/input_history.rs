@@ -0,0 +1,290 @@
+// tokio-tui/src/widgets/input/input_history.rs
+use std::path::PathBuf;
+
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    sync::{mpsc, oneshot},
+};
+
+/// A sensible limit for [`HistoryPolicy::with_max_entries`]; the default
+/// policy keeps every entry.
+pub const DEFAULT_HISTORY_ENTRIES: usize = 1000;
+
+/// What [`InputWidget`](crate::InputWidget) keeps in its history. The
+/// default keeps every non-blank entry, like a plain append-only log.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryPolicy {
+    max_entries: Option<usize>,
+    dedup_consecutive: bool,
+    ignore_space_prefixed: bool,
+}
+
+impl HistoryPolicy {
+    /// Keep everything, like a plain append-only log. Same as the default.
+    pub fn unbounded() -> Self {
+        Self::default()
+    }
+
+    /// Keep only the newest `max` entries, in memory and in the history
+    /// file. `None` keeps them all.
+    pub fn with_max_entries(mut self, max: Option<usize>) -> Self {
+        self.max_entries = max;
+        self
+    }
+
+    /// Don't add an entry that repeats the one before it.
+    pub fn with_dedup_consecutive(mut self, dedup: bool) -> Self {
+        self.dedup_consecutive = dedup;
+        self
+    }
+
+    /// Don't add entries starting with a space, like bash's
+    /// `HISTCONTROL=ignorespace`, so one-offs can be kept out of history.
+    pub fn with_ignore_space_prefixed(mut self, ignore: bool) -> Self {
+        self.ignore_space_prefixed = ignore;
+        self
+    }
+
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    pub fn dedup_consecutive(&self) -> bool {
+        self.dedup_consecutive
+    }
+
+    pub fn ignore_space_prefixed(&self) -> bool {
+        self.ignore_space_prefixed
+    }
+
+    /// Whether `entry` should be added after `history`.
+    pub fn accepts(&self, history: &[String], entry: &str) -> bool {
+        if entry.trim().is_empty() {
+            return false;
+        }
+        if self.ignore_space_prefixed && entry.starts_with(' ') {
+            return false;
+        }
+        !(self.dedup_consecutive && history.last().is_some_and(|last| last == entry))
+    }
+
+    /// Drop the oldest entries beyond the limit; true if any were dropped.
+    pub fn trim(&self, history: &mut Vec<String>) -> bool {
+        match self.max_entries {
+            Some(max) if history.len() > max => {
+                history.drain(..history.len() - max);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub(crate) enum HistoryCommand {
+    Append(String),
+    // Replace the whole file, to bring it back within the limit
+    Rewrite(Vec<String>),
+    Flush(oneshot::Sender<()>),
+}
+
+// Appends history to its file from a background task, in order.
+#[derive(Debug)]
+pub(crate) struct HistoryWriter {
+    tx: mpsc::UnboundedSender<HistoryCommand>,
+    // Lines in the file, including ones since trimmed from memory
+    file_lines: usize,
+}
+
+impl HistoryWriter {
+    pub(crate) fn spawn(path: PathBuf, file_lines: usize) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(command) = rx.recv().await {
+                match command {
+                    HistoryCommand::Append(entry) => {
+                        if let Ok(mut file) = OpenOptions::new()
+                            .create(true)
+                            .append(true)
+                            .open(&path)
+                            .await
+                        {
+                            let _ = file.write_all(escape_entry(&entry).as_bytes()).await;
+                            let _ = file.write_all(b"\n").await;
+                        }
+                    }
+                    HistoryCommand::Rewrite(entries) => {
+                        let mut contents = entries
+                            .iter()
+                            .map(|entry| escape_entry(entry))
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        if !contents.is_empty() {
+                            contents.push('\n');
+                        }
+                        let _ = tokio::fs::write(&path, contents).await;
+                    }
+                    HistoryCommand::Flush(done) => {
+                        let _ = done.send(());
+                    }
+                }
+            }
+        });
+        Self { tx, file_lines }
+    }
+
+    // Append `entry`, rewriting the file from `history` instead once it has
+    // grown to twice the limit.
+    pub(crate) fn append(&mut self, entry: String, history: &[String], policy: &HistoryPolicy) {
+        let over = policy
+            .max_entries()
+            .is_some_and(|max| self.file_lines + 1 > max.max(1) * 2);
+        if over {
+            self.rewrite(history.to_vec());
+        } else {
+            self.file_lines += 1;
+            let _ = self.tx.send(HistoryCommand::Append(entry));
+        }
+    }
+
+    pub(crate) fn rewrite(&mut self, history: Vec<String>) {
+        self.file_lines = history.len();
+        let _ = self.tx.send(HistoryCommand::Rewrite(history));
+    }
+
+    pub(crate) async fn flush(&self) {
+        let (done, wait) = oneshot::channel();
+        if self.tx.send(HistoryCommand::Flush(done)).is_ok() {
+            let _ = wait.await;
+        }
+    }
+}
+
+// Every entry in the history file, oldest first.
+pub(crate) async fn read_history(path: &PathBuf) -> Vec<String> {
+    let mut history = Vec::new();
+    if let Ok(file) = File::open(path).await {
+        let mut lines = BufReader::new(file).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            history.push(unescape_entry(&line));
+        }
+    }
+    history
+}
+
+// One line of the history file per entry: backslashes and line breaks in
+// multi-line entries are written as `\\`, `\n` and `\r`.
+fn escape_entry(entry: &str) -> String {
+    let mut out = String::with_capacity(entry.len());
+    for ch in entry.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+fn unescape_entry(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('\\') => out.push('\\'),
+            // Not an escape, as in files written before entries were escaped
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn default_keeps_every_entry() {
+        let policy = HistoryPolicy::default();
+        let mut history = entries(&["ls", "ls"]);
+        assert!(policy.accepts(&history, "ls"));
+        history.extend(entries(&["ls"; 5000]));
+        assert!(!policy.trim(&mut history));
+        assert_eq!(history.len(), 5002);
+    }
+
+    #[test]
+    fn caps_to_the_newest_entries() {
+        let policy = HistoryPolicy::default().with_max_entries(Some(2));
+        let mut history = entries(&["a", "b", "c"]);
+        assert!(policy.trim(&mut history));
+        assert_eq!(history, entries(&["b", "c"]));
+        assert!(!policy.trim(&mut history));
+    }
+
+    #[test]
+    fn dedups_only_consecutive_repeats() {
+        let policy = HistoryPolicy::default().with_dedup_consecutive(true);
+        let history = entries(&["ls", "pwd"]);
+        assert!(!policy.accepts(&history, "pwd"));
+        assert!(policy.accepts(&history, "ls"));
+    }
+
+    #[test]
+    fn ignores_blank_and_space_prefixed_entries() {
+        let policy = HistoryPolicy::default().with_ignore_space_prefixed(true);
+        assert!(!policy.accepts(&[], "   "));
+        assert!(!policy.accepts(&[], " secret"));
+        assert!(policy.accepts(&[], "plain"));
+    }
+
+    #[test]
+    fn escapes_entries_onto_one_line() {
+        for entry in ["one\ntwo", "C:\\new\\", "cr\r\nlf", "\\n literal"] {
+            let line = escape_entry(entry);
+            assert!(!line.contains('\n') && !line.contains('\r'));
+            assert_eq!(unescape_entry(&line), entry);
+        }
+        // Lines written before entries were escaped read back as they were
+        assert_eq!(unescape_entry("C:\\dir"), "C:\\dir");
+    }
+
+    #[tokio::test]
+    async fn multi_line_entries_survive_a_reload() {
+        let path = std::env::temp_dir().join(format!(
+            "tokio-tui-history-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        let _ = tokio::fs::remove_file(&path).await;
+        let policy = HistoryPolicy::default();
+        let mut writer = HistoryWriter::spawn(path.clone(), 0);
+        let mut history = Vec::new();
+        for entry in ["first\nsecond", "third"] {
+            history.push(entry.to_string());
+            writer.append(entry.to_string(), &history, &policy);
+        }
+        writer.flush().await;
+        assert_eq!(read_history(&path).await, history);
+
+        writer.rewrite(entries(&["a\nb"]));
+        writer.flush().await;
+        assert_eq!(read_history(&path).await, entries(&["a\nb"]));
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+}
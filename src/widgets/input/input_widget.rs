@@ -1,21 +1,95 @@
 // tokio-tui/src/widgets/input/input_widget.rs
-use std::path::PathBuf;
+use std::{
+    borrow::Cow, future::Future, ops::Range, path::PathBuf, pin::Pin, sync::Arc, time::Instant,
+};
 
+use crate::{Locale, TuiWidget, spinner_frame, tui_theme};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
-    layout::Rect,
-    style::{Color, Style},
-    text::{Line, Span},
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph, Widget},
 };
-use tokio::{
-    fs::{File, OpenOptions},
-    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
-    sync::mpsc,
+use tokio::sync::oneshot;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{
+    CharFilter, HistoryPolicy, HistoryWriter, InputFilter, ViMode,
+    compose::{combining_mark, compose},
+    graphemes::{cluster_at, display_width, next_boundary, offset_at_column, prev_boundary},
+    read_history,
+    vi_mode::{ViState, line_bounds, next_word_start, prev_word_start, word_end},
 };
 
-use crate::{TuiWidget, tui_theme};
+/// How many rows of text an [`InputWidget`] shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputLines {
+    /// One row; Enter always submits.
+    #[default]
+    Single,
+    /// Grows with the text up to this many rows, then scrolls. Lay the
+    /// widget out with [`InputWidget::desired_height`].
+    Grow(u16),
+    /// Always this many rows, scrolling once the text is longer.
+    Fixed(u16),
+}
+
+impl InputLines {
+    pub fn is_multiline(self) -> bool {
+        !matches!(self, Self::Single)
+    }
+}
+
+/// Suggests completions for the text before the cursor. See
+/// [`InputWidget::set_completion_provider`].
+pub type CompletionProvider = Box<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+/// Suggests how the whole text of an [`InputWidget`] might go on, shown as
+/// ghost text after the cursor. See [`InputWidget::set_suggestion_provider`].
+pub type SuggestionProvider = Box<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Checks the text of an [`InputWidget`], returning the message to show when
+/// it isn't acceptable. See [`InputWidget::set_validator`].
+pub type InputValidator = Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Handles submitted text in the background, resolving to the message to
+/// show if it failed. See [`InputWidget::set_async_submit`].
+pub type AsyncSubmit =
+    Box<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> + Send + Sync>;
+
+// A submission the async handler is still working on.
+#[derive(Debug)]
+struct PendingSubmit {
+    text: String,
+    result: oneshot::Receiver<Result<(), String>>,
+    started: Instant,
+}
+
+// Text being composed at the cursor, not yet part of the input.
+#[derive(Debug)]
+struct Composition {
+    text: String,
+    // A dead key waiting for the key it accents, rather than IME preedit
+    dead_key: bool,
+}
+
+// State of a Ctrl+R reverse history search.
+#[derive(Debug, Default)]
+struct HistorySearch {
+    query: String,
+    // History index of the entry shown, if any matched
+    found: Option<usize>,
+    // The last search came up empty (the previous match stays shown)
+    failed: bool,
+    // Text and cursor to restore on Esc
+    saved: (String, usize),
+}
+
+/// Rows of suggestions shown at once; longer lists scroll.
+pub const DEFAULT_COMPLETION_ROWS: usize = 8;
 
 pub struct InputWidget {
     input: String,
@@ -24,7 +98,8 @@ pub struct InputWidget {
     history: Vec<String>,
     history_index: usize,
     history_file: Option<PathBuf>,
-    history_tx: Option<mpsc::UnboundedSender<String>>,
+    history_writer: Option<HistoryWriter>,
+    history_policy: HistoryPolicy,
     hint: String,
     borders: Option<Borders>,
     border_tl_text: Option<String>,
@@ -35,21 +110,55 @@ pub struct InputWidget {
     prefix: String,
     suffix: String,
     submission: Option<String>,
+    async_submit: Option<AsyncSubmit>,
+    pending_submit: Option<PendingSubmit>,
     history_enabled: bool,
     needs_redraw: bool,
     last_area: Rect,
+    lines: InputLines,
+    scroll_row: usize,
+    scroll_col: usize,
+    overflow_indicators: bool,
+    cursor_cell: Option<Position>,
+    completion_provider: Option<CompletionProvider>,
+    completions: Vec<String>,
+    completion_index: usize,
+    completion_rows: usize,
+    suggestions: bool,
+    suggestion_provider: Option<SuggestionProvider>,
+    mask: Option<char>,
+    filter: Option<CharFilter>,
+    max_len: Option<usize>,
+    length_counter: bool,
+    validator: Option<InputValidator>,
+    validation_error: Option<String>,
+    history_search: Option<HistorySearch>,
+    vi: Option<ViState>,
+    // Other end of the Shift+arrow selection; the cursor is one end
+    selection_anchor: Option<usize>,
+    composition: Option<Composition>,
+    dead_keys: bool,
+    // Used instead of the app-wide locale, if set
+    locale: Option<Arc<Locale>>,
 }
 
 impl std::fmt::Debug for InputWidget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let input = match self.mask {
+            Some(_) => "<masked>",
+            None => &self.input,
+        };
         f.debug_struct("InputBox")
-            .field("input", &self.input)
+            .field("input", &input)
             .field("cursor_position", &self.cursor_position)
             .field("is_focused", &self.is_focused)
             .field("history", &self.history)
+            .field("mask", &self.mask)
+            .field("filter", &self.filter)
             .field("history_index", &self.history_index)
             .field("history_file", &self.history_file)
-            .field("history_tx", &self.history_tx)
+            .field("history_writer", &self.history_writer)
+            .field("history_policy", &self.history_policy)
             .field("hint", &self.hint)
             .field("borders", &self.borders)
             .field("border_tl_text", &self.border_tl_text)
@@ -58,6 +167,10 @@ impl std::fmt::Debug for InputWidget {
             .field("hint_style", &self.hint_style)
             .field("prefix", &self.prefix)
             .field("suffix", &self.suffix)
+            .field("lines", &self.lines)
+            .field("completions", &self.completions)
+            .field("validation_error", &self.validation_error)
+            .field("pending_submit", &self.pending_submit)
             .finish()
     }
 }
@@ -72,7 +185,8 @@ impl InputWidget {
             history: Vec::new(),
             history_index: 0,
             history_file: None,
-            history_tx: None,
+            history_writer: None,
+            history_policy: HistoryPolicy::default(),
             history_enabled: true,
             border_tl_text: None,
             border_tr_text: None,
@@ -83,8 +197,990 @@ impl InputWidget {
             prefix: String::new(),
             suffix: String::new(),
             submission: None,
+            async_submit: None,
+            pending_submit: None,
             needs_redraw: true,
             last_area: Rect::default(),
+            lines: InputLines::Single,
+            scroll_row: 0,
+            scroll_col: 0,
+            overflow_indicators: false,
+            cursor_cell: None,
+            completion_provider: None,
+            completions: Vec::new(),
+            completion_index: 0,
+            completion_rows: DEFAULT_COMPLETION_ROWS,
+            suggestions: false,
+            suggestion_provider: None,
+            mask: None,
+            filter: None,
+            max_len: None,
+            length_counter: false,
+            validator: None,
+            validation_error: None,
+            history_search: None,
+            vi: None,
+            selection_anchor: None,
+            composition: None,
+            dead_keys: false,
+            locale: None,
+        }
+    }
+
+    /// Use `locale` for this widget instead of the
+    /// app-wide one.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.set_locale(Some(locale));
+        self
+    }
+
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        self.locale = locale.map(Arc::new);
+    }
+
+    /// Show every typed character as `mask` (e.g. `*` or `•`), for
+    /// passwords and other secrets. [`text`](Self::text) still returns what
+    /// was typed. Masked input is never added to or recalled from the
+    /// history, and gets no completions.
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    pub fn set_mask(&mut self, mask: Option<char>) {
+        if self.mask != mask {
+            self.mask = mask;
+            self.close_completions();
+            self.redraw();
+        }
+    }
+
+    pub fn mask(&self) -> Option<char> {
+        self.mask
+    }
+
+    pub fn is_masked(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    fn uses_history(&self) -> bool {
+        self.history_enabled && self.mask.is_none()
+    }
+
+    // The text as drawn, and the cursor's byte offset in it.
+    fn shown_text(&self) -> (Cow<'_, str>, usize) {
+        let shown = match self.mask {
+            // One mask character per cluster, so the cursor still lines up
+            Some(mask) => Cow::Owned(
+                self.input
+                    .graphemes(true)
+                    .map(|cluster| if cluster == "\n" { '\n' } else { mask })
+                    .collect(),
+            ),
+            None => Cow::Borrowed(self.input.as_str()),
+        };
+        (shown, self.shown_offset(self.cursor_position))
+    }
+
+    // Byte offset `at` of the input as an offset into the shown text.
+    fn shown_offset(&self, at: usize) -> usize {
+        let Some(mask) = self.mask else {
+            return at;
+        };
+        self.input[..at]
+            .graphemes(true)
+            .map(|cluster| if cluster == "\n" { 1 } else { mask.len_utf8() })
+            .sum()
+    }
+
+    /// Accept multi-line text: Shift+Enter (or Alt+Enter) inserts a newline,
+    /// Enter still submits, and Up/Down move between lines before reaching
+    /// the history.
+    pub fn with_lines(mut self, lines: InputLines) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    pub fn set_lines(&mut self, lines: InputLines) {
+        if self.lines != lines {
+            self.lines = lines;
+            self.scroll_row = 0;
+            self.redraw();
+        }
+    }
+
+    pub fn lines(&self) -> InputLines {
+        self.lines
+    }
+
+    /// Draw `<` and `>` at the edges when text is scrolled out of view
+    /// there. Long text scrolls sideways to follow the cursor either way.
+    pub fn with_overflow_indicators(mut self, show: bool) -> Self {
+        self.overflow_indicators = show;
+        self
+    }
+
+    pub fn set_overflow_indicators(&mut self, show: bool) {
+        if self.overflow_indicators != show {
+            self.overflow_indicators = show;
+            self.redraw();
+        }
+    }
+
+    /// Compose accents typed as dead keys with the next key (`´` then `e`
+    /// types `é`), for terminals that send dead keys through as the bare
+    /// accent. The pending accent is shown underlined until then; Esc or
+    /// Backspace drops it.
+    pub fn with_dead_keys(mut self, enabled: bool) -> Self {
+        self.dead_keys = enabled;
+        self
+    }
+
+    pub fn set_dead_keys(&mut self, enabled: bool) {
+        self.dead_keys = enabled;
+        if !enabled && self.composition.as_ref().is_some_and(|c| c.dead_key) {
+            self.cancel_composition();
+        }
+    }
+
+    /// Show text an input method is still composing (its preedit) at the
+    /// cursor, underlined and apart from the text; an empty string clears
+    /// it. Terminals usually compose on their own and send only the
+    /// result, so this is for apps that get preedit from elsewhere.
+    pub fn set_composition(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        self.composition = (!text.is_empty()).then_some(Composition {
+            text,
+            dead_key: false,
+        });
+        self.redraw();
+    }
+
+    /// Text being composed at the cursor, if any.
+    pub fn composition(&self) -> Option<&str> {
+        self.composition.as_ref().map(|c| c.text.as_str())
+    }
+
+    /// Type the text being composed as it stands.
+    pub fn commit_composition(&mut self) {
+        if let Some(composition) = self.composition.take() {
+            self.insert_typed(&composition.text);
+        }
+    }
+
+    pub fn cancel_composition(&mut self) {
+        if self.composition.take().is_some() {
+            self.redraw();
+        }
+    }
+
+    // Insert `text` at the cursor as if typed, a character at a time
+    // through the filter and length limit, normalized to composed form.
+    fn insert_typed(&mut self, text: &str) {
+        self.delete_selection();
+        for ch in text.nfc() {
+            if self.accepts_char(ch) {
+                self.input.insert(self.cursor_position, ch);
+                self.cursor_position += ch.len_utf8();
+            }
+        }
+        self.after_edit();
+    }
+
+    // Keys while composing, and dead keys that start a composition. `None`
+    // lets the key through to the regular editing keys.
+    fn composition_key(&mut self, key: KeyEvent) -> Option<bool> {
+        let Some(composition) = &self.composition else {
+            let KeyCode::Char(ch) = key.code else {
+                return None;
+            };
+            if !self.dead_keys || combining_mark(ch).is_none() || self.is_searching_history() {
+                return None;
+            }
+            self.composition = Some(Composition {
+                text: ch.to_string(),
+                dead_key: true,
+            });
+            self.redraw();
+            return Some(true);
+        };
+        // Preedit is the app's to update
+        if !composition.dead_key {
+            return None;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.cancel_composition();
+                Some(true)
+            }
+            KeyCode::Char(ch) => {
+                let dead = composition.text.chars().next().unwrap_or(ch);
+                self.composition = None;
+                self.insert_typed(&compose(dead, ch));
+                Some(true)
+            }
+            // Anything else types the accent on its own first
+            _ => {
+                self.commit_composition();
+                None
+            }
+        }
+    }
+
+    /// Edit with vi keys: the widget starts in insert mode, Esc switches to
+    /// normal mode with `h`/`l`/`0`/`$`/`w`/`b`/`e` motions, the `d`, `c`
+    /// and `y` operators (doubled for the whole line), `x`, `D`, `C`, `p`
+    /// and the usual ways back into insert mode. `j` and `k` act as Down
+    /// and Up. The mode is shown on the top border.
+    pub fn with_vi_mode(mut self, enabled: bool) -> Self {
+        self.vi = enabled.then(ViState::default);
+        self
+    }
+
+    pub fn set_vi_mode(&mut self, enabled: bool) {
+        if self.vi.is_some() != enabled {
+            self.vi = enabled.then(ViState::default);
+            self.redraw();
+        }
+    }
+
+    /// The current vi mode, or `None` without vi editing.
+    pub fn vi_mode(&self) -> Option<ViMode> {
+        self.vi.as_ref().map(|vi| vi.mode)
+    }
+
+    /// Rows needed to show the current text, borders included, and a
+    /// validation error when there's no bottom border to show it in.
+    pub fn desired_height(&self) -> u16 {
+        let text_rows = match self.lines {
+            InputLines::Single => 1,
+            InputLines::Grow(max) => (self.input.split('\n').count() as u16).clamp(1, max.max(1)),
+            InputLines::Fixed(rows) => rows.max(1),
+        };
+        text_rows + self.border_rows() + self.error_rows()
+    }
+
+    // The row an error takes under the input, without a bottom border.
+    fn error_rows(&self) -> u16 {
+        let in_border = self
+            .borders
+            .is_some_and(|borders| borders.contains(Borders::BOTTOM));
+        u16::from(self.validation_error.is_some() && !in_border)
+    }
+
+    fn border_rows(&self) -> u16 {
+        let borders = self.borders.unwrap_or(Borders::NONE);
+        u16::from(borders.contains(Borders::TOP)) + u16::from(borders.contains(Borders::BOTTOM))
+    }
+
+    /// Only let through typed or pasted characters for which `accepts`
+    /// returns true. [`set_text`](Self::set_text) isn't filtered.
+    pub fn with_filter(mut self, accepts: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        self.set_filter(accepts);
+        self
+    }
+
+    pub fn set_filter(&mut self, accepts: impl Fn(char) -> bool + Send + Sync + 'static) {
+        self.filter = Some(CharFilter::Custom(Box::new(accepts)));
+    }
+
+    /// Use one of the built-in filters, e.g. [`InputFilter::Integer`].
+    pub fn with_filter_mode(mut self, filter: InputFilter) -> Self {
+        self.set_filter_mode(filter);
+        self
+    }
+
+    pub fn set_filter_mode(&mut self, filter: InputFilter) {
+        self.filter = Some(CharFilter::Builtin(filter));
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+    }
+
+    /// The built-in filter in use, if any.
+    pub fn filter_mode(&self) -> Option<InputFilter> {
+        match self.filter {
+            Some(CharFilter::Builtin(filter)) => Some(filter),
+            _ => None,
+        }
+    }
+
+    // Whether the filter lets `ch` in at the cursor
+    fn accepts_char(&self, ch: char) -> bool {
+        self.fits(ch.encode_utf8(&mut [0; 4]))
+            && self
+                .filter
+                .as_ref()
+                .is_none_or(|filter| filter.accepts(&self.input, self.cursor_position, ch))
+    }
+
+    /// Stop typing and pasting past `max` characters (grapheme clusters,
+    /// so an emoji counts as one). Text set with
+    /// [`set_text`](Self::set_text) isn't cut short.
+    pub fn with_max_len(mut self, max: usize) -> Self {
+        self.max_len = Some(max);
+        self
+    }
+
+    pub fn set_max_len(&mut self, max: Option<usize>) {
+        if self.max_len != max {
+            self.max_len = max;
+            self.redraw();
+        }
+    }
+
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Show the length against the maximum, e.g. `12/64`, at the right of
+    /// the top border. Needs [`with_max_len`](Self::with_max_len).
+    pub fn with_length_counter(mut self, show: bool) -> Self {
+        self.length_counter = show;
+        self
+    }
+
+    pub fn set_length_counter(&mut self, show: bool) {
+        if self.length_counter != show {
+            self.length_counter = show;
+            self.redraw();
+        }
+    }
+
+    /// Length of the text in characters (grapheme clusters).
+    pub fn len(&self) -> usize {
+        self.input.graphemes(true).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    // Whether inserting `text` at the cursor, over the selection if there
+    // is one, stays within the maximum length.
+    fn fits(&self, text: &str) -> bool {
+        let Some(max) = self.max_len else {
+            return true;
+        };
+        let mut after = self.input.clone();
+        let at = match self.selection() {
+            Some(range) => {
+                after.replace_range(range.clone(), "");
+                range.start
+            }
+            None => self.cursor_position,
+        };
+        after.insert_str(at, text);
+        after.graphemes(true).count() <= max
+    }
+
+    // The longest start of `text` that fits at the cursor.
+    fn fitting<'a>(&self, text: &'a str) -> &'a str {
+        if self.fits(text) {
+            return text;
+        }
+        let mut end = 0;
+        for (idx, cluster) in text.grapheme_indices(true) {
+            if !self.fits(&text[..idx + cluster.len()]) {
+                break;
+            }
+            end = idx + cluster.len();
+        }
+        &text[..end]
+    }
+
+    pub fn with_validator(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.set_validator(validator);
+        self
+    }
+
+    /// Check the text as it's edited and on Enter. While the validator
+    /// returns an error, its message is shown in the bottom border (or the
+    /// row under the input when it has none) and Enter doesn't submit, so
+    /// [`take_submission`](Self::take_submission) only ever sees valid
+    /// text.
+    pub fn set_validator(
+        &mut self,
+        validator: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) {
+        self.validator = Some(Box::new(validator));
+        self.validation_error = None;
+        if !self.input.is_empty() {
+            self.validate();
+        }
+    }
+
+    pub fn clear_validator(&mut self) {
+        self.validator = None;
+        if self.validation_error.take().is_some() {
+            self.redraw();
+        }
+    }
+
+    pub fn with_async_submit<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.set_async_submit(handler);
+        self
+    }
+
+    /// Hand submitted text to `handler` instead of
+    /// [`take_submission`](Self::take_submission). Its future runs on the
+    /// tokio runtime; meanwhile a spinner shows in the top border and Enter
+    /// does nothing. If it fails, the message is shown like a validation
+    /// error and the text comes back for another try.
+    pub fn set_async_submit<F, Fut>(&mut self, handler: F)
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.async_submit = Some(Box::new(move |text| Box::pin(handler(text))));
+    }
+
+    pub fn clear_async_submit(&mut self) {
+        self.async_submit = None;
+    }
+
+    /// Whether the async submit handler is still working on a submission.
+    pub fn is_submitting(&self) -> bool {
+        self.pending_submit.is_some()
+    }
+
+    fn start_async_submit(&mut self, text: String) {
+        let Some(handler) = &self.async_submit else {
+            return;
+        };
+        let future = handler(text.clone());
+        let (tx, result) = oneshot::channel();
+        tokio::spawn(async move {
+            let _ = tx.send(future.await);
+        });
+        self.pending_submit = Some(PendingSubmit {
+            text,
+            result,
+            started: Instant::now(),
+        });
+    }
+
+    // Pick up the result of a finished async submission.
+    fn poll_async_submit(&mut self) {
+        let Some(pending) = &mut self.pending_submit else {
+            return;
+        };
+        let result = match pending.result.try_recv() {
+            Ok(result) => result,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            // The handler panicked
+            Err(oneshot::error::TryRecvError::Closed) => {
+                Err(Locale::current().submit_failed.clone())
+            }
+        };
+        let Some(pending) = self.pending_submit.take() else {
+            return;
+        };
+        if let Err(error) = result {
+            // Give the text back, unless something new was typed meanwhile
+            if self.input.is_empty() {
+                self.input = pending.text;
+                self.cursor_position = self.input.len();
+            }
+            self.validation_error = Some(error);
+        }
+        self.redraw();
+    }
+
+    /// Run the validator on the current text now. True when there is no
+    /// validator or the text passes.
+    pub fn validate(&mut self) -> bool {
+        let error = match &self.validator {
+            Some(validator) => validator(&self.input).err(),
+            None => None,
+        };
+        if error != self.validation_error {
+            self.validation_error = error;
+            self.redraw();
+        }
+        self.validation_error.is_none()
+    }
+
+    /// The message from the last failed validation, if the text is invalid.
+    pub fn validation_error(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.validation_error.is_none()
+    }
+
+    // The error message, in the bottom border when there is one, otherwise
+    // on `error_row`, the last row of the widget's area.
+    fn render_validation_error(&self, area: Rect, error_row: Option<Rect>, buf: &mut Buffer) {
+        let Some(error) = &self.validation_error else {
+            return;
+        };
+        let in_border = self
+            .borders
+            .is_some_and(|borders| borders.contains(Borders::BOTTOM));
+        let row = if in_border {
+            Rect {
+                x: area.x + 1,
+                y: area.bottom().saturating_sub(1),
+                width: area.width.saturating_sub(2),
+                height: 1,
+            }
+        } else {
+            match error_row {
+                Some(row) => row,
+                None => return,
+            }
+        };
+        let row = row.intersection(buf.area);
+        if row.is_empty() {
+            return;
+        }
+        let style = Style::default().fg(tui_theme::ERROR_FG);
+        Line::from(Span::styled(format!(" {error} "), style)).render(row, buf);
+    }
+
+    pub fn with_completion_provider(
+        mut self,
+        provider: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.completion_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Suggest completions while typing. The provider is given the text
+    /// before the cursor and returns candidates for the word being typed,
+    /// which are listed in a popup under the input. Up/Down or
+    /// Tab/Shift+Tab pick one and Enter replaces the word with it. Tab also
+    /// asks for suggestions on an empty word, and completes right away when
+    /// there is only one.
+    pub fn set_completion_provider(
+        &mut self,
+        provider: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) {
+        self.completion_provider = Some(Box::new(provider));
+        self.close_completions();
+    }
+
+    pub fn clear_completion_provider(&mut self) {
+        self.completion_provider = None;
+        self.close_completions();
+    }
+
+    pub fn with_completion_rows(mut self, rows: usize) -> Self {
+        self.completion_rows = rows.max(1);
+        self
+    }
+
+    /// Suggestions currently shown, empty when the popup is closed.
+    pub fn completions(&self) -> &[String] {
+        &self.completions
+    }
+
+    pub fn selected_completion(&self) -> Option<&str> {
+        self.completions
+            .get(self.completion_index)
+            .map(String::as_str)
+    }
+
+    pub fn is_completing(&self) -> bool {
+        !self.completions.is_empty()
+    }
+
+    pub fn close_completions(&mut self) {
+        if !self.completions.is_empty() {
+            self.completions.clear();
+            self.completion_index = 0;
+            self.redraw();
+        }
+    }
+
+    /// Show how the text might go on as dimmed ghost text after the cursor,
+    /// fish-style: the completion picked in the popup, the newest history
+    /// entry starting with the text, or else the first completion for the
+    /// word being typed. Right or End takes it.
+    pub fn with_suggestions(mut self, enabled: bool) -> Self {
+        self.suggestions = enabled;
+        self
+    }
+
+    pub fn set_suggestions(&mut self, enabled: bool) {
+        if self.suggestions != enabled {
+            self.suggestions = enabled;
+            self.redraw();
+        }
+    }
+
+    pub fn with_suggestion_provider(
+        mut self,
+        provider: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.set_suggestion_provider(provider);
+        self
+    }
+
+    /// Suggest ghost text with `provider` instead of the history and
+    /// completions. It is given the whole text and returns the text it
+    /// might become; only suggestions that start with the text are shown.
+    /// Turns suggestions on.
+    pub fn set_suggestion_provider(
+        &mut self,
+        provider: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) {
+        self.suggestion_provider = Some(Box::new(provider));
+        self.suggestions = true;
+        self.redraw();
+    }
+
+    pub fn clear_suggestion_provider(&mut self) {
+        self.suggestion_provider = None;
+        self.redraw();
+    }
+
+    /// The ghost text shown after the cursor, if any. Only offered with the
+    /// cursor at the end of unmasked text.
+    pub fn suggestion(&self) -> Option<String> {
+        if !self.suggestions
+            || self.input.is_empty()
+            || self.cursor_position < self.input.len()
+            || self.mask.is_some()
+            || self.is_searching_history()
+            || self.composition.is_some()
+        {
+            return None;
+        }
+        let word = &self.input[self.word_start()..];
+        let rest = if let Some(provider) = &self.suggestion_provider {
+            provider(&self.input)?
+                .strip_prefix(&self.input)?
+                .to_string()
+        } else if let Some(completion) = self.selected_completion() {
+            completion.strip_prefix(word)?.to_string()
+        } else if let Some(entry) = self
+            .history
+            .iter()
+            .rev()
+            .find(|entry| entry.starts_with(&self.input) && entry.len() > self.input.len())
+        {
+            entry[self.input.len()..].to_string()
+        } else {
+            let provider = self.completion_provider.as_ref()?;
+            let completion = provider(&self.input)
+                .into_iter()
+                .find(|completion| completion.starts_with(word))?;
+            completion[word.len()..].to_string()
+        };
+        let rest = self.fitting(&rest);
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+
+    fn accept_suggestion(&mut self) -> bool {
+        let Some(rest) = self.suggestion() else {
+            return false;
+        };
+        self.input.push_str(&rest);
+        self.cursor_position = self.input.len();
+        true
+    }
+
+    // Byte offset where the word before the cursor starts.
+    fn word_start(&self) -> usize {
+        self.input[..self.cursor_position]
+            .char_indices()
+            .rev()
+            .find(|(_, ch)| ch.is_whitespace())
+            .map_or(0, |(idx, ch)| idx + ch.len_utf8())
+    }
+
+    // Ask the provider for suggestions. Unless `force`d, an empty word
+    // closes the popup instead.
+    fn update_completions(&mut self, force: bool) {
+        let Some(provider) = &self.completion_provider else {
+            return;
+        };
+        if self.mask.is_some() {
+            return;
+        }
+        let word = &self.input[self.word_start()..self.cursor_position];
+        let mut completions = if word.is_empty() && !force {
+            Vec::new()
+        } else {
+            provider(&self.input[..self.cursor_position])
+        };
+        // Nothing left to complete
+        if completions.len() == 1 && completions[0] == word {
+            completions.clear();
+        }
+        self.completions = completions;
+        self.completion_index = 0;
+    }
+
+    fn select_completion(&mut self, forward: bool) {
+        let count = self.completions.len();
+        if count > 0 {
+            self.completion_index = if forward {
+                (self.completion_index + 1) % count
+            } else {
+                (self.completion_index + count - 1) % count
+            };
+        }
+    }
+
+    // Replace the word before the cursor with the selected suggestion.
+    fn accept_completion(&mut self) {
+        if let Some(choice) = self.completions.get(self.completion_index).cloned() {
+            let start = self.word_start();
+            let mut completed = self.input.clone();
+            completed.replace_range(start..self.cursor_position, &choice);
+            // Left as typed when the completion would be too long
+            if self
+                .max_len
+                .is_none_or(|max| completed.graphemes(true).count() <= max)
+            {
+                self.input = completed;
+                self.cursor_position = start + choice.len();
+            }
+        }
+        self.close_completions();
+    }
+
+    /// Whether a Ctrl+R reverse history search is in progress.
+    pub fn is_searching_history(&self) -> bool {
+        self.history_search.is_some()
+    }
+
+    fn start_history_search(&mut self) {
+        self.close_completions();
+        self.history_search = Some(HistorySearch {
+            saved: (self.input.clone(), self.cursor_position),
+            ..Default::default()
+        });
+        self.find_in_history(self.history.len());
+    }
+
+    // Find the newest entry before `before` containing the query, keeping
+    // the current match when there is none.
+    fn find_in_history(&mut self, before: usize) {
+        let Some(search) = &mut self.history_search else {
+            return;
+        };
+        let found = self.history[..before.min(self.history.len())]
+            .iter()
+            .rposition(|entry| entry.contains(&search.query));
+        search.failed = found.is_none();
+        if found.is_some() {
+            search.found = found;
+        }
+        self.redraw();
+    }
+
+    // Keys while searching: typing narrows the search, Ctrl+R steps to
+    // older matches, Enter takes the match and Esc (or Ctrl+G) restores the
+    // text from before. Anything else takes the match and is then handled
+    // as usual.
+    fn history_search_key(&mut self, key: KeyEvent) -> Option<bool> {
+        let search = self.history_search.as_mut()?;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('r') if ctrl => {
+                let before = search.found.unwrap_or(self.history.len());
+                self.find_in_history(before);
+            }
+            KeyCode::Char('g') if ctrl => self.cancel_history_search(),
+            KeyCode::Esc => self.cancel_history_search(),
+            KeyCode::Char(ch) if !ctrl => {
+                search.query.push(ch);
+                let before = search.found.map_or(self.history.len(), |idx| idx + 1);
+                self.find_in_history(before);
+            }
+            KeyCode::Backspace => {
+                search.query.pop();
+                self.find_in_history(self.history.len());
+            }
+            KeyCode::Enter => self.accept_history_search(),
+            _ => {
+                self.accept_history_search();
+                return None;
+            }
+        }
+        Some(true)
+    }
+
+    fn accept_history_search(&mut self) {
+        if let Some(search) = self.history_search.take() {
+            if let Some(idx) = search.found {
+                self.input = self.history[idx].clone();
+                self.cursor_position = self.input.len();
+                self.history_index = idx;
+                if self.validator.is_some() {
+                    self.validate();
+                }
+            }
+            self.redraw();
+        }
+    }
+
+    fn cancel_history_search(&mut self) {
+        if let Some(search) = self.history_search.take() {
+            (self.input, self.cursor_position) = search.saved;
+            self.redraw();
+        }
+    }
+
+    // `(reverse-i-search)`query': match`, with the query highlighted in the
+    // match and the cursor at its start.
+    fn history_search_label(search: &HistorySearch) -> &'static str {
+        if search.failed {
+            "(failed reverse-i-search)`"
+        } else {
+            "(reverse-i-search)`"
+        }
+    }
+
+    // Column of the cursor cell in `history_search_line`.
+    fn history_search_cursor_column(&self) -> usize {
+        let Some(search) = &self.history_search else {
+            return 0;
+        };
+        let entry = search.found.map_or("", |idx| &self.history[idx]);
+        let before = entry
+            .find(&search.query)
+            .filter(|_| !search.query.is_empty())
+            .map_or(entry, |at| &entry[..at]);
+        display_width(Self::history_search_label(search))
+            + display_width(&search.query)
+            + display_width("': ")
+            + display_width(before)
+    }
+
+    // Screen cell of the cursor within `inner`, if it's in view.
+    fn cursor_cell(&self, inner: Rect) -> Option<Position> {
+        let (column, line) = if self.is_searching_history() {
+            (self.history_search_cursor_column(), 0)
+        } else {
+            self.line_widths().1
+        };
+        let x = u16::try_from(column.checked_sub(self.scroll_col)?).ok()?;
+        let y = u16::try_from(line.checked_sub(self.scroll_row)?).ok()?;
+        let cell = Position::new(inner.x.saturating_add(x), inner.y.saturating_add(y));
+        inner.contains(cell).then_some(cell)
+    }
+
+    fn history_search_line(&self, base: Style, cursor: Style) -> Line<'_> {
+        let Some(search) = &self.history_search else {
+            return Line::default();
+        };
+        let hint = self.hint_style;
+        let mut spans = vec![
+            Span::styled(Self::history_search_label(search), hint),
+            Span::styled(&search.query, base),
+            Span::styled("': ", hint),
+        ];
+        let entry = search.found.map_or("", |idx| &self.history[idx]);
+        let at = entry
+            .find(&search.query)
+            .filter(|_| !search.query.is_empty());
+        match at {
+            Some(at) => {
+                let end = at + search.query.len();
+                let first = entry[at..]
+                    .chars()
+                    .next()
+                    .map_or(at, |ch| at + ch.len_utf8());
+                let matched = base
+                    .bg(tui_theme::SEARCH_HIGHLIGHT_COLOR)
+                    .fg(tui_theme::TEXT_BG);
+                spans.push(Span::styled(&entry[..at], base));
+                spans.push(Span::styled(&entry[at..first], cursor));
+                spans.push(Span::styled(&entry[first..end], matched));
+                spans.push(Span::styled(&entry[end..], base));
+            }
+            None => {
+                spans.push(Span::styled(entry, base));
+                spans.push(Span::styled(" ", cursor));
+            }
+        }
+        Line::from(spans)
+    }
+
+    // Suggestion list below the input (above it when there's more room
+    // there), lined up with the word being completed.
+    fn render_completions(&self, area: Rect, buf: &mut Buffer) {
+        let bounds = *buf.area();
+        let borders = self.borders.unwrap_or(Borders::NONE);
+        let (line_start, line) = self.cursor_line();
+        let mut column = display_width(&self.input[line_start..self.word_start()]);
+        if line == 0 {
+            column += display_width(&self.prefix);
+        }
+        let column = column.saturating_sub(self.scroll_col);
+
+        let wanted = self.completions.len().min(self.completion_rows) as u16;
+        let rows_below = bounds.bottom().saturating_sub(area.bottom());
+        let rows_above = area.top().saturating_sub(bounds.top());
+        let (y, height) = if rows_below >= wanted || rows_below >= rows_above {
+            (area.bottom(), wanted.min(rows_below))
+        } else {
+            let height = wanted.min(rows_above);
+            (area.top() - height, height)
+        };
+        if height == 0 {
+            return;
+        }
+
+        let longest = self
+            .completions
+            .iter()
+            .map(|c| display_width(c))
+            .max()
+            .unwrap_or_default();
+        let width = (longest as u16 + 2).min(bounds.width);
+        // One column left, so the padded text lines up with the word
+        let x = (area.x + u16::from(borders.contains(Borders::LEFT)) + column as u16)
+            .saturating_sub(1)
+            .min(bounds.right().saturating_sub(width))
+            .max(bounds.left());
+
+        // Scroll the list so the selection stays visible
+        let first = (self.completion_index + 1).saturating_sub(height as usize);
+        let rows = self
+            .completions
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(height as usize);
+        for (row_y, (idx, completion)) in (y..).zip(rows) {
+            let style = if idx == self.completion_index {
+                Style::default()
+                    .fg(tui_theme::SELECTED_FG)
+                    .bg(tui_theme::SELECTED_BG)
+            } else {
+                Style::default()
+                    .fg(tui_theme::TOOLTIP_FG)
+                    .bg(tui_theme::TOOLTIP_BG)
+            };
+            let row = Rect::new(x, row_y, width, 1);
+            buf.set_style(row, style);
+            for cell_x in row.left()..row.right() {
+                if let Some(cell) = buf.cell_mut(Position::new(cell_x, row_y)) {
+                    cell.set_char(' ');
+                }
+            }
+            buf.set_stringn(
+                x + 1,
+                row_y,
+                completion,
+                width.saturating_sub(2) as usize,
+                style,
+            );
         }
     }
 
@@ -101,35 +1197,138 @@ impl InputWidget {
         self
     }
 
+    /// Load history from `path` and append each new entry to it from a
+    /// background task. Set the [`HistoryPolicy`] first, so it applies to
+    /// the loaded entries too.
     pub async fn with_history_file(mut self, path: PathBuf) -> Self {
         self.history_enabled = true;
         self.history_file = Some(path.clone());
-        self.load_history().await;
-        let (tx, mut rx) = mpsc::unbounded_channel();
-        self.history_tx = Some(tx);
+        let file_lines = self.load_history().await;
+        self.history_writer = Some(HistoryWriter::spawn(path, file_lines));
+        self
+    }
 
-        tokio::spawn(async move {
-            while let Some(command) = rx.recv().await {
-                if let Ok(mut file) = OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(&path)
-                    .await
-                {
-                    let _ = file.write_all(command.as_bytes()).await;
-                    let _ = file.write_all(b"\n").await;
-                }
+    pub fn with_history_policy(mut self, policy: HistoryPolicy) -> Self {
+        self.set_history_policy(policy);
+        self
+    }
+
+    /// Change the policy, dropping the oldest entries if there are now too
+    /// many. The history file is rewritten to match.
+    pub fn set_history_policy(&mut self, policy: HistoryPolicy) {
+        self.history_policy = policy;
+        if policy.trim(&mut self.history) {
+            self.history_index = self.history.len();
+            if let Some(writer) = &mut self.history_writer {
+                writer.rewrite(self.history.clone());
             }
-        });
+        }
+    }
 
-        self
+    pub fn history_policy(&self) -> &HistoryPolicy {
+        &self.history_policy
+    }
+
+    /// Entries in the history, oldest first.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Wait until every entry submitted so far has been written to the
+    /// history file. Returns immediately without one.
+    pub async fn flush_history(&self) {
+        if let Some(writer) = &self.history_writer {
+            writer.flush().await;
+        }
+    }
+
+    /// Byte range of the text selected with Shift+arrows, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        if anchor == self.cursor_position || !self.input.is_char_boundary(anchor) {
+            return None;
+        }
+        Some(anchor.min(self.cursor_position)..anchor.max(self.cursor_position))
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.input[range])
+    }
+
+    pub fn clear_selection(&mut self) {
+        if self.selection_anchor.take().is_some() {
+            self.redraw();
+        }
+    }
+
+    /// Copy the selection to the clipboard. Masked text is never copied.
+    /// Returns false when there is nothing to copy.
+    pub fn copy_selection(&self) -> bool {
+        if self.mask.is_some() {
+            return false;
+        }
+        let Some(text) = self.selected_text() else {
+            return false;
+        };
+        use clipboard::{ClipboardContext, ClipboardProvider};
+        if let Ok(mut ctx) = ClipboardContext::new() {
+            let _ = ctx.set_contents(text.to_string());
+        }
+        true
+    }
+
+    /// Copy the selection to the clipboard and remove it from the text.
+    pub fn cut_selection(&mut self) -> bool {
+        if !self.copy_selection() {
+            return false;
+        }
+        self.delete_selection();
+        self.after_edit();
+        true
+    }
+
+    /// Insert the clipboard's text at the cursor, over the selection if
+    /// there is one, as if it were pasted.
+    pub fn paste_clipboard(&mut self) -> bool {
+        use clipboard::{ClipboardContext, ClipboardProvider};
+        let Some(text) = ClipboardContext::new()
+            .and_then(|mut ctx| ctx.get_contents())
+            .ok()
+            .filter(|text| !text.is_empty())
+        else {
+            return false;
+        };
+        self.paste_event(&text)
+    }
+
+    // Remove the selected text, leaving the cursor where it started.
+    fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else {
+            return false;
+        };
+        self.selection_anchor = None;
+        self.input.replace_range(range.clone(), "");
+        self.cursor_position = range.start;
+        true
+    }
+
+    fn after_edit(&mut self) {
+        self.update_completions(false);
+        if self.validator.is_some() {
+            self.validate();
+        }
+        self.redraw();
     }
 
     pub fn set_text(&mut self, text: impl AsRef<str>) {
         let new_text = text.as_ref().to_string();
         if self.input != new_text {
+            self.selection_anchor = None;
+            self.history_search = None;
             self.input = new_text;
             self.cursor_position = self.input.len();
+            self.close_completions();
+            self.validate();
             self.redraw();
         }
     }
@@ -292,17 +1491,37 @@ impl InputWidget {
         &self.suffix
     }
 
-    async fn load_history(&mut self) {
-        if let Some(path) = &self.history_file {
-            if let Ok(file) = File::open(path).await {
-                let reader = BufReader::new(file);
-                let mut lines = reader.lines();
-                while let Ok(Some(line)) = lines.next_line().await {
-                    self.history.push(line);
-                }
-                self.history_index = self.history.len();
+    // Returns the number of lines in the file, before any were trimmed
+    async fn load_history(&mut self) -> usize {
+        let Some(path) = &self.history_file else {
+            return 0;
+        };
+        let lines = read_history(path).await;
+        let file_lines = lines.len();
+        for line in lines {
+            if self.history_policy.accepts(&self.history, &line) {
+                self.history.push(line);
             }
         }
+        self.history_policy.trim(&mut self.history);
+        self.history_index = self.history.len();
+        file_lines
+    }
+
+    // Add a submitted entry, as far as the policy allows
+    fn push_history(&mut self, entry: String) {
+        self.history_index = self.history.len();
+        if !self.history_policy.accepts(&self.history, &entry) {
+            return;
+        }
+        self.history.push(entry.clone());
+        self.history_policy.trim(&mut self.history);
+        self.history_index = self.history.len();
+
+        // Save to history file if enabled
+        if let Some(writer) = &mut self.history_writer {
+            writer.append(entry, &self.history, &self.history_policy);
+        }
     }
 
     pub fn input(&self) -> &str {
@@ -310,24 +1529,48 @@ impl InputWidget {
     }
 
     pub fn clear(&mut self) {
+        self.selection_anchor = None;
         if !self.input.is_empty() {
             self.input.clear();
             self.cursor_position = 0;
+            self.close_completions();
+            self.redraw();
+        }
+        // A cleared input hasn't been typed in yet, so don't flag it
+        if self.validation_error.take().is_some() {
+            self.redraw();
+        }
+        // and, like a fresh prompt, takes typing straight away
+        if let Some(vi) = self.vi.as_mut()
+            && vi.mode != ViMode::Insert
+        {
+            vi.mode = ViMode::Insert;
             self.redraw();
         }
     }
 
     fn handle_enter(&mut self) {
-        if !self.input.is_empty() && self.submission.is_none() {
+        // One async submission at a time
+        self.poll_async_submit();
+        if self.is_submitting() {
+            return;
+        }
+        if !self.validate() {
+            return;
+        }
+        if !self.input.is_empty() && self.async_submit.is_some() {
+            let input = self.input.clone();
+            if self.mask.is_none() {
+                self.push_history(input.clone());
+            }
+            self.start_async_submit(input);
+            self.clear();
+        } else if !self.input.is_empty() && self.submission.is_none() {
             let input = self.input.clone();
 
-            // Add to history
-            self.history.push(input.clone());
-            self.history_index = self.history.len();
-
-            // Save to history file if enabled
-            if let Some(tx) = self.history_tx.clone() {
-                let _ = tx.send(input.clone());
+            // Add to history, unless it's a secret
+            if self.mask.is_none() {
+                self.push_history(input.clone());
             }
 
             // Invoke callback if set
@@ -339,6 +1582,405 @@ impl InputWidget {
         }
     }
 
+    // Byte offset where the cursor's line starts, and the cursor's line
+    // number.
+    fn cursor_line(&self) -> (usize, usize) {
+        let before = &self.input[..self.cursor_position];
+        let start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        (start, before.matches('\n').count())
+    }
+
+    // Move the cursor one line up or down, keeping its column where the
+    // target line is long enough. Returns false at the first/last line.
+    fn move_cursor_line(&mut self, down: bool) -> bool {
+        let (start, _) = self.cursor_line();
+        let column = display_width(&self.input[start..self.cursor_position]);
+
+        let target_start = if down {
+            match self.input[self.cursor_position..].find('\n') {
+                Some(idx) => self.cursor_position + idx + 1,
+                None => return false,
+            }
+        } else {
+            if start == 0 {
+                return false;
+            }
+            self.input[..start - 1].rfind('\n').map_or(0, |idx| idx + 1)
+        };
+        let target_line = self.input[target_start..]
+            .split('\n')
+            .next()
+            .unwrap_or_default();
+        self.cursor_position = target_start + offset_at_column(target_line, column);
+        true
+    }
+
+    // Vi normal-mode commands, and Esc leaving insert mode. `None` lets the
+    // key through to the regular editing keys.
+    fn vi_key(&mut self, key: KeyEvent) -> Option<bool> {
+        let vi = self.vi.as_ref()?;
+        if vi.mode == ViMode::Insert {
+            if key.code != KeyCode::Esc || self.is_completing() {
+                return None;
+            }
+            self.set_vi_mode_normal();
+            return Some(true);
+        }
+        let KeyCode::Char(ch) = key.code else {
+            // Esc cancels a pending operator, otherwise it's the owner's
+            if key.code == KeyCode::Esc && vi.pending.is_some() {
+                self.vi.as_mut()?.pending = None;
+                return Some(true);
+            }
+            return None;
+        };
+        let pending = self.vi.as_mut()?.pending.take();
+
+        let text = &self.input;
+        let at = self.cursor_position;
+        let line = line_bounds(text, at);
+        let next = next_boundary(text, at).min(line.end);
+        // `cw` changes to the end of the word, like `ce`
+        let motion = if ch == 'w' && pending == Some('c') && !text[at..].starts_with(' ') {
+            'e'
+        } else {
+            ch
+        };
+        // Where the motion moves the cursor, and the text an operator
+        // applies to
+        let target = match motion {
+            'h' => {
+                let to = prev_boundary(text, at).max(line.start);
+                Some((to, to..at))
+            }
+            'l' => Some((next, at..next)),
+            '0' => Some((line.start, line.start..at)),
+            '$' => Some((line.end, at..line.end)),
+            'w' => {
+                let to = next_word_start(text, at).min(line.end);
+                Some((to, at..to))
+            }
+            'b' => {
+                let to = prev_word_start(text, at).max(line.start);
+                Some((to, to..at))
+            }
+            'e' => {
+                let to = word_end(text, at).min(line.end);
+                Some((to, at..next_boundary(text, to).min(line.end)))
+            }
+            _ => None,
+        };
+
+        match (target, pending) {
+            (Some((to, _)), None) => self.cursor_position = to,
+            (Some((_, range)), Some(op)) => self.vi_operate(op, range),
+            (None, _) => match ch {
+                'd' | 'c' | 'y' if pending == Some(ch) => self.vi_operate(ch, line),
+                'd' | 'c' | 'y' => self.vi.as_mut()?.pending = Some(ch),
+                'D' => self.vi_operate('d', at..line.end),
+                'C' => self.vi_operate('c', at..line.end),
+                'x' => self.vi_operate('d', at..next),
+                's' => self.vi_operate('c', at..next),
+                'i' => self.vi.as_mut()?.mode = ViMode::Insert,
+                'a' => {
+                    self.cursor_position = next;
+                    self.vi.as_mut()?.mode = ViMode::Insert;
+                }
+                'I' => {
+                    self.cursor_position = line.start;
+                    self.vi.as_mut()?.mode = ViMode::Insert;
+                }
+                'A' => {
+                    self.cursor_position = line.end;
+                    self.vi.as_mut()?.mode = ViMode::Insert;
+                }
+                'p' | 'P' => {
+                    let register = self.vi.as_ref()?.register.clone();
+                    let start = if ch == 'p' { next } else { at };
+                    let cursor = self.cursor_position;
+                    // A character at a time through the filter and length
+                    // limit, like pasted text
+                    self.cursor_position = start;
+                    for pasted in register.chars() {
+                        let accepted = if pasted == '\n' {
+                            self.fits("\n")
+                        } else {
+                            self.accepts_char(pasted)
+                        };
+                        if accepted {
+                            self.input.insert(self.cursor_position, pasted);
+                            self.cursor_position += pasted.len_utf8();
+                        }
+                    }
+                    self.cursor_position = if self.cursor_position > start {
+                        prev_boundary(&self.input, self.cursor_position)
+                    } else {
+                        cursor
+                    };
+                }
+                'j' => return Some(self.key_event(KeyEvent::new(KeyCode::Down, key.modifiers))),
+                'k' => return Some(self.key_event(KeyEvent::new(KeyCode::Up, key.modifiers))),
+                // Anything else is swallowed rather than typed
+                _ => {}
+            },
+        }
+        Some(true)
+    }
+
+    // Apply vi operator `op` to `range` of the text, keeping it in the
+    // register.
+    fn vi_operate(&mut self, op: char, range: Range<usize>) {
+        let Some(vi) = self.vi.as_mut() else {
+            return;
+        };
+        if !range.is_empty() {
+            vi.register = self.input[range.clone()].to_string();
+        }
+        if op != 'y' {
+            self.input.replace_range(range.clone(), "");
+        }
+        if op == 'c' {
+            vi.mode = ViMode::Insert;
+        }
+        self.cursor_position = range.start;
+    }
+
+    fn set_vi_mode_normal(&mut self) {
+        let Some(vi) = self.vi.as_mut() else {
+            return;
+        };
+        vi.mode = ViMode::Normal;
+        vi.pending = None;
+        if self.cursor_position > self.cursor_line().0 {
+            self.cursor_position = prev_boundary(&self.input, self.cursor_position);
+        }
+    }
+
+    // In normal mode the cursor sits on a character, not past the end of
+    // its line.
+    fn clamp_vi_cursor(&mut self) {
+        if self.vi_mode() != Some(ViMode::Normal) {
+            return;
+        }
+        let line = line_bounds(&self.input, self.cursor_position);
+        if self.cursor_position == line.end && line.end > line.start {
+            self.cursor_position = prev_boundary(&self.input, self.cursor_position);
+        }
+    }
+
+    // Keep the cursor's line within the `rows` visible rows.
+    fn scroll_to_cursor(&mut self, rows: usize) {
+        let (_, line) = self.cursor_line();
+        if line < self.scroll_row {
+            self.scroll_row = line;
+        } else if rows > 0 && line >= self.scroll_row + rows {
+            self.scroll_row = line + 1 - rows;
+        }
+    }
+
+    // Cells each line of the text takes as drawn, prefix, suffix and the
+    // cursor cell past the end of a line included, and the cursor's cell
+    // and line.
+    fn line_widths(&self) -> (Vec<usize>, (usize, usize)) {
+        let (shown, cursor_position) = self.shown_text();
+        let last = shown.matches('\n').count();
+        let mut offset = 0;
+        let mut cursor = (0, 0);
+        let mut widths = Vec::new();
+        for (idx, text) in shown.split('\n').enumerate() {
+            let mut width = display_width(text);
+            if idx == 0 {
+                width += display_width(&self.prefix);
+            }
+            let end = offset + text.len();
+            if (offset..=end).contains(&cursor_position) {
+                let mut column = width - display_width(&text[cursor_position - offset..]);
+                // Composed text is drawn before the cursor
+                if let Some(composition) = &self.composition {
+                    let composed = display_width(&composition.text);
+                    width += composed;
+                    column += composed;
+                }
+                cursor = (column, idx);
+                if cursor_position == end && self.is_focused {
+                    width += 1;
+                }
+            }
+            if idx == last {
+                width += display_width(&self.suffix);
+            }
+            widths.push(width);
+            offset = end + 1;
+        }
+        (widths, cursor)
+    }
+
+    // Scroll sideways just enough to show the cursor in `width` columns,
+    // keeping it off the overflow indicators.
+    fn scroll_to_cursor_column(&mut self, width: usize) {
+        let (widths, (column, line)) = self.line_widths();
+        let line_width = widths[line];
+        if width == 0 || line_width <= width {
+            self.scroll_col = 0;
+            return;
+        }
+        let (shown, cursor_position) = self.shown_text();
+        let cursor_width = cluster_at(&shown, cursor_position)
+            .filter(|cluster| *cluster != "\n")
+            .map_or(1, |cluster| display_width(cluster).max(1));
+        let margin = usize::from(self.overflow_indicators && width > cursor_width + 2);
+
+        if column < self.scroll_col + margin {
+            // No use hiding less than the indicator would cover
+            self.scroll_col = column.saturating_sub(margin);
+            if self.scroll_col <= margin {
+                self.scroll_col = 0;
+            }
+        } else if column + cursor_width + margin > self.scroll_col + width {
+            self.scroll_col = column + cursor_width + margin - width;
+        }
+        self.scroll_col = self.scroll_col.min(line_width - width);
+    }
+
+    // Mark rows of `inner` with text scrolled out of view on either side.
+    fn render_overflow_indicators(&self, inner: Rect, buf: &mut Buffer) {
+        if inner.is_empty() {
+            return;
+        }
+        let (widths, _) = self.line_widths();
+        let style = Style::default().fg(tui_theme::HINT_FG);
+        let rows = widths
+            .iter()
+            .skip(self.scroll_row)
+            .take(inner.height as usize);
+        for (y, &width) in (inner.y..).zip(rows) {
+            if width == 0 {
+                continue;
+            }
+            if self.scroll_col > 0 {
+                buf[(inner.x, y)]
+                    .set_symbol(tui_theme::OVERFLOW_LEFT_SYMBOL)
+                    .set_style(style);
+            }
+            if width > self.scroll_col + inner.width as usize {
+                buf[(inner.right() - 1, y)]
+                    .set_symbol(tui_theme::OVERFLOW_RIGHT_SYMBOL)
+                    .set_style(style);
+            }
+        }
+    }
+
+    // One `Line` per line of input, the prefix on the first, the suffix on
+    // the last and the cursor wherever it is.
+    fn multiline_text<'a>(
+        &'a self,
+        (shown, cursor_position): (&'a str, usize),
+        ghost: Option<&'a str>,
+        base: Style,
+        prefix: Style,
+        cursor: Style,
+    ) -> Vec<Line<'a>> {
+        let last = shown.matches('\n').count();
+        let mut offset = 0;
+        let mut lines = Vec::new();
+        for (idx, text) in shown.split('\n').enumerate() {
+            let mut spans = Vec::new();
+            if idx == 0 {
+                spans.push(Span::styled(&self.prefix, prefix));
+            }
+            let end = offset + text.len();
+            if self.is_focused {
+                spans.extend(self.focused_spans(
+                    text,
+                    offset,
+                    cursor_position,
+                    ghost,
+                    base,
+                    cursor,
+                ));
+            } else {
+                spans.push(Span::styled(text, base));
+            }
+            if idx == last {
+                spans.push(Span::styled(&self.suffix, base));
+            }
+            lines.push(Line::from(spans));
+            offset = end + 1;
+        }
+        lines
+    }
+
+    // Spans for `text`, which starts `offset` bytes into the shown text,
+    // with the selection and the cursor (a cell past the end when it's
+    // there, over the start of any ghost text) picked out. `cursor` is an
+    // offset into the shown text too.
+    fn focused_spans<'a>(
+        &'a self,
+        text: &'a str,
+        offset: usize,
+        cursor: usize,
+        ghost: Option<&'a str>,
+        base: Style,
+        cursor_style: Style,
+    ) -> Vec<Span<'a>> {
+        let end = offset + text.len();
+        let selected = self.selection().map_or(0..0, |range| {
+            self.shown_offset(range.start)..self.shown_offset(range.end)
+        });
+        let cursor_end = if (offset..end).contains(&cursor) {
+            cluster_at(text, cursor - offset).map_or(cursor, |c| cursor + c.len())
+        } else {
+            cursor
+        };
+        let mut cuts = vec![
+            offset,
+            end,
+            selected.start,
+            selected.end,
+            cursor,
+            cursor_end,
+        ];
+        cuts.retain(|cut| (offset..=end).contains(cut));
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let selected_style = base.fg(tui_theme::SELECTED_FG).bg(tui_theme::SELECTED_BG);
+        // Text being composed goes just before the cursor
+        let composed = self.composition.as_ref().map(|composition| {
+            let text = match self.mask {
+                Some(mask) => Cow::Owned(composition.text.graphemes(true).map(|_| mask).collect()),
+                None => Cow::Borrowed(composition.text.as_str()),
+            };
+            Span::styled(text, base.add_modifier(Modifier::UNDERLINED))
+        });
+        let mut spans = Vec::new();
+        for pair in cuts.windows(2) {
+            let style = if pair[0] == cursor {
+                spans.extend(composed.clone());
+                cursor_style
+            } else if selected.contains(&pair[0]) {
+                selected_style
+            } else {
+                base
+            };
+            spans.push(Span::styled(
+                &text[pair[0] - offset..pair[1] - offset],
+                style,
+            ));
+        }
+        if cursor == end {
+            spans.extend(composed);
+            match ghost.and_then(|ghost| cluster_at(ghost, 0).map(|first| (ghost, first))) {
+                Some((ghost, first)) => {
+                    spans.push(Span::styled(first, cursor_style));
+                    spans.push(Span::styled(&ghost[first.len()..], self.hint_style));
+                }
+                None => spans.push(Span::styled(" ", cursor_style)),
+            }
+        }
+        spans
+    }
+
     pub fn clear_and_unfocus(&mut self) {
         self.clear();
         self.unfocus();
@@ -356,17 +1998,57 @@ impl Default for InputWidget {
 }
 
 impl TuiWidget for InputWidget {
+    fn preprocess(&mut self) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        self.poll_async_submit();
+    }
+
     fn need_draw(&self) -> bool {
-        self.needs_redraw
+        // The spinner keeps turning while a submission runs
+        self.needs_redraw || self.is_submitting()
     }
 
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        self.poll_async_submit();
+        // An error without a bottom border to show it in takes the last row,
+        // when there's one to spare
+        let error_row = (self.error_rows() > 0 && area.height > 1).then(|| Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..area
+        });
+        let area = match error_row {
+            Some(_) => Rect {
+                height: area.height - 1,
+                ..area
+            },
+            None => area,
+        };
         // Check if area changed
         if self.last_area != area {
             self.redraw();
         }
         self.last_area = area;
 
+        // Keep the cursor in view when there are more lines than rows
+        if self.lines.is_multiline() {
+            let rows = area.height.saturating_sub(self.border_rows());
+            self.scroll_to_cursor(rows as usize);
+        } else {
+            self.scroll_row = 0;
+        }
+        // Follow the cursor sideways through lines wider than the box
+        let scrolls_sideways =
+            self.is_focused && !self.is_searching_history() && !self.input.is_empty();
+        if scrolls_sideways {
+            let borders = self.borders.unwrap_or(Borders::NONE);
+            let width = Block::default().borders(borders).inner(area).width;
+            self.scroll_to_cursor_column(width as usize);
+        } else {
+            self.scroll_col = 0;
+        }
+
         // Create the content with prefix and suffix
         let base_style = if self.is_focused {
             self.text_style
@@ -386,62 +2068,64 @@ impl TuiWidget for InputWidget {
             })
             .fg(tui_theme::TEXT_BG);
         let mut spans = vec![Span::styled(&self.prefix, prefix_style)];
+        let (shown, cursor_position) = self.shown_text();
+        let ghost = if self.is_focused {
+            self.suggestion()
+        } else {
+            None
+        };
 
-        let content = if self.input.is_empty() && !self.hint.is_empty() {
+        let content = if self.is_searching_history() {
+            Text::from(self.history_search_line(base_style, cursor_style))
+        } else if self.lines.is_multiline() && !shown.is_empty() {
+            Text::from(self.multiline_text(
+                (&shown, cursor_position),
+                ghost.as_deref(),
+                base_style,
+                prefix_style,
+                cursor_style,
+            ))
+        } else if self.input.is_empty() && !self.hint.is_empty() {
             // Show hint text with prefix/suffix
             if self.is_focused {
                 spans.push(Span::styled(" ", cursor_style));
             }
             spans.push(Span::styled(&self.suffix, base_style));
 
-            Line::from(spans)
+            Text::from(Line::from(spans))
         } else {
             // Show normal input text with prefix/suffix and cursor
 
             if self.is_focused {
-                // Split the input at cursor position
-                if self.cursor_position <= self.input.len() {
-                    // Text before cursor
-                    if self.cursor_position > 0 {
-                        let before_cursor = &self.input[..self.cursor_position];
-                        spans.push(Span::styled(before_cursor, base_style));
-                    }
-
-                    // Character at cursor (or space if at end)
-                    if self.cursor_position < self.input.len() {
-                        // Get single character at cursor position
-                        let cursor_char = &self.input[self.cursor_position..=self.cursor_position];
-                        spans.push(Span::styled(cursor_char, cursor_style));
-
-                        // Text after cursor
-                        if self.cursor_position + 1 < self.input.len() {
-                            let after_cursor = &self.input[self.cursor_position + 1..];
-                            spans.push(Span::styled(after_cursor, base_style));
-                        }
-                    } else {
-                        // Cursor is at the end, show a highlighted space
-                        spans.push(Span::styled(" ", cursor_style));
-                    }
-                }
+                spans.extend(self.focused_spans(
+                    &shown,
+                    0,
+                    cursor_position,
+                    ghost.as_deref(),
+                    base_style,
+                    cursor_style,
+                ));
             } else {
                 // When not focused, just show the full text
-                spans.push(Span::styled(&self.input, base_style));
+                spans.push(Span::styled(&*shown, base_style));
             }
 
             spans.push(Span::styled(&self.suffix, base_style));
-            Line::from(spans)
+            Text::from(Line::from(spans))
         };
 
         let mut block = Block::default();
 
         if let Some(border) = &self.borders {
-            block = block
-                .borders(*border)
-                .border_style(Style::default().fg(if self.is_focused {
+            block = block.borders(*border).border_style(Style::default().fg(
+                if self.validation_error.is_some() {
+                    tui_theme::ERROR_FG
+                } else if self.is_focused {
                     tui_theme::BORDER_FOCUSED
                 } else {
                     tui_theme::BORDER_DEFAULT
-                }));
+                },
+            ));
 
             if let Some(tl_text) = &self.border_tl_text {
                 block = block.title_top(Line::from(Span::raw(tl_text)).left_aligned());
@@ -450,56 +2134,215 @@ impl TuiWidget for InputWidget {
             if let Some(tr_text) = &self.border_tr_text {
                 block = block.title_top(Line::from(Span::raw(tr_text)).right_aligned());
             }
+
+            if let Some(vi) = &self.vi {
+                block = block.title_top(
+                    Line::from(Span::styled(vi.mode.label(), self.hint_style)).right_aligned(),
+                );
+            }
+
+            if let Some(max) = self.max_len
+                && self.length_counter
+            {
+                let len = self.len();
+                let style = if len >= max {
+                    Style::default().fg(tui_theme::WARNING_FG)
+                } else {
+                    self.hint_style
+                };
+                block = block.title_top(
+                    Line::from(Span::styled(format!(" {len}/{max} "), style)).right_aligned(),
+                );
+            }
+
+            if let Some(pending) = &self.pending_submit {
+                let working = format!(
+                    " {} {} ",
+                    spinner_frame(pending.started.elapsed()),
+                    Locale::current().working
+                );
+                block = block.title_top(
+                    Line::from(Span::styled(
+                        working,
+                        Style::default().fg(tui_theme::ACTIVE_FG),
+                    ))
+                    .right_aligned(),
+                );
+            }
         }
 
         // Render the paragraph with the block
-        Paragraph::new(content).block(block).render(area, buf);
+        let inner = block.inner(area);
+        Paragraph::new(content)
+            .block(block)
+            .scroll((self.scroll_row as u16, self.scroll_col as u16))
+            .render(area, buf);
+
+        if self.overflow_indicators && !self.input.is_empty() && !self.is_searching_history() {
+            self.render_overflow_indicators(inner.intersection(buf.area), buf);
+        }
+
+        self.render_validation_error(area, error_row, buf);
+
+        if self.is_focused && self.is_completing() {
+            self.render_completions(area, buf);
+        }
+
+        self.cursor_cell = if self.is_focused {
+            self.cursor_cell(inner)
+        } else {
+            None
+        };
 
         // Reset the flag after rendering
         self.needs_redraw = false;
     }
 
     fn key_event(&mut self, key: KeyEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
         if key.kind != KeyEventKind::Press {
             return false;
         }
-        if key.modifiers.contains(KeyModifiers::CONTROL) {
-            return false;
-        }
         if !self.is_focused {
             return false;
         }
+        // Some platforms report AltGr as Ctrl+Alt; what it types is text
+        let key = match key.code {
+            KeyCode::Char(ch)
+                if key
+                    .modifiers
+                    .contains(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                    && !ch.is_ascii_alphanumeric() =>
+            {
+                KeyEvent::new(key.code, KeyModifiers::NONE)
+            }
+            _ => key,
+        };
+        if let Some(handled) = self.history_search_key(key) {
+            return handled;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return match key.code {
+                KeyCode::Char('r') if self.uses_history() => {
+                    self.start_history_search();
+                    true
+                }
+                KeyCode::Char('c') => self.copy_selection(),
+                KeyCode::Char('x') => self.cut_selection(),
+                KeyCode::Char('v') => self.paste_clipboard(),
+                _ => false,
+            };
+        }
+        if let Some(handled) = self.vi_key(key) {
+            if handled {
+                self.selection_anchor = None;
+                if self.validator.is_some() {
+                    self.validate();
+                }
+                self.close_completions();
+                self.clamp_vi_cursor();
+                self.redraw();
+            }
+            return handled;
+        }
+        if let Some(handled) = self.composition_key(key) {
+            return handled;
+        }
 
         let mut handled = true;
 
+        let newline = self.lines.is_multiline()
+            && key
+                .modifiers
+                .intersects(KeyModifiers::SHIFT | KeyModifiers::ALT);
+        let submits = key.code == KeyCode::Enter && !newline && !self.is_completing();
+        // Shift+arrows select from where the cursor was
+        let selects = key.modifiers.contains(KeyModifiers::SHIFT)
+            && match key.code {
+                KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => true,
+                KeyCode::Up | KeyCode::Down => self.lines.is_multiline(),
+                _ => false,
+            };
+        if selects && self.selection_anchor.is_none() {
+            self.selection_anchor = Some(self.cursor_position);
+        }
+
         match key.code {
+            KeyCode::Tab if self.completions.len() == 1 => {
+                self.accept_completion();
+            }
+            KeyCode::Down | KeyCode::Tab if self.is_completing() => {
+                self.select_completion(true);
+            }
+            KeyCode::Up | KeyCode::BackTab if self.is_completing() => {
+                self.select_completion(false);
+            }
+            KeyCode::Enter if self.is_completing() && !newline => {
+                self.accept_completion();
+            }
+            KeyCode::Esc if self.is_completing() => {
+                self.close_completions();
+            }
+            KeyCode::Tab if self.completion_provider.is_some() => {
+                self.update_completions(true);
+                if self.completions.len() == 1 {
+                    self.accept_completion();
+                }
+            }
+            KeyCode::Right | KeyCode::End if !selects && self.accept_suggestion() => {}
+            // Swallowed at the maximum length, rather than submitting
+            KeyCode::Enter if newline && !self.fits("\n") => {}
+            KeyCode::Enter if newline => {
+                self.delete_selection();
+                self.input.insert(self.cursor_position, '\n');
+                self.cursor_position += 1;
+            }
             KeyCode::Enter => {
                 self.handle_enter();
             }
+            // Swallowed, so a rejected character doesn't act as a shortcut
+            KeyCode::Char(ch) if !self.accepts_char(ch) => {
+                return true;
+            }
             KeyCode::Char(to_insert) => {
+                self.delete_selection();
                 self.input.insert(self.cursor_position, to_insert);
-                self.cursor_position += 1;
+                self.cursor_position += to_insert.len_utf8();
             }
+            KeyCode::Backspace if self.delete_selection() => {}
             KeyCode::Backspace => {
                 if self.cursor_position > 0 {
-                    self.input.remove(self.cursor_position - 1);
-                    self.cursor_position -= 1;
+                    let start = prev_boundary(&self.input, self.cursor_position);
+                    self.input.replace_range(start..self.cursor_position, "");
+                    self.cursor_position = start;
                 }
             }
             KeyCode::Left if self.cursor_position > 0 => {
-                self.cursor_position -= 1;
+                self.cursor_position = prev_boundary(&self.input, self.cursor_position);
             }
             KeyCode::Right if self.cursor_position < self.input.len() => {
-                self.cursor_position += 1;
+                self.cursor_position = next_boundary(&self.input, self.cursor_position);
+            }
+            KeyCode::Home if self.lines.is_multiline() => {
+                self.cursor_position = self.cursor_line().0;
+            }
+            KeyCode::End if self.lines.is_multiline() => {
+                self.cursor_position += self.input[self.cursor_position..]
+                    .find('\n')
+                    .unwrap_or(self.input.len() - self.cursor_position);
             }
-            KeyCode::Up if self.history_enabled && self.history_index > 0 => {
+            KeyCode::Up if self.lines.is_multiline() && self.move_cursor_line(false) => {}
+            KeyCode::Down if self.lines.is_multiline() && self.move_cursor_line(true) => {}
+            KeyCode::Up if self.uses_history() && self.history_index > 0 => {
                 self.history_index -= 1;
+                self.selection_anchor = None;
                 self.input = self.history[self.history_index].clone();
                 self.cursor_position = self.input.len();
             }
-            KeyCode::Down if self.history_enabled => {
+            KeyCode::Down if self.uses_history() => {
                 if self.history_index + 1 < self.history.len() {
                     self.history_index += 1;
+                    self.selection_anchor = None;
                     self.input = self.history[self.history_index].clone();
                     self.cursor_position = self.input.len();
                 } else if self.history_index > 0 {
@@ -512,13 +2355,63 @@ impl TuiWidget for InputWidget {
             }
         }
 
+        if !selects && self.selection_anchor.take().is_some() {
+            self.redraw();
+        }
+
         if handled {
+            // Enter validates for itself, before submitting and clearing
+            if self.validator.is_some() && !submits {
+                self.validate();
+            }
+            match key.code {
+                KeyCode::Char(_) | KeyCode::Backspace => self.update_completions(false),
+                KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End => {
+                    self.close_completions()
+                }
+                _ => {}
+            }
+            self.clamp_vi_cursor();
             self.redraw();
         }
 
         handled
     }
 
+    fn paste_event(&mut self, text: &str) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if !self.is_focused {
+            return false;
+        }
+        if let Some(search) = &mut self.history_search {
+            search.query.push_str(&text.replace('\n', " "));
+            let before = search.found.map_or(self.history.len(), |idx| idx + 1);
+            self.find_in_history(before);
+            return true;
+        }
+        self.delete_selection();
+        let text = if self.lines.is_multiline() {
+            Cow::Borrowed(text)
+        } else {
+            Cow::Owned(text.replace('\n', " "))
+        };
+        if self.filter.is_some() {
+            // Checked one at a time, as the built-ins depend on what's there
+            for ch in text.chars() {
+                if ch == '\n' || self.accepts_char(ch) {
+                    self.input.insert(self.cursor_position, ch);
+                    self.cursor_position += ch.len_utf8();
+                }
+            }
+        } else {
+            let text = self.fitting(&text);
+            self.input.insert_str(self.cursor_position, text);
+            self.cursor_position += text.len();
+        }
+        self.after_edit();
+        true
+    }
+
     fn focus(&mut self) {
         if !self.is_focused {
             self.is_focused = true;
@@ -526,9 +2419,18 @@ impl TuiWidget for InputWidget {
         }
     }
 
+    fn cursor_position(&self) -> Option<Position> {
+        self.cursor_cell
+    }
+
     fn unfocus(&mut self) {
         if self.is_focused {
             self.is_focused = false;
+            self.cursor_cell = None;
+            self.selection_anchor = None;
+            self.composition = None;
+            self.close_completions();
+            self.accept_history_search();
             self.redraw();
         }
     }
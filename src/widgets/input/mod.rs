@@ -2,5 +2,21 @@
 mod input_widget;
 pub use input_widget::*;
 
+mod input_filter;
+pub use input_filter::*;
+
+mod input_history;
+pub use input_history::*;
+
+mod graphemes;
+
+mod compose;
+
+mod vi_mode;
+pub use vi_mode::*;
+
+mod tags_input;
+pub use tags_input::*;
+
 mod command_set;
 pub use command_set::*;
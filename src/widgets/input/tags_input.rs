@@ -0,0 +1,393 @@
+// tokio-tui/src/widgets/input/tags_input.rs
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Position, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+use crate::{GlyphSet, TuiWidget, tui_theme};
+
+use super::{InputWidget, graphemes::display_width};
+
+/// Columns kept free for typing after the tags; older tags are folded into a
+/// `+N` count when they don't fit.
+const MIN_INPUT_WIDTH: u16 = 8;
+
+/// A list of short values edited as removable tags ("pills"): Enter or a
+/// separator turns the typed text into a tag, Backspace on empty text removes
+/// the last one, and Left/Right walk the tags so Delete can remove any of
+/// them.
+#[derive(Debug)]
+pub struct TagsInputWidget {
+    input: InputWidget,
+    tags: Vec<String>,
+    // Tag picked out with Left/Right; typing goes to the input again once
+    // it's cleared
+    selected: Option<usize>,
+    separators: Vec<char>,
+    duplicates: bool,
+    max_tags: Option<usize>,
+    is_focused: bool,
+    needs_redraw: bool,
+    changed: bool,
+    borders: Option<Borders>,
+    title: Option<String>,
+    tag_style: Style,
+    cursor_cell: Option<Position>,
+}
+
+impl TagsInputWidget {
+    pub fn new() -> Self {
+        Self {
+            input: InputWidget::new().without_history().without_border(),
+            tags: Vec::new(),
+            selected: None,
+            separators: vec![','],
+            duplicates: false,
+            max_tags: None,
+            is_focused: false,
+            needs_redraw: true,
+            changed: false,
+            borders: Some(Borders::ALL),
+            title: None,
+            tag_style: Style::default()
+                .fg(tui_theme::TEXT_BG)
+                .bg(tui_theme::ACTIVE_FG),
+            cursor_cell: None,
+        }
+    }
+
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.set_tags(tags);
+        self
+    }
+
+    /// Replace the tags as they are, without the checks typed tags go
+    /// through.
+    pub fn set_tags(&mut self, tags: impl IntoIterator<Item = impl Into<String>>) {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self.selected = None;
+        self.redraw();
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Characters that end a tag as they are typed or pasted, besides Enter.
+    /// Defaults to a comma.
+    pub fn with_separators(mut self, separators: impl IntoIterator<Item = char>) -> Self {
+        self.separators = separators.into_iter().collect();
+        self
+    }
+
+    /// Accept a tag equal to one already there. Off by default.
+    pub fn with_duplicates(mut self, allowed: bool) -> Self {
+        self.duplicates = allowed;
+        self
+    }
+
+    pub fn with_max_tags(mut self, max: usize) -> Self {
+        self.max_tags = Some(max);
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl AsRef<str>) -> Self {
+        self.input.set_hint(hint);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_border(mut self, borders: Borders) -> Self {
+        self.borders = Some(borders);
+        self
+    }
+
+    pub fn without_border(mut self) -> Self {
+        self.borders = None;
+        self
+    }
+
+    pub fn no_border(&mut self) -> &mut Self {
+        self.borders = None;
+        self.redraw();
+        self
+    }
+
+    pub fn with_tag_style(mut self, style: Style) -> Self {
+        self.tag_style = style;
+        self
+    }
+
+    /// The text typed so far that isn't a tag yet.
+    pub fn text(&self) -> &str {
+        self.input.text()
+    }
+
+    /// Add `tag`, trimmed. Returns false if it's empty, already there (unless
+    /// duplicates are allowed) or there's no room for more tags.
+    pub fn add_tag(&mut self, tag: impl AsRef<str>) -> bool {
+        let tag = tag.as_ref().trim();
+        let full = self.max_tags.is_some_and(|max| self.tags.len() >= max);
+        if tag.is_empty() || full || (!self.duplicates && self.tags.iter().any(|t| t == tag)) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        self.changed = true;
+        self.redraw();
+        true
+    }
+
+    pub fn remove_tag(&mut self, idx: usize) -> Option<String> {
+        if idx >= self.tags.len() {
+            return None;
+        }
+        let tag = self.tags.remove(idx);
+        self.selected = self
+            .selected
+            .filter(|_| !self.tags.is_empty())
+            .map(|selected| selected.min(self.tags.len() - 1));
+        self.changed = true;
+        self.redraw();
+        Some(tag)
+    }
+
+    /// Remove the tags and any text typed after them.
+    pub fn clear(&mut self) {
+        self.changed |= !self.tags.is_empty();
+        self.tags.clear();
+        self.selected = None;
+        self.input.clear();
+        self.redraw();
+    }
+
+    /// Whether the tags changed since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// Turn the typed text into a tag. Text that isn't accepted as one stays
+    /// in the input.
+    pub fn commit_text(&mut self) -> bool {
+        let text = self.input.text().to_string();
+        if self.add_tag(&text) {
+            self.input.clear();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn redraw(&mut self) {
+        self.needs_redraw = true;
+    }
+
+    fn is_separator(&self, ch: char) -> bool {
+        self.separators.contains(&ch)
+    }
+
+    fn selected_key(&mut self, selected: usize, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Left => {
+                self.selected = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Right => {
+                self.selected = (selected + 1 < self.tags.len()).then_some(selected + 1);
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                self.remove_tag(selected);
+            }
+            KeyCode::Esc => self.selected = None,
+            KeyCode::Char(_) => {
+                // Typing goes back to the input
+                self.selected = None;
+                return self.input.key_event(key);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    // The tags as pills, dropping leading ones until they fit in `width`.
+    fn tag_spans(&self, width: u16) -> Vec<Span<'_>> {
+        let close = GlyphSet::current().pick("×", "x");
+        let pill = |tag: &str| display_width(tag) + display_width(close) + 4;
+        let budget = width.saturating_sub(MIN_INPUT_WIDTH) as usize;
+        let mut skipped = 0;
+        let mut used: usize = self.tags.iter().map(|tag| pill(tag)).sum();
+        while used > budget && skipped < self.tags.len() {
+            used -= pill(&self.tags[skipped]);
+            skipped += 1;
+            // The count takes a little room of its own
+            if skipped == 1 {
+                used += 4;
+            }
+        }
+
+        let mut spans = Vec::new();
+        if skipped > 0 {
+            spans.push(Span::styled(
+                format!("+{skipped} "),
+                Style::default().fg(tui_theme::HINT_FG),
+            ));
+        }
+        for (idx, tag) in self.tags.iter().enumerate().skip(skipped) {
+            let style = if self.is_focused && self.selected == Some(idx) {
+                Style::default()
+                    .fg(tui_theme::SELECTED_FG)
+                    .bg(tui_theme::SELECTED_BG)
+            } else if self.is_focused {
+                self.tag_style
+            } else {
+                self.tag_style.bg(tui_theme::UNFOCUSED_FG)
+            };
+            spans.push(Span::styled(format!(" {tag} {close} "), style));
+            spans.push(Span::raw(" "));
+        }
+        spans
+    }
+}
+
+impl Default for TagsInputWidget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TuiWidget for TagsInputWidget {
+    fn need_draw(&self) -> bool {
+        self.needs_redraw || self.input.need_draw()
+    }
+
+    fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let mut block = Block::default();
+        if let Some(borders) = self.borders {
+            block = block
+                .borders(borders)
+                .border_style(Style::default().fg(if self.is_focused {
+                    tui_theme::BORDER_FOCUSED
+                } else {
+                    tui_theme::BORDER_DEFAULT
+                }));
+            if let Some(title) = &self.title {
+                block = block.title_top(Line::from(Span::raw(title)).left_aligned());
+            }
+        }
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let spans = self.tag_spans(inner.width);
+        let used = (Line::from(spans.clone()).width() as u16).min(inner.width);
+        Paragraph::new(Line::from(spans)).render(
+            Rect {
+                height: inner.height.min(1),
+                ..inner
+            },
+            buf,
+        );
+
+        let input_area = Rect {
+            x: inner.x + used,
+            width: inner.width - used,
+            height: inner.height.min(1),
+            ..inner
+        };
+        self.input.draw(input_area, buf);
+        self.cursor_cell = if self.selected.is_none() {
+            self.input.cursor_position()
+        } else {
+            None
+        };
+        self.needs_redraw = false;
+    }
+
+    fn key_event(&mut self, key: KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press || !self.is_focused {
+            return false;
+        }
+        if let Some(selected) = self.selected {
+            let handled = self.selected_key(selected, key);
+            self.redraw();
+            return handled;
+        }
+
+        let empty = self.input.text().is_empty();
+        let handled = match key.code {
+            KeyCode::Enter if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Enter on empty text is left to the container, e.g. to move on
+                !empty && self.commit_text()
+            }
+            KeyCode::Char(ch) if self.is_separator(ch) => {
+                self.commit_text();
+                true
+            }
+            KeyCode::Backspace if empty => self
+                .tags
+                .len()
+                .checked_sub(1)
+                .and_then(|last| self.remove_tag(last))
+                .is_some(),
+            KeyCode::Left if empty && !self.tags.is_empty() => {
+                self.selected = Some(self.tags.len() - 1);
+                true
+            }
+            _ => self.input.key_event(key),
+        };
+        if handled {
+            self.redraw();
+        }
+        handled
+    }
+
+    /// Pasted text is split at the separators; everything before the last
+    /// one becomes tags.
+    fn paste_event(&mut self, text: &str) -> bool {
+        if !self.is_focused {
+            return false;
+        }
+        self.selected = None;
+        let mut parts = text
+            .split(|ch: char| self.is_separator(ch) || ch == '\n')
+            .collect::<Vec<_>>();
+        let rest = parts.pop().unwrap_or_default();
+        if let Some((first, others)) = parts.split_first() {
+            let first = format!("{}{first}", self.input.text());
+            self.input.clear();
+            self.add_tag(first);
+            for part in others {
+                self.add_tag(part);
+            }
+        }
+        self.input.paste_event(rest);
+        self.redraw();
+        true
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.input.focus();
+        self.redraw();
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.selected = None;
+        self.input.unfocus();
+        self.redraw();
+    }
+
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        self.cursor_cell
+    }
+}
@@ -0,0 +1,114 @@
+// tokio-tui/src/widgets/input/vi_mode.rs
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Mode of an [`InputWidget`](crate::InputWidget) with vi editing enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViMode {
+    /// Keys are commands: motions, operators and mode switches.
+    Normal,
+    /// Keys type as usual; Esc returns to normal mode.
+    #[default]
+    Insert,
+}
+
+impl ViMode {
+    /// Shown on the widget's top border.
+    pub fn label(self) -> &'static str {
+        match self {
+            ViMode::Normal => " NORMAL ",
+            ViMode::Insert => " INSERT ",
+        }
+    }
+}
+
+// Vi state of an `InputWidget`.
+#[derive(Debug, Default)]
+pub(crate) struct ViState {
+    pub(crate) mode: ViMode,
+    // Operator (`d`, `c` or `y`) waiting for its motion
+    pub(crate) pending: Option<char>,
+    // Text last deleted or yanked, for `p` and `P`
+    pub(crate) register: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Space,
+    Word,
+    Punct,
+}
+
+fn class(cluster: &str) -> CharClass {
+    match cluster.chars().next() {
+        Some(ch) if ch.is_whitespace() => CharClass::Space,
+        Some(ch) if ch.is_alphanumeric() || ch == '_' => CharClass::Word,
+        _ => CharClass::Punct,
+    }
+}
+
+fn classes(text: &str, base: usize) -> impl Iterator<Item = (usize, CharClass)> + '_ {
+    text.grapheme_indices(true)
+        .map(move |(idx, cluster)| (base + idx, class(cluster)))
+}
+
+// Byte range of the line around byte offset `at`, without its newline.
+pub(crate) fn line_bounds(text: &str, at: usize) -> Range<usize> {
+    let start = text[..at].rfind('\n').map_or(0, |idx| idx + 1);
+    let end = text[at..].find('\n').map_or(text.len(), |idx| at + idx);
+    start..end
+}
+
+// `w`: start of the next word after the one at `at`.
+pub(crate) fn next_word_start(text: &str, at: usize) -> usize {
+    let mut clusters = classes(&text[at..], at);
+    let Some((_, first)) = clusters.next() else {
+        return text.len();
+    };
+    let mut in_first = first != CharClass::Space;
+    for (idx, class) in clusters {
+        if in_first && class == first {
+            continue;
+        }
+        in_first = false;
+        if class != CharClass::Space {
+            return idx;
+        }
+    }
+    text.len()
+}
+
+// `b`: start of the word before `at`, or of the one `at` is inside.
+pub(crate) fn prev_word_start(text: &str, at: usize) -> usize {
+    let clusters: Vec<_> = classes(&text[..at], 0).collect();
+    let mut idx = clusters.len();
+    while idx > 0 && clusters[idx - 1].1 == CharClass::Space {
+        idx -= 1;
+    }
+    let Some(&(_, word)) = idx.checked_sub(1).and_then(|last| clusters.get(last)) else {
+        return 0;
+    };
+    while idx > 0 && clusters[idx - 1].1 == word {
+        idx -= 1;
+    }
+    clusters.get(idx).map_or(0, |(start, _)| *start)
+}
+
+// `e`: start of the last cluster of the word after `at`, or of the one `at`
+// is inside if it doesn't end there.
+pub(crate) fn word_end(text: &str, at: usize) -> usize {
+    let mut clusters = classes(&text[at..], at)
+        .skip(1)
+        .skip_while(|(_, class)| *class == CharClass::Space);
+    let Some((mut end, word)) = clusters.next() else {
+        return at;
+    };
+    for (idx, class) in clusters {
+        if class != word {
+            break;
+        }
+        end = idx;
+    }
+    end
+}
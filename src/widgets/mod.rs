@@ -11,6 +11,9 @@ pub use scrollbox::*;
 mod tabs;
 pub use tabs::*;
 
+mod tooltip;
+pub use tooltip::*;
+
 mod form;
 pub use form::*;
 mod tracer;
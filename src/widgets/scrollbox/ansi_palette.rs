@@ -0,0 +1,100 @@
+// tokio-tui/src/widgets/scrollbox/ansi_palette.rs
+use ratatui::style::{Color, Style};
+
+/// Theme values for the 16 base ANSI colors, applied when scrollback lines
+/// are rendered so external output matches the app's theme.
+///
+/// Stored line styles are left untouched; only named colors (`Color::Red`,
+/// `Color::LightBlue`, ...) and `Color::Indexed(0..16)` are remapped. RGB and
+/// 256-color values pass through as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnsiPalette {
+    colors: [Color; 16],
+}
+
+// Named colors in ANSI index order.
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+impl Default for AnsiPalette {
+    /// The identity palette: every color maps to itself.
+    fn default() -> Self {
+        Self {
+            colors: ANSI_COLORS,
+        }
+    }
+}
+
+impl AnsiPalette {
+    /// A palette from 16 colors in ANSI order (black, red, green, yellow,
+    /// blue, magenta, cyan, white, then the bright variants).
+    pub fn new(colors: [Color; 16]) -> Self {
+        Self { colors }
+    }
+
+    /// The standard 16-color Solarized Dark terminal palette.
+    pub fn solarized_dark() -> Self {
+        Self::new([
+            Color::Rgb(0x07, 0x36, 0x42), // base02
+            Color::Rgb(0xdc, 0x32, 0x2f), // red
+            Color::Rgb(0x85, 0x99, 0x00), // green
+            Color::Rgb(0xb5, 0x89, 0x00), // yellow
+            Color::Rgb(0x26, 0x8b, 0xd2), // blue
+            Color::Rgb(0xd3, 0x36, 0x82), // magenta
+            Color::Rgb(0x2a, 0xa1, 0x98), // cyan
+            Color::Rgb(0xee, 0xe8, 0xd5), // base2
+            Color::Rgb(0x00, 0x2b, 0x36), // base03
+            Color::Rgb(0xcb, 0x4b, 0x16), // orange
+            Color::Rgb(0x58, 0x6e, 0x75), // base01
+            Color::Rgb(0x65, 0x7b, 0x83), // base00
+            Color::Rgb(0x83, 0x94, 0x96), // base0
+            Color::Rgb(0x6c, 0x71, 0xc4), // violet
+            Color::Rgb(0x93, 0xa1, 0xa1), // base1
+            Color::Rgb(0xfd, 0xf6, 0xe3), // base3
+        ])
+    }
+
+    /// Override the color for ANSI index `index` (0-15).
+    pub fn with_color(mut self, index: usize, color: Color) -> Self {
+        if let Some(slot) = self.colors.get_mut(index) {
+            *slot = color;
+        }
+        self
+    }
+
+    /// The theme color for ANSI index `index`, if it is one of the 16.
+    pub fn color(&self, index: usize) -> Option<Color> {
+        self.colors.get(index).copied()
+    }
+
+    pub fn remap(&self, color: Color) -> Color {
+        let index = match color {
+            Color::Indexed(idx) if idx < 16 => Some(idx as usize),
+            _ => ANSI_COLORS.iter().position(|c| *c == color),
+        };
+        index.map_or(color, |idx| self.colors[idx])
+    }
+
+    pub fn remap_style(&self, mut style: Style) -> Style {
+        style.fg = style.fg.map(|c| self.remap(c));
+        style.bg = style.bg.map(|c| self.remap(c));
+        style.underline_color = style.underline_color.map(|c| self.remap(c));
+        style
+    }
+}
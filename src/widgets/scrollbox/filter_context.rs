@@ -0,0 +1,104 @@
+// tokio-tui/src/widgets/scrollbox/filter_context.rs
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Lines of context shown around each match when the scrollback is filtered
+/// down to matching lines, like `grep -B`/`-A`/`-C`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterContext {
+    pub before: usize,
+    pub after: usize,
+}
+
+impl FilterContext {
+    pub fn new(before: usize, after: usize) -> Self {
+        Self { before, after }
+    }
+
+    /// The same amount of context on both sides (`-C n`).
+    pub fn around(lines: usize) -> Self {
+        Self::new(lines, lines)
+    }
+
+    /// Split leading `-A n`, `-B n` and `-C n` flags off a search query.
+    ///
+    /// The number may be attached (`-C2`) or separate (`-C 2`). Returns the
+    /// context if at least one flag was given, and the remaining search term.
+    pub fn parse_query(query: &str) -> (Option<Self>, &str) {
+        let mut ctx: Option<Self> = None;
+        let mut rest = query;
+
+        loop {
+            let trimmed = rest.trim_start();
+            let Some((flag, after_flag)) = trimmed
+                .strip_prefix('-')
+                .and_then(|s| Some((s.chars().next()?, &s[1..])))
+            else {
+                break;
+            };
+            if !matches!(flag, 'A' | 'B' | 'C') {
+                break;
+            }
+
+            let digits_at = after_flag.trim_start();
+            let digits_len = digits_at
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(digits_at.len());
+            let Ok(n) = digits_at[..digits_len].parse::<usize>() else {
+                break;
+            };
+            let remainder = &digits_at[digits_len..];
+            if !remainder.is_empty() && !remainder.starts_with(char::is_whitespace) {
+                break;
+            }
+
+            let c = ctx.get_or_insert_default();
+            match flag {
+                'A' => c.after = n,
+                'B' => c.before = n,
+                _ => *c = Self::around(n),
+            }
+            rest = remainder;
+        }
+
+        match ctx {
+            Some(_) => (ctx, rest.trim_start()),
+            None => (None, query),
+        }
+    }
+
+    // Inclusive line ranges to show for the given sorted matching lines.
+    // Overlapping or adjacent ranges are merged, as grep does.
+    pub(crate) fn ranges(
+        self,
+        matched: impl IntoIterator<Item = usize>,
+        total: usize,
+    ) -> Vec<(usize, usize)> {
+        let mut ranges: Vec<(usize, usize)> = Vec::new();
+        for line in matched {
+            if line >= total {
+                break;
+            }
+            let first = line.saturating_sub(self.before);
+            let last = (line + self.after).min(total - 1);
+            match ranges.last_mut() {
+                Some((_, prev_last)) if first <= *prev_last + 1 => {
+                    *prev_last = (*prev_last).max(last);
+                }
+                _ => ranges.push((first, last)),
+            }
+        }
+        ranges
+    }
+}
+
+impl fmt::Display for FilterContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.before == self.after {
+            write!(f, "-C {}", self.before)
+        } else {
+            write!(f, "-B {} -A {}", self.before, self.after)
+        }
+    }
+}
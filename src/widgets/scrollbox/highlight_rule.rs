@@ -0,0 +1,97 @@
+// tokio-tui/src/widgets/scrollbox/highlight_rule.rs
+use std::ops::Range;
+
+use anyhow::Result;
+use ratatui::style::Style;
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+enum HighlightPattern {
+    Substring(String),
+    Regex(Regex),
+}
+
+/// Which part of a line a [`HighlightRule`] styles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HighlightTarget {
+    /// The whole line, if the pattern matches anywhere in it.
+    #[default]
+    Line,
+    /// Only the matched text.
+    Match,
+}
+
+/// A persistent pattern → style rule applied to scrollback lines when they
+/// are rendered, e.g. every line containing `ERROR` on a red background.
+///
+/// The rule's style is patched over the line's own (ANSI) style; selection
+/// and search highlighting still take priority.
+#[derive(Debug, Clone)]
+pub struct HighlightRule {
+    pattern: HighlightPattern,
+    style: Style,
+    target: HighlightTarget,
+}
+
+impl HighlightRule {
+    /// Highlight lines containing `text` (case-sensitive).
+    pub fn substring(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            pattern: HighlightPattern::Substring(text.into()),
+            style,
+            target: HighlightTarget::Line,
+        }
+    }
+
+    /// Highlight lines matching the regular expression `pattern`.
+    pub fn regex(pattern: impl AsRef<str>, style: Style) -> Result<Self> {
+        Ok(Self {
+            pattern: HighlightPattern::Regex(Regex::new(pattern.as_ref())?),
+            style,
+            target: HighlightTarget::Line,
+        })
+    }
+
+    /// Style only the matched text instead of the whole line.
+    pub fn matches_only(mut self) -> Self {
+        self.target = HighlightTarget::Match;
+        self
+    }
+
+    pub fn target(mut self, target: HighlightTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    // Character ranges of `plain` this rule styles.
+    pub(crate) fn char_ranges(&self, plain: &str) -> Vec<Range<usize>> {
+        let bytes: Vec<Range<usize>> = match &self.pattern {
+            HighlightPattern::Substring(text) if text.is_empty() => Vec::new(),
+            HighlightPattern::Substring(text) => plain
+                .match_indices(text.as_str())
+                .map(|(start, m)| start..start + m.len())
+                .collect(),
+            HighlightPattern::Regex(re) => re
+                .find_iter(plain)
+                .filter(|m| !m.is_empty())
+                .map(|m| m.range())
+                .collect(),
+        };
+        if bytes.is_empty() {
+            return bytes;
+        }
+
+        let to_char = |byte: usize| plain[..byte].chars().count();
+        match self.target {
+            HighlightTarget::Line => std::iter::once(0..plain.chars().count()).collect(),
+            HighlightTarget::Match => bytes
+                .into_iter()
+                .map(|r| to_char(r.start)..to_char(r.end))
+                .collect(),
+        }
+    }
+}
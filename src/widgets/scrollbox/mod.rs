@@ -1,7 +1,27 @@
 // tokio-tui/src/widgets/scrollbox/mod.rs
 mod scrollbox_widget;
 pub use scrollbox_widget::*;
+mod ansi_palette;
+pub use ansi_palette::*;
+mod filter_context;
+pub use filter_context::*;
+mod highlight_rule;
+pub use highlight_rule::*;
 mod parse_ansi;
 pub use parse_ansi::*;
+mod scroll_markers;
+pub use scroll_markers::*;
+mod scroll_line;
+pub(crate) use scroll_line::*;
+mod syntax_highlighter;
+pub use syntax_highlighter::*;
+#[cfg(feature = "syntect")]
+mod syntect_highlighter;
+#[cfg(feature = "syntect")]
+pub use syntect_highlighter::*;
 mod tabbed_scrollbox;
 pub use tabbed_scrollbox::*;
+mod view_state;
+pub use view_state::*;
+mod wrap_policy;
+pub use wrap_policy::*;
@@ -1,9 +1,17 @@
 // tokio-tui/src/widgets/scrollbox/parse_ansi.rs
-use ratatui::style::Modifier;
+use std::ops::Range;
+
+use ratatui::{
+    layout::Alignment,
+    style::Modifier,
+    text::{Line, Span},
+};
+
+use crate::GlyphSet;
 
 pub use ratatui::style::{Color, Style};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StyledChar {
     pub ch: char,
     pub style: Style,
@@ -24,7 +32,7 @@ impl<K: AsRef<char>> From<K> for StyledChar {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct StyledText {
     pub chars: Vec<StyledChar>,
 }
@@ -138,6 +146,112 @@ impl StyledText {
         self.chars.extend_from_slice(&other.chars);
         self
     }
+
+    /// `n` unstyled copies of `ch`.
+    pub fn repeat(ch: char, n: usize) -> Self {
+        StyledText::default()
+            .append_repeated(ch, n, Style::default())
+            .to_owned()
+    }
+
+    pub fn append_repeated(&mut self, ch: char, n: usize, style: Style) -> &mut Self {
+        self.chars
+            .extend(std::iter::repeat_n(StyledChar { ch, style }, n));
+        self
+    }
+
+    /// Pad with unstyled spaces up to `width` characters. Text already at
+    /// least `width` long is left as-is. Centered text puts the odd space on
+    /// the right.
+    pub fn pad_to(&mut self, width: usize, alignment: Alignment) -> &mut Self {
+        let missing = width.saturating_sub(self.len());
+        if missing == 0 {
+            return self;
+        }
+        let left = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => missing / 2,
+            Alignment::Right => missing,
+        };
+        let space = StyledChar::new(' ', Style::default());
+        self.chars
+            .splice(0..0, std::iter::repeat_n(space.clone(), left));
+        self.chars
+            .extend(std::iter::repeat_n(space, missing - left));
+        self
+    }
+
+    /// Cut the text down to at most `width` characters.
+    pub fn truncate(&mut self, width: usize) -> &mut Self {
+        self.chars.truncate(width);
+        self
+    }
+
+    /// Cut the text down to at most `width` characters, replacing the last
+    /// visible one with `…` (`~` with ASCII glyphs) when anything was
+    /// removed. The ellipsis keeps the style of the character it replaces.
+    pub fn truncate_with_ellipsis(&mut self, width: usize) -> &mut Self {
+        if self.len() > width {
+            self.chars.truncate(width);
+            if let Some(last) = self.chars.last_mut() {
+                last.ch = GlyphSet::current().pick('…', '~');
+            }
+        }
+        self
+    }
+
+    /// Truncate with an ellipsis, then pad, so the text is exactly `width`
+    /// characters long.
+    pub fn fit_to(&mut self, width: usize, alignment: Alignment) -> &mut Self {
+        self.truncate_with_ellipsis(width).pad_to(width, alignment)
+    }
+
+    /// Column ranges where `new` differs from `self`, in character or style.
+    /// See [`diff_styled`].
+    pub fn diff(&self, new: &StyledText) -> Vec<Range<usize>> {
+        diff_styled(&self.chars, &new.chars)
+    }
+
+    /// Convert into a ratatui [`Line`], merging runs of equally styled
+    /// characters into one span.
+    pub fn to_line(&self) -> Line<'static> {
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut run = String::new();
+        let mut run_style = None;
+        for sc in &self.chars {
+            if let Some(style) = run_style
+                && style != sc.style
+            {
+                spans.push(Span::styled(std::mem::take(&mut run), style));
+            }
+            run_style = Some(sc.style);
+            run.push(sc.ch);
+        }
+        if let Some(style) = run_style {
+            spans.push(Span::styled(run, style));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Column ranges that must be repainted to turn `old` into `new`.
+///
+/// Each range is a maximal run of differing columns, in order. When the
+/// lengths differ, the trailing range extends to the longer of the two so
+/// columns the new text no longer covers get cleared.
+pub fn diff_styled(old: &[StyledChar], new: &[StyledChar]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let width = old.len().max(new.len());
+    for col in 0..width {
+        if old.get(col) == new.get(col) {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == col => last.end = col + 1,
+            _ => ranges.push(col..col + 1),
+        }
+    }
+    ranges
 }
 
 pub fn parse_ansi_string(s: impl AsRef<str>) -> StyledText {
@@ -197,6 +311,42 @@ pub fn parse_ansi_string(s: impl AsRef<str>) -> StyledText {
     StyledText { chars }
 }
 
+/// Count the visible characters of an ANSI string without building styles.
+///
+/// Skips the same escape sequences as [`parse_ansi_string`], so the result
+/// always equals `parse_ansi_string(s).len()`.
+pub fn ansi_visible_len(s: impl AsRef<str>) -> usize {
+    let s = s.as_ref();
+    if !s.contains('\x1b') {
+        return s.chars().count();
+    }
+
+    let mut len = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i..].starts_with("\x1b]8;")
+            && let Some(end_idx) = find_hyperlink_end(&s[i..])
+        {
+            i += end_idx;
+            continue;
+        }
+
+        if s[i..].starts_with("\x1b[")
+            && let Some(end) = s[i..].find('m')
+        {
+            i += end + 1;
+            continue;
+        }
+
+        let Some(ch) = s[i..].chars().next() else {
+            break;
+        };
+        len += 1;
+        i += ch.len_utf8();
+    }
+    len
+}
+
 // Helper function to find the end of a hyperlink sequence
 fn find_hyperlink_end(s: &str) -> Option<usize> {
     // Look for the end of a hyperlink sequence (either \x07 or \x1b\\)
@@ -332,15 +482,15 @@ fn ansi_color_to_ratatui(code: u16) -> Color {
         4 => Color::Blue,
         5 => Color::Magenta,
         6 => Color::Cyan,
-        7 => Color::White,
-        8 => Color::Gray,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
         9 => Color::LightRed,
         10 => Color::LightGreen,
         11 => Color::LightYellow,
         12 => Color::LightBlue,
         13 => Color::LightMagenta,
         14 => Color::LightCyan,
-        15 => Color::Gray,
+        15 => Color::White,
         _ => Color::Reset,
     }
 }
@@ -0,0 +1,77 @@
+// tokio-tui/src/widgets/scrollbox/scroll_line.rs
+use std::sync::OnceLock;
+
+use super::{MarkerKind, StyledChar, StyledText, ansi_visible_len, parse_ansi_string};
+
+/// A single line stored in the scrollback buffer.
+///
+/// Lines added as raw ANSI text are kept unparsed until their styled
+/// characters are actually needed (rendering, searching, copying, or
+/// breaking a long line for wrapping). The parsed result is cached, so each
+/// line is parsed at most once.
+#[derive(Debug)]
+pub(crate) struct ScrollLine {
+    raw: Option<Box<str>>,
+    parsed: OnceLock<StyledText>,
+    len: usize,
+    // Scrollbar marker to record once the line reaches the buffer
+    marker: Option<MarkerKind>,
+}
+
+impl ScrollLine {
+    /// Store a raw ANSI line; parsing is deferred until first access.
+    pub fn from_ansi(raw: impl AsRef<str>) -> Self {
+        let raw = raw.as_ref();
+        Self {
+            len: ansi_visible_len(raw),
+            raw: Some(raw.into()),
+            parsed: OnceLock::new(),
+            marker: None,
+        }
+    }
+
+    /// Store an already styled line.
+    pub fn from_styled(text: StyledText) -> Self {
+        Self {
+            len: text.len(),
+            raw: None,
+            parsed: OnceLock::from(text),
+            marker: None,
+        }
+    }
+
+    pub fn with_marker(mut self, marker: Option<MarkerKind>) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// The marker the line was added with, leaving none behind.
+    pub fn take_marker(&mut self) -> Option<MarkerKind> {
+        self.marker.take()
+    }
+
+    /// Number of visible characters, available without parsing.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the styled form has already been produced.
+    #[inline]
+    pub fn is_parsed(&self) -> bool {
+        self.parsed.get().is_some()
+    }
+
+    /// The styled line, parsing and caching it on first use.
+    pub fn styled(&self) -> &StyledText {
+        self.parsed.get_or_init(|| match &self.raw {
+            Some(raw) => parse_ansi_string(raw),
+            None => StyledText::default(),
+        })
+    }
+
+    /// Styled characters of the line, parsing and caching them on first use.
+    pub fn chars(&self) -> &[StyledChar] {
+        &self.styled().chars
+    }
+}
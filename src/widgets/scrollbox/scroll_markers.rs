@@ -0,0 +1,99 @@
+// tokio-tui/src/widgets/scrollbox/scroll_markers.rs
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+
+use crate::{GlyphSet, tui_theme};
+
+/// What a scrollbar marker flags. When several markers land on the same
+/// track cell, the later variant wins, so errors are never hidden behind
+/// search matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarkerKind {
+    SearchMatch,
+    Bookmark,
+    Error,
+}
+
+impl MarkerKind {
+    pub const ALL: [MarkerKind; 3] = [
+        MarkerKind::SearchMatch,
+        MarkerKind::Bookmark,
+        MarkerKind::Error,
+    ];
+
+    pub fn style(self) -> Style {
+        Style::default().fg(match self {
+            MarkerKind::SearchMatch => tui_theme::MARKER_SEARCH_FG,
+            MarkerKind::Bookmark => tui_theme::MARKER_BOOKMARK_FG,
+            MarkerKind::Error => tui_theme::MARKER_ERROR_FG,
+        })
+    }
+
+    pub(crate) fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A position in the scrolled content to flag on the scrollbar track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollMarker {
+    /// Row of the content, in the same units as the scrollbar's content
+    /// length.
+    pub position: usize,
+    pub kind: MarkerKind,
+    pub style: Style,
+}
+
+impl ScrollMarker {
+    pub fn new(position: usize, kind: MarkerKind) -> Self {
+        Self {
+            position,
+            kind,
+            style: kind.style(),
+        }
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// Draw `markers` over a vertical scrollbar already rendered in `track`, a
+/// single column. Each marker lands on the cell at its share of
+/// `content_length`. Only cells still showing `track_symbol` are painted, so
+/// the thumb stays visible.
+pub fn render_scroll_markers(
+    track: Rect,
+    content_length: usize,
+    track_symbol: &str,
+    markers: impl IntoIterator<Item = ScrollMarker>,
+    buf: &mut Buffer,
+) {
+    let height = track.height as usize;
+    if height == 0 || content_length == 0 {
+        return;
+    }
+
+    let mut cells: Vec<Option<ScrollMarker>> = vec![None; height];
+    for marker in markers {
+        let row =
+            (marker.position.min(content_length - 1) * height / content_length).min(height - 1);
+        let cell = &mut cells[row];
+        if cell.is_none_or(|shown| shown.kind < marker.kind) {
+            *cell = Some(marker);
+        }
+    }
+
+    let symbol = GlyphSet::current().pick(tui_theme::MARKER_SYMBOL, tui_theme::MARKER_SYMBOL_ASCII);
+    for (row, marker) in cells.into_iter().enumerate() {
+        let Some(marker) = marker else {
+            continue;
+        };
+        let Some(cell) = buf.cell_mut((track.x, track.y + row as u16)) else {
+            continue;
+        };
+        if cell.symbol() == track_symbol {
+            cell.set_symbol(symbol).set_style(marker.style);
+        }
+    }
+}
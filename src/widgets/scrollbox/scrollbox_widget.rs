@@ -16,19 +16,27 @@
 //! `render()` implementation selectively re‑renders only the flagged
 //! regions.
 //!
+//! Text rows are additionally cached cell-by-cell. ratatui hands us a
+//! fresh frame buffer every draw, so a row whose inputs (line, segment,
+//! selection overlap, current match, search state) are unchanged is
+//! copied from the cache instead of being laid out and highlighted again.
+//!
 //! This keeps performance predictable even with very large scrollback
 //! buffers.
 //!
 //! -------------------------------------------------------------------
 
-use std::time::Instant;
-use std::{collections::VecDeque, time::Duration};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::ops::{Bound, Range, RangeBounds};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use itertools::Itertools;
 use ratatui::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell},
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     layout::{Margin, Position, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     symbols::line,
     text::{Line, Span},
     widgets::{
@@ -37,9 +45,17 @@ use ratatui::{
     },
 };
 
-use crate::{InputWidget, IntoEitherIter, TuiWidget, tui_theme};
+use tokio::sync::watch;
+
+use crate::{
+    ApplyConfig, ConfigWatch, FrameBudget, GlyphSet, InputWidget, InteractionConfig,
+    IntoEitherIter, Locale, TuiWidget, WidgetConfig, tui_theme,
+};
 
-use super::{StyledChar, StyledText, parse_ansi_string};
+use super::{
+    AnsiPalette, FilterContext, HighlightRule, MarkerKind, ScrollLine, ScrollMarker, StyledChar,
+    StyledText, SyntaxHighlighter, ViewState, WrapPolicy, diff_styled, render_scroll_markers,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum DragDirection {
@@ -190,6 +206,63 @@ enum CursorState {
     LineNumber, // Over line numbers (not selectable)
 }
 
+// Everything that determines the cells of one rendered text row. A cached
+// row is reused as long as its key is unchanged.
+#[derive(Debug, Clone, PartialEq)]
+struct RowKey {
+    inner: Rect,
+    line_id: usize,
+    line_num: usize,
+    segment: (usize, usize),
+    continuation: bool,
+    ln_width: usize,
+    wrap_indent: usize,
+    selected: Option<(usize, usize)>,
+    current_match: Option<usize>,
+    cursor: Option<usize>,
+    row_style: Option<Style>,
+    reduced: bool,
+    epoch: u64,
+}
+
+/// Duration of animated jumps when smooth scrolling is turned on.
+pub const DEFAULT_SMOOTH_SCROLL: Duration = Duration::from_millis(250);
+
+// A vertical jump being played out over several frames.
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    from: usize,
+    to: usize,
+    started: Instant,
+    duration: Duration,
+    // Heading for the bottom, so it follows the bottom as lines arrive
+    to_bottom: bool,
+}
+
+impl ScrollAnimation {
+    // Offset at `now` with an ease-out curve, or None once finished.
+    fn offset_at(&self, now: Instant) -> Option<usize> {
+        if self.duration.is_zero() {
+            return None;
+        }
+        let t = now.duration_since(self.started).as_secs_f64() / self.duration.as_secs_f64();
+        if t >= 1.0 {
+            return None;
+        }
+        let eased = 1.0 - (1.0 - t).powi(3);
+        let (from, to) = (self.from as f64, self.to as f64);
+        Some((from + (to - from) * eased).round() as usize)
+    }
+}
+
+// A search term kept highlighted in its own color while searching for others.
+#[derive(Debug, Clone)]
+struct PinnedTerm {
+    term: String,
+    color: Color,
+    rule: HighlightRule,
+}
+
 const INITIAL_WIDTH: usize = 80;
 
 /// A multi‑purpose scrollback widget with optional line‑wrapping,
@@ -204,18 +277,29 @@ pub struct ScrollbackWidget {
     border_style: Style,
     border_color: Color,
     scrollbar_style: Style,
+    zebra_style: Option<Style>,
+    focused_row_style: Option<Style>,
+    focused_line: Option<usize>,  // line id
+    markers: BTreeMap<usize, u8>, // line id -> `MarkerKind::bit`s
+    show_markers: bool,
 
     /* ---------- data  ----------- */
-    buffer: VecDeque<Vec<StyledChar>>,
+    buffer: VecDeque<ScrollLine>,
     line_capacity: usize,
-    lengths: VecDeque<usize>,
     max_line_width: usize,
 
+    /* ---------- paused ingestion ----------- */
+    paused: bool,
+    pending: VecDeque<ScrollLine>, // newest `line_capacity` lines held back
+    pending_count: usize,          // all lines received while paused
+
     /* ---------- wrapping state ----------- */
     wrap_lines: bool,
     wrap_indent: usize,
-    wrapped_lines: Vec<(usize, usize, usize)>, // (orig_idx, start, end)
+    wrap_policy: WrapPolicy,
+    wrapped_lines: VecDeque<(usize, usize, usize)>, // (line_id, start, end)
     wrapped_lines_width: usize,
+    wrap_base: usize, // line_id of buffer[0]
 
     /* ---------- scrolling state ----------- */
     v_scrollbar: ScrollbarState,
@@ -223,15 +307,36 @@ pub struct ScrollbackWidget {
     vertical_offset: usize,
     horizontal_offset: usize,
     auto_scroll: bool,
+    smooth_scroll: Option<Duration>,
+    scroll_animation: Option<ScrollAnimation>,
 
     /* ---------- selection state ----------- */
     selection: Selection,
     mouse_is_down: bool,
+    select_cursor: Option<(usize, usize)>, // keyboard selection end
 
     /* ---------- cursor state ----------- */
     cursor_state: CursorState,
     last_mouse_pos: Option<(u16, u16)>,
 
+    /* ---------- row cache ----------- */
+    row_cache: Vec<Option<(RowKey, Vec<Cell>)>>,
+    row_epoch: u64,
+    rows_redrawn: usize,
+
+    /* ---------- highlight rules ----------- */
+    highlight_rules: Vec<HighlightRule>,
+    highlighter: Option<Box<dyn SyntaxHighlighter>>,
+    ansi_palette: Option<AnsiPalette>,
+    // Frame budget exceeded: only the current search match is highlighted
+    reduced_highlighting: bool,
+    // Frame budget state when last drawn, for the dev overlay's warning
+    drawn_degraded: bool,
+
+    /* ---------- live config ----------- */
+    config_watch: Option<ConfigWatch>,
+    visible: Option<bool>,
+
     /* ---------- misc flags ----------- */
     redraw_requested: bool,
     is_focused: bool,
@@ -249,26 +354,71 @@ pub struct ScrollbackWidget {
     /* ---------- key handling helpers ----------- */
     waiting_for_g: bool,
     last_g_press: Instant,
+    interaction: Option<InteractionConfig>,
 
     /* ---------- search ----------- */
     search_mode: SearchMode,
     search_input: InputWidget,
+    search_query: String, // raw input, including any context flags
     search_term: String,
     search_matches: Vec<(usize, usize)>, // (line_idx, match_start)
     current_match: usize,
+    search_scope: Option<(SelectionStart, SelectionEnd)>,
+    pinned_terms: Vec<PinnedTerm>,
+    max_pinned_terms: usize,
+
+    /* ---------- match filtering ----------- */
+    filter_context: Option<FilterContext>, // applies to every search
+    query_context: Option<FilterContext>,  // from flags typed in the query
+    filter_rows: Vec<Option<(usize, usize, usize)>>, // (line_id, start, end); None = separator
+    filter_dirty: bool,
 
     /* ---------- drag-scroll state ----------- */
     drag_scroll_timer: Option<Instant>,
     drag_direction: DragDirection,
     last_mouse_in_bounds: bool,
+
+    // Used instead of the app-wide locale, if set
+    locale: Option<Arc<Locale>>,
 }
 
 impl TuiWidget for ScrollbackWidget {
     fn need_draw(&self) -> bool {
-        self.redraw_requested || self.is_drag_scrolling()
+        self.redraw_requested
+            || self.is_drag_scrolling()
+            || self.scroll_animation.is_some()
+            || self.reduced_highlighting != FrameBudget::skip_heavy_highlighting(self.buffer.len())
+            || (self.dev_mode && self.drawn_degraded != FrameBudget::is_degraded())
+    }
+
+    fn preprocess(&mut self) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if let Some(mut watch) = self.config_watch.take() {
+            watch.apply_to(self);
+            self.config_watch = Some(watch);
+        }
+        self.advance_scroll_animation();
+    }
+
+    fn need_visibility(&self) -> Option<bool> {
+        self.visible
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        if self.search_mode.has_focus() {
+            self.search_input.cursor_position()
+        } else {
+            None
+        }
     }
 
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if self.visible == Some(false) {
+            return;
+        }
+        self.advance_scroll_animation();
+
         // Handle drag-scroll during selection
         if self.is_drag_scrolling() {
             self.perform_drag_scroll();
@@ -282,43 +432,11 @@ impl TuiWidget for ScrollbackWidget {
             }
         }
 
-        // If the widget got resized – redraw everything.
-        if area != self.last_area {
-            Self::clear_buffer(area, buf);
-            self.last_area = area;
-        }
-
-        // Calculate inner area ( minus border – and search box space )
-        let mut inner = area.inner(Margin::new(1, 1));
-        if self.search_mode.is_active() && inner.height > 1 {
-            inner.height -= 2;
-        }
-        self.inner_width = inner.width as usize;
-
-        if self.inner_height != inner.height as usize {
-            self.inner_height = inner.height as usize;
-            self.check_and_auto_scroll();
-        }
-
-        /* ---------------- frame ---------------- */
-        self.recalculate_scrollbars();
-
-        /* ---------------- lines ---------------- */
-        if self.wrap_lines {
-            self.render_lines_wrapped(inner, buf);
-        } else {
-            self.render_lines_clipped(inner, buf);
-        }
-
-        /* ---------------- search box ----------- */
-        self.render_search_input(area, buf);
-
-        self.render_outer_frame(inner, area, buf);
-
-        self.redraw_requested = false;
+        self.render_frame(area, buf);
     }
 
     fn mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
         // Store the mouse position for cursor management
         self.last_mouse_pos = Some((mouse.column, mouse.row));
 
@@ -398,30 +516,75 @@ impl TuiWidget for ScrollbackWidget {
 
                 cursor_changed
             }
+            // Shift+wheel scrolls sideways when lines are clipped
+            MouseEventKind::ScrollDown
+                if mouse.modifiers.contains(KeyModifiers::SHIFT) && !self.wrap_lines =>
+            {
+                self.scroll_right(self.interaction_config().wheel_horizontal_step);
+                true
+            }
+            MouseEventKind::ScrollUp
+                if mouse.modifiers.contains(KeyModifiers::SHIFT) && !self.wrap_lines =>
+            {
+                self.scroll_left(self.interaction_config().wheel_horizontal_step);
+                true
+            }
             MouseEventKind::ScrollDown => {
-                self.scroll_down(1);
+                self.scroll_down(self.interaction_config().wheel_vertical_step);
                 true
             }
             MouseEventKind::ScrollUp => {
-                self.scroll_up(1);
+                self.scroll_up(self.interaction_config().wheel_vertical_step);
                 true
             }
             MouseEventKind::ScrollLeft => {
-                self.scroll_left(1);
+                self.scroll_left(self.interaction_config().wheel_horizontal_step);
                 true
             }
             MouseEventKind::ScrollRight => {
-                self.scroll_right(1);
+                self.scroll_right(self.interaction_config().wheel_horizontal_step);
                 true
             }
             _ => false,
         }
     }
 
+    fn paste_event(&mut self, text: &str) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        if self.search_mode != SearchMode::Input {
+            return false;
+        }
+        let handled = self.search_input.paste_event(text);
+        if handled {
+            self.update_search_term();
+        }
+        handled
+    }
+
     fn key_event(&mut self, key: KeyEvent) -> bool {
+        let _locale = Locale::enter(self.locale.as_ref());
+        // Alt+1..9 unpins a pinned search term, Alt+0 unpins all of them
+        if key.modifiers.contains(KeyModifiers::ALT)
+            && let KeyCode::Char(ch @ '0'..='9') = key.code
+            && !self.pinned_terms.is_empty()
+        {
+            match ch.to_digit(10) {
+                Some(0) => self.clear_pinned_terms(),
+                Some(n) => {
+                    self.unpin_term(n as usize - 1);
+                }
+                None => {}
+            }
+            return true;
+        }
+
         // Route keys to search input if needed
         if self.search_mode == SearchMode::Input {
             match key.code {
+                KeyCode::Enter if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.pin_search_term();
+                    return true;
+                }
                 KeyCode::Esc => {
                     if key.modifiers.contains(KeyModifiers::CONTROL) {
                         self.close_search();
@@ -484,10 +647,35 @@ impl TuiWidget for ScrollbackWidget {
             }
 
             /* -------- search ------------- */
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_search_in_selection()
+            }
             KeyCode::Char('/') if self.search_mode.is_closed() => self.open_search(),
             KeyCode::Char('/') if self.search_mode == SearchMode::Open => self.focus_search(),
             KeyCode::Char('n') if self.search_mode == SearchMode::Open => self.jump_to_next_match(),
             KeyCode::Char('N') if self.search_mode == SearchMode::Open => self.jump_to_prev_match(),
+            KeyCode::Enter
+                if self.search_mode == SearchMode::Open
+                    && key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                if self.pin_search_term() {
+                    self.focus_search();
+                }
+            }
+
+            /* -------- keyboard selection - */
+            KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Home
+            | KeyCode::End
+                if key.modifiers.contains(KeyModifiers::SHIFT) =>
+            {
+                self.extend_selection_by_key(key.code)
+            }
 
             /* -------- scrolling ---------- */
             KeyCode::Up => self.scroll_up(1),
@@ -525,12 +713,13 @@ impl TuiWidget for ScrollbackWidget {
                 self.show_line_numbers = !self.show_line_numbers;
                 self.request_redraw();
             }
-            KeyCode::F(9) => self.request_redraw(),
+            KeyCode::F(9) => self.redraw(),
 
             /* -------- vim‑style nav ----- */
             KeyCode::Char('g') => {
                 let now = Instant::now();
-                if self.waiting_for_g && now.duration_since(self.last_g_press).as_secs_f32() < 1.0 {
+                let timeout = self.interaction_config().key_chord_timeout;
+                if self.waiting_for_g && now.duration_since(self.last_g_press) < timeout {
                     self.scroll_to_top();
                     self.waiting_for_g = false;
                 } else {
@@ -539,6 +728,7 @@ impl TuiWidget for ScrollbackWidget {
                 }
             }
             KeyCode::Char('G') => self.scroll_to_bottom(),
+            KeyCode::Char('p') => self.set_paused(!self.paused),
 
             _ => return false,
         }
@@ -562,14 +752,10 @@ impl TuiWidget for ScrollbackWidget {
  * *****************************************************************/
 
 impl ScrollbackWidget {
-    const DRAG_EDGE_MARGIN: usize = 4; // Start scrolling when within 2 chars of edge
-    const DRAG_SPEED_SLOW: Duration = Duration::from_millis(150);
-    const DRAG_SPEED_FAST: Duration = Duration::from_millis(50);
-
-    const DRAG_VERTICAL_REPEAT: usize = 2;
-    const DRAG_VERTICAL_RESUME: usize = 3;
-    const DRAG_HORIZONTAL_REPEAT: usize = 8;
-    const DRAG_HORIZONTAL_RESUME: usize = 5;
+    // Per-widget override, or the app-wide interaction config.
+    fn interaction_config(&self) -> InteractionConfig {
+        self.interaction.unwrap_or_else(InteractionConfig::current)
+    }
 
     fn handle_mouse_drag(&mut self, x: u16, y: u16) {
         if !self.mouse_is_down {
@@ -608,7 +794,7 @@ impl ScrollbackWidget {
         let content_start_y = inner.y;
         let content_end_y = inner.y + content_height;
 
-        let margin = Self::DRAG_EDGE_MARGIN as u16;
+        let margin = self.interaction_config().drag_edge_margin as u16;
 
         // Determine scroll direction based on mouse position relative to content area
         let new_direction = if y < content_start_y {
@@ -676,11 +862,13 @@ impl ScrollbackWidget {
         };
 
         // Determine scroll speed based on how long we've been scrolling
+        let cfg = self.interaction_config();
         let elapsed = timer.elapsed();
-        let scroll_interval = if elapsed > Duration::from_millis(500) {
-            Self::DRAG_SPEED_FAST
+        let accelerated = elapsed > cfg.drag_accelerate_after;
+        let scroll_interval = if accelerated {
+            cfg.drag_speed_fast
         } else {
-            Self::DRAG_SPEED_SLOW
+            cfg.drag_speed_slow
         };
 
         // Only scroll if enough time has passed
@@ -692,15 +880,10 @@ impl ScrollbackWidget {
         self.drag_scroll_timer = Some(Instant::now());
 
         // Determine scroll amount - smaller amounts for smoother character-by-character selection
-        let vertical_amount = if elapsed > Duration::from_millis(500) {
-            Self::DRAG_VERTICAL_RESUME
+        let (vertical_amount, horizontal_amount) = if accelerated {
+            (cfg.drag_vertical_step.1, cfg.drag_horizontal_step.1)
         } else {
-            Self::DRAG_VERTICAL_REPEAT
-        };
-        let horizontal_amount = if elapsed > Duration::from_millis(500) {
-            Self::DRAG_HORIZONTAL_RESUME
-        } else {
-            Self::DRAG_HORIZONTAL_REPEAT
+            (cfg.drag_vertical_step.0, cfg.drag_horizontal_step.0)
         };
 
         // Perform scroll based on direction and check if we actually scrolled
@@ -804,7 +987,9 @@ impl ScrollbackWidget {
         let content_x = (x - content_start_x) as usize;
         let content_y = (y - inner.y) as usize;
 
-        if self.wrap_lines {
+        if self.active_filter().is_some() {
+            self.screen_to_buffer_position_filtered(content_x, content_y)
+        } else if self.wrap_lines {
             self.screen_to_buffer_position_wrapped(content_x, content_y)
         } else {
             self.screen_to_buffer_position_clipped_progressive(content_x, content_y)
@@ -1056,6 +1241,222 @@ impl ScrollbackWidget {
         }
     }
 
+    /// Number of lines in the buffer.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Stable id of the line at `index`. An id keeps identifying the same
+    /// line after older lines are trimmed from the front, and is never
+    /// reused, not even after [`clear`](Self::clear).
+    pub fn line_id(&self, index: usize) -> Option<usize> {
+        (index < self.buffer.len()).then(|| self.wrap_base + index)
+    }
+
+    /// Current index of the line with the given id, if still buffered.
+    pub fn line_index(&self, id: usize) -> Option<usize> {
+        id.checked_sub(self.wrap_base)
+            .filter(|index| *index < self.buffer.len())
+    }
+
+    /// Append a line flagged with a scrollbar marker.
+    pub fn add_marked_line(&mut self, line: StyledText, kind: MarkerKind) {
+        self.push_line(ScrollLine::from_styled(line).with_marker(Some(kind)));
+    }
+
+    /// Append lines, each flagged with a scrollbar marker.
+    pub fn add_marked_lines<I: Into<StyledText>>(
+        &mut self,
+        items: impl IntoEitherIter<I>,
+        kind: MarkerKind,
+    ) {
+        let lines: Vec<_> = items
+            .into_either_iter()
+            .map(|item| ScrollLine::from_styled(item.into()).with_marker(Some(kind)))
+            .collect();
+        self.push_lines(lines);
+    }
+
+    /// Flag the line at `index` on the scrollbar. A line can carry one
+    /// marker of each kind; they go away when the line is trimmed.
+    pub fn mark_line(&mut self, index: usize, kind: MarkerKind) -> bool {
+        let Some(id) = self.line_id(index) else {
+            return false;
+        };
+        *self.markers.entry(id).or_default() |= kind.bit();
+        self.request_redraw();
+        true
+    }
+
+    /// Remove one kind of marker from the line at `index`.
+    pub fn unmark_line(&mut self, index: usize, kind: MarkerKind) -> bool {
+        let Some(id) = self.line_id(index) else {
+            return false;
+        };
+        let Some(bits) = self.markers.get_mut(&id) else {
+            return false;
+        };
+        let had = *bits & kind.bit() != 0;
+        *bits &= !kind.bit();
+        if *bits == 0 {
+            self.markers.remove(&id);
+        }
+        if had {
+            self.request_redraw();
+        }
+        had
+    }
+
+    /// Remove every marker of `kind`, or all of them for `None`.
+    pub fn clear_markers(&mut self, kind: Option<MarkerKind>) {
+        match kind {
+            Some(kind) => self.markers.retain(|_, bits| {
+                *bits &= !kind.bit();
+                *bits != 0
+            }),
+            None => self.markers.clear(),
+        }
+        self.request_redraw();
+    }
+
+    /// Marker kinds on the line at `index`.
+    pub fn line_markers(&self, index: usize) -> Vec<MarkerKind> {
+        let bits = self
+            .line_id(index)
+            .and_then(|id| self.markers.get(&id))
+            .copied()
+            .unwrap_or(0);
+        MarkerKind::ALL
+            .into_iter()
+            .filter(|kind| bits & kind.bit() != 0)
+            .collect()
+    }
+
+    /// Every marker to show on the vertical scrollbar, positioned in rows of
+    /// the current view: marked lines plus the lines with search matches.
+    /// Lines hidden by a filter get no marker.
+    pub fn scroll_markers_in_view(&self) -> Vec<ScrollMarker> {
+        let matched = self
+            .search_matches
+            .iter()
+            .map(|(line, _)| *line)
+            .dedup()
+            .filter_map(|line| self.line_id(line))
+            .map(|id| (id, MarkerKind::SearchMatch.bit()));
+        let marked: Vec<(usize, u8)> = self
+            .markers
+            .iter()
+            .map(|(id, bits)| (*id, *bits))
+            .chain(matched)
+            .collect();
+
+        // First filter row of each line, built only when filtering
+        let filter_rows: HashMap<usize, usize> = if self.active_filter().is_some() {
+            let mut rows = HashMap::new();
+            for (row, entry) in self.filter_rows.iter().enumerate() {
+                if let Some((id, _, _)) = entry {
+                    rows.entry(*id).or_insert(row);
+                }
+            }
+            rows
+        } else {
+            HashMap::new()
+        };
+
+        let mut markers = Vec::new();
+        for (id, bits) in marked {
+            let row = if self.active_filter().is_some() {
+                filter_rows.get(&id).copied()
+            } else if self.wrap_lines {
+                Some(
+                    self.wrapped_lines
+                        .partition_point(|(row_id, _, _)| *row_id < id),
+                )
+            } else {
+                self.line_index(id)
+            };
+            let Some(row) = row else {
+                continue;
+            };
+            for kind in MarkerKind::ALL {
+                if bits & kind.bit() != 0 {
+                    markers.push(ScrollMarker::new(row, kind));
+                }
+            }
+        }
+        markers
+    }
+
+    /// Plain text of the line at `index`.
+    pub fn line_text(&self, index: usize) -> Option<String> {
+        self.buffer
+            .get(index)
+            .map(|line| line.chars().iter().map(|sc| sc.ch).collect())
+    }
+
+    /// Styled line at `index`.
+    pub fn line(&self, index: usize) -> Option<&StyledText> {
+        self.buffer.get(index).map(ScrollLine::styled)
+    }
+
+    /// Every buffered line, oldest first. Lines held back while paused are
+    /// not included. Lines added as ANSI text are parsed as they are
+    /// visited, and stay parsed.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &StyledText> + ExactSizeIterator + '_ {
+        self.buffer.iter().map(ScrollLine::styled)
+    }
+
+    /// Plain text of the lines in `range`, one per row. The range is
+    /// clamped to the buffer.
+    pub fn plain_text_range(&self, range: impl RangeBounds<usize>) -> String {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.buffer.len(),
+        }
+        .min(self.buffer.len());
+
+        let mut text = String::new();
+        for (i, line) in self.buffer.range(start.min(end)..end).enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+            text.extend(line.chars().iter().map(|sc| sc.ch));
+        }
+        text
+    }
+
+    /// The line the user is pointing at: the moving end of the selection,
+    /// else the current search match, else the last visible line.
+    pub fn active_line(&self) -> Option<usize> {
+        if self.selection.is_active() {
+            return Some(self.selection.end.line).filter(|line| *line < self.buffer.len());
+        }
+        if self.search_mode.is_active()
+            && let Some((line, _)) = self.search_matches.get(self.current_match)
+        {
+            return Some(*line);
+        }
+        self.last_visible_position().map(|(line, _)| line)
+    }
+
+    /// Scroll so the line at `index` is on screen.
+    pub fn reveal_line(&mut self, index: usize) {
+        if index < self.buffer.len() {
+            self.reveal_position(index, 0);
+            self.request_redraw();
+        }
+    }
+
     /// Get the currently selected text as a string
     pub fn get_selected_text(&self) -> Option<String> {
         if !self.selection.is_active() {
@@ -1107,9 +1508,10 @@ impl ScrollbackWidget {
                 result.push('\n');
             }
 
+            let chars = line.chars();
             for i in start_char..end_char {
-                if i < line.len() {
-                    result.push(line[i].ch);
+                if i < chars.len() {
+                    result.push(chars[i].ch);
                 }
             }
         }
@@ -1133,6 +1535,169 @@ impl ScrollbackWidget {
         true
     }
 
+    /// Render the widget as it was last drawn into an off-screen buffer and
+    /// return it row by row, with styles. Covers the whole widget area,
+    /// including borders, title and search box.
+    pub fn capture_viewport(&mut self) -> Vec<StyledText> {
+        let area = self.last_area;
+        let mut buf = Buffer::empty(area);
+        // Capturing must not swallow a redraw that is still pending
+        let redraw_requested = self.redraw_requested;
+        self.render_frame(area, &mut buf);
+        self.redraw_requested = redraw_requested;
+
+        (area.top()..area.bottom())
+            .map(|y| {
+                let mut row = StyledText::default();
+                for x in area.left()..area.right() {
+                    let Some(cell) = buf.cell(Position::new(x, y)) else {
+                        continue;
+                    };
+                    // Wide characters leave an empty cell behind them
+                    if let Some(ch) = cell.symbol().chars().next() {
+                        row.append_char(ch, cell.style());
+                    }
+                }
+                row
+            })
+            .collect()
+    }
+
+    /// Like [`capture_viewport`](Self::capture_viewport), as plain text with
+    /// trailing spaces trimmed from each row.
+    pub fn capture_viewport_text(&mut self) -> String {
+        self.capture_viewport()
+            .iter()
+            .map(|row| {
+                let text: String = row.chars.iter().map(|sc| sc.ch).collect();
+                text.trim_end().to_string()
+            })
+            .join("\n")
+    }
+
+    /// Copy the rendered viewport to the clipboard as plain text.
+    pub fn copy_viewport(&mut self) -> bool {
+        let text = self.capture_viewport_text();
+        if text.is_empty() {
+            return false;
+        }
+        use clipboard::{ClipboardContext, ClipboardProvider};
+        if let Ok(mut ctx) = ClipboardContext::new() {
+            let _ = ctx.set_contents(text);
+        }
+        true
+    }
+
+    /// Count case-insensitive occurrences of `term` within the current
+    /// selection. Returns 0 when nothing is selected.
+    pub fn count_in_selection(&self, term: impl AsRef<str>) -> usize {
+        if !self.selection.is_active() {
+            return 0;
+        }
+        self.collect_matches(term.as_ref(), Some(self.selection.normalize()))
+            .len()
+    }
+
+    /// Start or extend the selection from the keyboard. Without a selection,
+    /// it starts at the last visible line; the moving end is drawn as a
+    /// cursor and kept in view.
+    fn extend_selection_by_key(&mut self, code: KeyCode) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        if !self.selection.is_active() {
+            let Some((line, char_idx)) = self.last_visible_position() else {
+                return;
+            };
+            self.selection.start_selection(line, char_idx);
+        }
+
+        let SelectionEnd {
+            mut line,
+            mut char_idx,
+        } = self.selection.end;
+        let last_line = self.buffer.len() - 1;
+        let page = self.inner_height.max(1);
+        match code {
+            KeyCode::Left if char_idx > 0 => char_idx -= 1,
+            KeyCode::Left if line > 0 => {
+                line -= 1;
+                char_idx = self.buffer[line].len();
+            }
+            KeyCode::Right if char_idx < self.buffer[line].len() => char_idx += 1,
+            KeyCode::Right if line < last_line => {
+                line += 1;
+                char_idx = 0;
+            }
+            KeyCode::Up => line = line.saturating_sub(1),
+            KeyCode::Down => line = (line + 1).min(last_line),
+            KeyCode::PageUp => line = line.saturating_sub(page),
+            KeyCode::PageDown => line = (line + page).min(last_line),
+            KeyCode::Home => char_idx = 0,
+            KeyCode::End => char_idx = self.buffer[line].len(),
+            _ => {}
+        }
+        char_idx = char_idx.min(self.buffer[line].len());
+
+        self.selection.update_end(line, char_idx);
+        self.select_cursor = Some((line, char_idx));
+        self.reveal_position(line, char_idx);
+        self.recalculate_status();
+        self.request_redraw();
+    }
+
+    // The keyboard cursor, while it is still the end of the selection.
+    fn keyboard_cursor(&self) -> Option<(usize, usize)> {
+        self.select_cursor.filter(|&(line, char_idx)| {
+            self.selection.is_active() && self.selection.end == SelectionEnd { line, char_idx }
+        })
+    }
+
+    // Buffer position at the start of the lowest visible content row.
+    fn last_visible_position(&self) -> Option<(usize, usize)> {
+        (0..self.inner_height).rev().find_map(|y| {
+            if self.active_filter().is_some() {
+                self.screen_to_buffer_position_filtered(0, y)
+            } else if self.wrap_lines {
+                self.screen_to_buffer_position_wrapped(0, y)
+            } else {
+                self.screen_to_buffer_position_clipped_progressive(0, y)
+            }
+        })
+    }
+
+    // Scroll just enough for the given buffer position to be on screen.
+    fn reveal_position(&mut self, line_idx: usize, char_idx: usize) {
+        let line_id = self.wrap_base + line_idx;
+        let row = if self.active_filter().is_some() {
+            if self.filter_dirty {
+                self.rebuild_filter_rows();
+            }
+            self.filter_rows.iter().rposition(|row| {
+                row.is_some_and(|(id, start, _)| id == line_id && start <= char_idx)
+            })
+        } else if self.wrap_lines {
+            self.wrapped_lines
+                .iter()
+                .rposition(|(id, start, _)| *id == line_id && *start <= char_idx)
+        } else {
+            Some(line_idx)
+        };
+
+        if let Some(row) = row {
+            let height = self.inner_height.max(1);
+            if row < self.vertical_offset {
+                self.set_vertical_offset(row);
+            } else if row >= self.vertical_offset + height {
+                self.set_vertical_offset(row + 1 - height);
+            }
+        }
+        self.set_auto_scroll(false);
+
+        let len = self.buffer[line_idx].len();
+        self.drag_scroll_to_char(line_idx, char_idx.min(len.saturating_sub(1)));
+    }
+
     /// Clear current selection
     pub fn clear_selection(&mut self) {
         if self.selection.is_active() {
@@ -1155,7 +1720,8 @@ impl ScrollbackWidget {
             return None;
         }
 
-        let (orig_line_idx, start_char, end_char) = self.wrapped_lines[wrapped_line_idx];
+        let (line_id, start_char, end_char) = self.wrapped_lines[wrapped_line_idx];
+        let orig_line_idx = line_id - self.wrap_base;
 
         // Adjust for wrap indent - continuation lines are indented
         let char_idx_in_segment = if start_char > 0 {
@@ -1174,6 +1740,26 @@ impl ScrollbackWidget {
         Some((orig_line_idx, final_char_idx))
     }
 
+    fn screen_to_buffer_position_filtered(
+        &self,
+        content_x: usize,
+        content_y: usize,
+    ) -> Option<(usize, usize)> {
+        // Separator rows don't map to any line
+        let (line_id, start_char, end_char) =
+            (*self.filter_rows.get(self.vertical_offset + content_y)?)?;
+        let orig_line_idx = line_id.checked_sub(self.wrap_base)?;
+        let line = self.buffer.get(orig_line_idx)?;
+
+        let char_idx = if self.wrap_lines {
+            let indent = if start_char > 0 { self.wrap_indent } else { 0 };
+            (start_char + content_x.saturating_sub(indent)).min(end_char)
+        } else {
+            (self.horizontal_offset + content_x).min(line.len())
+        };
+        Some((orig_line_idx, char_idx))
+    }
+
     fn is_position_in_content_area(&self, x: u16, y: u16) -> bool {
         // Check if we're within the widget bounds first
         let inner = self.last_area.inner(Margin::new(1, 1));
@@ -1246,18 +1832,29 @@ impl ScrollbackWidget {
             border_style: Style::default().fg(tui_theme::BORDER_DEFAULT),
             border_color: tui_theme::BORDER_DEFAULT,
             scrollbar_style: Style::default().fg(tui_theme::SCROLLBAR_DEFAULT),
+            zebra_style: None,
+            focused_row_style: None,
+            focused_line: None,
+            markers: BTreeMap::new(),
+            show_markers: true,
 
             /* data */
             buffer: VecDeque::with_capacity(capacity),
             line_capacity: capacity,
-            lengths: VecDeque::with_capacity(capacity),
             max_line_width: 0,
 
+            /* paused ingestion */
+            paused: false,
+            pending: VecDeque::new(),
+            pending_count: 0,
+
             /* wrapping */
             wrap_lines: true,
             wrap_indent: 0,
-            wrapped_lines: Vec::new(),
+            wrap_policy: WrapPolicy::default(),
+            wrapped_lines: VecDeque::new(),
             wrapped_lines_width: 0,
+            wrap_base: 0,
 
             /* scrolling */
             v_scrollbar: ScrollbarState::default(),
@@ -1265,15 +1862,30 @@ impl ScrollbackWidget {
             vertical_offset: 0,
             horizontal_offset: 0,
             auto_scroll: true,
+            smooth_scroll: None,
+            scroll_animation: None,
 
             /* selection */
             selection: Selection::new(),
             mouse_is_down: false,
+            select_cursor: None,
 
             /* cursor */
             cursor_state: CursorState::Default,
             last_mouse_pos: None,
 
+            /* row cache */
+            row_cache: Vec::new(),
+            row_epoch: 0,
+            rows_redrawn: 0,
+
+            /* highlight rules */
+            highlight_rules: Vec::new(),
+            highlighter: None,
+            ansi_palette: None,
+            reduced_highlighting: false,
+            drawn_degraded: false,
+
             /* misc flags */
             redraw_requested: true,
             is_focused: false,
@@ -1288,26 +1900,41 @@ impl ScrollbackWidget {
             title: title.as_ref().to_string(),
             info_text: String::new(),
 
+            /* live config */
+            config_watch: None,
+            visible: None,
+
             /* key helpers */
             waiting_for_g: false,
             last_g_press: Instant::now(),
+            interaction: None,
 
             /* search */
             search_mode: SearchMode::Closed,
             search_input: InputWidget::new().with_border(Borders::TOP),
+            search_query: String::new(),
             search_term: String::new(),
             search_matches: Vec::new(),
+            pinned_terms: Vec::new(),
+            max_pinned_terms: tui_theme::PINNED_MATCH_BGS.len(),
             current_match: 0,
+            search_scope: None,
+
+            /* filtering */
+            filter_context: None,
+            query_context: None,
+            filter_rows: Vec::new(),
+            filter_dirty: false,
 
             /* drag-scroll */
             drag_scroll_timer: None,
             drag_direction: DragDirection::None,
             last_mouse_in_bounds: true,
+
+            locale: None,
         };
 
-        widget
-            .search_input
-            .set_hint("Search (Enter to find, Esc to cancel)");
+        widget.search_input.set_hint(&Locale::current().search_hint);
 
         widget.recalculate_status();
         widget
@@ -1332,23 +1959,334 @@ impl ScrollbackWidget {
         self
     }
 
-    pub fn set_borders(&mut self, borders: Borders) {
-        self.borders = borders;
-        self.request_redraw();
+    /// Where long lines may break when wrapping, and whether split tokens
+    /// get a continuation marker.
+    pub fn wrap_policy(mut self, policy: WrapPolicy) -> Self {
+        self.wrap_policy = policy;
+        self
     }
 
-    /// Force the widget to be considered dirty.
-    pub fn redraw(&mut self) {
-        self.request_redraw();
+    /// Shade every other line with `style`, e.g.
+    /// `Style::default().bg(tui_theme::ZEBRA_BG)`. Stripes follow lines, so
+    /// wrapped rows share their line's shade.
+    pub fn zebra_stripes(mut self, style: Style) -> Self {
+        self.zebra_style = Some(style);
+        self
     }
 
-    /// Adjust spaces inserted at the beginning of wrapped continuation lines.
-    pub fn set_wrap_indent(&mut self, wrap_indent: usize) {
-        if self.wrap_indent != wrap_indent {
-            self.wrap_indent = wrap_indent;
-            self.wrapped_lines_width = 0;
-            self.request_redraw();
-        }
+    /// Highlight the focused line (see [`focus_line`](Self::focus_line))
+    /// with `style`, e.g. `Style::default().bg(tui_theme::FOCUSED_ROW_BG)`.
+    pub fn focused_row_style(mut self, style: Style) -> Self {
+        self.focused_row_style = Some(style);
+        self
+    }
+
+    /// Flag errors, bookmarks and search matches on the vertical scrollbar
+    /// track. On by default.
+    pub fn scroll_markers(mut self, show: bool) -> Self {
+        self.show_markers = show;
+        self
+    }
+
+    /// Animate jumps to the top, bottom or a search match over `duration`
+    /// instead of moving there in one frame.
+    pub fn smooth_scroll(mut self, duration: Duration) -> Self {
+        self.smooth_scroll = Some(duration);
+        self
+    }
+
+    pub fn set_smooth_scroll(&mut self, duration: Option<Duration>) {
+        self.smooth_scroll = duration;
+        if duration.is_none() {
+            self.finish_scroll_animation();
+        }
+    }
+
+    /// Apply [`WidgetConfig`]s sent on `rx` as they change, starting with
+    /// the current one. Checked each [`preprocess`](TuiWidget::preprocess).
+    pub fn config_watch(mut self, rx: watch::Receiver<WidgetConfig>) -> Self {
+        self.watch_config(rx);
+        self
+    }
+
+    pub fn watch_config(&mut self, rx: watch::Receiver<WidgetConfig>) {
+        let mut watch = ConfigWatch::new(rx);
+        watch.apply_to(self);
+        self.config_watch = Some(watch);
+    }
+
+    /// Use `locale` for this widget instead of the app-wide one.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.set_locale(Some(locale));
+        self
+    }
+
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        self.locale = locale.map(Arc::new);
+    }
+
+    /// Use interaction timing for this widget instead of the app-wide config.
+    pub fn interaction(mut self, config: InteractionConfig) -> Self {
+        self.interaction = Some(config);
+        self
+    }
+
+    pub fn set_interaction(&mut self, config: Option<InteractionConfig>) {
+        self.interaction = config;
+    }
+
+    /// Add a persistent highlight rule, applied when lines are rendered.
+    pub fn highlight_rule(mut self, rule: HighlightRule) -> Self {
+        self.add_highlight_rule(rule);
+        self
+    }
+
+    /// Add a persistent highlight rule. Later rules are patched over
+    /// earlier ones where they overlap.
+    pub fn add_highlight_rule(&mut self, rule: HighlightRule) {
+        self.highlight_rules.push(rule);
+        self.invalidate_rows();
+        self.request_redraw();
+    }
+
+    pub fn clear_highlight_rules(&mut self) {
+        if !self.highlight_rules.is_empty() {
+            self.highlight_rules.clear();
+            self.invalidate_rows();
+            self.request_redraw();
+        }
+    }
+
+    pub fn highlight_rules(&self) -> &[HighlightRule] {
+        &self.highlight_rules
+    }
+
+    /// Remap the 16 base ANSI colors of line content when rendering.
+    pub fn ansi_palette(mut self, palette: AnsiPalette) -> Self {
+        self.set_ansi_palette(Some(palette));
+        self
+    }
+
+    pub fn set_ansi_palette(&mut self, palette: Option<AnsiPalette>) {
+        if self.ansi_palette != palette {
+            self.ansi_palette = palette;
+            self.invalidate_rows();
+            self.request_redraw();
+        }
+    }
+
+    /// Maximum number of pinned search terms (default: one per color in
+    /// [`tui_theme::PINNED_MATCH_BGS`]). Colors repeat past that.
+    pub fn max_pinned_terms(mut self, max: usize) -> Self {
+        self.max_pinned_terms = max;
+        self
+    }
+
+    /// Keep `term` highlighted in its own color, independent of the active
+    /// search. Returns false if the term is empty, already pinned, or the
+    /// limit is reached.
+    pub fn pin_term(&mut self, term: impl Into<String>) -> bool {
+        let term = term.into();
+        if term.is_empty()
+            || self.pinned_terms.len() >= self.max_pinned_terms
+            || self
+                .pinned_terms
+                .iter()
+                .any(|p| p.term.eq_ignore_ascii_case(&term))
+        {
+            return false;
+        }
+
+        // First palette color not taken by another pinned term
+        let palette = &tui_theme::PINNED_MATCH_BGS;
+        let color = palette
+            .iter()
+            .copied()
+            .find(|c| self.pinned_terms.iter().all(|p| p.color != *c))
+            .unwrap_or(palette[self.pinned_terms.len() % palette.len()]);
+        let style = Style::default().fg(tui_theme::PINNED_MATCH_FG).bg(color);
+        let Ok(rule) = HighlightRule::regex(format!("(?i){}", regex::escape(&term)), style) else {
+            return false;
+        };
+
+        self.pinned_terms.push(PinnedTerm {
+            term,
+            color,
+            rule: rule.matches_only(),
+        });
+        self.invalidate_rows();
+        self.recalculate_status();
+        self.request_redraw();
+        true
+    }
+
+    /// Remove the pinned term at `index` (in pinning order).
+    pub fn unpin_term(&mut self, index: usize) -> Option<String> {
+        if index >= self.pinned_terms.len() {
+            return None;
+        }
+        let pinned = self.pinned_terms.remove(index);
+        self.invalidate_rows();
+        self.recalculate_status();
+        self.request_redraw();
+        Some(pinned.term)
+    }
+
+    pub fn clear_pinned_terms(&mut self) {
+        if !self.pinned_terms.is_empty() {
+            self.pinned_terms.clear();
+            self.invalidate_rows();
+            self.recalculate_status();
+            self.request_redraw();
+        }
+    }
+
+    /// Pinned terms and their highlight colors, in pinning order.
+    pub fn pinned_terms(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.pinned_terms.iter().map(|p| (p.term.as_str(), p.color))
+    }
+
+    /// Colorize lines added with [`add_plain_line`](Self::add_plain_line).
+    pub fn highlighter(mut self, highlighter: impl SyntaxHighlighter + 'static) -> Self {
+        self.highlighter = Some(Box::new(highlighter));
+        self
+    }
+
+    /// Replace the highlighter. Lines already in the buffer keep their
+    /// colors.
+    pub fn set_highlighter(&mut self, highlighter: Option<Box<dyn SyntaxHighlighter>>) {
+        self.highlighter = highlighter;
+    }
+
+    pub fn has_highlighter(&self) -> bool {
+        self.highlighter.is_some()
+    }
+
+    pub fn set_borders(&mut self, borders: Borders) {
+        self.borders = borders;
+        self.request_redraw();
+    }
+
+    /// Lines kept before the oldest are dropped.
+    pub fn capacity(&self) -> usize {
+        self.line_capacity
+    }
+
+    /// Change how many lines are kept, dropping the oldest ones (and any
+    /// held back while paused) that no longer fit.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        let capacity = capacity.max(1);
+        if self.line_capacity == capacity {
+            return;
+        }
+        self.line_capacity = capacity;
+        let excess = self.pending.len().saturating_sub(capacity);
+        self.pending.drain(..excess);
+
+        let lines_removed = self.buffer.len().saturating_sub(capacity);
+        if lines_removed == 0 {
+            return;
+        }
+        self.buffer.drain(..lines_removed);
+        self.update_selection_after_buffer_change(lines_removed);
+        self.update_search_scope_after_buffer_change(lines_removed);
+        self.trim_wrapped_lines(lines_removed);
+
+        self.update_search_highlights();
+        self.invalidate_after_buffer_change();
+        self.recalculate_status();
+    }
+
+    /// Force the widget to be considered dirty.
+    pub fn redraw(&mut self) {
+        self.invalidate_rows();
+        self.request_redraw();
+    }
+
+    /// Choose where wrapped lines may break, and the marker shown where a
+    /// long token was split; see [`WrapPolicy`].
+    pub fn set_wrap_policy(&mut self, policy: WrapPolicy) {
+        if self.wrap_policy != policy {
+            self.wrap_policy = policy;
+            self.wrapped_lines_width = 0;
+            self.filter_dirty = true;
+            self.invalidate_rows();
+            self.request_redraw();
+        }
+    }
+
+    pub fn get_wrap_policy(&self) -> &WrapPolicy {
+        &self.wrap_policy
+    }
+
+    pub fn set_zebra_stripes(&mut self, style: Option<Style>) {
+        if self.zebra_style != style {
+            self.zebra_style = style;
+            self.request_redraw();
+        }
+    }
+
+    pub fn set_scroll_markers(&mut self, show: bool) {
+        if self.show_markers != show {
+            self.show_markers = show;
+            self.request_redraw();
+        }
+    }
+
+    pub fn set_focused_row_style(&mut self, style: Option<Style>) {
+        if self.focused_row_style != style {
+            self.focused_row_style = style;
+            self.request_redraw();
+        }
+    }
+
+    /// Mark the line at `index` as focused, for the focused row highlight.
+    /// The mark stays with the line as new lines arrive. While a keyboard
+    /// selection is being made, the line under its cursor is the focused
+    /// one instead.
+    pub fn focus_line(&mut self, index: usize) {
+        let id = self.line_id(index);
+        if id.is_some() && self.focused_line != id {
+            self.focused_line = id;
+            self.request_redraw();
+        }
+    }
+
+    pub fn clear_focused_line(&mut self) {
+        if self.focused_line.take().is_some() {
+            self.request_redraw();
+        }
+    }
+
+    /// Index of the focused line, if it is still buffered.
+    pub fn focused_line(&self) -> Option<usize> {
+        self.keyboard_cursor()
+            .map(|(line, _)| line)
+            .or_else(|| self.focused_line.and_then(|id| self.line_index(id)))
+    }
+
+    // Background for every row of `line_idx`, worked out at render time so
+    // the buffered styles stay untouched.
+    fn row_style(&self, line_idx: usize) -> Option<Style> {
+        let striped = self
+            .zebra_style
+            .filter(|_| !(self.wrap_base + line_idx).is_multiple_of(2));
+        let focused = self
+            .focused_row_style
+            .filter(|_| self.focused_line() == Some(line_idx));
+        match (striped, focused) {
+            (Some(stripe), Some(focus)) => Some(stripe.patch(focus)),
+            (stripe, focus) => focus.or(stripe),
+        }
+    }
+
+    /// Adjust spaces inserted at the beginning of wrapped continuation lines.
+    pub fn set_wrap_indent(&mut self, wrap_indent: usize) {
+        if self.wrap_indent != wrap_indent {
+            self.wrap_indent = wrap_indent;
+            self.wrapped_lines_width = 0;
+            self.request_redraw();
+        }
     }
 
     /// Toggle line wrapping on/off.
@@ -1357,11 +2295,125 @@ impl ScrollbackWidget {
             self.wrap_lines = wrap_lines;
             self.set_vertical_offset(self.vertical_offset.min(self.max_scroll_position()));
             self.wrapped_lines_width = 0;
+            self.filter_dirty = true;
             self.request_redraw();
             self.recalculate_status();
         }
     }
 
+    /// Show only matching lines plus the given context whenever a search
+    /// term is entered. Context flags typed into the query (`-A`, `-B`,
+    /// `-C`) take precedence for that search.
+    pub fn filter_context(mut self, context: FilterContext) -> Self {
+        self.filter_context = Some(context);
+        self
+    }
+
+    pub fn set_filter_context(&mut self, context: Option<FilterContext>) {
+        if self.filter_context != context {
+            let was_filtered = self.active_filter().is_some();
+            self.filter_context = context;
+            self.filter_dirty = true;
+            if was_filtered != self.active_filter().is_some() {
+                self.reset_view_offset();
+            }
+            self.invalidate_rows();
+            self.recalculate_status();
+            self.request_redraw();
+        }
+    }
+
+    /// Snapshot of the scroll, wrap, search and bookmark state.
+    pub fn save_state(&self) -> ViewState {
+        ViewState {
+            vertical_offset: self.vertical_offset,
+            horizontal_offset: self.horizontal_offset,
+            auto_scroll: self.auto_scroll,
+            wrap_lines: self.wrap_lines,
+            wrap_indent: self.wrap_indent,
+            show_line_numbers: self.show_line_numbers,
+            search_query: self.search_query.clone(),
+            search_open: self.search_mode.is_active(),
+            current_match: self.current_match,
+            pinned_terms: self.pinned_terms.iter().map(|p| p.term.clone()).collect(),
+            filter_context: self.filter_context,
+            bookmarks: self
+                .markers
+                .iter()
+                .filter(|(_, bits)| *bits & MarkerKind::Bookmark.bit() != 0)
+                .filter_map(|(id, _)| self.line_index(*id))
+                .collect(),
+        }
+    }
+
+    /// Apply a state from [`save_state`](Self::save_state). The search is
+    /// rerun against the current contents.
+    pub fn restore_state(&mut self, state: &ViewState) {
+        self.set_wrap_lines(state.wrap_lines);
+        self.set_wrap_indent(state.wrap_indent);
+        self.show_line_numbers = state.show_line_numbers;
+        self.set_filter_context(state.filter_context);
+
+        self.clear_pinned_terms();
+        for term in &state.pinned_terms {
+            self.pin_term(term.as_str());
+        }
+
+        self.clear_markers(Some(MarkerKind::Bookmark));
+        for index in &state.bookmarks {
+            self.mark_line(*index, MarkerKind::Bookmark);
+        }
+
+        if state.search_open || !state.search_query.is_empty() {
+            self.search_input.set_text(&state.search_query);
+            self.search_mode = SearchMode::Open;
+            self.search_input.unfocus();
+            self.update_search_term();
+            if state.current_match < self.search_matches.len() {
+                self.current_match = state.current_match;
+            }
+            if !state.search_open {
+                self.close_search();
+            }
+        } else {
+            self.clear_search();
+        }
+
+        // Rows may not be wrapped for the current width yet, so offsets are
+        // only clamped to the content, not the viewport
+        self.auto_scroll = state.auto_scroll;
+        if state.auto_scroll {
+            self.check_and_auto_scroll();
+        } else {
+            let rows = self.line_count().max(self.buffer.len());
+            self.vertical_offset = state.vertical_offset.min(rows.saturating_sub(1));
+        }
+        self.horizontal_offset = state.horizontal_offset.min(self.max_line_width);
+
+        self.invalidate_rows();
+        self.redraw_search_status();
+        self.recalculate_status();
+        self.request_redraw();
+    }
+
+    // Context to filter with, if matches are currently being filtered.
+    fn active_filter(&self) -> Option<FilterContext> {
+        if self.search_mode.is_closed() || self.search_term.is_empty() {
+            return None;
+        }
+        self.query_context.or(self.filter_context)
+    }
+
+    // Row offsets mean different things in the filtered and the full view,
+    // so switching between them starts over at the bottom (or top).
+    fn reset_view_offset(&mut self) {
+        if self.auto_scroll {
+            self.set_vertical_offset(self.max_scroll_position());
+        } else {
+            self.set_vertical_offset(self.vertical_offset.min(self.max_scroll_position()));
+        }
+    }
+
     /* ******************************************************************
      * Convenience helpers
      * *****************************************************************/
@@ -1379,9 +2431,16 @@ impl ScrollbackWidget {
         self.redraw_requested = true;
     }
 
+    // Drop every cached row, e.g. when search highlighting changes globally.
+    fn invalidate_rows(&mut self) {
+        self.row_epoch = self.row_epoch.wrapping_add(1);
+    }
+
     fn recalculate_status(&mut self) {
+        let locale = Locale::current();
         let mut parts = vec![];
         let mut _lines_from_bottom = String::new();
+        let mut _filter = String::new();
         if self.wrap_lines {
             parts.push("Wrap");
         } else {
@@ -1390,16 +2449,27 @@ impl ScrollbackWidget {
 
         if self.search_mode.is_active() {
             if self.search_term.is_empty() {
-                parts.push("Search");
+                parts.push(locale.search.as_str());
             } else {
                 parts.push("Filtering");
             }
+            if self.search_scope.is_some() {
+                parts.push("In Selection");
+            }
+            if let Some(ctx) = self.active_filter() {
+                _filter = format!("Grep {ctx}");
+                parts.push(&_filter);
+            }
         }
 
         if self.selection.is_active() {
             parts.push("Select");
         }
 
+        if self.paused {
+            parts.push("Paused");
+        }
+
         if self.auto_scroll {
             parts.push("Auto");
         } else {
@@ -1438,19 +2508,124 @@ impl ScrollbackWidget {
         }
     }
 
+    /// Append a raw ANSI line. Parsing into styled characters is deferred
+    /// until the line is rendered, searched or copied.
     pub fn add_ansi_line(&mut self, entry: impl AsRef<str>) {
-        self.add_styled_line(parse_ansi_string(entry));
+        self.push_line(ScrollLine::from_ansi(entry));
     }
 
     pub fn add_ansi_lines<T: AsRef<str>>(&mut self, entries: impl IntoEitherIter<T>) {
-        let entries = entries.into_either_iter();
-        let parsed: Vec<_> = entries.map(parse_ansi_string).collect();
-        if !parsed.is_empty() {
-            self.add_styled_lines(parsed);
-        }
+        let lines: Vec<_> = entries
+            .into_either_iter()
+            .map(ScrollLine::from_ansi)
+            .collect();
+        self.push_lines(lines);
     }
 
     pub fn add_styled_line(&mut self, line: StyledText) {
+        self.push_line(ScrollLine::from_styled(line));
+    }
+
+    pub fn add_styled_lines<I: Into<StyledText>>(&mut self, items: impl IntoEitherIter<I>) {
+        let lines: Vec<_> = items
+            .into_either_iter()
+            .map(|item| ScrollLine::from_styled(item.into()))
+            .collect();
+        self.push_lines(lines);
+    }
+
+    /// Replace the line at `index` in place, e.g. to advance a progress line.
+    ///
+    /// Returns the changed column ranges (see [`diff_styled`]); only rows
+    /// showing this line are laid out again, the rest are reused from the
+    /// row cache. Out-of-range indices are ignored.
+    pub fn update_line(&mut self, index: usize, text: StyledText) -> Vec<Range<usize>> {
+        let Some(line) = self.buffer.get(index) else {
+            return Vec::new();
+        };
+        let changed = diff_styled(line.chars(), &text.chars);
+        if changed.is_empty() {
+            return changed;
+        }
+
+        let line_id = self.wrap_base + index;
+        // Any change to the text, not just to its length, can move where
+        // the line breaks; a change of style alone can't
+        let rewrap = line.len() != text.len()
+            || line
+                .chars()
+                .iter()
+                .zip(&text.chars)
+                .any(|(old, new)| old.ch != new.ch);
+        self.update_max_width(text.len());
+        self.buffer[index] = ScrollLine::from_styled(text);
+
+        // Rewrap the line and everything after it.
+        if rewrap {
+            while self
+                .wrapped_lines
+                .back()
+                .is_some_and(|(id, _, _)| *id >= line_id)
+            {
+                self.wrapped_lines.pop_back();
+            }
+        }
+        for slot in &mut self.row_cache {
+            if slot.as_ref().is_some_and(|(key, _)| key.line_id == line_id) {
+                *slot = None;
+            }
+        }
+
+        self.filter_dirty = true;
+        self.update_search_highlights();
+        self.invalidate_after_buffer_change();
+        changed
+    }
+
+    /// Replace the newest line. While paused this updates the newest pending
+    /// line instead, if there is one.
+    pub fn update_last_line(&mut self, text: StyledText) -> Vec<Range<usize>> {
+        if self.paused
+            && let Some(last) = self.pending.back_mut()
+        {
+            *last = ScrollLine::from_styled(text);
+            return Vec::new();
+        }
+        match self.buffer.len() {
+            0 => Vec::new(),
+            len => self.update_line(len - 1, text),
+        }
+    }
+
+    /// Append a plain-text line, colorized by the highlighter if one is set.
+    pub fn add_plain_line(&mut self, line: impl AsRef<str>) {
+        let line = self.plain_line(line.as_ref());
+        self.push_line(line);
+    }
+
+    pub fn add_plain_lines<T: AsRef<str>>(&mut self, entries: impl IntoEitherIter<T>) {
+        let lines: Vec<_> = entries
+            .into_either_iter()
+            .map(|entry| self.plain_line(entry.as_ref()))
+            .collect();
+        self.push_lines(lines);
+    }
+
+    // Highlighting happens on append, in arrival order, so stateful
+    // highlighters see every line exactly once.
+    fn plain_line(&mut self, line: &str) -> ScrollLine {
+        match &mut self.highlighter {
+            Some(highlighter) => ScrollLine::from_styled(highlighter.highlight_line(line)),
+            None => ScrollLine::from_styled(StyledText::unstyled(line)),
+        }
+    }
+
+    fn push_line(&mut self, line: ScrollLine) {
+        if self.paused {
+            self.queue_pending(std::iter::once(line));
+            return;
+        }
+
         let lines_removed = if self.buffer.len() >= self.line_capacity {
             1
         } else {
@@ -1459,74 +2634,133 @@ impl ScrollbackWidget {
 
         if self.buffer.len() >= self.line_capacity {
             self.buffer.pop_front();
-            self.lengths.pop_front();
         }
 
         self.update_max_width(line.len());
-        self.lengths.push_back(line.len());
-        self.buffer.push_back(line.chars);
+        self.push_back_line(line, lines_removed);
 
         // Update selection after buffer change
         self.update_selection_after_buffer_change(lines_removed);
+        self.update_search_scope_after_buffer_change(lines_removed);
+        self.trim_wrapped_lines(lines_removed);
 
         self.update_search_highlights();
         self.invalidate_after_buffer_change();
         self.recalculate_status();
     }
 
-    pub fn add_styled_lines<I: Into<StyledText>>(&mut self, items: impl IntoEitherIter<I>) {
-        // Collect into Vec since we need to know length and potentially skip items
-        let parsed: Vec<I> = items.into_either_iter().collect();
-
-        if parsed.is_empty() {
+    fn push_lines(&mut self, lines: Vec<ScrollLine>) {
+        if lines.is_empty() {
+            return;
+        }
+        if self.paused {
+            self.queue_pending(lines);
             return;
         }
 
         let lines_removed;
 
         // Case 1: If incoming lines alone exceed capacity, take only the last N lines
-        if parsed.len() >= self.line_capacity {
+        if lines.len() >= self.line_capacity {
             // Clear existing buffer since we're replacing everything
             lines_removed = self.buffer.len(); // All existing lines are removed
             self.buffer.clear();
-            self.lengths.clear();
 
             // Take only the last line_capacity lines from the new data
-            let start_index = parsed.len() - self.line_capacity;
-            for entry in parsed.into_iter().skip(start_index) {
-                let entry: StyledText = entry.into();
-                self.update_max_width(entry.len());
-                self.lengths.push_back(entry.len());
-                self.buffer.push_back(entry.chars);
+            let start_index = lines.len() - self.line_capacity;
+            for line in lines.into_iter().skip(start_index) {
+                self.update_max_width(line.len());
+                self.push_back_line(line, lines_removed);
             }
         } else {
             // Case 2: Adding to existing buffer - remove old lines if we'd exceed capacity
-            let total_after_adding = self.buffer.len() + parsed.len();
+            let total_after_adding = self.buffer.len() + lines.len();
             lines_removed = total_after_adding.saturating_sub(self.line_capacity);
 
             // Remove old lines from the front
             for _ in 0..lines_removed {
                 self.buffer.pop_front();
-                self.lengths.pop_front();
             }
 
             // Add all new lines
-            for entry in parsed {
-                let entry: StyledText = entry.into();
-                self.update_max_width(entry.len());
-                self.lengths.push_back(entry.len());
-                self.buffer.push_back(entry.chars);
+            for line in lines {
+                self.update_max_width(line.len());
+                self.push_back_line(line, lines_removed);
             }
         }
 
         // Update selection after buffer change
         self.update_selection_after_buffer_change(lines_removed);
+        self.update_search_scope_after_buffer_change(lines_removed);
+        self.trim_wrapped_lines(lines_removed);
 
         self.update_search_highlights();
         self.invalidate_after_buffer_change();
         self.recalculate_status();
     }
 
+    // Append to the buffer, recording the line's marker under the id it will
+    // have once `lines_removed` trimmed lines are accounted for.
+    fn push_back_line(&mut self, mut line: ScrollLine, lines_removed: usize) {
+        if let Some(kind) = line.take_marker() {
+            let id = self.wrap_base + lines_removed + self.buffer.len();
+            *self.markers.entry(id).or_default() |= kind.bit();
+        }
+        self.buffer.push_back(line);
+    }
+
+    // Hold lines back while paused. Only the newest `line_capacity` are kept,
+    // since older ones would be trimmed on resume anyway.
+    fn queue_pending(&mut self, lines: impl IntoIterator<Item = ScrollLine>) {
+        for line in lines {
+            if self.pending.len() >= self.line_capacity {
+                self.pending.pop_front();
+            }
+            self.pending.push_back(line);
+            self.pending_count += 1;
+        }
+        self.request_redraw();
+    }
+
+    /// Stop appending new lines to the visible buffer. Incoming lines are
+    /// queued until [`resume`](Self::resume).
+    pub fn pause(&mut self) {
+        if !self.paused {
+            self.paused = true;
+            self.recalculate_status();
+            self.request_redraw();
+        }
+    }
+
+    /// Resume ingestion, flushing every line queued while paused.
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.paused = false;
+            self.pending_count = 0;
+            let pending: Vec<_> = self.pending.drain(..).collect();
+            self.push_lines(pending);
+            self.recalculate_status();
+            self.request_redraw();
+        }
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.pause();
+        } else {
+            self.resume();
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Number of lines received since the widget was paused.
+    pub fn pending_lines(&self) -> usize {
+        self.pending_count
+    }
+
     fn update_selection_after_buffer_change(&mut self, lines_removed: usize) {
         if !self.selection.is_active() || lines_removed == 0 {
             return;
@@ -1584,19 +2818,85 @@ impl ScrollbackWidget {
         }
     }
 
+    // Drop wrap rows belonging to lines trimmed from the front of the buffer.
+    // Rows are keyed by line id, so the remaining rows stay valid as-is.
+    fn trim_wrapped_lines(&mut self, lines_removed: usize) {
+        if lines_removed == 0 {
+            return;
+        }
+        self.wrap_base += lines_removed;
+        while let Some(entry) = self.markers.first_entry()
+            && *entry.key() < self.wrap_base
+        {
+            entry.remove();
+        }
+        while self
+            .wrapped_lines
+            .front()
+            .is_some_and(|(id, _, _)| *id < self.wrap_base)
+        {
+            self.wrapped_lines.pop_front();
+        }
+    }
+
+    fn update_search_scope_after_buffer_change(&mut self, lines_removed: usize) {
+        if lines_removed == 0 {
+            return;
+        }
+        let Some((start, end)) = self.search_scope.as_mut() else {
+            return;
+        };
+
+        if end.line < lines_removed {
+            // The whole scope was trimmed; keep an empty scope so the search
+            // doesn't silently widen to the entire buffer.
+            self.row_epoch = self.row_epoch.wrapping_add(1);
+            *start = SelectionStart {
+                line: 0,
+                char_idx: 0,
+            };
+            *end = SelectionEnd {
+                line: 0,
+                char_idx: 0,
+            };
+        } else {
+            if start.line < lines_removed {
+                *start = SelectionStart {
+                    line: 0,
+                    char_idx: 0,
+                };
+            } else {
+                start.line -= lines_removed;
+            }
+            end.line -= lines_removed;
+        }
+    }
+
     /// Remove all content and reset scrolling state.
     pub fn clear(&mut self) {
+        // Line ids keep counting up, so ids from before the clear stay unique
+        self.wrap_base += self.buffer.len();
         self.buffer.clear();
-        self.lengths.clear();
+        self.markers.clear();
         self.wrapped_lines.clear();
         self.wrapped_lines_width = 0;
         self.max_line_width = 0;
         self.vertical_offset = 0;
         self.horizontal_offset = 0;
         self.set_auto_scroll(true);
+        self.search_query.clear();
         self.search_term.clear();
         self.search_matches.clear();
         self.current_match = 0;
+        self.search_scope = None;
+        self.query_context = None;
+        self.filter_rows.clear();
+        self.pending.clear();
+        self.pending_count = 0;
+        if let Some(highlighter) = &mut self.highlighter {
+            highlighter.reset();
+        }
+        self.invalidate_rows();
 
         // Clear selection when buffer is cleared
         self.selection.clear();
@@ -1616,12 +2916,30 @@ impl ScrollbackWidget {
      * Search helpers
      * *****************************************************************/
     fn open_search(&mut self) {
-        self.search_input.set_text(&self.search_term);
+        self.search_scope = None;
+        self.search_input.set_text(&self.search_query);
+        self.focus_search();
+        self.invalidate_rows();
+        self.request_redraw();
+    }
+
+    /// Open search restricted to the current selection, or a regular search
+    /// when nothing is selected.
+    fn open_search_in_selection(&mut self) {
+        if !self.selection.is_active() {
+            self.open_search();
+            return;
+        }
+        self.search_scope = Some(self.selection.normalize());
+        self.search_input.set_text(&self.search_query);
         self.focus_search();
+        self.update_search_term();
+        self.invalidate_rows();
         self.request_redraw();
     }
 
     fn focus_search(&mut self) {
+        self.search_input.set_hint(&Locale::current().search_hint);
         self.search_mode = SearchMode::Input;
         self.search_input.focus();
         self.recalculate_status();
@@ -1638,20 +2956,39 @@ impl ScrollbackWidget {
     }
 
     fn close_search(&mut self) {
+        let was_filtered = self.active_filter().is_some();
         self.search_mode = SearchMode::Closed;
+        self.search_scope = None;
         self.search_input.clear_and_unfocus();
+        self.invalidate_rows();
+        if was_filtered {
+            self.reset_view_offset();
+        }
         self.recalculate_status();
         self.request_redraw();
         self.request_redraw();
     }
 
     fn clear_search(&mut self) {
+        self.search_query.clear();
         self.search_term.clear();
+        self.query_context = None;
         self.search_matches.clear();
         self.current_match = 0;
         self.close_search();
     }
 
+    // Pin the current search term and clear the input for the next one.
+    fn pin_search_term(&mut self) -> bool {
+        let term = self.search_term.clone();
+        if !self.pin_term(term) {
+            return false;
+        }
+        self.search_input.set_text("");
+        self.update_search_term();
+        true
+    }
+
     fn update_search_highlights(&mut self) {
         if self.search_mode.is_active() && !self.search_term.is_empty() {
             self.find_all_matches();
@@ -1664,6 +3001,8 @@ impl ScrollbackWidget {
             let text = if self.search_matches.is_empty() {
                 if self.search_term.is_empty() {
                     "".to_string()
+                } else if self.search_scope.is_some() {
+                    "[no matches in selection]".into()
                 } else {
                     "[no matches]".into()
                 }
@@ -1674,7 +3013,16 @@ impl ScrollbackWidget {
                 } else {
                     format!("{}", self.current_match + 1)
                 };
-                format!("[{current}/{total}] ")
+                if self.search_scope.is_some() {
+                    let noun = if total == 1 {
+                        "occurrence"
+                    } else {
+                        "occurrences"
+                    };
+                    format!("[{current}/{total}] {total} {noun} in selection ")
+                } else {
+                    format!("[{current}/{total}] ")
+                }
             };
             self.search_input.set_tl_text(text);
         } else {
@@ -1684,7 +3032,16 @@ impl ScrollbackWidget {
     }
 
     fn update_search_term(&mut self) {
-        self.search_term = self.search_input.text().to_string();
+        let was_filtered = self.active_filter().is_some();
+        self.search_query = self.search_input.text().to_string();
+        let (context, term) = FilterContext::parse_query(&self.search_query);
+        self.query_context = context;
+        self.search_term = term.to_string();
+        self.invalidate_rows();
+        self.filter_dirty = true;
+        if was_filtered && self.active_filter().is_none() {
+            self.reset_view_offset();
+        }
         if self.search_term.is_empty() {
             self.search_matches.clear();
             self.current_match = 0;
@@ -1696,24 +3053,58 @@ impl ScrollbackWidget {
             }
         }
         self.redraw_search_status();
+        self.recalculate_status();
     }
 
     fn find_all_matches(&mut self) {
-        self.search_matches.clear();
+        self.search_matches = self.collect_matches(&self.search_term, self.search_scope);
+        self.filter_dirty = true;
+        self.request_redraw();
+    }
 
-        for (idx, line) in self.buffer.iter().enumerate() {
-            let plain: String = line.iter().map(|sc| sc.ch).collect();
+    // Case-insensitive matches of `term`, optionally limited to those lying
+    // entirely within `scope`.
+    fn collect_matches(
+        &self,
+        term: &str,
+        scope: Option<(SelectionStart, SelectionEnd)>,
+    ) -> Vec<(usize, usize)> {
+        let mut matches = Vec::new();
+        if term.is_empty() {
+            return matches;
+        }
+        let term = term.to_lowercase();
+
+        let (first, last) = scope.map_or((0, usize::MAX), |(s, e)| (s.line, e.line));
+
+        for (idx, line) in self.buffer.iter().enumerate().skip(first) {
+            if idx > last {
+                break;
+            }
+            let plain: String = line.chars().iter().map(|sc| sc.ch).collect();
             let mut start = 0;
-            while let Some(pos) = plain[start..]
-                .to_lowercase()
-                .find(&self.search_term.to_lowercase())
-            {
+            while let Some(pos) = plain[start..].to_lowercase().find(&term) {
                 let abs = start + pos;
-                self.search_matches.push((idx, abs));
+                if Self::match_in_scope(scope, idx, abs, term.len()) {
+                    matches.push((idx, abs));
+                }
                 start = abs + 1;
             }
         }
-        self.request_redraw();
+        matches
+    }
+
+    #[inline]
+    fn match_in_scope(
+        scope: Option<(SelectionStart, SelectionEnd)>,
+        line_idx: usize,
+        start: usize,
+        len: usize,
+    ) -> bool {
+        scope.is_none_or(|(s, e)| {
+            (line_idx > s.line || start >= s.char_idx)
+                && (line_idx < e.line || start + len <= e.char_idx)
+        })
     }
 
     fn jump_to_current_match(&mut self) {
@@ -1723,7 +3114,18 @@ impl ScrollbackWidget {
 
         let (line_idx, _) = self.search_matches[self.current_match];
 
-        if self.wrap_lines {
+        if self.active_filter().is_some() {
+            if self.filter_dirty {
+                self.rebuild_filter_rows();
+            }
+            let line_id = self.wrap_base + line_idx;
+            let row = self
+                .filter_rows
+                .iter()
+                .position(|row| row.is_some_and(|(id, _, _)| id == line_id))
+                .unwrap_or(0);
+            self.scroll_vertically_to(row);
+        } else if self.wrap_lines {
             // translate to wrapped index
             let mut wrapped = 0;
             for i in 0..line_idx {
@@ -1731,15 +3133,51 @@ impl ScrollbackWidget {
                 let segs = len.div_ceil(self.inner_width);
                 wrapped += segs;
             }
-            self.set_vertical_offset(wrapped);
+            self.scroll_vertically_to(wrapped);
         } else {
-            self.set_vertical_offset(line_idx);
+            self.scroll_vertically_to(line_idx);
+        }
+
+        // Clipped lines also need the match column brought into view
+        if !self.wrap_lines {
+            let (_, match_start) = self.search_matches[self.current_match];
+            self.reveal_columns(match_start, self.search_term.chars().count());
         }
 
         self.auto_scroll = false;
         self.request_redraw();
     }
 
+    // Scroll horizontally so columns `start..start + len` are visible, with
+    // some margin, when they aren't already.
+    fn reveal_columns(&mut self, start: usize, len: usize) {
+        let width = self.clipped_content_width();
+        if width == 0 {
+            return;
+        }
+        let visible_start = self.horizontal_offset;
+        let visible_end = visible_start + width;
+        if start >= visible_start && start + len <= visible_end {
+            return;
+        }
+
+        let margin = width / 4;
+        let new_offset = if len + margin >= width {
+            start
+        } else {
+            start.saturating_sub(margin)
+        };
+        self.horizontal_offset = new_offset.min(self.max_line_width);
+        self.request_redraw();
+    }
+
+    // Width of the text area next to the line number gutter in clip mode.
+    fn clipped_content_width(&self) -> usize {
+        let ln_width = self.calculate_line_num_width(self.buffer.len() + 1);
+        let gutter = if ln_width > 0 { ln_width + 1 } else { 0 };
+        self.inner_width.saturating_sub(gutter)
+    }
+
     fn jump_to_next_match(&mut self) {
         if self.search_matches.is_empty() {
             return;
@@ -1765,7 +3203,9 @@ impl ScrollbackWidget {
      * *****************************************************************/
     #[inline]
     fn line_count(&self) -> usize {
-        if self.wrap_lines {
+        if self.active_filter().is_some() {
+            self.filter_rows.len()
+        } else if self.wrap_lines {
             self.wrapped_lines.len()
         } else {
             self.buffer.len()
@@ -1789,8 +3229,17 @@ impl ScrollbackWidget {
     }
 
     fn check_and_auto_scroll(&mut self) {
-        if self.auto_scroll {
-            self.set_vertical_offset(self.max_scroll_position());
+        if !self.auto_scroll {
+            return;
+        }
+        // A jump to the bottom still playing out follows the bottom as it
+        // moves, rather than being cut short
+        let max = self.max_scroll_position();
+        match &mut self.scroll_animation {
+            Some(animation) if animation.to_bottom => animation.to = max,
+            _ => {
+                self.set_vertical_offset(max);
+            }
         }
     }
 
@@ -1811,21 +3260,19 @@ impl ScrollbackWidget {
             .h_scrollbar
             .content_length(self.max_line_width)
             .position(self.horizontal_offset);
-
-        self.wrapped_lines_width = 0; // force re‑calc on next render
     }
 
     /* ******************************************************************
      * Public scrolling API (called from key / mouse events)
      * *****************************************************************/
     pub fn scroll_to_top(&mut self) {
-        if self.set_vertical_offset(0) {
-            self.set_auto_scroll(false);
-        }
+        // Leave auto-scroll first, as that pins the view to the bottom
+        self.set_auto_scroll(false);
+        self.scroll_vertically_to(0);
     }
 
     pub fn scroll_to_bottom(&mut self) {
-        if self.set_vertical_offset(self.max_scroll_position()) {
+        if self.scroll_vertically_to(self.max_scroll_position()) {
             self.set_auto_scroll(true);
         }
     }
@@ -1844,7 +3291,49 @@ impl ScrollbackWidget {
         self.set_vertical_offset((self.vertical_offset + offset).min(max));
     }
 
+    // Jump to `target`, animated when smooth scrolling is on. Returns
+    // whether the view will move.
+    fn scroll_vertically_to(&mut self, target: usize) -> bool {
+        let Some(duration) = self.smooth_scroll else {
+            return self.set_vertical_offset(target);
+        };
+        if duration.is_zero() || target.abs_diff(self.vertical_offset) <= 1 {
+            return self.set_vertical_offset(target);
+        }
+        self.scroll_animation = Some(ScrollAnimation {
+            from: self.vertical_offset,
+            to: target,
+            started: Instant::now(),
+            duration,
+            to_bottom: target == self.max_scroll_position(),
+        });
+        self.request_redraw();
+        true
+    }
+
+    fn advance_scroll_animation(&mut self) {
+        let Some(animation) = self.scroll_animation else {
+            return;
+        };
+        match animation.offset_at(Instant::now()) {
+            Some(offset) => {
+                self.vertical_offset = offset;
+                self.recalculate_status();
+                self.request_redraw();
+            }
+            None => self.finish_scroll_animation(),
+        }
+    }
+
+    fn finish_scroll_animation(&mut self) {
+        if let Some(animation) = self.scroll_animation.take() {
+            self.set_vertical_offset(animation.to);
+        }
+    }
+
+    // Any direct scroll cancels a running animation.
     fn set_vertical_offset(&mut self, vertical_offset: usize) -> bool {
+        self.scroll_animation = None;
         if vertical_offset != self.vertical_offset {
             self.vertical_offset = vertical_offset;
             self.recalculate_status();
@@ -1895,6 +3384,48 @@ impl ScrollbackWidget {
  * *********************************************************************/
 
 impl ScrollbackWidget {
+    // Lay out and render the whole widget into `buf`.
+    fn render_frame(&mut self, area: Rect, buf: &mut Buffer) {
+        // If the widget got resized – redraw everything.
+        if area != self.last_area {
+            Self::clear_buffer(area, buf);
+            self.last_area = area;
+        }
+
+        // Calculate inner area ( minus border – and search box space )
+        let mut inner = area.inner(Margin::new(1, 1));
+        if self.search_mode.is_active() && inner.height > 1 {
+            inner.height -= 2;
+        }
+        self.inner_width = inner.width as usize;
+        self.reduced_highlighting = FrameBudget::skip_heavy_highlighting(self.buffer.len());
+        self.drawn_degraded = FrameBudget::is_degraded();
+
+        if self.inner_height != inner.height as usize {
+            self.inner_height = inner.height as usize;
+            self.check_and_auto_scroll();
+        }
+
+        /* ---------------- frame ---------------- */
+        self.recalculate_scrollbars();
+
+        /* ---------------- lines ---------------- */
+        if self.active_filter().is_some() {
+            self.render_lines_filtered(inner, buf);
+        } else if self.wrap_lines {
+            self.render_lines_wrapped(inner, buf);
+        } else {
+            self.render_lines_clipped(inner, buf);
+        }
+
+        /* ---------------- search box ----------- */
+        self.render_search_input(area, buf);
+
+        self.render_outer_frame(inner, area, buf);
+
+        self.redraw_requested = false;
+    }
+
     /* ---- helpers to clear underlying buffer when resizing ---- */
     fn clear_buffer(area: Rect, buf: &mut Buffer) {
         for y in 0..area.height {
@@ -1959,17 +3490,52 @@ impl ScrollbackWidget {
         (start, end, line_idx): (usize, usize, usize),
         content_width: usize,
     ) {
+        let row_style = self.row_style(line_idx).unwrap_or_default();
+
         // clear line area
         for x in 0..content_width {
             if let Some(cell) = buf.cell_mut(Position::new(content_start + x as u16, y)) {
-                cell.set_char(' ').set_style(Style::default());
+                cell.set_char(' ').set_style(row_style);
+            }
+        }
+
+        // Styles from highlight rules and pinned search terms, patched over
+        // the line's own styles
+        let mut rule_styles: Vec<Option<Style>> = Vec::new();
+        if !self.highlight_rules.is_empty() || !self.pinned_terms.is_empty() {
+            let plain: String = line.iter().map(|sc| sc.ch).collect();
+            let pinned = self
+                .pinned_terms
+                .iter()
+                .filter(|_| !self.reduced_highlighting)
+                .map(|p| &p.rule);
+            for rule in self.highlight_rules.iter().chain(pinned) {
+                for range in rule.char_ranges(&plain) {
+                    if rule_styles.is_empty() {
+                        rule_styles = vec![None; line.len()];
+                    }
+                    for slot in &mut rule_styles[range] {
+                        *slot = Some(slot.unwrap_or_default().patch(rule.style()));
+                    }
+                }
             }
         }
 
+        let cursor = self
+            .keyboard_cursor()
+            .filter(|(cursor_line, _)| *cursor_line == line_idx)
+            .map(|(_, char_idx)| char_idx);
+
         // Handle selection highlighting and search highlighting
         for (x, ch) in line[start..end].iter().enumerate() {
             let absolute_char_idx = start + x;
-            let mut style = ch.style;
+            let mut style = match &self.ansi_palette {
+                Some(palette) => palette.remap_style(ch.style),
+                None => ch.style,
+            };
+            if let Some(Some(rule_style)) = rule_styles.get(absolute_char_idx) {
+                style = style.patch(*rule_style);
+            }
 
             // Check if this character is selected
             let is_selected = self
@@ -1982,6 +3548,22 @@ impl ScrollbackWidget {
                     .fg(tui_theme::SELECTED_FG)
                     .bg(tui_theme::SELECTED_BG);
             }
+            // Over the frame budget, only the current match is highlighted
+            else if self.search_mode.is_active()
+                && !self.search_term.is_empty()
+                && self.reduced_highlighting
+            {
+                if let Some(&(match_line, match_start)) =
+                    self.search_matches.get(self.current_match)
+                    && match_line == line_idx
+                    && (match_start..match_start + self.search_term.chars().count())
+                        .contains(&absolute_char_idx)
+                {
+                    style = Style::default()
+                        .fg(tui_theme::CURRENT_MATCH_COLOR)
+                        .bg(Color::DarkGray);
+                }
+            }
             // Apply search highlighting if not selected (selection takes priority)
             else if self.search_mode.is_active() && !self.search_term.is_empty() {
                 let plain: String = line.iter().map(|sc| sc.ch).collect();
@@ -1997,7 +3579,10 @@ impl ScrollbackWidget {
                     let m_start = pos + idx;
                     let m_end = m_start + s.len();
 
-                    if absolute_char_idx >= m_start && absolute_char_idx < m_end {
+                    if absolute_char_idx >= m_start
+                        && absolute_char_idx < m_end
+                        && Self::match_in_scope(self.search_scope, line_idx, m_start, s.len())
+                    {
                         is_search_match = true;
 
                         // Check if this is the current match
@@ -2028,14 +3613,40 @@ impl ScrollbackWidget {
                 }
             }
 
+            if cursor == Some(absolute_char_idx) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            style = row_style.patch(style);
+
             if let Some(cell) = buf.cell_mut(Position::new(content_start + x as u16, y)) {
                 cell.set_char(ch.ch).set_style(style);
             }
         }
+
+        // Mark where a wrapped token continues on the next row
+        if self.wrap_lines
+            && let Some(marker) = self.wrap_policy.marker_at(line, end)
+            && end - start < content_width
+        {
+            let x = content_start + (end - start) as u16;
+            if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
+                cell.set_char(marker)
+                    .set_style(row_style.fg(tui_theme::HINT_FG));
+            }
+        }
+
+        // A cursor past the end of the line sits on the following blank cell
+        if cursor == Some(line.len()) && end == line.len() && end - start < content_width {
+            let x = content_start + (end - start) as u16;
+            if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
+                cell.set_char(' ')
+                    .set_style(Style::default().add_modifier(Modifier::REVERSED));
+            }
+        }
     }
 
     /* ---- non‑wrapped render ---- */
-    fn render_lines_clipped(&self, inner: Rect, buf: &mut Buffer) {
+    fn render_lines_clipped(&mut self, inner: Rect, buf: &mut Buffer) {
         let max_h = inner.height as usize;
         let max_w = inner.width as usize;
         let total_lines = self.buffer.len();
@@ -2046,15 +3657,18 @@ impl ScrollbackWidget {
         let ln_width = self.calculate_line_num_width(total_lines + 1);
         let content_w = max_w.saturating_sub(if ln_width > 0 { ln_width + 1 } else { 0 });
 
-        for (i, line) in self
-            .buffer
-            .iter()
-            .skip(start_line)
-            .take(end_line - start_line)
-            .enumerate()
-        {
-            let idx = start_line + i;
+        self.begin_row_cache(inner);
+        for (i, idx) in (start_line..end_line).enumerate() {
             let y = inner.top() + i as u16;
+            let line = &self.buffer[idx];
+            let start_char = self.horizontal_offset.min(line.len());
+            let end_char = line.len().min(start_char + content_w);
+
+            let key = self.row_key(inner, idx, (start_char, end_char), false, ln_width);
+            if self.draw_cached_row(i, &key, buf) {
+                continue;
+            }
+
             self.render_line_numbers(buf, y, inner, idx + 1, ln_width, false);
 
             let content_start = if ln_width > 0 {
@@ -2062,16 +3676,15 @@ impl ScrollbackWidget {
             } else {
                 inner.left()
             };
-            let start_char = self.horizontal_offset.min(line.len());
-            let end_char = line.len().min(start_char + content_w);
             self.render_line_content(
                 buf,
                 y,
                 content_start,
-                line,
+                self.buffer[idx].chars(),
                 (start_char, end_char, idx),
                 content_w,
             );
+            self.store_row(i, key, buf);
         }
     }
 
@@ -2087,53 +3700,8 @@ impl ScrollbackWidget {
             return;
         }
 
-        let needs_recalc = self.wrapped_lines_width != content_w
-            || self
-                .wrapped_lines
-                .last()
-                .map(|(idx, _, _)| *idx + 1 != self.buffer.len())
-                .unwrap_or(!self.buffer.is_empty());
-
-        if needs_recalc {
-            self.wrapped_lines.clear();
-
-            for (orig_idx, line) in self.buffer.iter().enumerate() {
-                let first_w = content_w;
-                let rest_w = content_w.saturating_sub(self.wrap_indent);
-
-                if line.is_empty() {
-                    self.wrapped_lines.push((orig_idx, 0, 0));
-                    continue;
-                }
-
-                let mut pos = 0;
-                let seg_end = find_break(line, pos, first_w);
-                self.wrapped_lines.push((orig_idx, pos, seg_end));
-                pos = seg_end;
-
-                while pos < line.len() {
-                    let end = find_break(line, pos, rest_w);
-                    self.wrapped_lines.push((orig_idx, pos, end));
-                    pos = end;
-                }
-            }
-            self.wrapped_lines_width = content_w;
-            if self.auto_scroll {
-                self.set_vertical_offset(self.max_scroll_position());
-            }
-        }
-
-        fn find_break(line: &[StyledChar], start: usize, limit: usize) -> usize {
-            if start + limit >= line.len() {
-                return line.len();
-            }
-            let end = start + limit;
-            for i in (start..end).rev() {
-                if line[i].ch == ' ' {
-                    return i + 1;
-                }
-            }
-            if start == end { start + 1 } else { end }
+        if self.sync_wrapped_lines(content_w) && self.auto_scroll {
+            self.set_vertical_offset(self.max_scroll_position());
         }
 
         let total = self.wrapped_lines.len();
@@ -2142,12 +3710,19 @@ impl ScrollbackWidget {
 
         let mut prev_orig = usize::MAX;
 
+        self.begin_row_cache(inner);
         for (render_idx, wrapped_idx) in (start..end).enumerate() {
-            let (orig_idx, start_char, end_char) = self.wrapped_lines[wrapped_idx];
+            let (line_id, start_char, end_char) = self.wrapped_lines[wrapped_idx];
+            let orig_idx = line_id - self.wrap_base;
             let y = inner.top() + render_idx as u16;
             let is_first = orig_idx != prev_orig;
             prev_orig = orig_idx;
 
+            let key = self.row_key(inner, orig_idx, (start_char, end_char), !is_first, ln_width);
+            if self.draw_cached_row(render_idx, &key, buf) {
+                continue;
+            }
+
             self.render_line_numbers(buf, y, inner, orig_idx + 1, ln_width, !is_first);
 
             let mut content_start = if ln_width > 0 {
@@ -2159,7 +3734,7 @@ impl ScrollbackWidget {
                 content_start += self.wrap_indent as u16;
             }
 
-            let line = &self.buffer[orig_idx];
+            let line = self.buffer[orig_idx].chars();
             self.render_line_content(
                 buf,
                 y,
@@ -2168,6 +3743,282 @@ impl ScrollbackWidget {
                 (start_char, end_char, orig_idx),
                 content_w,
             );
+            self.store_row(render_idx, key, buf);
+        }
+    }
+
+    /* ---- filtered render ---- */
+    fn render_lines_filtered(&mut self, inner: Rect, buf: &mut Buffer) {
+        let max_h = inner.height as usize;
+        let max_w = inner.width as usize;
+
+        // Same gutter as the unfiltered view, so wrap rows can be shared
+        let numbered = self.buffer.len() + usize::from(!self.wrap_lines);
+        let ln_width = self.calculate_line_num_width(numbered);
+        let content_w = max_w.saturating_sub(if ln_width > 0 { ln_width + 1 } else { 0 });
+        if content_w == 0 {
+            return;
+        }
+
+        if self.wrap_lines && self.sync_wrapped_lines(content_w) {
+            self.filter_dirty = true;
+        }
+        if self.filter_dirty {
+            self.rebuild_filter_rows();
+            if self.auto_scroll {
+                self.set_vertical_offset(self.max_scroll_position());
+            }
+        }
+
+        let total = self.filter_rows.len();
+        let start = self.vertical_offset.min(total.saturating_sub(max_h));
+        let end = (start + max_h).min(total);
+
+        let content_start = if ln_width > 0 {
+            inner.left() + (ln_width + 1) as u16
+        } else {
+            inner.left()
+        };
+
+        self.begin_row_cache(inner);
+        for (render_idx, row_idx) in (start..end).enumerate() {
+            let y = inner.top() + render_idx as u16;
+            let Some((line_id, seg_start, seg_end)) = self.filter_rows[row_idx] else {
+                self.render_filter_separator(buf, y, inner, ln_width);
+                continue;
+            };
+            let orig_idx = line_id - self.wrap_base;
+
+            let (start_char, end_char) = if self.wrap_lines {
+                (seg_start, seg_end)
+            } else {
+                let len = self.buffer[orig_idx].len();
+                let start_char = self.horizontal_offset.min(len);
+                (start_char, len.min(start_char + content_w))
+            };
+            let continuation = self.wrap_lines && start_char != 0;
+
+            let key = self.row_key(
+                inner,
+                orig_idx,
+                (start_char, end_char),
+                continuation,
+                ln_width,
+            );
+            if self.draw_cached_row(render_idx, &key, buf) {
+                continue;
+            }
+
+            self.render_line_numbers(buf, y, inner, orig_idx + 1, ln_width, continuation);
+
+            let indent = if continuation {
+                self.wrap_indent as u16
+            } else {
+                0
+            };
+            self.render_line_content(
+                buf,
+                y,
+                content_start + indent,
+                self.buffer[orig_idx].chars(),
+                (start_char, end_char, orig_idx),
+                content_w,
+            );
+            self.store_row(render_idx, key, buf);
+        }
+    }
+
+    // `--` between non-adjacent groups of lines, as grep prints it.
+    fn render_filter_separator(&self, buf: &mut Buffer, y: u16, inner: Rect, ln_width: usize) {
+        self.render_line_numbers(buf, y, inner, 0, ln_width, true);
+        let start = if ln_width > 0 { ln_width + 1 } else { 0 };
+        for (x, ch) in "--".chars().enumerate() {
+            if let Some(cell) = buf.cell_mut(Position::new(inner.left() + (start + x) as u16, y)) {
+                cell.set_char(ch).set_style(self.line_number_style);
+            }
+        }
+    }
+
+    // Collect the rows of every matching line plus its context.
+    fn rebuild_filter_rows(&mut self) {
+        self.filter_rows.clear();
+        self.filter_dirty = false;
+        let Some(context) = self.active_filter() else {
+            return;
+        };
+
+        let matched = self.search_matches.iter().map(|(line, _)| *line).dedup();
+        let ranges = context.ranges(matched, self.buffer.len());
+
+        for (i, &(first, last)) in ranges.iter().enumerate() {
+            if i > 0 {
+                self.filter_rows.push(None);
+            }
+            if self.wrap_lines {
+                let (first_id, last_id) = (self.wrap_base + first, self.wrap_base + last);
+                let from = self
+                    .wrapped_lines
+                    .partition_point(|(id, _, _)| *id < first_id);
+                let rows = self.wrapped_lines.range(from..);
+                self.filter_rows.extend(
+                    rows.take_while(|(id, _, _)| *id <= last_id)
+                        .map(|&row| Some(row)),
+                );
+            } else {
+                self.filter_rows.extend(
+                    (first..=last)
+                        .map(|idx| Some((self.wrap_base + idx, 0, self.buffer[idx].len()))),
+                );
+            }
+        }
+    }
+
+    // Bring the wrap rows up to date for `content_w`. A full rebuild happens
+    // only when the width changed; otherwise just the lines appended since
+    // the last render are wrapped. Returns whether any rows were added.
+    fn sync_wrapped_lines(&mut self, content_w: usize) -> bool {
+        let first_unwrapped = if self.wrapped_lines_width != content_w {
+            self.wrapped_lines.clear();
+            self.wrapped_lines_width = content_w;
+            0
+        } else {
+            self.wrapped_lines
+                .back()
+                .map(|(id, _, _)| id + 1 - self.wrap_base)
+                .unwrap_or(0)
+        };
+
+        for orig_idx in first_unwrapped..self.buffer.len() {
+            self.wrap_line(orig_idx, content_w);
+        }
+        first_unwrapped < self.buffer.len()
+    }
+
+    /* ---- row cache ---- */
+    fn begin_row_cache(&mut self, inner: Rect) {
+        self.row_cache.resize_with(inner.height as usize, || None);
+        self.rows_redrawn = 0;
+    }
+
+    fn row_key(
+        &self,
+        inner: Rect,
+        line_idx: usize,
+        segment: (usize, usize),
+        continuation: bool,
+        ln_width: usize,
+    ) -> RowKey {
+        let current_match = if self.search_mode.is_active() {
+            self.search_matches
+                .get(self.current_match)
+                .filter(|(idx, _)| *idx == line_idx)
+                .map(|(_, start)| *start)
+        } else {
+            None
+        };
+
+        RowKey {
+            inner,
+            line_id: self.wrap_base + line_idx,
+            line_num: line_idx + 1,
+            segment,
+            continuation,
+            ln_width,
+            wrap_indent: self.wrap_indent,
+            selected: self.selected_span(line_idx, segment),
+            current_match,
+            cursor: self
+                .keyboard_cursor()
+                .filter(|(line, _)| *line == line_idx)
+                .map(|(_, char_idx)| char_idx),
+            row_style: self.row_style(line_idx),
+            reduced: self.reduced_highlighting,
+            epoch: self.row_epoch,
+        }
+    }
+
+    // Part of `segment` on `line_idx` covered by the selection.
+    fn selected_span(
+        &self,
+        line_idx: usize,
+        (start, end): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        if !self.selection.is_active() {
+            return None;
+        }
+        let (sel_start, sel_end) = self.selection.normalize();
+        if line_idx < sel_start.line || line_idx > sel_end.line {
+            return None;
+        }
+
+        let from = if line_idx == sel_start.line {
+            sel_start.char_idx
+        } else {
+            0
+        };
+        let to = if line_idx == sel_end.line {
+            sel_end.char_idx
+        } else {
+            usize::MAX
+        };
+
+        let (from, to) = (from.max(start), to.min(end));
+        (from < to).then_some((from, to))
+    }
+
+    // Copy a cached row into the frame if its key still matches.
+    fn draw_cached_row(&mut self, row: usize, key: &RowKey, buf: &mut Buffer) -> bool {
+        let Some(Some((cached_key, cells))) = self.row_cache.get(row) else {
+            return false;
+        };
+        if cached_key != key {
+            return false;
+        }
+
+        let y = key.inner.top() + row as u16;
+        for (x, cached) in cells.iter().enumerate() {
+            if let Some(cell) = buf.cell_mut(Position::new(key.inner.left() + x as u16, y)) {
+                *cell = cached.clone();
+            }
+        }
+        true
+    }
+
+    // Remember the cells of a freshly rendered row.
+    fn store_row(&mut self, row: usize, key: RowKey, buf: &Buffer) {
+        let y = key.inner.top() + row as u16;
+        let cells = (key.inner.left()..key.inner.right())
+            .map(|x| buf.cell(Position::new(x, y)).cloned().unwrap_or_default())
+            .collect();
+        if let Some(slot) = self.row_cache.get_mut(row) {
+            *slot = Some((key, cells));
+        }
+        self.rows_redrawn += 1;
+    }
+
+    // Append the wrap rows for a single buffer line.
+    fn wrap_line(&mut self, orig_idx: usize, content_w: usize) {
+        let line_id = self.wrap_base + orig_idx;
+        let line = &self.buffer[orig_idx];
+        let first_w = content_w;
+        let rest_w = content_w.saturating_sub(self.wrap_indent);
+
+        // Lines that fit on one row never need to be parsed here
+        if line.len() <= first_w {
+            self.wrapped_lines.push_back((line_id, 0, line.len()));
+            return;
+        }
+
+        let chars = line.chars();
+        let mut pos = 0;
+        let seg_end = self.wrap_policy.find_break(chars, pos, first_w);
+        self.wrapped_lines.push_back((line_id, pos, seg_end));
+        pos = seg_end;
+
+        while pos < chars.len() {
+            let end = self.wrap_policy.find_break(chars, pos, rest_w);
+            self.wrapped_lines.push_back((line_id, pos, end));
+            pos = end;
         }
     }
 
@@ -2194,7 +4045,13 @@ impl ScrollbackWidget {
                 self.horizontal_offset,
                 self.max_line_width
             );
-            let line_info = format!("B:{} W:{}", self.buffer.len(), self.wrapped_lines.len());
+            let parsed = self.buffer.iter().filter(|l| l.is_parsed()).count();
+            let line_info = format!(
+                "B:{} P:{parsed} W:{} R:{}",
+                self.buffer.len(),
+                self.wrapped_lines.len(),
+                self.rows_redrawn
+            );
             block = block.title_top(
                 Line::from(Span::raw(format!(
                     "{area_info}  {scroll_info}  {line_info}  {}",
@@ -2202,8 +4059,30 @@ impl ScrollbackWidget {
                 )))
                 .right_aligned(),
             );
+            if let Some(warning) = FrameBudget::warning() {
+                block = block.title_bottom(
+                    Line::from(Span::styled(
+                        warning,
+                        Style::default().fg(tui_theme::WARNING_FG),
+                    ))
+                    .left_aligned(),
+                );
+            }
         } else {
-            block = block.title_top(Line::from(Span::raw(&self.info_text)).right_aligned());
+            let mut spans = self.pinned_term_spans();
+            spans.push(Span::raw(&self.info_text));
+            block = block.title_top(Line::from(spans).right_aligned());
+        }
+
+        if self.paused {
+            let badge = format!(" paused, +{} pending ", self.pending_count);
+            block = block.title_top(
+                Line::from(Span::styled(
+                    badge,
+                    Style::default().fg(tui_theme::PAUSED_FG),
+                ))
+                .centered(),
+            );
         }
 
         block.render(area, buf);
@@ -2213,25 +4092,62 @@ impl ScrollbackWidget {
         self.render_h_scrollbar(area, buf);
     }
 
+    // Pinned terms as "1:term" badges in their highlight colors.
+    fn pinned_term_spans(&self) -> Vec<Span<'_>> {
+        let mut spans = Vec::new();
+        for (idx, pinned) in self.pinned_terms.iter().enumerate() {
+            spans.push(Span::styled(
+                format!("{}:{}", idx + 1, pinned.term),
+                Style::default()
+                    .fg(tui_theme::PINNED_MATCH_FG)
+                    .bg(pinned.color),
+            ));
+            spans.push(Span::raw(" "));
+        }
+        spans
+    }
+
     fn render_v_scrollbar(&mut self, inner: Rect, area: Rect, buf: &mut Buffer) {
         if self.line_count() > inner.height as usize {
+            let glyphs = GlyphSet::current();
+            let track_symbol = glyphs.pick(line::VERTICAL, tui_theme::V_TRACK_SYMBOL_ASCII);
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .thumb_symbol(glyphs.pick(tui_theme::V_THUMB_SYMBOL, tui_theme::THUMB_SYMBOL_ASCII))
                 .end_symbol(None)
                 .begin_symbol(None)
-                .track_symbol(Some(line::VERTICAL))
+                .track_symbol(Some(track_symbol))
                 .track_style(self.border_style)
                 .thumb_style(self.scrollbar_style)
                 .render(area.inner(Margin::new(0, 1)), buf, &mut self.v_scrollbar);
+
+            if self.show_markers {
+                let track = area.inner(Margin::new(0, 1));
+                let track = Rect {
+                    x: track.right().saturating_sub(1),
+                    width: 1,
+                    ..track
+                };
+                render_scroll_markers(
+                    track,
+                    self.line_count(),
+                    track_symbol,
+                    self.scroll_markers_in_view(),
+                    buf,
+                );
+            }
         }
     }
 
     fn render_h_scrollbar(&mut self, area: Rect, buf: &mut Buffer) {
         if !self.wrap_lines {
+            let glyphs = GlyphSet::current();
             Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
-                .thumb_symbol(tui_theme::THUMB_SYMBOL)
+                .thumb_symbol(glyphs.pick(tui_theme::THUMB_SYMBOL, tui_theme::THUMB_SYMBOL_ASCII))
                 .end_symbol(None)
                 .begin_symbol(None)
-                .track_symbol(Some(line::HORIZONTAL))
+                .track_symbol(Some(
+                    glyphs.pick(line::HORIZONTAL, tui_theme::H_TRACK_SYMBOL_ASCII),
+                ))
                 .track_style(self.border_style)
                 .thumb_style(self.scrollbar_style)
                 .render(area.inner(Margin::new(1, 0)), buf, &mut self.h_scrollbar);
@@ -2478,3 +4394,27 @@ impl ScrollbackWidget {
         y == scrollbar_y && x >= scrollbar_left && x < scrollbar_right
     }
 }
+
+impl ApplyConfig for ScrollbackWidget {
+    fn apply_config(&mut self, config: &WidgetConfig) {
+        if let Some(capacity) = config.capacity {
+            self.set_capacity(capacity);
+        }
+        if let Some(wrap) = config.wrap_lines {
+            self.set_wrap_lines(wrap);
+        }
+        if let Some(indent) = config.wrap_indent {
+            self.set_wrap_indent(indent);
+        }
+        if config.zebra_stripes.is_some() {
+            self.set_zebra_stripes(config.zebra_stripes);
+        }
+        if config.ansi_palette.is_some() {
+            self.set_ansi_palette(config.ansi_palette);
+        }
+        if config.visible.is_some() && self.visible != config.visible {
+            self.visible = config.visible;
+            self.request_redraw();
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// tokio-tui/src/widgets/scrollbox/syntax_highlighter.rs
+use ratatui::style::{Color, Modifier, Style};
+
+use super::StyledText;
+
+/// Colorizes plain-text lines as they are appended to a scrollback, e.g.
+/// compiler output, JSON or diffs.
+///
+/// Lines are passed in the order they arrive, so an implementation may keep
+/// state between calls (an open block comment, a multi-line string). Lines
+/// added as ANSI or already styled text never go through the highlighter.
+pub trait SyntaxHighlighter: Send + Sync {
+    fn highlight_line(&mut self, line: &str) -> StyledText;
+
+    /// Forget any state carried over from previous lines. Called when the
+    /// scrollback is cleared.
+    fn reset(&mut self) {}
+}
+
+impl<F> SyntaxHighlighter for F
+where
+    F: FnMut(&str) -> StyledText + Send + Sync,
+{
+    fn highlight_line(&mut self, line: &str) -> StyledText {
+        self(line)
+    }
+}
+
+/// Unified diff highlighting without any extra dependencies: added lines in
+/// green, removed lines in red, hunk headers in cyan and file headers bold.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiffHighlighter;
+
+impl SyntaxHighlighter for DiffHighlighter {
+    fn highlight_line(&mut self, line: &str) -> StyledText {
+        let style = if line.starts_with("+++")
+            || line.starts_with("---")
+            || line.starts_with("diff ")
+            || line.starts_with("index ")
+        {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else if line.starts_with("@@") {
+            Style::default().fg(Color::Cyan)
+        } else if line.starts_with('+') {
+            Style::default().fg(Color::Green)
+        } else if line.starts_with('-') {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default()
+        };
+        StyledText::from_styled(line, style)
+    }
+}
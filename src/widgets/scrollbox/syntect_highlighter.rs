@@ -0,0 +1,108 @@
+// tokio-tui/src/widgets/scrollbox/syntect_highlighter.rs
+use std::sync::OnceLock;
+
+use anyhow::{Result, anyhow};
+use ratatui::style::{Color, Modifier, Style};
+use syntect::{
+    easy::HighlightLines,
+    highlighting::{FontStyle, Theme, ThemeSet},
+    parsing::SyntaxSet,
+};
+
+use super::{StyledText, SyntaxHighlighter};
+
+pub const DEFAULT_SYNTECT_THEME: &str = "base16-ocean.dark";
+
+// Loading the bundled syntaxes and themes is slow, so it is done once and
+// shared by every highlighter.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_nonewlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+    THEMES.get_or_init(ThemeSet::load_defaults)
+}
+
+/// [`SyntaxHighlighter`] backed by syntect's bundled syntaxes and themes.
+/// Requires the `syntect` feature.
+pub struct SyntectHighlighter {
+    language: String,
+    theme: &'static Theme,
+    lines: HighlightLines<'static>,
+}
+
+impl SyntectHighlighter {
+    /// Highlight `language`, given as a syntax name (`"Rust"`, `"JSON"`) or
+    /// file extension (`"rs"`, `"json"`, `"diff"`), with the default theme.
+    pub fn new(language: &str) -> Result<Self> {
+        Self::with_theme(language, DEFAULT_SYNTECT_THEME)
+    }
+
+    /// Like [`new`](Self::new), with one of syntect's bundled themes.
+    pub fn with_theme(language: &str, theme: &str) -> Result<Self> {
+        let theme = theme_set()
+            .themes
+            .get(theme)
+            .ok_or_else(|| anyhow!("unknown syntect theme `{theme}`"))?;
+        Self::with_custom_theme(language, theme)
+    }
+
+    pub fn with_custom_theme(language: &str, theme: &'static Theme) -> Result<Self> {
+        let syntax = syntax_set()
+            .find_syntax_by_token(language)
+            .ok_or_else(|| anyhow!("no syntax found for `{language}`"))?;
+        Ok(Self {
+            language: language.to_string(),
+            theme,
+            lines: HighlightLines::new(syntax, theme),
+        })
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Names of the bundled themes.
+    pub fn themes() -> impl Iterator<Item = &'static str> {
+        theme_set().themes.keys().map(String::as_str)
+    }
+}
+
+fn convert_style(style: syntect::highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut out = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+    if style.font_style.contains(FontStyle::BOLD) {
+        out = out.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        out = out.add_modifier(Modifier::ITALIC);
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        out = out.add_modifier(Modifier::UNDERLINED);
+    }
+    out
+}
+
+impl SyntaxHighlighter for SyntectHighlighter {
+    fn highlight_line(&mut self, line: &str) -> StyledText {
+        match self.lines.highlight_line(line, syntax_set()) {
+            Ok(regions) => {
+                let mut text = StyledText::default();
+                for (style, piece) in regions {
+                    text.append(piece, convert_style(style));
+                }
+                text
+            }
+            // A parse failure leaves the line readable, just uncolored
+            Err(_) => StyledText::unstyled(line),
+        }
+    }
+
+    fn reset(&mut self) {
+        if let Ok(fresh) = Self::with_custom_theme(&self.language, self.theme) {
+            *self = fresh;
+        }
+    }
+}
@@ -12,18 +12,24 @@ use std::hash::Hash;
 use crossterm::event::KeyModifiers;
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent, MouseEvent},
-    layout::Rect,
+    crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
     style::{Color, Style},
     symbols,
     text::{Line, Span},
     widgets::{Borders, Widget as _},
 };
+use tokio::sync::watch;
 
 use crate::{
-    IntoEitherIter, OverflowMode, ScrollbackWidget, StyledText, TabsWidget, TuiWidget, tui_theme,
+    AnsiPalette, ApplyConfig, ConfigWatch, HighlightRule, IntoEitherIter, OverflowMode,
+    ScrollbackWidget, StyledText, TabsWidget, TitleTruncation, TuiWidget, WidgetConfig, tui_theme,
 };
 
+/// Lines each tab keeps unless [`TabbedScrollbox::with_capacity`] says
+/// otherwise.
+pub const DEFAULT_TAB_CAPACITY: usize = 1000;
+
 /* **********************************************************************
  * Main struct
  * *********************************************************************/
@@ -36,6 +42,7 @@ pub struct TabbedScrollbox<T: Send + Sync + Hash + Eq + Clone + Display + 'stati
 
     /* appearance */
     style: Style,
+    tab_highlight_style: Style,
     border_color: Color,
     border_style: Style,
     tab_divider: String,
@@ -44,14 +51,26 @@ pub struct TabbedScrollbox<T: Send + Sync + Hash + Eq + Clone + Display + 'stati
 
     /* options */
     overflow_mode: OverflowMode,
+    tab_max_width: Option<u16>,
+    tab_truncation: TitleTruncation,
     title: String,
     borders: Borders,
     wrap_indent: usize,
     wrap_lines: bool,
+    highlight_rules: Vec<HighlightRule>,
+    ansi_palette: Option<AnsiPalette>,
+    zebra_style: Option<Style>,
+    capacity: usize,
+
+    /* live config */
+    config_watch: Option<ConfigWatch>,
+    visible: Option<bool>,
 
     /* runtime */
     rendered_tab_titles: Vec<String>,
     titles_cache_dirty: bool,
+    tabs_area: Rect,
+    tab_hover: Option<Position>,
     redraw_requested: bool,
     is_focused: bool,
 }
@@ -64,18 +83,29 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
             tab_titles: HashMap::new(),
             selected_tab: 0,
             style: Style::default(),
+            tab_highlight_style: Style::default().fg(tui_theme::ACTIVE_FG),
             border_color: tui_theme::BORDER_DEFAULT,
             border_style: Style::default().fg(tui_theme::BORDER_DEFAULT),
             tab_divider: symbols::line::VERTICAL.to_string(),
             tab_padding_left: " ".into(),
             tab_padding_right: " ".into(),
             overflow_mode: OverflowMode::Scroll,
+            tab_max_width: None,
+            tab_truncation: TitleTruncation::default(),
             title: title.as_ref().into(),
             borders: Borders::all(),
             wrap_indent: 0,
             wrap_lines: false,
+            highlight_rules: Vec::new(),
+            ansi_palette: None,
+            zebra_style: None,
+            capacity: DEFAULT_TAB_CAPACITY,
+            config_watch: None,
+            visible: None,
             rendered_tab_titles: Vec::new(),
             titles_cache_dirty: true,
+            tabs_area: Rect::default(),
+            tab_hover: None,
             redraw_requested: true,
             is_focused: false,
         }
@@ -117,6 +147,41 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
         self.overflow_mode = mode;
         self
     }
+    pub fn tab_max_width(mut self, width: u16) -> Self {
+        self.tab_max_width = Some(width);
+        self
+    }
+    pub fn tab_truncation(mut self, truncation: TitleTruncation) -> Self {
+        self.tab_truncation = truncation;
+        self
+    }
+    pub fn with_highlight_rule(mut self, rule: HighlightRule) -> Self {
+        self.add_highlight_rule(rule);
+        self
+    }
+    pub fn with_ansi_palette(mut self, palette: AnsiPalette) -> Self {
+        self.set_ansi_palette(Some(palette));
+        self
+    }
+    pub fn with_zebra_stripes(mut self, style: Style) -> Self {
+        self.set_zebra_stripes(Some(style));
+        self
+    }
+    /// Lines each tab keeps.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.set_capacity(capacity);
+        self
+    }
+    pub fn tab_highlight_style(mut self, style: Style) -> Self {
+        self.tab_highlight_style = style;
+        self
+    }
+    /// Apply [`WidgetConfig`]s sent on `rx` to every tab as they change,
+    /// starting with the current one.
+    pub fn with_config_watch(mut self, rx: watch::Receiver<WidgetConfig>) -> Self {
+        self.watch_config(rx);
+        self
+    }
 
     /* ******************************************************************
      * Internal helpers
@@ -163,10 +228,15 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
      * Public tab/scrollbox management
      * *****************************************************************/
     pub fn add_tab(&mut self, name: impl Into<T>, title: impl AsRef<str>) -> &mut Self {
-        let mut sb = ScrollbackWidget::new("", 1000).wrap_indent(self.wrap_indent);
+        let mut sb = ScrollbackWidget::new("", self.capacity).wrap_indent(self.wrap_indent);
         sb.set_borders(self.borders);
         sb.set_wrap_indent(self.wrap_indent);
         sb.set_wrap_lines(self.wrap_lines);
+        for rule in &self.highlight_rules {
+            sb.add_highlight_rule(rule.clone());
+        }
+        sb.set_ansi_palette(self.ansi_palette);
+        sb.set_zebra_stripes(self.zebra_style);
 
         let name: T = name.into();
         if !title.as_ref().is_empty() {
@@ -230,12 +300,53 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
             sb.set_wrap_indent(indent);
         }
     }
+    /// Add a highlight rule to every tab, including tabs added later.
+    pub fn add_highlight_rule(&mut self, rule: HighlightRule) {
+        for sb in self.tabs.values_mut() {
+            sb.add_highlight_rule(rule.clone());
+        }
+        self.highlight_rules.push(rule);
+    }
+    /// Set the ANSI palette of every tab, including tabs added later.
+    pub fn set_ansi_palette(&mut self, palette: Option<AnsiPalette>) {
+        for sb in self.tabs.values_mut() {
+            sb.set_ansi_palette(palette);
+        }
+        self.ansi_palette = palette;
+    }
+    /// Stripe every tab, including tabs added later.
+    pub fn set_zebra_stripes(&mut self, style: Option<Style>) {
+        for sb in self.tabs.values_mut() {
+            sb.set_zebra_stripes(style);
+        }
+        self.zebra_style = style;
+    }
     pub fn set_all_wrap_lines(&mut self, wrap: bool) {
         self.wrap_lines = wrap;
         for sb in self.tabs.values_mut() {
             sb.set_wrap_lines(wrap);
         }
     }
+    /// Set the capacity of every tab, including tabs added later.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        for sb in self.tabs.values_mut() {
+            sb.set_capacity(capacity);
+        }
+    }
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+        self.redraw_requested = true;
+    }
+    pub fn set_tab_highlight_style(&mut self, style: Style) {
+        self.tab_highlight_style = style;
+        self.redraw_requested = true;
+    }
+    pub fn watch_config(&mut self, rx: watch::Receiver<WidgetConfig>) {
+        let mut watch = ConfigWatch::new(rx);
+        watch.apply_to(self);
+        self.config_watch = Some(watch);
+    }
 
     /* ******************************************************************
      * Content helpers for CURRENT tab
@@ -249,6 +360,12 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
     pub fn get_tab_mut(&mut self, name: &T) -> Option<&mut ScrollbackWidget> {
         self.tabs.get_mut(name)
     }
+    pub fn get_tab(&self, name: &T) -> Option<&ScrollbackWidget> {
+        self.tabs.get(name)
+    }
+    pub fn current_tab_name(&self) -> Option<&T> {
+        self.tab_order.get(self.selected_tab)
+    }
 
     pub fn add_ansi_to_tab<I: AsRef<str>>(&mut self, name: &T, entries: impl IntoEitherIter<I>) {
         if let Some(sb) = self.get_tab_mut(name) {
@@ -280,6 +397,19 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
         }
     }
 
+    /// Pause or resume ingestion on every tab.
+    pub fn set_all_paused(&mut self, paused: bool) {
+        for sb in self.tabs.values_mut() {
+            sb.set_paused(paused);
+        }
+        self.redraw_requested = true;
+    }
+
+    pub fn is_current_paused(&self) -> bool {
+        self.current_scrollbox_ref()
+            .is_some_and(|sb| sb.is_paused())
+    }
+
     pub fn clear_current_tab(&mut self) -> bool {
         if let Some(sb) = self.current_scrollbox_mut() {
             sb.clear();
@@ -294,6 +424,24 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TabbedScrollbox<T>
  * TuiWidget implementation
  * *********************************************************************/
 impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TuiWidget for TabbedScrollbox<T> {
+    fn preprocess(&mut self) {
+        if let Some(mut watch) = self.config_watch.take() {
+            watch.apply_to(self);
+            self.config_watch = Some(watch);
+        }
+        if let Some(sb) = self.current_scrollbox_mut() {
+            sb.preprocess();
+        }
+    }
+
+    fn need_visibility(&self) -> Option<bool> {
+        self.visible
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        self.current_scrollbox_ref()?.cursor_position()
+    }
+
     fn need_draw(&self) -> bool {
         self.redraw_requested
             || self
@@ -304,7 +452,7 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TuiWidget for Tabbe
     }
 
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
-        if self.tab_order.is_empty() {
+        if self.tab_order.is_empty() || self.visible == Some(false) {
             return;
         }
 
@@ -329,13 +477,24 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TuiWidget for Tabbe
 
         /* tabs */
         let tabs_area = Rect::new(area.x + 1, area.y, area.width, 1);
+        self.tabs_area = tabs_area;
         let lines: Vec<Line> = self
             .rendered_tab_titles
             .iter()
-            .map(|t| Line::from(Span::raw(t)))
+            .zip(&self.tab_order)
+            .map(|(t, name)| {
+                let mut line = Line::from(Span::raw(t));
+                if let Some(sb) = self.tabs.get(name).filter(|sb| sb.is_paused()) {
+                    line.push_span(Span::styled(
+                        format!(" +{}", sb.pending_lines()),
+                        Style::default().fg(tui_theme::PAUSED_FG),
+                    ));
+                }
+                line
+            })
             .collect();
 
-        TabsWidget::new(lines)
+        let mut tabs = TabsWidget::new(lines)
             .select(self.selected_tab)
             .divider(&self.tab_divider)
             .padding(
@@ -343,17 +502,37 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TuiWidget for Tabbe
                 self.tab_padding_right.as_str(),
             )
             .overflow_mode(self.overflow_mode)
-            .highlight_style(Style::default().fg(tui_theme::ACTIVE_FG))
-            .render(tabs_area, buf);
+            .style(self.style)
+            .highlight_style(self.tab_highlight_style)
+            .truncation(self.tab_truncation)
+            .hover(self.tab_hover);
+        tabs.set_max_tab_width(self.tab_max_width);
+        tabs.render(tabs_area, buf);
 
         self.redraw_requested = false;
     }
 
     fn mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        if mouse.kind == MouseEventKind::Moved {
+            let pos = Position::new(mouse.column, mouse.row);
+            let hover = self.tabs_area.contains(pos).then_some(pos);
+            if hover != self.tab_hover {
+                self.tab_hover = hover;
+                self.redraw_requested = true;
+            }
+            if hover.is_some() {
+                return true;
+            }
+        }
         self.current_scrollbox_mut()
             .is_some_and(|sb| sb.mouse_event(mouse))
     }
 
+    fn paste_event(&mut self, text: &str) -> bool {
+        self.current_scrollbox_mut()
+            .is_some_and(|sb| sb.paste_event(text))
+    }
+
     fn key_event(&mut self, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Tab => {
@@ -390,3 +569,33 @@ impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> TuiWidget for Tabbe
         self.is_focused
     }
 }
+
+impl<T: Send + Sync + Hash + Eq + Clone + Display + 'static> ApplyConfig for TabbedScrollbox<T> {
+    fn apply_config(&mut self, config: &WidgetConfig) {
+        if let Some(capacity) = config.capacity {
+            self.set_capacity(capacity);
+        }
+        if let Some(wrap) = config.wrap_lines {
+            self.set_all_wrap_lines(wrap);
+        }
+        if let Some(indent) = config.wrap_indent {
+            self.set_wrap_indent(indent);
+        }
+        if let Some(style) = config.style {
+            self.set_style(style);
+        }
+        if let Some(style) = config.highlight_style {
+            self.set_tab_highlight_style(style);
+        }
+        if config.zebra_stripes.is_some() {
+            self.set_zebra_stripes(config.zebra_stripes);
+        }
+        if config.ansi_palette.is_some() {
+            self.set_ansi_palette(config.ansi_palette);
+        }
+        if config.visible.is_some() {
+            self.visible = config.visible;
+        }
+        self.redraw_requested = true;
+    }
+}
@@ -0,0 +1,30 @@
+// tokio-tui/src/widgets/scrollbox/view_state.rs
+use serde::{Deserialize, Serialize};
+
+use super::FilterContext;
+
+/// Scroll, wrap, search and bookmark state of a [`ScrollbackWidget`](super::ScrollbackWidget),
+/// for persisting a view across restarts or while the widget is swapped out.
+///
+/// Offsets are in rows of the view they were saved from. They are clamped
+/// to the content on restore, but a wrapped view restored at a different
+/// width may land a few rows away.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewState {
+    pub vertical_offset: usize,
+    pub horizontal_offset: usize,
+    /// Following new output at the bottom; the offsets are ignored.
+    pub auto_scroll: bool,
+    pub wrap_lines: bool,
+    pub wrap_indent: usize,
+    pub show_line_numbers: bool,
+    /// Search input as typed, including any `-A`/`-B`/`-C` flags.
+    pub search_query: String,
+    pub search_open: bool,
+    pub current_match: usize,
+    pub pinned_terms: Vec<String>,
+    pub filter_context: Option<FilterContext>,
+    /// Indexes of the lines marked as bookmarks, as buffered when saved.
+    pub bookmarks: Vec<usize>,
+}
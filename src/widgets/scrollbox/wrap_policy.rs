@@ -0,0 +1,89 @@
+// tokio-tui/src/widgets/scrollbox/wrap_policy.rs
+use super::StyledChar;
+use crate::GlyphSet;
+
+/// Marker drawn where a wrapped token continues on the next row.
+pub const CONTINUATION_MARKER: char = '↩';
+/// [`CONTINUATION_MARKER`] for ASCII-only terminals.
+pub const CONTINUATION_MARKER_ASCII: char = '\\';
+
+/// Where wrapped lines may break when a row is too narrow for them.
+///
+/// Lines always break after a space if one fits. A token longer than the
+/// row (a URL, a base64 blob) breaks after the last of the
+/// [`break_after`](Self::break_after) characters that fits, and only at an
+/// arbitrary column when there is none. With a continuation marker set,
+/// one column of every row is kept free so the marker can show where a
+/// token was split.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WrapPolicy {
+    break_after: Vec<char>,
+    marker: Option<char>,
+}
+
+impl WrapPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Breaks suited to URLs, paths and `key=value` lists, with the
+    /// default continuation marker for the current [`GlyphSet`].
+    pub fn for_long_tokens() -> Self {
+        Self::new()
+            .break_after(['/', ',', '=', '&', '?', ';'])
+            .marker(GlyphSet::current().pick(CONTINUATION_MARKER, CONTINUATION_MARKER_ASCII))
+    }
+
+    /// Characters a long token may break after.
+    pub fn break_after(mut self, chars: impl IntoIterator<Item = char>) -> Self {
+        self.break_after = chars.into_iter().collect();
+        self
+    }
+
+    pub fn marker(mut self, marker: char) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    pub fn without_marker(mut self) -> Self {
+        self.marker = None;
+        self
+    }
+
+    pub fn break_chars(&self) -> &[char] {
+        &self.break_after
+    }
+
+    pub fn continuation_marker(&self) -> Option<char> {
+        self.marker
+    }
+
+    // End of the row starting at `start` when at most `limit` chars fit.
+    pub(crate) fn find_break(&self, line: &[StyledChar], start: usize, limit: usize) -> usize {
+        if start + limit >= line.len() {
+            return line.len();
+        }
+        // Leave room for the marker on rows that don't end the line
+        let limit = if self.marker.is_some() && limit > 1 {
+            limit - 1
+        } else {
+            limit
+        };
+        let end = start + limit;
+        let row = &line[start..end];
+        if let Some(i) = row.iter().rposition(|sc| sc.ch == ' ') {
+            return start + i + 1;
+        }
+        if let Some(i) = row.iter().rposition(|sc| self.break_after.contains(&sc.ch)) {
+            return start + i + 1;
+        }
+        if start == end { start + 1 } else { end }
+    }
+
+    // The marker to draw after a row ending at `end`, if it splits a token.
+    pub(crate) fn marker_at(&self, line: &[StyledChar], end: usize) -> Option<char> {
+        let marker = self.marker?;
+        let splits_token = end > 0 && end < line.len() && line[end - 1].ch != ' ';
+        splits_token.then_some(marker)
+    }
+}
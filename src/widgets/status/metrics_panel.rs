@@ -0,0 +1,503 @@
+// tokio-tui/src/widgets/status/metrics_panel.rs
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Sparkline, Widget as _},
+};
+use tokio::sync::mpsc;
+
+use crate::{Locale, TuiWidget, tui_theme};
+
+/// Samples kept per metric by default, enough for a wide sparkline.
+pub const DEFAULT_METRIC_HISTORY: usize = 120;
+
+/// How far a metric is from its healthy range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MetricLevel {
+    Ok,
+    Warn,
+    Critical,
+}
+
+impl MetricLevel {
+    pub fn color(self) -> Color {
+        match self {
+            MetricLevel::Ok => Color::Green,
+            MetricLevel::Warn => tui_theme::WARNING_FG,
+            MetricLevel::Critical => tui_theme::ERROR_FG,
+        }
+    }
+}
+
+/// Warning and critical limits for a metric.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    pub warn: f64,
+    pub critical: f64,
+    /// Whether values above the limits are the bad ones (e.g. CPU load), as
+    /// opposed to values below them (e.g. free disk space).
+    pub higher_is_worse: bool,
+}
+
+impl Threshold {
+    /// Values at or above `warn` and `critical` are bad.
+    pub fn above(warn: f64, critical: f64) -> Self {
+        Self {
+            warn,
+            critical,
+            higher_is_worse: true,
+        }
+    }
+
+    /// Values at or below `warn` and `critical` are bad.
+    pub fn below(warn: f64, critical: f64) -> Self {
+        Self {
+            warn,
+            critical,
+            higher_is_worse: false,
+        }
+    }
+
+    pub fn level(&self, value: f64) -> MetricLevel {
+        let past = |limit: f64| {
+            if self.higher_is_worse {
+                value >= limit
+            } else {
+                value <= limit
+            }
+        };
+        if past(self.critical) {
+            MetricLevel::Critical
+        } else if past(self.warn) {
+            MetricLevel::Warn
+        } else {
+            MetricLevel::Ok
+        }
+    }
+}
+
+/// One row of a [`MetricsPanel`]: the latest value and a sparkline of the
+/// recent ones.
+#[derive(Debug, Clone)]
+pub struct Metric {
+    name: String,
+    label: String,
+    unit: String,
+    decimals: usize,
+    threshold: Option<Threshold>,
+    history: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl Metric {
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            label: name.clone(),
+            name,
+            unit: String::new(),
+            decimals: 1,
+            threshold: None,
+            history: VecDeque::new(),
+            capacity: DEFAULT_METRIC_HISTORY,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Shown after the value, e.g. `%` or `req/s`.
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: Threshold) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Keep the last `samples` values for the sparkline.
+    pub fn with_history(mut self, samples: usize) -> Self {
+        self.capacity = samples.max(1);
+        self.trim();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn threshold(&self) -> Option<Threshold> {
+        self.threshold
+    }
+
+    pub fn set_threshold(&mut self, threshold: Option<Threshold>) {
+        self.threshold = threshold;
+    }
+
+    pub fn record(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        self.history.push_back(value);
+        self.trim();
+    }
+
+    pub fn last(&self) -> Option<f64> {
+        self.history.back().copied()
+    }
+
+    /// Recorded values, oldest first.
+    pub fn history(&self) -> impl Iterator<Item = f64> + '_ {
+        self.history.iter().copied()
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.history().reduce(f64::min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.history().reduce(f64::max)
+    }
+
+    /// Level of the latest value; `Ok` without a threshold or any value.
+    pub fn level(&self) -> MetricLevel {
+        match (self.threshold, self.last()) {
+            (Some(threshold), Some(value)) => threshold.level(value),
+            _ => MetricLevel::Ok,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    fn trim(&mut self) {
+        while self.history.len() > self.capacity {
+            self.history.pop_front();
+        }
+    }
+
+    // Latest value with its unit, or a dash before the first sample.
+    fn value_text(&self) -> String {
+        match self.last() {
+            Some(value) => {
+                let value = Locale::current().format_decimal(value, self.decimals);
+                if self.unit.is_empty() {
+                    value
+                } else {
+                    format!("{value} {}", self.unit)
+                }
+            }
+            None => "-".to_string(),
+        }
+    }
+
+    // The newest `width` samples scaled for `Sparkline`, which only takes
+    // non-negative integers. Zero stays the baseline unless values go below.
+    fn sparkline_data(&self, width: usize) -> (Vec<u64>, u64) {
+        const SCALE: f64 = 1000.0;
+        let skip = self.history.len().saturating_sub(width);
+        let low = self.min().unwrap_or(0.0).min(0.0);
+        let high = self.max().unwrap_or(0.0).max(low);
+        let span = (high - low).max(f64::EPSILON);
+        let data = self
+            .history
+            .iter()
+            .skip(skip)
+            .map(|value| ((value - low) / span * SCALE).round() as u64)
+            .collect();
+        (data, SCALE as u64)
+    }
+}
+
+/// Handle for recording metrics from other tasks; see
+/// [`MetricsPanel::sender`].
+#[derive(Debug, Clone)]
+pub struct MetricsSender {
+    tx: mpsc::UnboundedSender<(String, f64)>,
+}
+
+impl MetricsSender {
+    pub fn record(&self, metric: impl Into<String>, value: f64) {
+        let _ = self.tx.send((metric.into(), value));
+    }
+}
+
+/// A dashboard pane with one row per metric: label, latest value colored by
+/// its [`Threshold`], and a sparkline of recent values.
+///
+/// Metrics appear in the order they're added; [`record`](Self::record)ing
+/// an unknown name adds it with default settings, so a panel can be as
+/// little as `MetricsPanel::new("Ops")` plus `record` calls.
+pub struct MetricsPanel {
+    title: String,
+    metrics: Vec<Metric>,
+    index: HashMap<String, usize>,
+    borders: Borders,
+    label_width: Option<u16>,
+    tx: mpsc::UnboundedSender<(String, f64)>,
+    rx: mpsc::UnboundedReceiver<(String, f64)>,
+    needs_redraw: bool,
+    // Used instead of the app-wide locale, if set
+    locale: Option<Arc<Locale>>,
+}
+
+impl MetricsPanel {
+    pub fn new(title: impl Into<String>) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            title: title.into(),
+            metrics: Vec::new(),
+            index: HashMap::new(),
+            borders: Borders::ALL,
+            label_width: None,
+            tx,
+            rx,
+            needs_redraw: true,
+            locale: None,
+        }
+    }
+
+    pub fn with_metric(mut self, metric: Metric) -> Self {
+        self.add_metric(metric);
+        self
+    }
+
+    /// Use `locale` for this widget instead of the
+    /// app-wide one.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.set_locale(Some(locale));
+        self
+    }
+
+    pub fn set_locale(&mut self, locale: Option<Locale>) {
+        self.locale = locale.map(Arc::new);
+    }
+
+    pub fn with_borders(mut self, borders: Borders) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Width of the label column; by default it fits the longest label.
+    pub fn with_label_width(mut self, width: u16) -> Self {
+        self.label_width = Some(width);
+        self
+    }
+
+    /// Add a metric, or replace the settings of one with the same name while
+    /// keeping its history.
+    pub fn add_metric(&mut self, mut metric: Metric) {
+        match self.index.get(metric.name()) {
+            Some(&idx) => {
+                metric.history = std::mem::take(&mut self.metrics[idx].history);
+                metric.trim();
+                self.metrics[idx] = metric;
+            }
+            None => {
+                self.index.insert(metric.name.clone(), self.metrics.len());
+                self.metrics.push(metric);
+            }
+        }
+        self.needs_redraw = true;
+    }
+
+    pub fn remove_metric(&mut self, name: &str) -> Option<Metric> {
+        let idx = self.index.remove(name)?;
+        let removed = self.metrics.remove(idx);
+        for later in self.index.values_mut().filter(|later| **later > idx) {
+            *later -= 1;
+        }
+        self.needs_redraw = true;
+        Some(removed)
+    }
+
+    /// Record a value for `metric`, adding the metric if it's new.
+    pub fn record(&mut self, metric: &str, value: f64) {
+        let idx = match self.index.get(metric) {
+            Some(&idx) => idx,
+            None => {
+                self.add_metric(Metric::new(metric));
+                self.metrics.len() - 1
+            }
+        };
+        self.metrics[idx].record(value);
+        self.needs_redraw = true;
+    }
+
+    /// A handle that records into this panel from anywhere; values arrive
+    /// the next time the panel is preprocessed.
+    pub fn sender(&self) -> MetricsSender {
+        MetricsSender {
+            tx: self.tx.clone(),
+        }
+    }
+
+    /// Apply values sent through [`sender`](Self::sender) handles.
+    pub fn process_messages(&mut self) {
+        while let Ok((metric, value)) = self.rx.try_recv() {
+            self.record(&metric, value);
+        }
+    }
+
+    pub fn metric(&self, name: &str) -> Option<&Metric> {
+        self.index.get(name).map(|&idx| &self.metrics[idx])
+    }
+
+    pub fn metric_mut(&mut self, name: &str) -> Option<&mut Metric> {
+        self.needs_redraw = true;
+        self.index.get(name).map(|&idx| &mut self.metrics[idx])
+    }
+
+    pub fn metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+
+    /// The worst level across all metrics.
+    pub fn level(&self) -> MetricLevel {
+        self.metrics
+            .iter()
+            .map(Metric::level)
+            .max()
+            .unwrap_or(MetricLevel::Ok)
+    }
+
+    /// Rows needed to show every metric, borders included.
+    pub fn desired_height(&self) -> u16 {
+        let borders = u16::from(self.borders.contains(Borders::TOP))
+            + u16::from(self.borders.contains(Borders::BOTTOM));
+        self.metrics.len() as u16 + borders
+    }
+
+    fn draw_metric(
+        metric: &Metric,
+        value: String,
+        [label_width, value_width]: [u16; 2],
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let color = metric.level().color();
+        let [label_area, value_area, spark_area] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(label_width),
+                Constraint::Length(value_width),
+                Constraint::Fill(1),
+            ])
+            .spacing(1)
+            .areas(area);
+
+        Paragraph::new(metric.label())
+            .style(Style::default().fg(tui_theme::TEXT_FG))
+            .render(label_area, buf);
+        Paragraph::new(Line::from(Span::styled(
+            value,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )))
+        .right_aligned()
+        .render(value_area, buf);
+
+        let (data, max) = metric.sparkline_data(spark_area.width as usize);
+        Sparkline::default()
+            .data(&data)
+            .max(max)
+            .style(Style::default().fg(color))
+            .render(spark_area, buf);
+    }
+}
+
+impl std::fmt::Debug for MetricsPanel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsPanel")
+            .field("title", &self.title)
+            .field("metrics", &self.metrics)
+            .field("borders", &self.borders)
+            .field("label_width", &self.label_width)
+            .finish()
+    }
+}
+
+impl TuiWidget for MetricsPanel {
+    fn preprocess(&mut self) {
+        self.process_messages();
+    }
+
+    fn need_draw(&self) -> bool {
+        self.needs_redraw
+    }
+
+    fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let _locale = Locale::enter(self.locale.as_ref());
+        let block = Block::default()
+            .borders(self.borders)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(tui_theme::BORDER_DEFAULT))
+            .title(self.title.as_str());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let label_width = self.label_width.unwrap_or_else(|| {
+            self.metrics
+                .iter()
+                .map(|metric| metric.label().chars().count() as u16)
+                .max()
+                .unwrap_or(0)
+        });
+        let values: Vec<String> = self.metrics.iter().map(Metric::value_text).collect();
+        let value_width = values
+            .iter()
+            .map(|value| value.chars().count() as u16)
+            .max()
+            .unwrap_or(0);
+        let inner = Rect {
+            x: inner.x + 1,
+            width: inner.width.saturating_sub(2),
+            ..inner
+        };
+        for (row, (metric, value)) in self.metrics.iter().zip(values).enumerate() {
+            if row as u16 >= inner.height {
+                break;
+            }
+            let row_area = Rect {
+                y: inner.y + row as u16,
+                height: 1,
+                ..inner
+            };
+            Self::draw_metric(metric, value, [label_width, value_width], row_area, buf);
+        }
+        self.needs_redraw = false;
+    }
+
+    fn key_event(&mut self, _key: KeyEvent) -> bool {
+        false
+    }
+
+    fn focus(&mut self) {}
+
+    fn unfocus(&mut self) {}
+
+    fn is_focused(&self) -> bool {
+        false
+    }
+}
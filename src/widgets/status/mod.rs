@@ -10,3 +10,5 @@ mod status_update;
 pub use status_update::*;
 mod line_builder;
 pub use line_builder::*;
+mod metrics_panel;
+pub use metrics_panel::*;
@@ -8,7 +8,7 @@ use ratatui::{
     style::{Color, Style},
 };
 
-use crate::{CellRef, StatusCell, StatusCellUpdate, ToStatusCell};
+use crate::{CellRef, FrameBudget, GlyphSet, StatusCell, StatusCellUpdate, ToStatusCell};
 
 pub struct IconStatus {
     pub mode: IconMode,
@@ -37,36 +37,70 @@ const SPINNER_FRAMES: ([char; 10], f32) = (['⠋', '⠙', '⠹', '⠸', '⠼', '
 const PULSATE_FRAMES: ([char; 6], f32) = (['·', '∘', '●', '○', '●', '∘'], 2.0);
 const DOWNLOAD_FRAMES: ([char; 8], f32) = (['█', '▇', '▆', '▅', '▄', '▃', '▂', '▁'], 3.0);
 
+// Same frame counts as above, so switching glyph sets keeps the timing
+const SPINNER_FRAMES_ASCII: [char; 10] = ['|', '/', '-', '\\', '|', '/', '-', '\\', '|', '/'];
+const PULSATE_FRAMES_ASCII: [char; 6] = ['.', 'o', 'O', '0', 'O', 'o'];
+const DOWNLOAD_FRAMES_ASCII: [char; 8] = ['V', 'v', '.', ' ', ' ', '.', 'v', 'V'];
+
+// Spinner frame `elapsed` into a spin, for widgets that show one of their
+// own while busy.
+pub(crate) fn spinner_frame(elapsed: Duration) -> char {
+    let frames = GlyphSet::current().pick(&SPINNER_FRAMES.0, &SPINNER_FRAMES_ASCII);
+    frames[(elapsed.as_secs_f32() * SPINNER_FRAMES.1) as usize % frames.len()]
+}
+
 impl IconStatus {
     fn update_state(&mut self, delta: Duration, speed: &f32) {
         self.state += delta.as_secs_f32() * speed;
     }
 
     fn get_current_frame(&self) -> (char, usize) {
+        let glyphs = GlyphSet::current();
         match self.mode {
             IconMode::Spinner => {
-                let (frames, _) = &SPINNER_FRAMES;
+                let frames = glyphs.pick(&SPINNER_FRAMES.0, &SPINNER_FRAMES_ASCII);
                 let frame_idx = (self.state as usize) % frames.len();
                 (frames[frame_idx], frame_idx)
             }
             IconMode::Pulsate => {
-                let (frames, _) = &PULSATE_FRAMES;
+                let frames = glyphs.pick(&PULSATE_FRAMES.0, &PULSATE_FRAMES_ASCII);
                 let frame_idx = (self.state as usize) % frames.len();
                 (frames[frame_idx], frame_idx)
             }
             IconMode::Download => {
-                let (frames, _) = &DOWNLOAD_FRAMES;
+                let frames = glyphs.pick(&DOWNLOAD_FRAMES.0, &DOWNLOAD_FRAMES_ASCII);
                 let frame_idx = (self.state as usize) % frames.len();
                 (frames[frame_idx], frame_idx)
             }
-            IconMode::Check => ('✓', 0),
-            IconMode::Cancel => ('🚫', 0),
-            IconMode::Exclamation => ('!', 0),
-            IconMode::Question => ('?', 0),
-            IconMode::Cross => ('✗', 0),
-            IconMode::Pause => ('⏸', 0),
-            IconMode::Alert => ('⚠', 0),
-            IconMode::Wait => ('⏳', 0),
+            _ => (Self::static_icon(self.mode, glyphs), 0),
+        }
+    }
+
+    fn static_icon(mode: IconMode, glyphs: GlyphSet) -> char {
+        match (mode, glyphs) {
+            (IconMode::Exclamation, _) => '!',
+            (IconMode::Question, GlyphSet::NerdFont) => '\u{f128}',
+            (IconMode::Question, _) => '?',
+            (IconMode::Check, GlyphSet::Ascii) => '+',
+            (IconMode::Check, GlyphSet::Unicode) => '✓',
+            (IconMode::Check, GlyphSet::NerdFont) => '\u{f00c}',
+            (IconMode::Cross, GlyphSet::Ascii) => 'x',
+            (IconMode::Cross, GlyphSet::Unicode) => '✗',
+            (IconMode::Cross, GlyphSet::NerdFont) => '\u{f00d}',
+            (IconMode::Cancel, GlyphSet::Ascii) => '-',
+            (IconMode::Cancel, GlyphSet::Unicode) => '🚫',
+            (IconMode::Cancel, GlyphSet::NerdFont) => '\u{f05e}',
+            (IconMode::Pause, GlyphSet::Ascii) => '=',
+            (IconMode::Pause, GlyphSet::Unicode) => '⏸',
+            (IconMode::Pause, GlyphSet::NerdFont) => '\u{f04c}',
+            (IconMode::Alert, GlyphSet::Ascii) => '!',
+            (IconMode::Alert, GlyphSet::Unicode) => '⚠',
+            (IconMode::Alert, GlyphSet::NerdFont) => '\u{f071}',
+            (IconMode::Wait, GlyphSet::Ascii) => '~',
+            (IconMode::Wait, GlyphSet::Unicode) => '⏳',
+            (IconMode::Wait, GlyphSet::NerdFont) => '\u{f254}',
+            // Animated modes are drawn from their frame tables
+            (IconMode::Spinner | IconMode::Pulsate | IconMode::Download, _) => ' ',
         }
     }
 
@@ -96,7 +130,8 @@ impl StatusCell for IconStatus {
             None => return, // Static icon, no updates needed
         };
 
-        if self.last_update.elapsed() < frame_duration {
+        // Animations hold their current frame while the app is over budget
+        if self.last_update.elapsed() < frame_duration || FrameBudget::is_degraded() {
             return;
         }
 
@@ -10,7 +10,7 @@ use ratatui::{
     widgets::{Paragraph, Widget as _},
 };
 
-use crate::{CellRef, StatusCell, StatusCellUpdate,  ToStatusCell};
+use crate::{CellRef, GlyphSet, StatusCell, StatusCellUpdate, ToStatusCell};
 
 use super::ETAStatus;
 
@@ -128,15 +128,17 @@ impl Default for ProgressStatus {
 impl ProgressStatus {
     fn render_progress_bar(&self, area: Rect, buf: &mut Buffer) {
         let filled_width = (area.width as f64 * self.percent) as u16;
+        let glyphs = GlyphSet::current();
+        let (filled, empty) = (glyphs.pick("█", "#"), glyphs.pick("░", "-"));
         for y in area.top()..area.bottom() {
             for x in area.left()..area.left() + filled_width {
                 if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
-                    cell.set_symbol("█");
+                    cell.set_symbol(filled);
                 }
             }
             for x in area.left() + filled_width..area.right() {
                 if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
-                    cell.set_symbol("░");
+                    cell.set_symbol(empty);
                 }
             }
         }
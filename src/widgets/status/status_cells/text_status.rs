@@ -12,7 +12,7 @@ use ratatui::{
     widgets::Paragraph,
 };
 
-use crate::{CellRef, StatusCell, StatusCellUpdate, ToStatusCell};
+use crate::{CellRef, StatusCell, StatusCellUpdate, StyledText, ToStatusCell};
 
 pub struct TextStatus {
     pub text: Vec<(String, Style)>,
@@ -68,16 +68,11 @@ impl StatusCell for TextStatus {
         };
 
         let content_width = clipped_message.width();
-        let padding = if content_width < available_width {
-            " ".repeat(available_width - content_width)
-        } else {
-            String::new()
-        };
-
         let final_message = match self.alignment {
             TextAlignment::Left => clipped_message,
             TextAlignment::Right => {
-                let mut spans = vec![Span::raw(padding)];
+                let padding = available_width.saturating_sub(content_width);
+                let mut spans = vec![Span::raw(" ".repeat(padding))];
                 spans.extend(
                     clipped_message
                         .lines
@@ -163,25 +158,11 @@ impl Default for TextStatus {
 
 impl TextStatus {
     fn truncate_message(&self, available_width: usize) -> Text<'static> {
-        let mut current_width = 0;
-        let mut clipped = Vec::new();
-
-        let message_iter = self.text.iter();
-
-        for (content, style) in message_iter {
-            let content_width = content.len();
-            if current_width + content_width <= available_width {
-                clipped.push(Span::styled(content.clone(), *style));
-                current_width += content_width;
-            } else {
-                let remaining = available_width - current_width;
-                let truncated_content = content.chars().take(remaining).collect::<String>();
-                clipped.push(Span::styled(truncated_content, *style));
-                break;
-            }
+        let mut message = StyledText::default();
+        for (content, style) in &self.text {
+            message.append(content, *style);
         }
-
-        Text::from(Line::from(clipped))
+        Text::from(message.truncate(available_width).to_line())
     }
 
     fn ellipsis_end_message(&self, available_width: usize, n: usize) -> Text<'static> {
@@ -2,14 +2,17 @@
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::Style,
 };
 use std::{
     collections::HashMap,
+    rc::Rc,
     sync::{Arc, atomic::AtomicU64},
     time::Instant,
 };
+use tokio::sync::watch;
 
-use crate::{IntoStatusUpdates, LineBuilder, TuiWidget};
+use crate::{ApplyConfig, ConfigWatch, IntoStatusUpdates, LineBuilder, TuiWidget, WidgetConfig};
 
 use super::{StatusCell, StatusCellUpdate, StatusLineId, StatusUpdate};
 
@@ -49,6 +52,39 @@ impl CellVisibility {
     }
 }
 
+/// How a [`StatusWidget`] arranges its visible lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusLayout {
+    /// One line per row.
+    #[default]
+    Rows,
+    /// Lines side by side as dashboard tiles, filled left to right. Up to
+    /// `columns` tiles share a row, fewer when the area is too narrow to
+    /// give each at least `min_width` columns.
+    Columns { columns: u16, min_width: u16 },
+}
+
+impl StatusLayout {
+    pub fn columns(columns: u16, min_width: u16) -> Self {
+        Self::Columns {
+            columns: columns.max(1),
+            min_width: min_width.max(1),
+        }
+    }
+
+    /// Tiles per row for `lines` lines in an area `width` columns wide.
+    pub fn columns_for(&self, width: u16, lines: usize) -> usize {
+        match *self {
+            Self::Rows => 1,
+            Self::Columns { columns, min_width } => {
+                // Tiles are separated by a one column gap
+                let fit = (width + 1) / (min_width.max(1) + 1);
+                (fit.clamp(1, columns.max(1)) as usize).min(lines.max(1))
+            }
+        }
+    }
+}
+
 pub struct StatusLineHandle {
     cells: Vec<BoxedCell>,
     line_id: StatusLineId,
@@ -77,6 +113,11 @@ pub struct StatusWidget {
     render_order: Vec<StatusLineId>,
     cell_visibility: CellVisibility,
     margin: Margin,
+    layout: StatusLayout,
+    style: Style,
+    config_watch: Option<ConfigWatch>,
+    visible: Option<bool>,
+    redraw_requested: bool,
 }
 
 impl StatusWidget {
@@ -89,9 +130,66 @@ impl StatusWidget {
             render_order: Vec::new(),
             cell_visibility: CellVisibility::default(),
             margin: Margin::new(1, 0),
+            layout: StatusLayout::Rows,
+            style: Style::default(),
+            config_watch: None,
+            visible: None,
+            redraw_requested: true,
         }
     }
 
+    pub fn with_layout(mut self, layout: StatusLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn set_layout(&mut self, layout: StatusLayout) {
+        self.layout = layout;
+    }
+
+    pub fn layout(&self) -> StatusLayout {
+        self.layout
+    }
+
+    /// Base style of the whole area, under each cell's own styling.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+        self.redraw_requested = true;
+    }
+
+    /// Apply [`WidgetConfig`]s sent on `rx` as they change, starting with
+    /// the current one.
+    pub fn with_config_watch(mut self, rx: watch::Receiver<WidgetConfig>) -> Self {
+        self.watch_config(rx);
+        self
+    }
+
+    pub fn watch_config(&mut self, rx: watch::Receiver<WidgetConfig>) {
+        let mut watch = ConfigWatch::new(rx);
+        watch.apply_to(self);
+        self.config_watch = Some(watch);
+    }
+
+    /// Number of lines currently shown.
+    pub fn visible_lines(&self) -> usize {
+        self.render_order.len()
+    }
+
+    /// Rows needed to show every visible line in an area `width` columns
+    /// wide, which is fewer than [`visible_lines`](Self::visible_lines) in
+    /// column layout.
+    pub fn desired_height(&self, width: u16) -> u16 {
+        let width = width.saturating_sub(self.margin.horizontal * 2);
+        let lines = self.render_order.len();
+        let columns = self.layout.columns_for(width, lines);
+        lines.div_ceil(columns) as u16 + self.margin.vertical * 2
+    }
+
     pub fn new_builder(&mut self) -> LineBuilder {
         LineBuilder::new(self)
     }
@@ -209,6 +307,38 @@ impl StatusWidget {
     }
 }
 
+impl StatusLineHandle {
+    fn draw(&mut self, visibility: &CellVisibility, area: Rect, buf: &mut Buffer) {
+        let constraints: Vec<_> = self
+            .cells
+            .iter()
+            .enumerate()
+            .filter_map(|(i, c)| {
+                if visibility.is_visible(self.line_id, c.index)
+                    || visibility.is_visible_by_index(self.line_id, i)
+                {
+                    Some(c.cell.constraint())
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let col_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        for (i, (boxed, layout)) in self.cells.iter_mut().zip(col_layout.iter()).enumerate() {
+            if visibility.is_visible(self.line_id, boxed.index)
+                || visibility.is_visible_by_index(self.line_id, i)
+            {
+                boxed.cell.draw_cell(*layout, buf);
+            }
+        }
+    }
+}
+
 impl Default for StatusWidget {
     fn default() -> Self {
         Self::new()
@@ -217,6 +347,9 @@ impl Default for StatusWidget {
 
 impl TuiWidget for StatusWidget {
     fn need_draw(&self) -> bool {
+        if self.redraw_requested {
+            return true;
+        }
         // Check if any visible line has cells that need drawing
         for line_id in &self.render_order {
             if let Some(line_handle) = self.line_handles.get(line_id) {
@@ -237,6 +370,11 @@ impl TuiWidget for StatusWidget {
         let now = Instant::now();
         self.last_update = now;
 
+        if let Some(mut watch) = self.config_watch.take() {
+            watch.apply_to(self);
+            self.config_watch = Some(watch);
+        }
+
         // Preprocess all visible cells
         for line_id in &self.render_order {
             if let Some(line_handle) = self.line_handles.get_mut(line_id) {
@@ -251,46 +389,42 @@ impl TuiWidget for StatusWidget {
         }
     }
 
+    fn need_visibility(&self) -> Option<bool> {
+        self.visible
+    }
+
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
         let now = Instant::now();
         self.last_update = now;
+        self.redraw_requested = false;
+        if self.visible == Some(false) {
+            return;
+        }
 
+        buf.set_style(area, self.style);
         let area = area.inner(self.margin);
 
+        let columns = self.layout.columns_for(area.width, self.render_order.len());
+        let rows = self.render_order.len().div_ceil(columns);
+
         let row_layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Length(1); self.render_order.len()])
+            .constraints(vec![Constraint::Length(1); rows])
             .split(area);
 
-        for (row_id, row_area) in self.render_order.iter().zip(row_layout.iter()) {
-            if let Some(row) = self.line_handles.get_mut(row_id) {
-                let constraints: Vec<_> = row
-                    .cells
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(i, c)| {
-                        if self.cell_visibility.is_visible(row.line_id, c.index)
-                            || self.cell_visibility.is_visible_by_index(row.line_id, i)
-                        {
-                            Some(c.cell.constraint())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                let col_layout = Layout::default()
+        for (row_ids, row_area) in self.render_order.chunks(columns).zip(row_layout.iter()) {
+            let tiles = if columns == 1 {
+                Rc::from([*row_area])
+            } else {
+                Layout::default()
                     .direction(Direction::Horizontal)
-                    .constraints(constraints)
-                    .split(*row_area);
-
-                for (i, (boxed, layout)) in row.cells.iter_mut().zip(col_layout.iter()).enumerate()
-                {
-                    if self.cell_visibility.is_visible(row.line_id, boxed.index)
-                        || self.cell_visibility.is_visible_by_index(row.line_id, i)
-                    {
-                        boxed.cell.draw_cell(*layout, buf);
-                    }
+                    .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+                    .spacing(1)
+                    .split(*row_area)
+            };
+            for (line_id, tile) in row_ids.iter().zip(tiles.iter()) {
+                if let Some(line) = self.line_handles.get_mut(line_id) {
+                    line.draw(&self.cell_visibility, *tile, buf);
                 }
             }
         }
@@ -308,3 +442,15 @@ impl TuiWidget for StatusWidget {
         false
     }
 }
+
+impl ApplyConfig for StatusWidget {
+    fn apply_config(&mut self, config: &WidgetConfig) {
+        if let Some(style) = config.style {
+            self.set_style(style);
+        }
+        if config.visible.is_some() && self.visible != config.visible {
+            self.visible = config.visible;
+            self.redraw_requested = true;
+        }
+    }
+}
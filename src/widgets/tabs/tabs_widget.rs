@@ -1,19 +1,25 @@
 // tokio-tui/src/widgets/tabs/tabs_widget.rs
 
+use std::borrow::Cow;
+
 use itertools::Itertools;
 use ratatui::{
     buffer::Buffer,
-    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind},
     layout::{Position, Rect},
     style::{Modifier, Style, Styled},
     symbols,
     text::{Line, Span},
     widgets::{Block, Widget},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use crate::TuiWidget;
+use crate::{ApplyConfig, GlyphSet, Tooltip, TuiWidget, WidgetConfig};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const ELLIPSIS: char = '…';
+const ELLIPSIS_ASCII: char = '~';
 
 /// Controls how tabs are handled when they don't fit in the available width
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -32,6 +38,16 @@ impl Default for OverflowMode {
     }
 }
 
+/// Controls how titles longer than the maximum tab width are shortened
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum TitleTruncation {
+    /// Keep the start of the title: `A long ti…`
+    #[default]
+    End,
+    /// Keep both ends of the title: `A lon…itle`
+    Middle,
+}
+
 /// A widget that displays tabs with overflow handling capabilities.
 ///
 /// This widget extends the functionality of the standard `Tabs` widget by adding
@@ -79,6 +95,12 @@ pub struct TabsWidget<'a> {
     scroll_left_indicator: Span<'a>,
     /// Right indicator for scrolling mode (e.g., "»")
     scroll_right_indicator: Span<'a>,
+    /// Maximum title width (in characters) before truncation
+    max_tab_width: Option<u16>,
+    /// How titles exceeding `max_tab_width` are truncated
+    truncation: TitleTruncation,
+    /// Mouse position used to show the full title of a truncated tab
+    hover: Option<Position>,
     /// Whether the widget is focused
     is_focused: bool,
 }
@@ -114,8 +136,11 @@ impl<'a> TabsWidget<'a> {
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
             overflow_mode: OverflowMode::default(),
-            scroll_left_indicator: Span::raw("«"),
-            scroll_right_indicator: Span::raw("»"),
+            scroll_left_indicator: Span::raw(GlyphSet::current().pick("«", "<")),
+            scroll_right_indicator: Span::raw(GlyphSet::current().pick("»", ">")),
+            max_tab_width: None,
+            truncation: TitleTruncation::default(),
+            hover: None,
             is_focused: false,
         }
     }
@@ -328,6 +353,42 @@ impl<'a> TabsWidget<'a> {
         self.scroll_right_indicator = right.into();
     }
 
+    /// Sets the maximum title width; longer titles are truncated with an ellipsis.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_tab_width(mut self, width: u16) -> Self {
+        self.max_tab_width = Some(width);
+        self
+    }
+
+    /// Mutable access to set the maximum title width
+    pub fn set_max_tab_width(&mut self, width: Option<u16>) {
+        self.max_tab_width = width;
+    }
+
+    /// Sets where the ellipsis goes when a title is truncated.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation(mut self, truncation: TitleTruncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Mutable access to set the truncation mode
+    pub fn set_truncation(&mut self, truncation: TitleTruncation) {
+        self.truncation = truncation;
+    }
+
+    /// Sets the mouse position; hovering a truncated tab shows its full title.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hover(mut self, position: Option<Position>) -> Self {
+        self.hover = position;
+        self
+    }
+
+    /// Mutable access to set the hover position
+    pub fn set_hover(&mut self, position: Option<Position>) {
+        self.hover = position;
+    }
+
     /// Select the next tab
     pub fn next_tab(&mut self) {
         if self.titles.is_empty() {
@@ -395,27 +456,58 @@ impl<'a> TabsWidget<'a> {
         }
     }
 
+    // Title as it should be displayed, truncated to `max_tab_width` if needed
+    fn display_title(&self, idx: usize) -> Cow<'_, Line<'a>> {
+        let title = &self.titles[idx];
+        match self.max_tab_width {
+            Some(max) if title.width() > max as usize => {
+                Cow::Owned(truncate_line(title, max as usize, self.truncation))
+            }
+            _ => Cow::Borrowed(title),
+        }
+    }
+
     // Calculate the widths of all tabs including padding
     fn calculate_tab_widths(&self) -> Vec<u16> {
-        self.titles
-            .iter()
-            .map(|title| {
+        (0..self.titles.len())
+            .map(|idx| {
                 self.padding_left.width() as u16
-                    + title.width() as u16
+                    + self.display_title(idx).width() as u16
                     + self.padding_right.width() as u16
             })
             .collect()
     }
 
+    // Show the full title of a truncated tab under the mouse
+    fn render_tooltip(&self, hits: &[(usize, Rect)], buf: &mut Buffer) {
+        let Some(hover) = self.hover else {
+            return;
+        };
+        let Some(&(idx, rect)) = hits.iter().find(|(_, rect)| rect.contains(hover)) else {
+            return;
+        };
+        if self.display_title(idx).width() == self.titles[idx].width() {
+            return;
+        }
+
+        let full: String = self.titles[idx]
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        let bounds = *buf.area();
+        Tooltip::new(full).render_at(rect.as_position(), bounds, buf);
+    }
+
     // Render tabs with standard mode (original behavior)
-    fn render_tabs_normal(&self, tabs_area: Rect, buf: &mut Buffer) {
+    fn render_tabs_normal(&self, tabs_area: Rect, buf: &mut Buffer, hits: &mut Vec<(usize, Rect)>) {
         if tabs_area.is_empty() {
             return;
         }
 
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
+        for i in 0..titles_length {
             let last_title = titles_length - 1 == i;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
@@ -435,7 +527,8 @@ impl<'a> TabsWidget<'a> {
             }
 
             // Title
-            let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
+            let title = self.display_title(i);
+            let pos = buf.set_line(x, tabs_area.top(), &title, remaining_width);
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
@@ -460,6 +553,10 @@ impl<'a> TabsWidget<'a> {
                     cell.set_style(tab_style);
                 }
             }
+            hits.push((
+                i,
+                Rect::new(tab_start_x, tabs_area.top(), padding_end_x - tab_start_x, 1),
+            ));
 
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 || last_title {
@@ -473,7 +570,7 @@ impl<'a> TabsWidget<'a> {
     }
 
     // Render tabs with scroll mode
-    fn render_tabs_scroll(&self, tabs_area: Rect, buf: &mut Buffer) {
+    fn render_tabs_scroll(&self, tabs_area: Rect, buf: &mut Buffer, hits: &mut Vec<(usize, Rect)>) {
         if tabs_area.is_empty() || self.titles.is_empty() {
             return;
         }
@@ -491,7 +588,7 @@ impl<'a> TabsWidget<'a> {
 
         // If all tabs fit, just render normally
         if total_tabs_width <= tabs_area.width {
-            self.render_tabs_normal(tabs_area, buf);
+            self.render_tabs_normal(tabs_area, buf, hits);
             return;
         }
 
@@ -634,7 +731,7 @@ impl<'a> TabsWidget<'a> {
                 break;
             }
 
-            let pos = buf.set_line(x, tabs_area.top(), &self.titles[i], remaining_width);
+            let pos = buf.set_line(x, tabs_area.top(), &self.display_title(i), remaining_width);
             x = pos.0;
 
             // Right Padding
@@ -660,6 +757,10 @@ impl<'a> TabsWidget<'a> {
                     cell.set_style(tab_style);
                 }
             }
+            hits.push((
+                i,
+                Rect::new(tab_start_x, tabs_area.top(), padding_end_x - tab_start_x, 1),
+            ));
 
             // Divider (if not last tab)
             if !last_title {
@@ -688,7 +789,7 @@ impl<'a> TabsWidget<'a> {
     }
 
     // Render tabs with wrap mode
-    fn render_tabs_wrap(&self, tabs_area: Rect, buf: &mut Buffer) {
+    fn render_tabs_wrap(&self, tabs_area: Rect, buf: &mut Buffer, hits: &mut Vec<(usize, Rect)>) {
         if tabs_area.is_empty() || self.titles.is_empty() || tabs_area.height == 0 {
             return;
         }
@@ -765,7 +866,7 @@ impl<'a> TabsWidget<'a> {
                     break;
                 }
 
-                let pos = buf.set_line(x, y, &self.titles[tab_idx], remaining_width);
+                let pos = buf.set_line(x, y, &self.display_title(tab_idx), remaining_width);
                 x = pos.0;
 
                 // Right Padding
@@ -791,6 +892,10 @@ impl<'a> TabsWidget<'a> {
                         cell.set_style(tab_style);
                     }
                 }
+                hits.push((
+                    tab_idx,
+                    Rect::new(tab_start_x, y, padding_end_x - tab_start_x, 1),
+                ));
 
                 // Divider (if not last tab in line)
                 if !last_in_line {
@@ -832,12 +937,80 @@ impl Widget for &TabsWidget<'_> {
         // Don't set style for the entire area - let each tab control its own style
         // This is the key fix - removing buf.set_style(area, self.style);
 
+        let mut hits = Vec::new();
         match self.overflow_mode {
-            OverflowMode::None => self.render_tabs_normal(area, buf),
-            OverflowMode::Scroll => self.render_tabs_scroll(area, buf),
-            OverflowMode::Wrap => self.render_tabs_wrap(area, buf),
+            OverflowMode::None => self.render_tabs_normal(area, buf, &mut hits),
+            OverflowMode::Scroll => self.render_tabs_scroll(area, buf, &mut hits),
+            OverflowMode::Wrap => self.render_tabs_wrap(area, buf, &mut hits),
+        }
+        self.render_tooltip(&hits, buf);
+    }
+}
+
+// Shorten a title to `max` columns, inserting an ellipsis at the end or in
+// the middle while keeping the style of the surviving characters.
+fn truncate_line<'a>(line: &Line<'a>, max: usize, mode: TitleTruncation) -> Line<'a> {
+    if line.width() <= max {
+        return line.clone();
+    }
+    let clusters: Vec<(&str, Style)> = line
+        .spans
+        .iter()
+        .flat_map(|span| span.content.graphemes(true).map(move |g| (g, span.style)))
+        .collect();
+
+    // Fill the columns left beside the ellipsis, keeping wide characters
+    // whole
+    let keep = max.saturating_sub(1);
+    let mut head = 0;
+    let mut head_width = 0;
+    let head_max = match mode {
+        TitleTruncation::End => keep,
+        TitleTruncation::Middle => keep - keep / 2,
+    };
+    for (cluster, _) in &clusters {
+        if head_width + cluster.width() > head_max {
+            break;
+        }
+        head_width += cluster.width();
+        head += 1;
+    }
+    let mut tail = 0;
+    let mut tail_width = 0;
+    if mode == TitleTruncation::Middle {
+        for (cluster, _) in clusters[head..].iter().rev() {
+            if head_width + tail_width + cluster.width() > keep {
+                break;
+            }
+            tail_width += cluster.width();
+            tail += 1;
+        }
+    }
+    let ellipsis_style = clusters
+        .get(head)
+        .map(|(_, style)| *style)
+        .unwrap_or_default();
+    let ellipsis = GlyphSet::current()
+        .pick(ELLIPSIS, ELLIPSIS_ASCII)
+        .to_string();
+
+    let kept = clusters[..head]
+        .iter()
+        .copied()
+        .chain(std::iter::once((ellipsis.as_str(), ellipsis_style)))
+        .chain(clusters[clusters.len() - tail..].iter().copied());
+
+    let mut spans: Vec<Span<'a>> = Vec::new();
+    for (cluster, style) in kept {
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(cluster),
+            _ => spans.push(Span::styled(cluster.to_string(), style)),
         }
     }
+
+    let mut truncated = line.clone();
+    truncated.spans = spans;
+    truncated
 }
 
 impl<'a, Item> FromIterator<Item> for TabsWidget<'a>
@@ -856,6 +1029,16 @@ impl TuiWidget for TabsWidget<'_> {
         Widget::render(self as &Self, area, buf);
     }
 
+    fn mouse_event(&mut self, mouse: MouseEvent) -> bool {
+        if mouse.kind != MouseEventKind::Moved {
+            return false;
+        }
+        let hover = Some(Position::new(mouse.column, mouse.row));
+        let changed = self.hover != hover;
+        self.hover = hover;
+        changed
+    }
+
     fn key_event(&mut self, key: KeyEvent) -> bool {
         if key.kind != KeyEventKind::Press {
             return false;
@@ -922,3 +1105,37 @@ impl TuiWidget for TabsWidget<'_> {
         self.is_focused
     }
 }
+
+// Tabs are usually rebuilt each frame from their owner's state, so there's
+// no watch here; owners like `TabbedScrollbox` forward the styles instead,
+// or drive one with `ConfigWatch::apply_to`.
+impl ApplyConfig for TabsWidget<'_> {
+    fn apply_config(&mut self, config: &WidgetConfig) {
+        if let Some(style) = config.style {
+            self.set_style(style);
+        }
+        if let Some(style) = config.highlight_style {
+            self.set_highlight_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_wide_titles_by_columns() {
+        let title = Line::from("日本語のタブ");
+        let end = truncate_line(&title, 6, TitleTruncation::End);
+        assert_eq!(end.to_string(), "日本…");
+        assert!(end.width() <= 6);
+
+        let middle = truncate_line(&title, 8, TitleTruncation::Middle);
+        assert_eq!(middle.to_string(), "日本…ブ");
+        assert!(middle.width() <= 8);
+
+        let short = Line::from("tab");
+        assert_eq!(truncate_line(&short, 3, TitleTruncation::End), short);
+    }
+}
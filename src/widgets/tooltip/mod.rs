@@ -0,0 +1,3 @@
+// tokio-tui/src/widgets/tooltip/mod.rs
+mod tooltip_widget;
+pub use tooltip_widget::*;
@@ -0,0 +1,79 @@
+// tokio-tui/src/widgets/tooltip/tooltip_widget.rs
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Style,
+};
+
+use crate::tui_theme;
+
+/// A single-line popup drawn next to an anchor cell, e.g. to reveal the full
+/// text of something that had to be truncated.
+///
+/// Tooltips are drawn on top of whatever is already in the buffer, so render
+/// them after the content they overlap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tooltip {
+    text: String,
+    style: Style,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::default()
+                .fg(tui_theme::TOOLTIP_FG)
+                .bg(tui_theme::TOOLTIP_BG),
+        }
+    }
+
+    /// Sets the style of the tooltip.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Draw the tooltip on the row below `anchor` (or above it when there is
+    /// no room below), starting at the anchor column and shifted left as
+    /// needed to stay inside `bounds`.
+    pub fn render_at(&self, anchor: Position, bounds: Rect, buf: &mut Buffer) {
+        if bounds.is_empty() {
+            return;
+        }
+
+        let width = (self.text.chars().count() as u16 + 2).min(bounds.width);
+        let y = if anchor.y + 1 < bounds.bottom() {
+            anchor.y + 1
+        } else if anchor.y > bounds.top() {
+            anchor.y - 1
+        } else {
+            return;
+        };
+        let x = anchor
+            .x
+            .min(bounds.right().saturating_sub(width))
+            .max(bounds.left());
+
+        let area = Rect::new(x, y, width, 1);
+        buf.set_style(area, self.style);
+        for cell_x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut(Position::new(cell_x, y)) {
+                cell.set_char(' ');
+            }
+        }
+        buf.set_stringn(
+            x + 1,
+            y,
+            &self.text,
+            width.saturating_sub(2) as usize,
+            self.style,
+        );
+    }
+}
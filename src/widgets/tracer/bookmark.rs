@@ -0,0 +1,120 @@
+// tokio-tui/src/widgets/tracer/bookmark.rs
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Local};
+use ratatui::style::{Color, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::{GlyphSet, Locale, ScrollbackWidget, StyledText, tui_theme};
+
+/// Name of the tab listing every bookmark.
+pub const BOOKMARKS_TAB: &str = "Bookmarks";
+
+/// A bookmarked log line, or a free-standing session note when `line_id`
+/// is `None`. Serializable so apps can keep bookmarks across restarts; line
+/// ids only resolve within the session that created them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// Tab the bookmark was made in.
+    pub tab: String,
+    /// Stable scrollback line id (see [`ScrollbackWidget::line_id`]).
+    pub line_id: Option<usize>,
+    /// The line's text when it was bookmarked, kept in case it scrolls out
+    /// of the buffer.
+    pub line: Option<String>,
+    pub note: String,
+    pub created: DateTime<Local>,
+}
+
+impl Bookmark {
+    pub fn new(tab: impl Into<String>, line_id: usize, line: impl Into<String>) -> Self {
+        Self {
+            tab: tab.into(),
+            line_id: Some(line_id),
+            line: Some(line.into()),
+            note: String::new(),
+            created: Local::now(),
+        }
+    }
+
+    pub fn session_note(tab: impl Into<String>, note: impl Into<String>) -> Self {
+        Self {
+            tab: tab.into(),
+            line_id: None,
+            line: None,
+            note: note.into(),
+            created: Local::now(),
+        }
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = note.into();
+        self
+    }
+
+    pub fn is_session_note(&self) -> bool {
+        self.line_id.is_none()
+    }
+
+    // One row of the bookmarks tab.
+    pub(crate) fn list_entry(&self, number: usize) -> StyledText {
+        let mut entry = StyledText::default();
+        entry
+            .append(format!("#{number:<3}"), Style::default().fg(Color::Yellow))
+            .append(
+                format!("{} ", Locale::current().format_time(&self.created)),
+                Style::default().fg(tui_theme::GRAY1_FG),
+            )
+            .append(format!("[{}] ", self.tab), Style::default().fg(Color::Cyan));
+        if let Some(line) = &self.line {
+            entry.append(line.trim_end(), Style::default().fg(Color::White));
+        }
+        if !self.note.is_empty() {
+            if self.line.is_some() {
+                entry.append("  ", Style::default());
+            }
+            let (open, close) = GlyphSet::current().pick(("«", "»"), ("<<", ">>"));
+            entry.append(
+                format!("{open} {} {close}", self.note),
+                Style::default().fg(Color::Green),
+            );
+        }
+        entry
+    }
+
+    // Plain-text export with up to `context` lines on each side, taken from
+    // `scrollback` when the line is still buffered.
+    pub(crate) fn export(
+        &self,
+        out: &mut String,
+        scrollback: Option<&ScrollbackWidget>,
+        context: usize,
+    ) {
+        let created = Locale::current().format_time(&self.created);
+        let _ = write!(out, "## [{}] {created}", self.tab);
+        if !self.note.is_empty() {
+            let _ = write!(out, " {}", self.note);
+        }
+        out.push('\n');
+
+        let buffered = scrollback
+            .zip(self.line_id)
+            .and_then(|(sb, id)| sb.line_index(id).map(|index| (sb, index)));
+        match (buffered, &self.line) {
+            (Some((sb, index)), _) => {
+                let first = index.saturating_sub(context);
+                let last = (index + context).min(sb.len().saturating_sub(1));
+                for i in first..=last {
+                    let marker = if i == index { '>' } else { ' ' };
+                    let text = sb.line_text(i).unwrap_or_default();
+                    let _ = writeln!(out, "{marker} {}", text.trim_end());
+                }
+            }
+            (None, Some(line)) => {
+                let _ = writeln!(out, "> {}  (no longer in scrollback)", line.trim_end());
+            }
+            (None, None) => {}
+        }
+        out.push('\n');
+    }
+}
@@ -3,7 +3,7 @@ use anyhow::Result;
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
-    layout::{Constraint, Direction, Layout, Margin, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Position, Rect},
     style::{Color, Style},
     widgets::Borders,
 };
@@ -184,6 +184,20 @@ impl TuiWidget for ConsoleWidget {
     fn mouse_event(&mut self, mouse: crossterm::event::MouseEvent) -> bool {
         self.tracer_widget.mouse_event(mouse)
     }
+    fn cursor_position(&self) -> Option<Position> {
+        if self.input_focused {
+            self.input_widget.cursor_position()
+        } else {
+            self.tracer_widget.cursor_position()
+        }
+    }
+    fn paste_event(&mut self, text: &str) -> bool {
+        if self.input_focused {
+            self.input_widget.paste_event(text)
+        } else {
+            self.tracer_widget.paste_event(text)
+        }
+    }
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
         // Split the area vertically: tracer on top, input box at bottom
         let chunks = Layout::default()
@@ -191,7 +205,7 @@ impl TuiWidget for ConsoleWidget {
             .constraints([
                 Constraint::Min(10),   // Tracer takes most of the space
                 Constraint::Length(1), // Gap
-                Constraint::Length(1), // Input box is 1 row high (without border)
+                Constraint::Length(self.input_widget.desired_height()), // Input box (without border)
             ])
             .split(area);
 
@@ -212,7 +226,12 @@ impl TuiWidget for ConsoleWidget {
         match key.code {
             // Toggle focus between panels on Tab
             KeyCode::Esc => {
-                if self.input_focused {
+                if self.input_focused
+                    && (self.input_widget.is_completing()
+                        || self.input_widget.is_searching_history())
+                {
+                    self.input_widget.key_event(key)
+                } else if self.input_focused {
                     self.focus_tracer();
                     true
                 } else {
@@ -230,8 +249,14 @@ impl TuiWidget for ConsoleWidget {
                 }
             }
             _ => {
+                // Ctrl+R searches the input's history; while searching
+                // every key belongs to the search
+                let history_key =
+                    self.input_widget.is_searching_history() || key.code == KeyCode::Char('r');
+                let tracer_key = key.modifiers.contains(KeyModifiers::CONTROL) && !history_key;
+
                 // Pass to active component
-                if !self.input_focused || key.modifiers.contains(KeyModifiers::CONTROL) {
+                if !self.input_focused || tracer_key {
                     self.tracer_widget.key_event(key)
                 } else {
                     self.input_widget.key_event(key)
@@ -5,3 +5,7 @@ mod console_widget;
 pub use console_widget::*;
 mod tracer_form;
 pub use tracer_form::*;
+mod bookmark;
+pub use bookmark::*;
+mod trace_source;
+pub use trace_source::*;
@@ -0,0 +1,154 @@
+// tokio-tui/src/widgets/tracer/trace_source.rs
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU8, Ordering},
+};
+
+use ratatui::style::Style;
+use tokio::sync::mpsc;
+use tokio_tracer::TraceEvent;
+use tracing::Level;
+
+use crate::{StyledText, TraceEventSender};
+
+use super::TraceUIMessage;
+
+/// Handle to an external log source registered with
+/// [`TracerWidget::register_source`](crate::TracerWidget::register_source).
+///
+/// Cheap to clone; every clone controls the same source. Mute state and the
+/// minimum level are checked on the sending side, so filtered events never
+/// reach the widget.
+#[derive(Clone)]
+pub struct TraceSource {
+    id: Arc<str>,
+    tx: mpsc::UnboundedSender<TraceUIMessage>,
+    state: Arc<SourceState>,
+}
+
+#[derive(Default)]
+struct SourceState {
+    muted: AtomicBool,
+    // 0 lets every level through, otherwise `level_rank` of the minimum
+    min_level: AtomicU8,
+    deregistered: AtomicBool,
+}
+
+// ERROR is the most severe, so it ranks highest
+fn level_rank(level: Level) -> u8 {
+    match level {
+        Level::TRACE => 1,
+        Level::DEBUG => 2,
+        Level::INFO => 3,
+        Level::WARN => 4,
+        Level::ERROR => 5,
+    }
+}
+
+fn rank_level(rank: u8) -> Option<Level> {
+    match rank {
+        1 => Some(Level::TRACE),
+        2 => Some(Level::DEBUG),
+        3 => Some(Level::INFO),
+        4 => Some(Level::WARN),
+        5 => Some(Level::ERROR),
+        _ => None,
+    }
+}
+
+impl TraceSource {
+    pub(crate) fn new(id: String, tx: mpsc::UnboundedSender<TraceUIMessage>) -> Self {
+        Self {
+            id: id.into(),
+            tx,
+            state: Arc::default(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Log an event from this source to the given tabs, unless it's muted,
+    /// below the minimum level, or deregistered.
+    pub fn send(&self, event: TraceEvent, tabs: Vec<String>) {
+        if !self.accepts(event.level.0) {
+            return;
+        }
+        let _ = self
+            .tx
+            .send(TraceUIMessage::External(event, tabs, self.id.to_string()));
+    }
+
+    /// Whether an event at `level` would currently be logged.
+    pub fn accepts(&self, level: Level) -> bool {
+        !self.is_muted()
+            && !self.is_deregistered()
+            && self.state.min_level.load(Ordering::Relaxed) <= level_rank(level)
+    }
+
+    /// A plain callback that forwards to [`send`](Self::send).
+    pub fn sender(&self) -> TraceEventSender {
+        let source = self.clone();
+        Arc::new(move |event, tabs| source.send(event, tabs))
+    }
+
+    pub fn mute(&self) {
+        self.state.muted.store(true, Ordering::Relaxed);
+    }
+
+    pub fn unmute(&self) {
+        self.state.muted.store(false, Ordering::Relaxed);
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.state.muted.store(muted, Ordering::Relaxed);
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.state.muted.load(Ordering::Relaxed)
+    }
+
+    /// Drop events less severe than `level`; `None` logs every level.
+    pub fn set_min_level(&self, level: Option<Level>) {
+        let rank = level.map(level_rank).unwrap_or(0);
+        self.state.min_level.store(rank, Ordering::Relaxed);
+    }
+
+    pub fn min_level(&self) -> Option<Level> {
+        rank_level(self.state.min_level.load(Ordering::Relaxed))
+    }
+
+    /// Replace the prefix shown before this source's lines. Takes effect for
+    /// lines logged after the widget next processes its messages.
+    pub fn set_prefix(&self, prefix: impl AsRef<str>, style: Style) {
+        let _ = self.tx.send(TraceUIMessage::SetPrefix(
+            self.id.to_string(),
+            StyledText::from_styled(prefix, style),
+        ));
+    }
+
+    /// Remove the source from the widget. Every clone of this handle stops
+    /// sending; lines already logged stay.
+    pub fn deregister(self) {
+        self.state.deregistered.store(true, Ordering::Relaxed);
+        let _ = self
+            .tx
+            .send(TraceUIMessage::RemoveSource(self.id.to_string()));
+    }
+
+    pub fn is_deregistered(&self) -> bool {
+        self.state.deregistered.load(Ordering::Relaxed)
+    }
+}
+
+impl std::fmt::Debug for TraceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TraceSource")
+            .field("id", &self.id)
+            .field("muted", &self.is_muted())
+            .field("min_level", &self.min_level())
+            .field("deregistered", &self.is_deregistered())
+            .finish()
+    }
+}
@@ -5,7 +5,7 @@ use anyhow::Result;
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Position, Rect},
     style::{Color, Style},
     widgets::Borders,
 };
@@ -14,12 +14,17 @@ use tracing::{Level, error};
 
 use tokio_tracer::{TraceData, TraceEvent, Tracer};
 
-use crate::{StyledText, TabbedScrollbox, TuiWidget, tui_theme};
+use crate::{
+    BOOKMARKS_TAB, Bookmark, InputWidget, MarkerKind, StyledText, TabbedScrollbox, TraceSource,
+    TuiWidget, tui_theme,
+};
 
-enum TraceUIMessage {
+pub(crate) enum TraceUIMessage {
     Normal(TraceEvent, Vec<String>),
     ClearTab(String),
     External(TraceEvent, Vec<String>, String),
+    SetPrefix(String, StyledText),
+    RemoveSource(String),
 }
 
 pub type TraceEventSender = Arc<dyn Fn(TraceEvent, Vec<String>) + Send + Sync>;
@@ -37,8 +42,16 @@ pub struct TracerWidget {
     default_prefix: Option<StyledText>,
     borders: Borders,
     tx: mpsc::UnboundedSender<TraceUIMessage>,
+    // Bookmarks and session notes, in creation order
+    bookmarks: Vec<Bookmark>,
+    // Bookmark waiting for its note to be typed
+    pending_bookmark: Option<Bookmark>,
+    note_input: InputWidget,
 }
 
+/// Context lines on each side of a bookmark when copying bookmarks.
+pub const BOOKMARK_CONTEXT_LINES: usize = 3;
+
 impl TracerWidget {
     pub fn new(tracer: Tracer) -> Result<Self> {
         // Create channel for messages
@@ -74,6 +87,11 @@ impl TracerWidget {
             source_prefixes: std::collections::HashMap::new(),
             default_prefix: None,
             borders: Borders::all(),
+            bookmarks: Vec::new(),
+            pending_bookmark: None,
+            note_input: InputWidget::new()
+                .without_history()
+                .with_hint("Note (optional), Enter to save, Esc to cancel"),
         })
     }
     pub fn set_borders(&mut self, borders: Borders) {
@@ -113,7 +131,7 @@ impl TracerWidget {
         &mut self,
         source_id: impl Into<String>,
         prefix: impl AsRef<str>,
-    ) -> TraceEventSender {
+    ) -> TraceSource {
         self.register_source_with_style(source_id, prefix, Style::default())
     }
     pub fn register_source_with_style(
@@ -121,7 +139,7 @@ impl TracerWidget {
         source_id: impl Into<String>,
         prefix: impl AsRef<str>,
         style: Style,
-    ) -> TraceEventSender {
+    ) -> TraceSource {
         let source_id = source_id.into();
 
         // Store the styled prefix directly
@@ -130,17 +148,19 @@ impl TracerWidget {
 
         self.update_wrap_indent();
 
-        // Create a sender for this source
-        let tx = self.tx.clone();
-        let source_id_clone = source_id.clone();
+        TraceSource::new(source_id, self.tx.clone())
+    }
 
-        Arc::new(move |event, tabs| {
-            let _ = tx.send(TraceUIMessage::External(
-                event,
-                tabs,
-                source_id_clone.clone(),
-            ));
-        })
+    /// Forget a source's prefix. Its handles keep sending, with no prefix;
+    /// use [`TraceSource::deregister`] to stop them too.
+    pub fn remove_source(&mut self, source_id: &str) {
+        if self.source_prefixes.remove(source_id).is_some() {
+            self.update_wrap_indent();
+        }
+    }
+
+    pub fn has_source(&self, source_id: &str) -> bool {
+        self.source_prefixes.contains_key(source_id)
     }
 
     pub fn clear(&self, tab: String) {
@@ -169,6 +189,7 @@ impl TracerWidget {
             match self.rx.try_recv() {
                 Ok(TraceUIMessage::Normal(trace_event, tab_names)) => {
                     let entries = self.styled_log_message(self.get_default_prefix(), &trace_event);
+                    let marker = Self::level_marker(&trace_event);
 
                     // Optimization: If there's only one subscriber, we can avoid cloning
                     if tab_names.len() == 1 {
@@ -178,7 +199,7 @@ impl TracerWidget {
                             self.logs.add_tab(tab, tab);
                         }
                         // Add to the tab
-                        self.logs.add_styled_to_tab(tab, entries);
+                        self.add_entries(tab, entries, marker);
                     } else {
                         // Prepare all the copies we need upfront
                         let mut copied_entries = Vec::with_capacity(tab_names.len());
@@ -194,14 +215,14 @@ impl TracerWidget {
                                 self.logs.add_tab(tab_name, tab_name);
                             }
 
-                            self.logs
-                                .add_styled_to_tab(tab_name, copied_entries.remove(0));
+                            self.add_entries(tab_name, copied_entries.remove(0), marker);
                         }
                     }
                 }
 
                 Ok(TraceUIMessage::External(message, tab_names, source_id)) => {
                     let entries = self.styled_log_message(self.get_prefix(&source_id), &message);
+                    let marker = Self::level_marker(&message);
 
                     // Optimization: If there's only one tab, we can avoid cloning
                     if tab_names.len() == 1 {
@@ -211,7 +232,7 @@ impl TracerWidget {
                             self.logs.add_tab(tab, tab);
                         }
                         // Add to the tab
-                        self.logs.add_styled_to_tab(tab, entries);
+                        self.add_entries(tab, entries, marker);
                     } else {
                         // Prepare all the copies we need upfront
                         let mut copied_entries = Vec::with_capacity(tab_names.len());
@@ -228,8 +249,7 @@ impl TracerWidget {
                             }
 
                             // Add to the tab (using remove to transfer ownership)
-                            self.logs
-                                .add_styled_to_tab(tab_name, copied_entries.remove(0));
+                            self.add_entries(tab_name, copied_entries.remove(0), marker);
                         }
                     }
                 }
@@ -238,11 +258,34 @@ impl TracerWidget {
                         tab.clear();
                     }
                 }
+                Ok(TraceUIMessage::SetPrefix(source_id, prefix)) => {
+                    self.source_prefixes.insert(source_id, prefix);
+                    self.update_wrap_indent();
+                }
+                Ok(TraceUIMessage::RemoveSource(source_id)) => {
+                    self.remove_source(&source_id);
+                }
                 Err(_) => break, // No more messages
             }
         }
     }
 
+    // Errors are flagged on the scrollbar
+    fn level_marker(trace_event: &TraceData) -> Option<MarkerKind> {
+        (trace_event.level.0 == Level::ERROR).then_some(MarkerKind::Error)
+    }
+
+    fn add_entries(&mut self, tab: &String, entries: Vec<StyledText>, marker: Option<MarkerKind>) {
+        match marker {
+            Some(kind) => {
+                if let Some(sb) = self.logs.get_tab_mut(tab) {
+                    sb.add_marked_lines(entries, kind);
+                }
+            }
+            None => self.logs.add_styled_to_tab(tab, entries),
+        }
+    }
+
     fn styled_log_message(
         &self,
         mut prefix: StyledText,
@@ -568,6 +611,191 @@ impl TracerWidget {
         self.logs_mut().focus();
     }
 
+    /* ---------- bookmarks ----------- */
+
+    /// Bookmark the line the user is pointing at in the current tab (see
+    /// [`ScrollbackWidget::active_line`]) and prompt for an optional note.
+    /// With no line to point at, the note becomes a session note.
+    pub fn start_bookmark(&mut self) {
+        let Some(tab) = self.logs.current_tab_name().cloned() else {
+            return;
+        };
+        if tab == BOOKMARKS_TAB {
+            return;
+        }
+        let line = self.logs.get_tab(&tab).and_then(|sb| {
+            let index = sb.active_line()?;
+            Some((sb.line_id(index)?, sb.line_text(index)?))
+        });
+        let bookmark = match line {
+            Some((id, text)) => Bookmark::new(&tab, id, text),
+            None => Bookmark::session_note(&tab, ""),
+        };
+        let title = if bookmark.is_session_note() {
+            " Session note ".to_string()
+        } else {
+            format!(" Bookmark in {tab} ")
+        };
+
+        self.pending_bookmark = Some(bookmark);
+        self.note_input.set_tl_text(title);
+        self.note_input.focus_and_clear();
+        self.logs.unfocus();
+    }
+
+    fn finish_bookmark(&mut self, save: bool) {
+        if let Some(bookmark) = self.pending_bookmark.take() {
+            let note = self.note_input.text().trim().to_string();
+            if save && (!bookmark.is_session_note() || !note.is_empty()) {
+                self.add_bookmark(bookmark.with_note(note));
+            }
+        }
+        self.note_input.clear_and_unfocus();
+        if self.is_focused {
+            self.focus_logs();
+        }
+    }
+
+    /// Bookmark line `index` of `tab`.
+    pub fn bookmark_line(&mut self, tab: &str, index: usize, note: impl Into<String>) -> bool {
+        let Some(line) = self
+            .logs
+            .get_tab(&tab.to_string())
+            .and_then(|sb| Some((sb.line_id(index)?, sb.line_text(index)?)))
+        else {
+            return false;
+        };
+        self.add_bookmark(Bookmark::new(tab, line.0, line.1).with_note(note));
+        true
+    }
+
+    /// Record a note that isn't attached to any line.
+    pub fn add_session_note(&mut self, note: impl Into<String>) {
+        let tab = self.logs.current_tab_name().cloned().unwrap_or_default();
+        self.add_bookmark(Bookmark::session_note(tab, note));
+    }
+
+    pub fn add_bookmark(&mut self, bookmark: Bookmark) {
+        self.set_bookmark_marker(&bookmark, true);
+        self.bookmarks.push(bookmark);
+        self.refresh_bookmarks_tab();
+    }
+
+    pub fn remove_bookmark(&mut self, index: usize) -> Option<Bookmark> {
+        if index >= self.bookmarks.len() {
+            return None;
+        }
+        let removed = self.bookmarks.remove(index);
+        // Another bookmark on the same line keeps the marker
+        let shared = self
+            .bookmarks
+            .iter()
+            .any(|other| other.tab == removed.tab && other.line_id == removed.line_id);
+        if !shared {
+            self.set_bookmark_marker(&removed, false);
+        }
+        self.refresh_bookmarks_tab();
+        Some(removed)
+    }
+
+    /// Replace the bookmarks, e.g. with ones kept from a previous run.
+    pub fn set_bookmarks(&mut self, bookmarks: impl IntoIterator<Item = Bookmark>) {
+        self.clear_bookmarks();
+        for bookmark in bookmarks {
+            self.set_bookmark_marker(&bookmark, true);
+            self.bookmarks.push(bookmark);
+        }
+        self.refresh_bookmarks_tab();
+    }
+
+    pub fn clear_bookmarks(&mut self) {
+        for bookmark in std::mem::take(&mut self.bookmarks) {
+            self.set_bookmark_marker(&bookmark, false);
+        }
+        self.refresh_bookmarks_tab();
+    }
+
+    // Flag the bookmarked line on its tab's scrollbar, if still buffered.
+    fn set_bookmark_marker(&mut self, bookmark: &Bookmark, on: bool) {
+        let Some(sb) = self.logs.get_tab_mut(&bookmark.tab) else {
+            return;
+        };
+        let Some(index) = bookmark.line_id.and_then(|id| sb.line_index(id)) else {
+            return;
+        };
+        if on {
+            sb.mark_line(index, MarkerKind::Bookmark);
+        } else {
+            sb.unmark_line(index, MarkerKind::Bookmark);
+        }
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    /// Switch to the bookmark's tab and scroll its line into view.
+    pub fn jump_to_bookmark(&mut self, index: usize) -> bool {
+        let Some(bookmark) = self.bookmarks.get(index) else {
+            return false;
+        };
+        let (tab, line_id) = (bookmark.tab.clone(), bookmark.line_id);
+        self.logs.select_tab(&tab);
+        let Some(sb) = self.logs.get_tab_mut(&tab) else {
+            return false;
+        };
+        match line_id.and_then(|id| sb.line_index(id)) {
+            Some(line) => {
+                sb.reveal_line(line);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All bookmarks and notes as plain text, each bookmarked line with up
+    /// to `context` surrounding lines when it is still in the scrollback.
+    pub fn export_bookmarks(&self, context: usize) -> String {
+        let mut out = String::new();
+        for bookmark in &self.bookmarks {
+            bookmark.export(&mut out, self.logs.get_tab(&bookmark.tab), context);
+        }
+        out
+    }
+
+    /// Copy [`export_bookmarks`](Self::export_bookmarks) to the clipboard.
+    pub fn copy_bookmarks(&self, context: usize) -> bool {
+        if self.bookmarks.is_empty() {
+            return false;
+        }
+        use clipboard::{ClipboardContext, ClipboardProvider};
+        if let Ok(mut ctx) = ClipboardContext::new() {
+            let _ = ctx.set_contents(self.export_bookmarks(context));
+        }
+        true
+    }
+
+    // Rebuild the bookmarks tab, creating it on first use.
+    fn refresh_bookmarks_tab(&mut self) {
+        let tab = BOOKMARKS_TAB.to_string();
+        if !self.logs.tab_exists(&tab) {
+            if self.bookmarks.is_empty() {
+                return;
+            }
+            self.logs.add_tab(&tab, &tab);
+        }
+        let entries: Vec<StyledText> = self
+            .bookmarks
+            .iter()
+            .enumerate()
+            .map(|(idx, bookmark)| bookmark.list_entry(idx + 1))
+            .collect();
+        if let Some(sb) = self.logs.get_tab_mut(&tab) {
+            sb.clear();
+            sb.add_styled_lines(entries);
+        }
+    }
+
     // Get statistics about messages
     pub fn get_stats(&self) -> (u64, u64, u64) {
         (
@@ -587,7 +815,7 @@ impl TracerWidget {
 
 impl TuiWidget for TracerWidget {
     fn need_draw(&self) -> bool {
-        self.logs.need_draw()
+        self.logs.need_draw() || (self.pending_bookmark.is_some() && self.note_input.need_draw())
     }
     fn preprocess(&mut self) {
         // Process any pending messages
@@ -596,6 +824,17 @@ impl TuiWidget for TracerWidget {
     fn draw(&mut self, area: Rect, buf: &mut Buffer) {
         // Check form status
         self.check_form_status();
+
+        // Note prompt for a new bookmark along the bottom
+        let area = if self.pending_bookmark.is_some() && area.height > 3 {
+            let [logs_area, note_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+            self.note_input.draw(note_area, buf);
+            logs_area
+        } else {
+            area
+        };
+
         // Split the screen depending on whether form is visible
         if self.form_visible {
             // Create a horizontal split
@@ -619,10 +858,39 @@ impl TuiWidget for TracerWidget {
         self.logs_mut().mouse_event(mouse)
     }
 
+    fn paste_event(&mut self, text: &str) -> bool {
+        if self.pending_bookmark.is_some() {
+            self.note_input.paste_event(text);
+            return true;
+        }
+        self.logs_mut().paste_event(text)
+    }
+
     fn key_event(&mut self, key: KeyEvent) -> bool {
+        if self.pending_bookmark.is_some() {
+            match key.code {
+                KeyCode::Enter => self.finish_bookmark(true),
+                KeyCode::Esc => self.finish_bookmark(false),
+                _ => {
+                    self.note_input.key_event(key);
+                }
+            }
+            return true;
+        }
+
         let mut handled = true;
 
         match key.code {
+            // Bookmark the current line, with an optional note
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.start_bookmark();
+            }
+
+            // Copy all bookmarks with their context
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.copy_bookmarks(BOOKMARK_CONTEXT_LINES);
+            }
+
             // Edit current tab configuration
             KeyCode::Char('e')
                 if !self.form_visible && key.modifiers.contains(KeyModifiers::CONTROL) =>
@@ -681,4 +949,12 @@ impl TuiWidget for TracerWidget {
     fn is_focused(&self) -> bool {
         self.is_focused
     }
+
+    fn cursor_position(&self) -> Option<Position> {
+        if self.pending_bookmark.is_some() {
+            self.note_input.cursor_position()
+        } else {
+            self.logs.cursor_position()
+        }
+    }
 }